@@ -0,0 +1,370 @@
+use crate::db::setup_test_db;
+
+#[cfg(test)]
+mod test_cli {
+    use std::{fs, path::PathBuf};
+
+    use crate::{
+        cli::{CardKind, Cli, Command, execute},
+        db::seeders::init_data,
+        helpers::search::{DueState, PER_PAGE, SearchFilter},
+    };
+
+    use super::*;
+
+    const WORTE_CSV: &str = "\
+gram_type,gender_id,worte_de,worte_es,plural,niveau_id,example_de,example_es,verb_aux,trennbar,reflexiv,tags
+noun_common,Maskuline,Hund,el perro,Hunde,A1,\"Der Hund spielt, oder?\",El perro juega.,,,,\"Tiere,Alltag\"
+verb_separable,,anrufen,llamar por teléfono,,A2,Ich rufe dich an.,Te llamo.,haben,Si,No,
+";
+
+    const SETZE_CSV: &str = "\
+setze_spanisch,setze_deutsch,thema,schwirig_id,niveau,tags
+Tengo un perro,Ich habe einen Hund,Akkusativ,Einfach,A1,Tiere
+\"Voy a casa, luego\",\"Ich gehe nach Hause, dann\",Dativ,2,B1,
+";
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn parse(list: &[&str]) -> Cli {
+        Cli::parse(&args(list))
+            .unwrap()
+            .expect("Debe haber comando")
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("anki_cli_{}_{}", name, std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_parse() {
+        assert!(Cli::parse(&[]).unwrap().is_none());
+
+        let cli = parse(&["import", "words", "a.csv", "--deck=Goethe B1", "--json"]);
+        assert_eq!(
+            cli.command,
+            Command::Import {
+                kind: CardKind::Worte,
+                path: "a.csv".into()
+            }
+        );
+        assert!(cli.json);
+        assert_eq!(cli.deck.as_deref(), Some("Goethe B1"));
+
+        assert_eq!(
+            parse(&["audio", "generate"]).command,
+            Command::AudioGenerate { kind: None }
+        );
+        assert_eq!(
+            parse(&["export", "sentences", "-o", "out.csv"]).command,
+            Command::Export {
+                kind: CardKind::Setze,
+                output: Some("out.csv".into())
+            }
+        );
+        assert_eq!(
+            parse(&["import", "conjugations", "k.csv"]).command,
+            Command::ImportKonjugation {
+                path: "k.csv".into()
+            }
+        );
+        assert_eq!(parse(&["levels"]).command, Command::Levels);
+        assert_eq!(
+            parse(&["import", "frequency", "f.csv"]).command,
+            Command::ImportFrequenz {
+                path: "f.csv".into()
+            }
+        );
+        assert_eq!(
+            parse(&["review", "--mode", "words-due"]).command,
+            Command::Review {
+                mode: "words-due".into()
+            }
+        );
+
+        assert_eq!(
+            parse(&[
+                "search", "words", "der", "Hund", "--niveau", "A1", "due:new"
+            ])
+            .command,
+            Command::Search {
+                kind: CardKind::Worte,
+                filter: SearchFilter {
+                    text: Some("der Hund".into()),
+                    niveau: Some("A1".into()),
+                    due: Some(DueState::Neue),
+                    ..Default::default()
+                },
+                page: 1,
+                per_page: PER_PAGE,
+            }
+        );
+
+        let invalid = [
+            vec!["import", "worte", "a.csv"],
+            vec!["import", "words"],
+            vec!["stats", "--verbose"],
+            vec!["stats", "--output", "a.csv"],
+            vec!["review"],
+            vec!["review", "--mode", "worte"],
+            vec!["due", "--mode", "daily"],
+            vec!["export", "words", "--json"],
+            vec!["review", "--mode", "words-due", "--json"],
+            vec!["tui", "--json"],
+            vec!["deck"],
+            vec!["search"],
+            vec!["search", "cards"],
+            vec!["search", "words", "--due", "ayer"],
+            vec!["search", "words", "--page", "0"],
+            vec!["stats", "--niveau", "A1"],
+            vec!["due", "--page", "2"],
+        ];
+        for list in invalid {
+            assert!(Cli::parse(&args(&list)).is_err(), "{:?}", list);
+        }
+    }
+
+    #[test]
+    fn test_import_and_export_round_trip() {
+        let mut conn = setup_test_db().unwrap();
+        init_data(&mut conn).unwrap();
+        let dir = temp_dir("round_trip");
+
+        for (kind, text) in [("words", WORTE_CSV), ("sentences", SETZE_CSV)] {
+            let input = dir.join(format!("{}.csv", kind));
+            fs::write(&input, text).unwrap();
+
+            let import = parse(&["import", kind, input.to_str().unwrap(), "--deck", "A::B"]);
+            let out = execute(&mut conn, &import).unwrap();
+            assert_eq!(out.code, 0);
+            assert_eq!(out.json["imported"], 2, "{}", kind);
+            assert_eq!(out.json["deck"], "A::B");
+
+            let output = dir.join(format!("{}_export.csv", kind));
+            let export = parse(&[
+                "export",
+                kind,
+                "--deck",
+                "A",
+                "-o",
+                output.to_str().unwrap(),
+            ]);
+            let out = execute(&mut conn, &export).unwrap();
+            assert_eq!(out.json["exported"], 2, "{}", kind);
+
+            // Lo exportado se puede volver a importar y queda igual
+            let import = parse(&["import", kind, output.to_str().unwrap(), "--deck", "C"]);
+            execute(&mut conn, &import).unwrap();
+            let output_2 = dir.join(format!("{}_export_2.csv", kind));
+            let export = parse(&[
+                "export",
+                kind,
+                "--deck",
+                "C",
+                "-o",
+                output_2.to_str().unwrap(),
+            ]);
+            execute(&mut conn, &export).unwrap();
+
+            let exported = fs::read_to_string(&output).unwrap();
+            assert_eq!(exported, fs::read_to_string(&output_2).unwrap(), "{}", kind);
+            insta::assert_snapshot!(format!("export_{}", kind), exported);
+        }
+
+        // Sin deck se exporta todo
+        let output = dir.join("all.csv");
+        let export = parse(&["export", "words", "-o", output.to_str().unwrap()]);
+        assert_eq!(execute(&mut conn, &export).unwrap().json["exported"], 4);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_import_conjugations() {
+        let mut conn = setup_test_db().unwrap();
+        init_data(&mut conn).unwrap();
+        let dir = temp_dir("conjugations");
+        let input = dir.join("words.csv");
+        fs::write(&input, WORTE_CSV).unwrap();
+        execute(
+            &mut conn,
+            &parse(&["import", "words", input.to_str().unwrap()]),
+        )
+        .unwrap();
+
+        let input = dir.join("konjugation.csv");
+        fs::write(
+            &input,
+            "worte_de,tempus,person,form\nanrufen,praeteritum,ich,rief an\nanrufen,partizip2,,angerufen\n",
+        )
+        .unwrap();
+        let import = parse(&["import", "conjugations", input.to_str().unwrap(), "--json"]);
+        let out = execute(&mut conn, &import).unwrap();
+        assert_eq!(out.json["imported"], 2);
+        assert_eq!(out.json["ids"], serde_json::json!([2]));
+
+        // El verbo debe existir y la persona corresponder al tiempo
+        for text in [
+            "worte_de,tempus,person,form\nHund,praesens,ich,hunde\n",
+            "worte_de,tempus,person,form\nanrufen,partizip2,ich,angerufen\n",
+            "worte_de,tempus,person,form\nanrufen,imperativ,ich,rufe an\n",
+            "worte_de,tempus,person,form\nanrufen,futur,ich,werde anrufen\n",
+        ] {
+            fs::write(&input, text).unwrap();
+            assert!(execute(&mut conn, &import).is_err(), "{}", text);
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_import_frequency() {
+        let mut conn = setup_test_db().unwrap();
+        init_data(&mut conn).unwrap();
+        let dir = temp_dir("frequency");
+        let input = dir.join("words.csv");
+        fs::write(&input, WORTE_CSV).unwrap();
+        execute(
+            &mut conn,
+            &parse(&["import", "words", input.to_str().unwrap()]),
+        )
+        .unwrap();
+
+        let input = dir.join("frequency.csv");
+        fs::write(
+            &input,
+            "lemma,rank
+der,1
+Hund,250
+anrufen,900
+machen,80
+",
+        )
+        .unwrap();
+        let import = parse(&["import", "frequency", input.to_str().unwrap(), "--json"]);
+        let out = execute(&mut conn, &import).unwrap();
+        assert_eq!(out.json["imported"], 4);
+        assert_eq!(out.json["matched"], 2);
+
+        let out = execute(&mut conn, &parse(&["stats", "--json"])).unwrap();
+        assert_eq!(out.json["frequenz"]["top_n"], 4);
+        assert_eq!(out.json["frequenz"]["vorhanden"], 2);
+        assert_eq!(out.json["frequenz"]["gelernt"], 0);
+        assert!(out.text.contains("Top 4"));
+
+        fs::write(
+            &input,
+            "lemma,rank
+der,0
+",
+        )
+        .unwrap();
+        let import = parse(&["import", "frequency", input.to_str().unwrap()]);
+        assert!(execute(&mut conn, &import).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_stats_and_due() {
+        let mut conn = setup_test_db().unwrap();
+        init_data(&mut conn).unwrap();
+        let dir = temp_dir("stats");
+        let input = dir.join("setze.csv");
+        fs::write(&input, SETZE_CSV).unwrap();
+        execute(
+            &mut conn,
+            &parse(&["import", "sentences", input.to_str().unwrap()]),
+        )
+        .unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        let out = execute(&mut conn, &parse(&["stats", "--json"])).unwrap();
+        assert_eq!(out.json["setze"]["total"], 2);
+        assert_eq!(out.json["setze"]["neue"], 2);
+        assert_eq!(out.json["worte"]["total"], 0);
+        assert!(out.text.contains("Oraciones"));
+
+        let out = execute(&mut conn, &parse(&["due", "--json"])).unwrap();
+        assert_eq!(out.json["worte"].as_array().unwrap().len(), 0);
+        assert_eq!(out.json["setze"].as_array().unwrap().len(), 0);
+    }
+
+    #[test]
+    fn test_search() {
+        let mut conn = setup_test_db().unwrap();
+        init_data(&mut conn).unwrap();
+        let dir = temp_dir("search");
+        for (kind, text) in [("words", WORTE_CSV), ("sentences", SETZE_CSV)] {
+            let input = dir.join(format!("{}.csv", kind));
+            fs::write(&input, text).unwrap();
+            execute(
+                &mut conn,
+                &parse(&["import", kind, input.to_str().unwrap()]),
+            )
+            .unwrap();
+        }
+        fs::remove_dir_all(&dir).unwrap();
+
+        let out = execute(
+            &mut conn,
+            &parse(&["search", "words", "--gram", "verb_separable", "--json"]),
+        )
+        .unwrap();
+        assert_eq!(out.json["total"], 1);
+        assert_eq!(out.json["items"][0]["de"], "anrufen");
+        assert_eq!(out.json["items"][0]["review"]["state"], "new");
+        assert_eq!(out.json["items"][0]["audio"], false);
+
+        let out = execute(
+            &mut conn,
+            &parse(&["search", "sentences", "--niveau", "B1"]),
+        )
+        .unwrap();
+        assert!(out.text.contains("Tarjetas encontradas: 1 (página 1 de 1)"));
+        assert!(out.text.contains("Tema: Dativ"));
+        assert!(out.text.contains("Estado: Nueva"));
+
+        let out = execute(
+            &mut conn,
+            &parse(&[
+                "search",
+                "sentences",
+                "--per-page",
+                "1",
+                "--page",
+                "2",
+                "--json",
+            ]),
+        )
+        .unwrap();
+        assert_eq!(out.json["pages"], 2);
+        assert_eq!(out.json["items"][0]["de"], "Ich gehe nach Hause, dann");
+
+        // Los filtros de palabras no aplican a oraciones
+        let bad = parse(&["search", "sentences", "--gender", "der"]);
+        assert!(execute(&mut conn, &bad).is_err());
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut conn = setup_test_db().unwrap();
+        init_data(&mut conn).unwrap();
+
+        // El deck para filtrar debe existir
+        assert!(execute(&mut conn, &parse(&["stats", "--deck", "No existe"])).is_err());
+
+        let missing = parse(&["import", "words", "/no/existe.csv"]);
+        assert!(execute(&mut conn, &missing).is_err());
+
+        let dir = temp_dir("errors");
+        let input = dir.join("malo.csv");
+        fs::write(&input, "a,b\n1,2\n").unwrap();
+        let bad = parse(&["import", "sentences", input.to_str().unwrap()]);
+        assert!(execute(&mut conn, &bad).is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,778 @@
+use std::{fs::File, path::PathBuf};
+
+use color_eyre::eyre::{Report, Result, bail, eyre};
+use rusqlite::Connection;
+use serde_json::{Value, json};
+
+use crate::{
+    console::REVIEW_MODES,
+    db::{
+        deck::DeckRepo,
+        schemas::deck::{DEFAULT_DECK_ID, DeckSchema},
+        setze::SetzeRepo,
+        worte::WorteRepo,
+    },
+    helpers::{
+        audios::generate::{self, AudioReport},
+        csv, deck,
+        fehler::{self, Analyse},
+        frequenz, konjugation,
+        niveau::{self, NiveauFortschritt},
+        search::{self, ReviewInfo, SearchFilter},
+        settings,
+        stats::{self, Stats},
+        verwechslung::{self, Paar},
+    },
+    tui,
+};
+
+#[cfg(test)]
+mod cli_test;
+
+pub const EXIT_OK: i32 = 0;
+pub const EXIT_ERROR: i32 = 1;
+/// Comando u opciones no válidas.
+pub const EXIT_USAGE: i32 = 2;
+/// El comando terminó, pero algunas tarjetas fallaron (ejem: audios que no se generaron).
+pub const EXIT_PARTIAL: i32 = 3;
+
+pub const TEXT_HELP_COMMANDS: &str = r#"
+Comandos (sin comando se abre el menú interactivo):
+    import words <csv>              Importa palabras de un CSV.
+    import sentences <csv>          Importa oraciones de un CSV.
+    import conjugations <csv>       Importa formas conjugadas que reemplazan a las generadas.
+    import frequency <csv>          Importa una lista de frecuencia (lemma,rank) que reemplaza a la anterior.
+    audio generate [words|sentences] Genera los audios que falten.
+    stats                           Muestra el número de tarjetas nuevas, por repasar y repasadas hoy.
+    mistakes                        Muestra los errores por categoría, tipo gramatical, tema y nivel.
+    confusions                      Lista los pares de palabras que más confundes.
+    levels                          Muestra el avance de cada nivel (A1–C2) y cuáles ya dominas.
+    due                             Lista las tarjetas que toca repasar hoy.
+    export words|sentences          Exporta las tarjetas en el formato de importación.
+    review --mode <modo>            Abre una sesión de práctica: words-due, words-new, conjugation,
+                                    declension, prepositions, comparison, confusables,
+                                    sentences-random, sentences-schwirig, sentences-new, daily.
+    search words|sentences [texto]  Busca tarjetas por texto en alemán o español y filtros.
+    tui                             Abre la interfaz de pantalla completa.
+
+Opciones de los comandos:
+    --json                Salida en JSON.
+    --deck <ruta>         Deck destino al importar, o deck con el que se filtra.
+    -o, --output <archivo> Archivo de salida de export (por defecto la salida estándar).
+
+Filtros de search (también se escriben en el texto como clave:valor, ejem: niveau:A1):
+    --gram <tipo>         Tipo gramatical, por código o nombre (solo palabras).
+    --gender <género>     Género o artículo: der, die, das (solo palabras).
+    --niveau <nivel>      Nivel: A1, A2, B1...
+    --thema <tema>        Tema (solo oraciones).
+    --due <estado>        new, due o scheduled.
+    --ease <min..max>     Rango de facilidad, ejem: ..2.0 o 1.3..2.5.
+    --lapses <n>          Mínimo de fallos (solo oraciones).
+    --page <n>            Página de resultados (por defecto 1).
+    --per-page <n>        Tarjetas por página (por defecto 20).
+
+Códigos de salida: 0 correcto, 1 error, 2 uso incorrecto, 3 algunas tarjetas fallaron.
+"#;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CardKind {
+    Worte,
+    Setze,
+}
+
+impl CardKind {
+    fn parse(s: &str) -> Result<Self> {
+        match s {
+            "words" => Ok(Self::Worte),
+            "sentences" => Ok(Self::Setze),
+            _ => bail!("Tipo no válido: {}. Usa \"words\" o \"sentences\"", s),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Worte => "words",
+            Self::Setze => "sentences",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Import {
+        kind: CardKind,
+        path: String,
+    },
+    ImportKonjugation {
+        path: String,
+    },
+    ImportFrequenz {
+        path: String,
+    },
+    AudioGenerate {
+        kind: Option<CardKind>,
+    },
+    Stats,
+    Mistakes,
+    Confusions,
+    Levels,
+    Due,
+    Export {
+        kind: CardKind,
+        output: Option<PathBuf>,
+    },
+    Review {
+        mode: String,
+    },
+    Search {
+        kind: CardKind,
+        filter: SearchFilter,
+        page: usize,
+        per_page: usize,
+    },
+    Tui,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Cli {
+    pub command: Command,
+    pub json: bool,
+    pub deck: Option<String>,
+}
+
+/// Resultado de un comando: el texto para personas, el JSON y el código de salida.
+#[derive(Debug)]
+pub struct Output {
+    pub code: i32,
+    pub json: Value,
+    pub text: String,
+}
+
+impl Output {
+    fn ok(json: Value, text: String) -> Self {
+        Self {
+            code: EXIT_OK,
+            json,
+            text,
+        }
+    }
+}
+
+impl Cli {
+    /// Lee el comando de los argumentos que no son de perfil. `None` si no hay comando.
+    pub fn parse(args: &[String]) -> Result<Option<Self>> {
+        if args.is_empty() {
+            return Ok(None);
+        }
+
+        let mut json = false;
+        let mut deck = None;
+        let mut output = None;
+        let mut mode = None;
+        let mut filters: Vec<(&str, String)> = Vec::new();
+        let mut page = None;
+        let mut per_page = None;
+        let mut positional: Vec<&str> = Vec::new();
+
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            let (flag, inline) = match arg.split_once('=') {
+                Some((f, v)) if f.starts_with("--") => (f, Some(v.to_string())),
+                _ => (arg.as_str(), None),
+            };
+
+            let mut value = |name: &str| match inline.clone().or_else(|| iter.next().cloned()) {
+                Some(v) if !v.is_empty() => Ok(v),
+                _ => Err(eyre!("Falta el valor de {}", name)),
+            };
+
+            match flag {
+                "--json" => json = true,
+                "--deck" => deck = Some(value("--deck")?),
+                "-o" | "--output" => output = Some(PathBuf::from(value("--output")?)),
+                "--mode" => mode = Some(value("--mode")?),
+                "--gram" | "--gender" | "--niveau" | "--thema" | "--due" | "--ease"
+                | "--lapses" => filters.push((&flag[2..], value(flag)?)),
+                "--page" => page = Some(number(&value("--page")?)?),
+                "--per-page" => per_page = Some(number(&value("--per-page")?)?),
+                f if f.starts_with('-') => bail!("Opción no reconocida: {}", f),
+                _ => positional.push(arg),
+            }
+        }
+
+        let command = match positional.as_slice() {
+            ["import", "conjugations", path] => Command::ImportKonjugation {
+                path: path.to_string(),
+            },
+            ["import", "frequency", path] => Command::ImportFrequenz {
+                path: path.to_string(),
+            },
+            ["import", kind, path] => Command::Import {
+                kind: CardKind::parse(kind)?,
+                path: path.to_string(),
+            },
+            ["audio", "generate"] => Command::AudioGenerate { kind: None },
+            ["audio", "generate", kind] => Command::AudioGenerate {
+                kind: Some(CardKind::parse(kind)?),
+            },
+            ["stats"] => Command::Stats,
+            ["mistakes"] => Command::Mistakes,
+            ["confusions"] => Command::Confusions,
+            ["levels"] => Command::Levels,
+            ["due"] => Command::Due,
+            ["export", kind] => Command::Export {
+                kind: CardKind::parse(kind)?,
+                output: output.take(),
+            },
+            ["review"] => {
+                let Some(mode) = mode.take() else {
+                    bail!("review necesita --mode <modo>");
+                };
+                if !REVIEW_MODES.iter().any(|(m, _)| *m == mode) {
+                    bail!("Modo no válido: {}", mode);
+                }
+                Command::Review { mode }
+            }
+            ["search", kind, text @ ..] => {
+                let mut filter = SearchFilter::parse(&text.join(" "))?;
+                for (key, value) in filters.drain(..) {
+                    filter.set(key, &value)?;
+                }
+                Command::Search {
+                    kind: CardKind::parse(kind)?,
+                    filter,
+                    page: page.take().unwrap_or(1),
+                    per_page: per_page.take().unwrap_or(search::PER_PAGE),
+                }
+            }
+            ["tui"] => Command::Tui,
+            _ => bail!("Comando no reconocido: {}", positional.join(" ")),
+        };
+
+        if output.is_some() {
+            bail!("--output solo aplica a export");
+        }
+        if mode.is_some() {
+            bail!("--mode solo aplica a review");
+        }
+        if !filters.is_empty() || page.is_some() || per_page.is_some() {
+            bail!("Los filtros y la paginación solo aplican a search");
+        }
+        if json && command == Command::Tui {
+            bail!("tui no tiene salida en JSON");
+        }
+        // Las preguntas del repaso van a la salida estándar, antes de cualquier JSON
+        if json && matches!(command, Command::Review { .. }) {
+            bail!("review es interactivo y no tiene salida en JSON");
+        }
+        if json && matches!(command, Command::Export { output: None, .. }) {
+            bail!("export con --json necesita --output, el CSV se escribe en la salida estándar");
+        }
+
+        Ok(Some(Self {
+            command,
+            json,
+            deck,
+        }))
+    }
+}
+
+/// Número positivo de una opción (ejem: --page).
+fn number(s: &str) -> Result<usize> {
+    match s.parse::<usize>() {
+        Ok(n) if n > 0 => Ok(n),
+        _ => bail!("Número no válido: {}", s),
+    }
+}
+
+/// Ejecuta el comando, imprime su salida y regresa el código de salida.
+pub fn run(conn: &mut Connection, cli: &Cli) -> i32 {
+    match execute(conn, cli) {
+        Ok(out) => {
+            if cli.json {
+                println!("{}", out.json);
+            } else if !out.text.is_empty() {
+                println!("{}", out.text);
+            }
+            out.code
+        }
+        Err(err) => {
+            print_error(cli.json, &err);
+            EXIT_ERROR
+        }
+    }
+}
+
+/// Imprime el error de un comando: como JSON en la salida estándar con --json, si no en stderr.
+pub fn print_error(json: bool, err: &Report) {
+    if json {
+        println!("{}", json!({ "ok": false, "error": format!("{:#}", err) }));
+    } else {
+        eprintln!("Error: {:#}", err);
+    }
+}
+
+pub fn execute(conn: &mut Connection, cli: &Cli) -> Result<Output> {
+    match &cli.command {
+        Command::Import { kind, path } => import(conn, *kind, path, cli.deck.as_deref()),
+        Command::ImportKonjugation { path } => {
+            let scope = scope(conn, cli.deck.as_deref())?;
+            import_konjugation(conn, path, scope.as_deref())
+        }
+        Command::ImportFrequenz { path } => import_frequenz(conn, path),
+        Command::AudioGenerate { kind } => {
+            let scope = scope(conn, cli.deck.as_deref())?;
+            audio_generate(conn, *kind, scope.as_deref(), !cli.json)
+        }
+        Command::Stats => {
+            let scope = scope(conn, cli.deck.as_deref())?;
+            let stats = stats::collect(conn, scope.as_deref())?;
+            Ok(Output::ok(json!(stats), stats_text(&stats)))
+        }
+        Command::Mistakes => {
+            let scope = scope(conn, cli.deck.as_deref())?;
+            let analyse = fehler::analyse(conn, scope.as_deref())?;
+            Ok(Output::ok(json!(analyse), mistakes_text(&analyse)))
+        }
+        Command::Confusions => {
+            let scope = scope(conn, cli.deck.as_deref())?;
+            let paare = verwechslung::paare(conn, scope.as_deref())?;
+            Ok(Output::ok(json!(paare), confusions_text(&paare)))
+        }
+        Command::Levels => {
+            let scope = scope(conn, cli.deck.as_deref())?;
+            let mastery_percent = settings::get().niveau.mastery_percent;
+            let fortschritt = niveau::fortschritt(conn, scope.as_deref(), mastery_percent)?;
+            Ok(Output::ok(json!(fortschritt), levels_text(&fortschritt)))
+        }
+        Command::Due => {
+            let scope = scope(conn, cli.deck.as_deref())?;
+            due(conn, scope.as_deref())
+        }
+        Command::Export { kind, output } => {
+            let scope = scope(conn, cli.deck.as_deref())?;
+            export(conn, *kind, output.as_ref(), scope.as_deref())
+        }
+        Command::Review { mode } => {
+            let scope = scope(conn, cli.deck.as_deref())?;
+            review(conn, mode, scope.as_deref())
+        }
+        Command::Search {
+            kind,
+            filter,
+            page,
+            per_page,
+        } => {
+            let mut filter = filter.clone();
+            filter.decks = scope(conn, cli.deck.as_deref())?;
+            search(conn, *kind, &filter, *page, *per_page)
+        }
+        Command::Tui => {
+            scope(conn, cli.deck.as_deref())?;
+            tui::run(conn)?;
+            Ok(Output::ok(json!({}), String::new()))
+        }
+    }
+}
+
+/// Decks con los que se filtra; también se vuelve el deck activo de las sesiones.
+fn scope(conn: &Connection, path: Option<&str>) -> Result<Option<Vec<i32>>> {
+    let Some(path) = path else {
+        return Ok(None);
+    };
+    let Some(d) = deck::find_deck(conn, path)? else {
+        bail!("No existe el deck: {}", path);
+    };
+
+    deck::set_active_deck(Some(d.id));
+    Ok(Some(DeckRepo::fetch_subtree_ids(conn, d.id)?))
+}
+
+fn import(conn: &mut Connection, kind: CardKind, path: &str, deck: Option<&str>) -> Result<Output> {
+    let deck: DeckSchema = match deck {
+        Some(p) => DeckRepo::ensure_path(conn, p)?,
+        None => DeckRepo::fetch_by_id(conn, &[DEFAULT_DECK_ID])?
+            .pop()
+            .ok_or_else(|| eyre!("No existe el deck por defecto"))?,
+    };
+
+    let ids: Vec<i32> = match kind {
+        CardKind::Worte => {
+            csv::is_csv_valid(path, csv::CsvType::Worte)?;
+            let data = csv::extract_worte_csv(path, deck.id)?;
+            WorteRepo::bulk_insert(conn, &data)?
+                .iter()
+                .map(|w| w.id)
+                .collect()
+        }
+        CardKind::Setze => {
+            csv::is_csv_valid(path, csv::CsvType::Setze)?;
+            let data = csv::extract_sentences_csv(path, deck.id)?;
+            SetzeRepo::bulk_insert(conn, &data)?
+                .iter()
+                .map(|s| s.id)
+                .collect()
+        }
+    };
+
+    let text = format!(
+        "Se importaron {} tarjetas ({}) al deck {}.",
+        ids.len(),
+        kind.name(),
+        deck.name
+    );
+    let json = json!({
+        "kind": kind.name(),
+        "deck": deck.name,
+        "imported": ids.len(),
+        "ids": ids,
+    });
+    Ok(Output::ok(json, text))
+}
+
+/// Formas conjugadas del CSV; `--deck` limita los verbos a los que se aplican.
+fn import_konjugation(conn: &mut Connection, path: &str, scope: Option<&[i32]>) -> Result<Output> {
+    csv::is_csv_valid(path, csv::CsvType::Konjugation)?;
+    let rows = csv::extract_konjugation_csv(path)?;
+    let saved = konjugation::import(conn, &rows, scope)?;
+
+    let mut verben: Vec<i32> = saved.iter().map(|k| k.wort_id).collect();
+    verben.sort();
+    verben.dedup();
+
+    let text = format!(
+        "Se importaron {} formas de {} verbos.",
+        saved.len(),
+        verben.len()
+    );
+    let json = json!({
+        "kind": "conjugations",
+        "imported": saved.len(),
+        "ids": verben,
+    });
+    Ok(Output::ok(json, text))
+}
+
+fn import_frequenz(conn: &mut Connection, path: &str) -> Result<Output> {
+    csv::is_csv_valid(path, csv::CsvType::Frequenz)?;
+    let rows = csv::extract_frequenz_csv(path)?;
+    let report = frequenz::import(conn, &rows)?;
+
+    let text = format!(
+        "Se importaron {} lemas; {} de tus palabras tienen rango.",
+        report.imported, report.matched
+    );
+    let json = json!({
+        "kind": "frequency",
+        "imported": report.imported,
+        "matched": report.matched,
+    });
+    Ok(Output::ok(json, text))
+}
+
+fn audio_generate(
+    conn: &mut Connection,
+    kind: Option<CardKind>,
+    scope: Option<&[i32]>,
+    verbose: bool,
+) -> Result<Output> {
+    let in_scope = |deck_id: i32| scope.is_none_or(|s| s.contains(&deck_id));
+
+    let mut worte = AudioReport::default();
+    if kind.is_none_or(|k| k == CardKind::Worte) {
+        let pending: Vec<_> = WorteRepo::fetch_worte_without_audio(conn)?
+            .into_iter()
+            .filter(|w| in_scope(w.deck_id))
+            .collect();
+        worte = generate::generate_worte(conn, &pending, verbose)?;
+    }
+
+    let mut setze = AudioReport::default();
+    if kind.is_none_or(|k| k == CardKind::Setze) {
+        let pending: Vec<_> = SetzeRepo::fetch_setze_without_audio(conn)?
+            .into_iter()
+            .filter(|s| in_scope(s.deck_id))
+            .collect();
+        setze = generate::generate_setze(conn, &pending, verbose)?;
+    }
+
+    let failed = worte.failed.len() + setze.failed.len();
+    let text = format!(
+        "Audios generados: {} palabras, {} oraciones. Fallidos: {}.",
+        worte.generated, setze.generated, failed
+    );
+
+    Ok(Output {
+        code: if failed > 0 { EXIT_PARTIAL } else { EXIT_OK },
+        json: json!({ "worte": worte, "setze": setze }),
+        text,
+    })
+}
+
+fn stats_text(stats: &Stats) -> String {
+    let mut text = format!(
+        "{:<10} {:>7} {:>7} {:>12} {:>7}\n",
+        "", "total", "neue", "por repasar", "hoy"
+    );
+    for (name, s) in [("Palabras", &stats.worte), ("Oraciones", &stats.setze)] {
+        text.push_str(&format!(
+            "{:<10} {:>7} {:>7} {:>12} {:>7}\n",
+            name, s.total, s.neue, s.due, s.reviewed_today
+        ));
+    }
+    if let Some(f) = &stats.frequenz {
+        text.push_str(&format!(
+            "\nTop {} más frecuentes: {} en tus palabras, {} repasadas ({:.1}%)\n",
+            f.top_n, f.vorhanden, f.gelernt, f.percent
+        ));
+    }
+    text.trim_end().to_string()
+}
+
+fn mistakes_text(analyse: &Analyse) -> String {
+    if analyse.total == 0 {
+        return "No hay errores guardados.".to_string();
+    }
+
+    let mut text = format!("Errores: {}\n", analyse.total);
+    for (title, zeilen) in [
+        ("Por categoría", &analyse.kategorie),
+        ("Por ejercicio", &analyse.art),
+        ("Por caso, tiempo o grado", &analyse.detail),
+        ("Por tipo gramatical", &analyse.gram_type),
+        ("Por tema", &analyse.thema),
+        ("Por nivel", &analyse.niveau),
+    ] {
+        if zeilen.is_empty() {
+            continue;
+        }
+        text.push_str(&format!("\n{}:\n", title));
+        for z in zeilen {
+            text.push_str(&format!(
+                "  {:<40} {:>5} {:>6.1}%\n",
+                z.label, z.count, z.percent
+            ));
+        }
+    }
+    text.trim_end().to_string()
+}
+
+fn confusions_text(paare: &[Paar]) -> String {
+    if paare.is_empty() {
+        return "No hay palabras confundidas.".to_string();
+    }
+
+    let mut text = format!("{:>5}  {:<30} {}\n", "veces", "se preguntó", "se respondió");
+    for p in paare {
+        let wort = format!("{} ({})", p.wort_de, p.wort_es);
+        let verwechselt = format!("{} ({})", p.verwechselt_de, p.verwechselt_es);
+        text.push_str(&format!("{:>5}  {:<30} {}\n", p.count, wort, verwechselt));
+    }
+    text.trim_end().to_string()
+}
+
+fn levels_text(fortschritt: &[NiveauFortschritt]) -> String {
+    let mut text = format!(
+        "{:<6} {:>9} {:>9} {:>9}\n",
+        "nivel", "palabras", "oraciones", "maduras"
+    );
+    for n in fortschritt {
+        text.push_str(&format!(
+            "{:<6} {:>9} {:>9} {:>8.1}%{}\n",
+            n.niveau,
+            n.worte.total,
+            n.setze.total,
+            n.percent,
+            if n.mastered { "  dominado" } else { "" }
+        ));
+    }
+    if let Some(aktuell) = niveau::aktuelles_niveau(fortschritt)
+        .and_then(|id| fortschritt.iter().find(|n| n.niveau_id == id))
+    {
+        text.push_str(&format!("\nNivel actual: {}\n", aktuell.niveau));
+    }
+    text.trim_end().to_string()
+}
+
+fn due(conn: &Connection, scope: Option<&[i32]>) -> Result<Output> {
+    let ids = stats::due_ids(conn, scope)?;
+    let worte = WorteRepo::fetch_by_id(conn, &ids.worte)?;
+    let setze = SetzeRepo::fetch_by_id(conn, &ids.setze)?;
+
+    let mut text = format!("Palabras por repasar: {}\n", worte.len());
+    for w in &worte {
+        text.push_str(&format!("  {:>6}  {} - {}\n", w.id, w.worte_de, w.worte_es));
+    }
+    text.push_str(&format!("Oraciones por repasar: {}\n", setze.len()));
+    for s in &setze {
+        text.push_str(&format!("  {:>6}  {}\n", s.id, s.setze_deutsch));
+    }
+
+    let worte: Vec<Value> = worte
+        .iter()
+        .map(|w| json!({ "id": w.id, "de": w.worte_de, "es": w.worte_es }))
+        .collect();
+    let setze: Vec<Value> = setze
+        .iter()
+        .map(|s| json!({ "id": s.id, "de": s.setze_deutsch, "es": s.setze_spanisch }))
+        .collect();
+
+    Ok(Output::ok(
+        json!({ "worte": worte, "setze": setze }),
+        text.trim_end().to_string(),
+    ))
+}
+
+fn export(
+    conn: &Connection,
+    kind: CardKind,
+    output: Option<&PathBuf>,
+    scope: Option<&[i32]>,
+) -> Result<Output> {
+    let exported = match kind {
+        CardKind::Worte => {
+            let ids = deck::filter_worte(conn, WorteRepo::fetch_all_only_ids(conn)?, scope)?;
+            let mut worte = WorteRepo::fetch_by_id(conn, &ids)?;
+            worte.sort_by_key(|w| w.id);
+            match output {
+                Some(path) => csv::write_worte_csv(File::create(path)?, &worte)?,
+                None => csv::write_worte_csv(std::io::stdout().lock(), &worte)?,
+            }
+            worte.len()
+        }
+        CardKind::Setze => {
+            let ids = deck::filter_setze(conn, SetzeRepo::fetch_all_only_ids(conn)?, scope)?;
+            let mut setze = SetzeRepo::fetch_by_id(conn, &ids)?;
+            setze.sort_by_key(|s| s.id);
+            match output {
+                Some(path) => csv::write_setze_csv(File::create(path)?, &setze)?,
+                None => csv::write_setze_csv(std::io::stdout().lock(), &setze)?,
+            }
+            setze.len()
+        }
+    };
+
+    // Sin archivo el CSV ya salió por la salida estándar
+    let text = match output {
+        Some(path) => format!("Se exportaron {} tarjetas a {}.", exported, path.display()),
+        None => String::new(),
+    };
+    let json = json!({
+        "kind": kind.name(),
+        "exported": exported,
+        "output": output,
+    });
+    Ok(Output::ok(json, text))
+}
+
+fn review(conn: &mut Connection, mode: &str, scope: Option<&[i32]>) -> Result<Output> {
+    let Some((_, menu)) = REVIEW_MODES.iter().find(|(m, _)| *m == mode) else {
+        bail!("Modo no válido: {}", mode);
+    };
+
+    let before = stats::collect(conn, scope)?;
+    menu(conn)?;
+    let after = stats::collect(conn, scope)?;
+
+    // Tarjetas que se repasaron por primera vez en el día durante la sesión
+    let worte = after
+        .worte
+        .reviewed_today
+        .saturating_sub(before.worte.reviewed_today);
+    let setze = after
+        .setze
+        .reviewed_today
+        .saturating_sub(before.setze.reviewed_today);
+
+    let text = format!("Repasadas: {} palabras, {} oraciones.", worte, setze);
+    let json = json!({ "mode": mode, "worte_reviewed": worte, "setze_reviewed": setze });
+    Ok(Output::ok(json, text))
+}
+
+fn search(
+    conn: &Connection,
+    kind: CardKind,
+    filter: &SearchFilter,
+    page: usize,
+    per_page: usize,
+) -> Result<Output> {
+    let (page, pages, total, cards) = match kind {
+        CardKind::Worte => {
+            let found = search::search_worte(conn, filter, page, per_page)?;
+            let cards = found
+                .items
+                .iter()
+                .map(|f| {
+                    let w = &f.card;
+                    let json = json!({
+                        "id": w.id,
+                        "de": w.worte_de,
+                        "es": w.worte_es,
+                        "artikel": w.gender_id.as_ref().map(|g| g.artikel.clone()),
+                        "gram_type": w.gram_type_id.iter().map(|g| g.code.clone()).collect::<Vec<_>>(),
+                        "niveau": w.niveau_id.niveau,
+                        "deck_id": w.deck_id,
+                        "sentences": f.sentences,
+                        "review": review_json(&f.review),
+                        "audio": f.audio,
+                    });
+                    (f.details(), json)
+                })
+                .collect::<Vec<_>>();
+            (found.page, found.pages, found.total, cards)
+        }
+        CardKind::Setze => {
+            let found = search::search_setze(conn, filter, page, per_page)?;
+            let cards = found
+                .items
+                .iter()
+                .map(|f| {
+                    let s = &f.card;
+                    let json = json!({
+                        "id": s.id,
+                        "de": s.setze_deutsch,
+                        "es": s.setze_spanisch,
+                        "thema": s.thema,
+                        "niveau": s.niveau_id.niveau,
+                        "deck_id": s.deck_id,
+                        "review": review_json(&f.review),
+                        "audio": f.audio,
+                    });
+                    (f.details(), json)
+                })
+                .collect::<Vec<_>>();
+            (found.page, found.pages, found.total, cards)
+        }
+    };
+
+    let mut text = format!(
+        "Tarjetas encontradas: {} (página {} de {})\n",
+        total, page, pages
+    );
+    for (details, _) in &cards {
+        text.push('\n');
+        for (label, value) in details {
+            text.push_str(&format!("  {}: {}\n", label, value));
+        }
+    }
+
+    let json = json!({
+        "kind": kind.name(),
+        "page": page,
+        "pages": pages,
+        "total": total,
+        "items": cards.into_iter().map(|(_, json)| json).collect::<Vec<_>>(),
+    });
+    Ok(Output::ok(json, text.trim_end().to_string()))
+}
+
+fn review_json(review: &ReviewInfo) -> Value {
+    json!({
+        "state": review.state.name(),
+        "next_review": review.next_review,
+        "interval": review.interval,
+        "ease_factor": review.ease_factor,
+        "repetitions": review.repetitions,
+        "lapses": review.lapses,
+    })
+}
@@ -3,7 +3,7 @@ use rusqlite::Connection;
 
 use crate::{
     db::setze::SetzeRepo,
-    helpers::{csv, ui},
+    helpers::{csv, deck, ui},
     utils::path_file_oder_dir,
 };
 
@@ -14,17 +14,22 @@ Cabeceras:
     - setze_deutsch (Oración en alemán)
     - thema (Tema al que corresponde la oración)
     - schwirig_id (Calificación de la oración de dificultad, puede ser con número o letra)
+    - niveau (Opcional. Nivel CEFR de la oración: A1, A2, B1, B2, C1 ó C2. Por defecto A1)
+    - tags (Opcional. Etiquetas separadas por coma, ejem: "Genitiv,Arbeit")
 
 schwirig_id:
-    - 0: "Einfag"
+    - 0: "Einfach"
     - 1: "Normal"
     - 2: "Schwirig"
 
+La dificultad se ajusta sola con tus repasos: las oraciones que fallas seguido pasan a
+"Schwirig" y las que dominas a "Einfach".
+
 Ejemplo:
 
-setze_spanisch,setze_deutsch,thema,schwirig_id
-"Tengo un perro","Ich habe einen Hund","Akkusativ",0
-"Voy con mi gato hacia Alemania","Ich fahre mit meiner Katze nach Deutschland","Präpositionen mit Dativ","Normal"
+setze_spanisch,setze_deutsch,thema,schwirig_id,niveau
+"Tengo un perro","Ich habe einen Hund","Akkusativ",0,A1
+"Voy con mi gato hacia Alemania","Ich fahre mit meiner Katze nach Deutschland","Präpositionen mit Dativ","Normal",A2
 
 Recomendaciones:
     1. Poner las oraciones entre comillas dobles, en caso de tener "," dentro de las mismas.
@@ -51,9 +56,9 @@ Favor de colocar la ruta en formato relativo o absulto. Ejemplo:
 
 Recuerda que el archivo debe de tener este formato:
 
-setze_spanisch,setze_deutsch,schwirig_id
-"Tengo un perro","Ich habe einen Hund",0
-"Voy con mi gato hacia Alemania","Ich fahre mit meiner Katze nach Deutschland",Normal
+setze_spanisch,setze_deutsch,thema,schwirig_id
+"Tengo un perro","Ich habe einen Hund","Akkusativ",0
+"Voy con mi gato hacia Alemania","Ich fahre mit meiner Katze nach Deutschland","Präpositionen mit Dativ",Normal
 
 Para regresar al menu principal favor de escribir "exit".
 "##;
@@ -61,6 +66,11 @@ Para regresar al menu principal favor de escribir "exit".
 pub fn menu_1_add_sentences(conn: &mut Connection) -> Result<()> {
     // clean_screen();
 
+    let Some(deck) = deck::prompt_deck(conn, "¿A qué deck se agregan las oraciones?")? else {
+        return Ok(());
+    };
+    println!("Deck: {}", deck.name);
+
     println!("{}", TEXT_MENU);
 
     let mut csv_path: String = String::new();
@@ -96,7 +106,7 @@ pub fn menu_1_add_sentences(conn: &mut Connection) -> Result<()> {
         println!("{}", err_2_show.unwrap());
     }
 
-    let new_data = csv::extract_sentences_csv(&csv_path)?;
+    let new_data = csv::extract_sentences_csv(&csv_path, deck.id)?;
     SetzeRepo::bulk_insert(conn, &new_data)?;
 
     // println!("{:#?}", SetzeRepo::fetch_random(100)?);
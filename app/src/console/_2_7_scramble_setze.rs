@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+
+use color_eyre::eyre::Result;
+use inquire::MultiSelect;
+use rand::seq::SliceRandom;
+use rusqlite::Connection;
+
+use crate::{
+    db::{
+        schemas::setze_alternativ::NewSetzeAlternativSchema, setze::SetzeRepo,
+        setze_alternativ::SetzeAlternativRepo,
+    },
+    helpers::{
+        console, deck, editor,
+        scramble::{self, ScrambleCard},
+        session::{Session, SessionCard},
+        settings,
+    },
+    utils,
+};
+
+const TEXT_SCRAMBLE_ONCE: &str = r##"
+Para salir pon la palara "exit".                 Faltantes: {remainding}
+{commands}
+
+  Tema: {thema}
+  Oración: {satz}
+
+Ordena las palabras en alemán:
+  {tokens}
+
+Escribe los números en orden (ejem: "3 1 2 4") o las palabras.
+"##;
+
+pub fn menu_2_7_scramble_setze(conn: &mut Connection) -> Result<()> {
+    let titles: Vec<String> =
+        SetzeRepo::fetch_all_themas(conn, deck::active_scope(conn)?.as_deref())?;
+    let offset = settings::get().session.setze_scramble;
+
+    let ans = loop {
+        let a = MultiSelect::new("Selecciona los temas a ordenar:", titles.clone())
+            .with_page_size(20)
+            .prompt()
+            .unwrap();
+        if !a.is_empty() {
+            break a;
+        }
+    };
+
+    let ids_setze = SetzeRepo::fetch_id_where_thema(conn, &ans)?;
+    let mut ids_setze = deck::scope_setze(conn, ids_setze)?;
+
+    let mut seed_rand = rand::rng();
+    ids_setze.shuffle(&mut seed_rand);
+
+    let mut alternatives: HashMap<i32, Vec<String>> = HashMap::new();
+    for alt in SetzeAlternativRepo::fetch_by_satz_id(conn, &ids_setze)? {
+        alternatives
+            .entry(alt.satz_id)
+            .or_default()
+            .push(alt.antwort);
+    }
+
+    let mut session: Session<ScrambleCard> =
+        Session::new(conn, ids_setze, offset)?.with_alternatives(alternatives);
+
+    let val_out = console::run_session(
+        conn,
+        &mut session,
+        |card, remaining| {
+            let tokens = card
+                .shuffled
+                .iter()
+                .enumerate()
+                .map(|(i, t)| format!("{}) {}", i + 1, t))
+                .collect::<Vec<_>>()
+                .join("   ");
+
+            println!(
+                "{}",
+                TEXT_SCRAMBLE_ONCE
+                    .replace("{commands}", console::TEXT_COMMANDS)
+                    .replace("{satz}", &card.satz.setze_spanisch)
+                    .replace("{thema}", &card.satz.thema)
+                    .replace("{tokens}", &tokens)
+                    .replace("{remainding}", &remaining.to_string())
+            );
+            Ok(())
+        },
+        |card, input| {
+            let fb = scramble::feedback(&card.satz.setze_deutsch, &card.parse_input(input));
+            println!("Tu respuesta: {}", fb.marked);
+            for hint in fb.hints {
+                println!("  - {}", hint);
+            }
+        },
+        |conn, satz_id, antwort| {
+            SetzeAlternativRepo::bulk_insert(
+                conn,
+                &[NewSetzeAlternativSchema { satz_id, antwort }],
+            )?;
+            Ok(())
+        },
+        |conn, card| editor::edit_satz(conn, card.satz.id),
+    )?;
+
+    // guardamos en db la info de las revisiones; ordenar palabras no hace avanzar la oración
+    console::save_scramble_review(conn, session.into_results())?;
+
+    if val_out == 1 {
+        return Ok(());
+    }
+
+    utils::clean_screen();
+    println!("Oraciones del tema ordenadas.");
+    println!();
+
+    Ok(())
+}
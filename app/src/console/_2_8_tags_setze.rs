@@ -0,0 +1,49 @@
+use std::collections::HashSet;
+
+use color_eyre::eyre::Result;
+use rand::seq::SliceRandom;
+use rusqlite::Connection;
+
+use crate::{
+    db::{setze::SetzeRepo, setze_audio::SetzeAudioRepo},
+    helpers::{console, deck, settings},
+    utils,
+};
+
+pub fn menu_2_8_tags_setze(conn: &mut Connection) -> Result<()> {
+    let offset = settings::get().session.setze_tags;
+
+    let Some(expr) = console::prompt_tag_expr(conn)? else {
+        return Ok(());
+    };
+
+    let ids_setze = SetzeRepo::fetch_id_where_tags(conn, &expr)?;
+    let mut ids_setze = deck::scope_setze(conn, ids_setze)?;
+    if ids_setze.is_empty() {
+        println!("Ninguna oración coincide con la expresión.");
+        return Ok(());
+    }
+
+    let hash_audios: HashSet<i32> = SetzeAudioRepo::fetch_by_id(conn, &ids_setze)?
+        .into_iter()
+        .map(|a| a.satz_id)
+        .collect();
+
+    let mut seed_rand = rand::rng();
+    ids_setze.shuffle(&mut seed_rand);
+
+    let r = console::make_setze_exercise_repeat(conn, ids_setze, hash_audios, offset)?;
+
+    // guardamos en db la info de las revisiones
+    console::save_setze_review(conn, r.1)?;
+
+    if r.0 == 1 {
+        return Ok(());
+    }
+
+    utils::clean_screen();
+    println!("Oraciones finalizadas de las etiquetas.");
+    println!();
+
+    Ok(())
+}
@@ -5,7 +5,8 @@ use crate::{
     console::{
         _2_1_random_sentences::menu_2_1_random_sentences, _2_2_select_thema::menu_2_2_select_thema,
         _2_3_schwirig_sentences::menu_2_3_schwirig_sentences,
-        _2_4_neue_setze::menu_2_4_neue_sentences,
+        _2_4_neue_setze::menu_2_4_neue_sentences, _2_7_scramble_setze::menu_2_7_scramble_setze,
+        _2_8_tags_setze::menu_2_8_tags_setze,
     },
     helpers::ui,
 };
@@ -17,6 +18,8 @@ const TEXT_MENU: &str = r##"¿Cuál de entrenamiento quieres realizar?
   4. Oraciones nuevas.
   5. Oraciones dificiles con tema.
   6. Solo errores anteriores.
+  7. Ordenar oraciones por tema.
+  8. Oraciones por etiquetas.
 
 Para regresar al menu principal favor de escribir "exit".
 "##;
@@ -41,6 +44,8 @@ pub fn menu_2_practice_sentences(conn: &mut Connection) -> Result<()> {
             "4" => menu_2_4_neue_sentences(conn)?,
             "5" => todo!(),
             "6" => todo!(),
+            "7" => menu_2_7_scramble_setze(conn)?,
+            "8" => menu_2_8_tags_setze(conn)?,
             "exit" => return Ok(()),
             _ => println!("Comando no reconocido"),
         }
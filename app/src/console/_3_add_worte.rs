@@ -2,11 +2,8 @@ use color_eyre::eyre::Result;
 use rusqlite::Connection;
 
 use crate::{
-    db::{
-        schemas::worte_audio::NewWorteAudioSchema, worte::WorteRepo, worte_audio::WorteAudioRepo,
-    },
-    helpers::{audios::ManageAudios, csv, ui},
-    services::tts::{self, eleven_labs::LanguageVoice},
+    db::worte::WorteRepo,
+    helpers::{audios, csv, deck, ui},
     utils::path_file_oder_dir,
 };
 
@@ -30,6 +27,12 @@ Cabeceras:
     - trennbar (Si / No. Si es un verbo separable)
     - reflexiv (Si / No. Si es un verbo reflexivo)
 
+    (Opcional)
+    - tags (Etiquetas separadas por coma)
+    - komparativ (Solo adjetivos irregulares. Ejem: "besser"; vacío se genera)
+    - superlativ (Solo adjetivos irregulares. Ejem: "am besten"; vacío se genera)
+    - tags (Etiquetas separadas por coma, ejem: "Arbeit,Buch Kapitel 3")
+
 gram_type:
     - noun_common (Sustantivo comun)
     - noun_proper (Nombre propio)
@@ -116,6 +119,11 @@ El archivo proporcionado no existe o no es un archivo CSV válido.
 pub fn menu_3_add_worte(conn: &mut Connection) -> Result<()> {
     // clean_screen();
 
+    let Some(deck) = deck::prompt_deck(conn, "¿A qué deck se agregan las palabras?")? else {
+        return Ok(());
+    };
+    println!("Deck: {}", deck.name);
+
     println!("{}", TEXT_MENU);
 
     let mut csv_path: String;
@@ -150,7 +158,7 @@ pub fn menu_3_add_worte(conn: &mut Connection) -> Result<()> {
             continue;
         }
 
-        let new_data = csv::extract_worte_csv(&csv_path)?;
+        let new_data = csv::extract_worte_csv(&csv_path, deck.id)?;
         // println!("new_data: {:#?}", new_data);
 
         println!();
@@ -167,37 +175,7 @@ pub fn menu_3_add_worte(conn: &mut Connection) -> Result<()> {
 
         println!("Base de datos ejecutado, realizando descarga de audios");
 
-        for (i, wort) in res.iter().enumerate() {
-            let audio_bytes: Vec<u8> =
-                match tts::eleven_labs::generate_tts(&wort.worte_es, LanguageVoice::Spanisch) {
-                    Ok(v) => v,
-                    Err(err) => {
-                        println!("Error al generar el TTS de la palabra: {}", wort.worte_es);
-                        println!("{:#?}", err);
-                        continue;
-                    }
-                };
-
-            let audio_path = match ManageAudios::save_audio_worte(audio_bytes, wort.id) {
-                Ok(v) => v,
-                Err(err) => {
-                    println!("Error al guardar el archivo: {}", wort.worte_es);
-                    println!("{:#?}", err);
-                    continue;
-                }
-            };
-
-            WorteAudioRepo::bulk_insert(
-                conn,
-                &[NewWorteAudioSchema {
-                    wort_id: wort.id,
-                    voice_id: "masc_eleven_labs".into(),
-                    file_path: audio_path,
-                }],
-            )?;
-
-            println!("Audio procesado {}/{}.", i + 1, new_data.len());
-        }
+        audios::generate::generate_worte(conn, &res, true)?;
 
         break;
     }
@@ -1,29 +1,21 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 
-use chrono::Utc;
 use color_eyre::eyre::Result;
 use rand::seq::SliceRandom;
 use rusqlite::Connection;
 
 use crate::{
-    db::{
-        schemas::{
-            worte_audio::WorteAudioSchema,
-            worte_review::{NewWorteReviewSchema, WorteReviewSchema},
-        },
-        worte::WorteRepo,
-        worte_audio::WorteAudioRepo,
-        worte_review::WorteReviewRepo,
-    },
-    helpers::{self, review_state::ReviewState, time},
+    db::{schemas::worte_audio::WorteAudioSchema, worte::WorteRepo, worte_audio::WorteAudioRepo},
+    helpers::{console, deck, frequenz, niveau, settings},
     utils,
 };
 
 pub fn menu_4_1_neue_worte(conn: &mut Connection) -> Result<()> {
-    let offset: usize = 10;
+    let offset = settings::get().session.worte_neue;
 
     // 1) Obtenemos ids de las palabras nuevas
-    let mut ids_worte: Vec<i32> = WorteRepo::fetch_id_neue_worte(conn)?;
+    let ids_worte = WorteRepo::fetch_id_neue_worte(conn)?;
+    let mut ids_worte = deck::scope_worte(conn, ids_worte)?;
 
     let ids_audios: Vec<WorteAudioSchema> = WorteAudioRepo::fetch_by_id(conn, &ids_worte)?;
     let mut hash_audios: HashSet<i32> = HashSet::new();
@@ -35,51 +27,19 @@ pub fn menu_4_1_neue_worte(conn: &mut Connection) -> Result<()> {
     // Les hacemos un shuffle
     let mut seed_rand = rand::rng();
     ids_worte.shuffle(&mut seed_rand);
+    let ids_worte = frequenz::order_neue_worte(conn, ids_worte)?;
+    let max_neue_worte = deck::active_settings(conn)?.max_neue_worte as usize;
+    let ids_worte: Vec<i32> =
+        niveau::unlock_worte(conn, ids_worte, deck::active_scope(conn)?.as_deref())?
+            .into_iter()
+            .take(max_neue_worte)
+            .collect();
 
     // le hacemos el ejercicio al usuario
-    let r = helpers::console::make_worte_exercise_repeat(conn, ids_worte, hash_audios, offset)?;
-
-    // Obtenemos el id de las palabras que respondio
-    let wort_ids: Vec<i32> = r.1.iter().map(|(id, _)| *id).collect();
-
-    // Obtenemos si estas palabras ya tenian informacion hsitorica de revisiones anteriores
-    let vec_worte_review = WorteReviewRepo::fetch_by_wort_id(conn, &wort_ids)?;
-
-    let mut hash_worte_review: HashMap<i32, WorteReviewSchema> = HashMap::new();
-    for wr in vec_worte_review {
-        hash_worte_review.insert(wr.wort_id, wr);
-    }
-
-    let mut vec_new_worte_review: Vec<NewWorteReviewSchema> = vec![];
-    let now = Utc::now();
-
-    // Recorremos el arreglo de palabras que respondio el usuario
-    for wort in r.1 {
-        let wort_id = wort.0;
-        let quality = wort.1;
-
-        // Si tiene historico de revisiones usamos esa info, si no creamos un nuevo struct
-        let review_state = if let Some(val) = hash_worte_review.get(&wort_id) {
-            ReviewState::from(val.interval, val.ease_factor, val.repetitions)
-        } else {
-            ReviewState::new()
-        };
-
-        // generamos el arreglo para guardar las revisiones para un futuro
-        let review_state = review_state.review(quality);
-        let next = review_state.next_review_date_from(now);
-        vec_new_worte_review.push(NewWorteReviewSchema {
-            wort_id,
-            interval: review_state.interval,
-            ease_factor: review_state.ease_factor,
-            repetitions: review_state.repetitions,
-            last_review: time::datetime_2_string(now),
-            next_review: time::datetime_2_string(next),
-        })
-    }
+    let r = console::make_worte_exercise_repeat(conn, ids_worte, hash_audios, offset)?;
 
     // guardamos en db la info de las revisiones
-    WorteReviewRepo::bulk_insert(conn, &vec_new_worte_review)?;
+    console::save_worte_review(conn, r.1)?;
 
     if r.0 == 1 {
         return Ok(());
@@ -0,0 +1,49 @@
+use std::collections::HashSet;
+
+use color_eyre::eyre::Result;
+use rand::seq::SliceRandom;
+use rusqlite::Connection;
+
+use crate::{
+    db::{worte::WorteRepo, worte_audio::WorteAudioRepo},
+    helpers::{console, deck, settings},
+    utils,
+};
+
+pub fn menu_4_3_tags_worte(conn: &mut Connection) -> Result<()> {
+    let offset = settings::get().session.worte_tags;
+
+    let Some(expr) = console::prompt_tag_expr(conn)? else {
+        return Ok(());
+    };
+
+    let ids_worte = WorteRepo::fetch_id_where_tags(conn, &expr)?;
+    let mut ids_worte = deck::scope_worte(conn, ids_worte)?;
+    if ids_worte.is_empty() {
+        println!("Ninguna palabra coincide con la expresión.");
+        return Ok(());
+    }
+
+    let hash_audios: HashSet<i32> = WorteAudioRepo::fetch_by_id(conn, &ids_worte)?
+        .into_iter()
+        .map(|a| a.wort_id)
+        .collect();
+
+    let mut seed_rand = rand::rng();
+    ids_worte.shuffle(&mut seed_rand);
+
+    let r = console::make_worte_exercise_repeat(conn, ids_worte, hash_audios, offset)?;
+
+    // guardamos en db la info de las revisiones
+    console::save_worte_review(conn, r.1)?;
+
+    if r.0 == 1 {
+        return Ok(());
+    }
+
+    utils::clean_screen();
+    println!("Palabras finalizadas de las etiquetas.");
+    println!();
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use color_eyre::eyre::Result;
+use rusqlite::Connection;
+
+use crate::{
+    helpers::{
+        console, deck, editor,
+        konjugation::{self, KonjugationCard},
+        session::Session,
+        settings,
+    },
+    utils,
+};
+
+const TEXT_KONJUGATION_ONCE: &str = r##"
+Para salir pon la palara "exit".                 Faltantes: {remainding}
+{commands}
+Algunas letras que te pueden ayudar. :)
+          - ß ẞ ä ö ü Ä Ö Ü 
+
+  Verbo: {wort} ({wort_es})
+  Forma: {forma}
+
+Escribe solo la forma conjugada, sin el pronombre. Ejem: "rufe an"
+"##;
+
+pub fn menu_4_4_konjugation(conn: &mut Connection) -> Result<()> {
+    let offset = settings::get().session.konjugation;
+
+    // 1) Familias por repasar y nuevas de los verbos del deck activo
+    let scope = deck::active_scope(conn)?;
+    let keys = konjugation::build_drill(conn, scope.as_deref(), offset)?;
+    if keys.is_empty() {
+        println!("No hay verbos para conjugar.");
+        return Ok(());
+    }
+
+    let mut session: Session<KonjugationCard> = Session::new(conn, keys, offset)?;
+
+    let val_out = console::run_session(
+        conn,
+        &mut session,
+        |card, remaining| {
+            println!(
+                "{}",
+                TEXT_KONJUGATION_ONCE
+                    .replace("{commands}", console::TEXT_COMMANDS)
+                    .replace("{wort}", &card.wort.worte_de)
+                    .replace("{wort_es}", &card.wort.worte_es)
+                    .replace("{forma}", &card.konjugation.label())
+                    .replace("{remainding}", &remaining.to_string())
+            );
+            Ok(())
+        },
+        |card, _| {
+            println!();
+            println!("Ejemplo: {}", card.wort.example_de);
+        },
+        // Las formas no guardan respuestas alternativas: una forma mal generada se corrige
+        // importando la conjugación por CSV
+        |_, _, _| Ok(()),
+        |conn, card| editor::edit_wort(conn, card.wort.id),
+    )?;
+
+    // guardamos en db la info de las revisiones
+    console::save_konjugation_review(conn, session.into_results())?;
+
+    if val_out == 1 {
+        return Ok(());
+    }
+
+    utils::clean_screen();
+    println!("No hay mas verbos por conjugar. :)");
+    println!();
+
+    Ok(())
+}
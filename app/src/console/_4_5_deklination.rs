@@ -0,0 +1,97 @@
+use color_eyre::eyre::Result;
+use rusqlite::Connection;
+
+use crate::{
+    helpers::{
+        console, deck,
+        deklination::{self, DeklinationCard},
+        editor,
+        session::Session,
+        settings,
+    },
+    utils,
+};
+
+const TEXT_DEKLINATION_ONCE: &str = r##"
+Para salir pon la palara "exit".                 Faltantes: {remainding}
+{commands}
+Algunas letras que te pueden ayudar. :)
+          - ß ẞ ä ö ü Ä Ö Ü 
+
+  Caso: {kasus}
+  Determinante: {artikel}
+  Número: {numerus}
+  Palabras: {worte}
+
+Escribe el grupo nominal declinado. Ejem: "dem alten Hund"
+"##;
+
+pub fn menu_4_5_deklination(conn: &mut Connection) -> Result<()> {
+    let offset = settings::get().session.deklination;
+
+    // 1) Grupos nominales al azar con los sustantivos y adjetivos del deck activo
+    let scope = deck::active_scope(conn)?;
+    let keys = deklination::build_drill(conn, scope.as_deref(), offset)?;
+    if keys.is_empty() {
+        println!("No hay sustantivos con género para declinar.");
+        return Ok(());
+    }
+
+    let mut session: Session<DeklinationCard> = Session::new(conn, keys, offset)?;
+
+    let val_out = console::run_session(
+        conn,
+        &mut session,
+        |card, remaining| {
+            let worte = [card.adjective.as_ref(), Some(&card.noun)]
+                .into_iter()
+                .flatten()
+                .map(|w| format!("{} ({})", w.worte_de, w.worte_es))
+                .collect::<Vec<_>>()
+                .join(" + ");
+            let numerus = match card.deklination.genus {
+                deklination::Genus::Plural => "plural",
+                _ => "singular",
+            };
+
+            println!(
+                "{}",
+                TEXT_DEKLINATION_ONCE
+                    .replace("{commands}", console::TEXT_COMMANDS)
+                    .replace("{kasus}", card.key.kasus.label())
+                    .replace("{artikel}", card.key.artikel.label())
+                    .replace("{numerus}", numerus)
+                    .replace("{worte}", &worte)
+                    .replace("{remainding}", &remaining.to_string())
+            );
+            Ok(())
+        },
+        |card, _| {
+            println!();
+            println!("Regla: {}", card.deklination.rule);
+            println!();
+            let table = deklination::decline_all(
+                &card.noun,
+                card.adjective.as_ref().map(|a| a.worte_de.as_str()),
+                card.key.artikel,
+                card.key.plural,
+            );
+            for d in table {
+                println!("  {:<10} {}", d.kasus.label(), d.form);
+            }
+        },
+        // Solo hay una forma correcta; no se guardan respuestas alternativas
+        |_, _, _| Ok(()),
+        |conn, card| editor::edit_wort(conn, card.noun.id),
+    )?;
+
+    if val_out == 1 {
+        return Ok(());
+    }
+
+    utils::clean_screen();
+    println!("Terminaste la declinación. :)");
+    println!();
+
+    Ok(())
+}
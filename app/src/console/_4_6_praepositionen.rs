@@ -0,0 +1,84 @@
+use color_eyre::eyre::Result;
+use rusqlite::Connection;
+
+use crate::{
+    helpers::{
+        console, deck, editor,
+        praeposition::{self, PraepositionCard},
+        session::Session,
+        settings,
+    },
+    utils,
+};
+
+const TEXT_PRAEPOSITION_ONCE: &str = r##"
+Para salir pon la palara "exit".                 Faltantes: {remainding}
+{commands}
+Algunas letras que te pueden ayudar. :)
+          - ß ẞ ä ö ü Ä Ö Ü 
+
+  Preposición: {wort} ({wort_es})
+  {frage}
+
+{hilfe}
+"##;
+
+const TEXT_HILFE_KASUS: &str =
+    r#"Escribe el caso: "Akkusativ", "Dativ" o "Genitiv" (basta con "akk", "dat", "gen")."#;
+const TEXT_HILFE_ARTIKEL: &str = r#"Escribe el artículo definido que falta. Ejem: "dem""#;
+
+pub fn menu_4_6_praepositionen(conn: &mut Connection) -> Result<()> {
+    let offset = settings::get().session.praeposition;
+
+    // 1) Preposiciones por repasar y nuevas del deck activo
+    let scope = deck::active_scope(conn)?;
+    let keys = praeposition::build_drill(conn, scope.as_deref(), offset)?;
+    if keys.is_empty() {
+        println!("No hay preposiciones para practicar.");
+        return Ok(());
+    }
+
+    let mut session: Session<PraepositionCard> = Session::new(conn, keys, offset)?;
+
+    let val_out = console::run_session(
+        conn,
+        &mut session,
+        |card, remaining| {
+            let hilfe = match card.key.frage {
+                Some(_) => TEXT_HILFE_ARTIKEL,
+                None => TEXT_HILFE_KASUS,
+            };
+            println!(
+                "{}",
+                TEXT_PRAEPOSITION_ONCE
+                    .replace("{commands}", console::TEXT_COMMANDS)
+                    .replace("{wort}", &card.praeposition.worte_de)
+                    .replace("{wort_es}", &card.praeposition.worte_es)
+                    .replace("{frage}", &card.question())
+                    .replace("{hilfe}", hilfe)
+                    .replace("{remainding}", &remaining.to_string())
+            );
+            Ok(())
+        },
+        |card, _| {
+            println!();
+            println!("Regla: {}", card.rule());
+        },
+        // Solo hay una respuesta correcta; no se guardan respuestas alternativas
+        |_, _, _| Ok(()),
+        |conn, card| editor::edit_wort(conn, card.praeposition.id),
+    )?;
+
+    // guardamos en db la info de las revisiones
+    console::save_praeposition_review(conn, session.into_results())?;
+
+    if val_out == 1 {
+        return Ok(());
+    }
+
+    utils::clean_screen();
+    println!("No hay mas preposiciones por practicar. :)");
+    println!();
+
+    Ok(())
+}
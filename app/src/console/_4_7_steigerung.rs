@@ -0,0 +1,75 @@
+use color_eyre::eyre::Result;
+use rusqlite::Connection;
+
+use crate::{
+    helpers::{
+        console, deck, editor,
+        session::Session,
+        settings,
+        steigerung::{self, SteigerungCard},
+    },
+    utils,
+};
+
+const TEXT_STEIGERUNG_ONCE: &str = r##"
+Para salir pon la palara "exit".                 Faltantes: {remainding}
+{commands}
+Algunas letras que te pueden ayudar. :)
+          - ß ẞ ä ö ü Ä Ö Ü 
+
+  Adjetivo: {wort} ({wort_es})
+  Forma: {grad}
+
+Escribe la forma pedida. Ejem: "älter", "am ältesten"
+"##;
+
+pub fn menu_4_7_steigerung(conn: &mut Connection) -> Result<()> {
+    let offset = settings::get().session.steigerung;
+
+    // 1) Adjetivos al azar del deck activo
+    let scope = deck::active_scope(conn)?;
+    let keys = steigerung::build_drill(conn, scope.as_deref(), offset)?;
+    if keys.is_empty() {
+        println!("No hay adjetivos para comparar.");
+        return Ok(());
+    }
+
+    let mut session: Session<SteigerungCard> = Session::new(conn, keys, offset)?;
+
+    let val_out = console::run_session(
+        conn,
+        &mut session,
+        |card, remaining| {
+            println!(
+                "{}",
+                TEXT_STEIGERUNG_ONCE
+                    .replace("{commands}", console::TEXT_COMMANDS)
+                    .replace("{wort}", &card.wort.worte_de)
+                    .replace("{wort_es}", &card.wort.worte_es)
+                    .replace("{grad}", card.key.grad.label())
+                    .replace("{remainding}", &remaining.to_string())
+            );
+            Ok(())
+        },
+        |card, _| {
+            println!();
+            println!(
+                "{} - {} - {}",
+                card.wort.worte_de, card.steigerung.komparativ, card.steigerung.superlativ
+            );
+        },
+        // Una forma mal generada se corrige guardando komparativ/superlativ en la palabra
+        |_, _, _| Ok(()),
+        |conn, card| editor::edit_wort(conn, card.wort.id),
+    )?;
+
+    if val_out == 1 {
+        return Ok(());
+    }
+
+    utils::clean_screen();
+    println!("Terminaste la comparación de adjetivos. :)");
+    println!();
+
+    Ok(())
+}
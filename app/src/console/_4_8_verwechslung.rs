@@ -0,0 +1,84 @@
+use color_eyre::eyre::Result;
+use rusqlite::Connection;
+
+use crate::{
+    db::{
+        schemas::worte_alternativ::NewWorteAlternativSchema, worte_alternativ::WorteAlternativRepo,
+    },
+    helpers::{
+        console, deck, editor,
+        session::Session,
+        settings,
+        verwechslung::{self, VerwechslungCard},
+    },
+    utils,
+};
+
+const TEXT_VERWECHSLUNG_ONCE: &str = r##"
+Para salir pon la palara "exit".                 Faltantes: {remainding}
+{commands}
+Algunas letras que te pueden ayudar. :)
+          - ß ẞ ä ö ü Ä Ö Ü 
+
+  Palabra: {wort}
+  No la confundas con: {gegen}
+
+Escribe la palabra en alemán. Ejem: "der Hund", "gehen"
+"##;
+
+pub fn menu_4_8_verwechslung(conn: &mut Connection) -> Result<()> {
+    let max_paare = settings::get().session.verwechslung;
+
+    // 1) Los pares que más confundes del deck activo, las dos palabras seguidas
+    let scope = deck::active_scope(conn)?;
+    let keys = verwechslung::build_drill(conn, scope.as_deref(), max_paare)?;
+    if keys.is_empty() {
+        println!("Todavía no hay palabras que confundas.");
+        return Ok(());
+    }
+
+    let offset = keys.len();
+    let mut session: Session<VerwechslungCard> = Session::new(conn, keys, offset)?;
+
+    let val_out = console::run_session(
+        conn,
+        &mut session,
+        |card, remaining| {
+            println!(
+                "{}",
+                TEXT_VERWECHSLUNG_ONCE
+                    .replace("{commands}", console::TEXT_COMMANDS)
+                    .replace("{wort}", &card.wort.worte_es)
+                    .replace("{gegen}", &card.gegen.worte_es)
+                    .replace("{remainding}", &remaining.to_string())
+            );
+            Ok(())
+        },
+        |card, _| {
+            println!();
+            println!("{} = {}", card.wort.worte_es, card.wort.worte_de);
+            println!("{} = {}", card.gegen.worte_es, card.gegen.worte_de);
+        },
+        |conn, key, antwort| {
+            WorteAlternativRepo::bulk_insert(
+                conn,
+                &[NewWorteAlternativSchema {
+                    wort_id: key.wort_id,
+                    antwort,
+                }],
+            )?;
+            Ok(())
+        },
+        |conn, card| editor::edit_wort(conn, card.wort.id),
+    )?;
+
+    if val_out == 1 {
+        return Ok(());
+    }
+
+    utils::clean_screen();
+    println!("Terminaste las palabras que confundes. :)");
+    println!();
+
+    Ok(())
+}
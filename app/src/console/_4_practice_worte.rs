@@ -2,13 +2,24 @@ use color_eyre::eyre::Result;
 use rusqlite::Connection;
 
 use crate::{
-    console::{_4_1_neue_worte::menu_4_1_neue_worte, _4_2_worte_review::menu_4_2_worte_review},
+    console::{
+        _4_1_neue_worte::menu_4_1_neue_worte, _4_2_worte_review::menu_4_2_worte_review,
+        _4_3_tags_worte::menu_4_3_tags_worte, _4_4_konjugation::menu_4_4_konjugation,
+        _4_5_deklination::menu_4_5_deklination, _4_6_praepositionen::menu_4_6_praepositionen,
+        _4_7_steigerung::menu_4_7_steigerung, _4_8_verwechslung::menu_4_8_verwechslung,
+    },
     helpers::ui,
 };
 
 const TEXT_MENU: &str = r##"¿Cuál entrenamiento quieres realizar?
   1. Palabras nuevas.
   2. Repetición de palabras.
+  3. Palabras por etiquetas.
+  4. Conjugación de verbos.
+  5. Declinación de sustantivos y adjetivos.
+  6. Preposiciones y casos.
+  7. Comparativo y superlativo de adjetivos.
+  8. Palabras que confundes.
 
 Para regresar al menú principal favor de escribir "exit".
 "##;
@@ -27,6 +38,12 @@ pub fn menu_4_practice_worte(conn: &mut Connection) -> Result<()> {
         match input {
             "1" => menu_4_1_neue_worte(conn)?,
             "2" => menu_4_2_worte_review(conn)?,
+            "3" => menu_4_3_tags_worte(conn)?,
+            "4" => menu_4_4_konjugation(conn)?,
+            "5" => menu_4_5_deklination(conn)?,
+            "6" => menu_4_6_praepositionen(conn)?,
+            "7" => menu_4_7_steigerung(conn)?,
+            "8" => menu_4_8_verwechslung(conn)?,
             "exit" => return Ok(()),
             _ => println!("Comando no reconocido!!"),
         }
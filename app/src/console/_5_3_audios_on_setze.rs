@@ -0,0 +1,16 @@
+use color_eyre::eyre::Result;
+use rusqlite::Connection;
+
+use crate::{db::setze::SetzeRepo, helpers::audios};
+
+pub fn menu_5_3_audios_on_setze(conn: &mut Connection) -> Result<()> {
+    let setze_without_audio: Vec<_> = SetzeRepo::fetch_setze_without_audio(conn)?;
+
+    audios::generate::generate_setze(conn, &setze_without_audio, true)?;
+
+    println!();
+    println!("Descarga de audios completada :).");
+    println!();
+
+    Ok(())
+}
@@ -1,7 +1,13 @@
 use color_eyre::eyre::Result;
 use rusqlite::Connection;
 
-use crate::{console::_5_2_audios_on_worte::menu_5_2_audios_on_worte, helpers::ui};
+use crate::{
+    console::{
+        _5_2_audios_on_worte::menu_5_2_audios_on_worte,
+        _5_3_audios_on_setze::menu_5_3_audios_on_setze,
+    },
+    helpers::ui,
+};
 
 const TEXT_MENU: &str = r##"¿Cuál entrenamiento quieres realizar?
   1. Borrar audios no ocupados.
@@ -24,6 +30,7 @@ pub fn menu_5_manage_audios(conn: &mut Connection) -> Result<()> {
 
         match input {
             "2" => menu_5_2_audios_on_worte(conn)?,
+            "3" => menu_5_3_audios_on_setze(conn)?,
             "exit" => return Ok(()),
             _ => println!("Comando no reconocido!!"),
         }
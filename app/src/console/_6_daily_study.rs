@@ -0,0 +1,208 @@
+use std::collections::{HashMap, HashSet};
+
+use color_eyre::eyre::Result;
+use rusqlite::Connection;
+
+use crate::{
+    db::{
+        schemas::{
+            setze_alternativ::NewSetzeAlternativSchema, worte_alternativ::NewWorteAlternativSchema,
+        },
+        setze_alternativ::SetzeAlternativRepo,
+        setze_audio::SetzeAudioRepo,
+        worte_alternativ::WorteAlternativRepo,
+        worte_audio::WorteAudioRepo,
+    },
+    helpers::{
+        audios::{ManageAudios, audio_player::AudioPlayer},
+        console,
+        daily::{self, DailyPlan, StudyCard, StudyKey},
+        deck, editor,
+        session::Session,
+        settings, ui,
+    },
+    utils,
+};
+
+const TEXT_DAILY_ONCE: &str = r##"
+Para salir pon la palara "exit".                 Faltantes: {remainding}
+{commands}
+Algunas letras que te pueden ayudar. :)
+          - ß ẞ ä ö ü Ä Ö Ü 
+
+  {label}: {info}
+  {kind}: {question}
+
+Por favor traducela...
+"##;
+
+pub fn menu_6_daily_study(conn: &mut Connection) -> Result<()> {
+    let settings = settings::get();
+    let offset = settings.session.daily;
+    let default_plan = DailyPlan {
+        worte_ratio: settings.daily.worte_ratio,
+        setze_ratio: settings.daily.setze_ratio,
+        ..DailyPlan::default()
+    }
+    .with_deck(&deck::active_settings(conn)?, deck::active_scope(conn)?);
+
+    println!(
+        "Proporción palabras:oraciones (enter para {}:{})",
+        default_plan.worte_ratio, default_plan.setze_ratio
+    );
+    let plan = loop {
+        let Some(input) = ui::prompt("> ")? else {
+            return Ok(());
+        };
+        if input.is_empty() {
+            break default_plan.clone();
+        }
+        match default_plan.clone().with_ratio(&input) {
+            Some(plan) => break plan,
+            None => println!("Formato no válido, ejem: \"2:1\""),
+        }
+    };
+
+    // 1) Armamos la cola intercalada de palabras y oraciones
+    let keys = daily::build_queue(conn, &plan)?;
+    if keys.is_empty() {
+        println!("No hay nada por estudiar hoy. :)");
+        return Ok(());
+    }
+
+    let mut ids_worte: Vec<i32> = vec![];
+    let mut ids_setze: Vec<i32> = vec![];
+    for k in &keys {
+        match k {
+            StudyKey::Wort(id) => ids_worte.push(*id),
+            StudyKey::Satz(id) => ids_setze.push(*id),
+        }
+    }
+
+    let hash_audios_worte: HashSet<i32> = WorteAudioRepo::fetch_by_id(conn, &ids_worte)?
+        .into_iter()
+        .map(|a| a.wort_id)
+        .collect();
+    let hash_audios_setze: HashSet<i32> = SetzeAudioRepo::fetch_by_id(conn, &ids_setze)?
+        .into_iter()
+        .map(|a| a.satz_id)
+        .collect();
+
+    let mut alternatives: HashMap<StudyKey, Vec<String>> = HashMap::new();
+    for alt in WorteAlternativRepo::fetch_by_wort_id(conn, &ids_worte)? {
+        alternatives
+            .entry(StudyKey::Wort(alt.wort_id))
+            .or_default()
+            .push(alt.antwort);
+    }
+    for alt in SetzeAlternativRepo::fetch_by_satz_id(conn, &ids_setze)? {
+        alternatives
+            .entry(StudyKey::Satz(alt.satz_id))
+            .or_default()
+            .push(alt.antwort);
+    }
+
+    // 2) Una sola sesión para ambos tipos de tarjeta
+    let mut session: Session<StudyCard> =
+        Session::new(conn, keys, offset)?.with_alternatives(alternatives);
+
+    let player = AudioPlayer::new();
+    let val_out = console::run_session(
+        conn,
+        &mut session,
+        |card, remaining| {
+            let text = TEXT_DAILY_ONCE
+                .replace("{commands}", console::TEXT_COMMANDS)
+                .replace("{remainding}", &remaining.to_string());
+
+            let (text, audio) = match card {
+                StudyCard::Wort(w) => (
+                    text.replace("{label}", "Tipo")
+                        .replace(
+                            "{info}",
+                            &w.gram_type_id
+                                .iter()
+                                .map(|r| r.name.clone())
+                                .collect::<Vec<_>>()
+                                .join(", "),
+                        )
+                        .replace("{kind}", "Palabra")
+                        .replace("{question}", &w.worte_es),
+                    if hash_audios_worte.contains(&w.id) {
+                        ManageAudios::get_audio_worte(w.id).ok().flatten()
+                    } else {
+                        None
+                    },
+                ),
+                StudyCard::Satz(s) => (
+                    text.replace("{label}", "Tema")
+                        .replace("{info}", &s.thema)
+                        .replace("{kind}", "Oración")
+                        .replace("{question}", &s.setze_spanisch),
+                    if hash_audios_setze.contains(&s.id) {
+                        ManageAudios::get_audio_setze(s.id).ok().flatten()
+                    } else {
+                        None
+                    },
+                ),
+            };
+
+            println!("{}", text);
+            if let Some(path) = audio {
+                player.play(path)?;
+            }
+            Ok(())
+        },
+        |card, _| {
+            if let StudyCard::Wort(w) = card {
+                println!();
+                println!("Ejemplo: {}", w.example_de);
+                println!("Traducción: {}", w.example_es);
+            }
+        },
+        |conn, key, antwort| {
+            match key {
+                StudyKey::Wort(wort_id) => {
+                    WorteAlternativRepo::bulk_insert(
+                        conn,
+                        &[NewWorteAlternativSchema { wort_id, antwort }],
+                    )?;
+                }
+                StudyKey::Satz(satz_id) => {
+                    SetzeAlternativRepo::bulk_insert(
+                        conn,
+                        &[NewSetzeAlternativSchema { satz_id, antwort }],
+                    )?;
+                }
+            }
+            Ok(())
+        },
+        |conn, card| match card {
+            StudyCard::Wort(w) => editor::edit_wort(conn, w.id),
+            StudyCard::Satz(s) => editor::edit_satz(conn, s.id),
+        },
+    )?;
+
+    // 3) Guardamos las revisiones en la tabla que le toca a cada tarjeta
+    let mut results_worte: Vec<(i32, u8)> = vec![];
+    let mut results_setze: Vec<(i32, u8)> = vec![];
+    for (key, quality) in session.into_results() {
+        match key {
+            StudyKey::Wort(id) => results_worte.push((id, quality)),
+            StudyKey::Satz(id) => results_setze.push((id, quality)),
+        }
+    }
+
+    console::save_worte_review(conn, results_worte)?;
+    console::save_setze_review(conn, results_setze)?;
+
+    if val_out == 1 {
+        return Ok(());
+    }
+
+    utils::clean_screen();
+    println!("Terminaste el estudio de hoy. :)");
+    println!();
+
+    Ok(())
+}
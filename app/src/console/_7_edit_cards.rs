@@ -0,0 +1,147 @@
+use color_eyre::eyre::Result;
+use inquire::{MultiSelect, Select};
+use rusqlite::Connection;
+
+use crate::{
+    db::{setze::SetzeRepo, worte::WorteRepo},
+    helpers::{editor, ui},
+};
+
+const TEXT_MENU: &str = r##"¿Qué quieres hacer?
+  1. Editar o borrar una palabra.
+  2. Editar o borrar una oración.
+  3. Restaurar palabras borradas.
+  4. Restaurar oraciones borradas.
+
+Para regresar al menú principal favor de escribir "exit".
+"##;
+
+pub fn menu_7_edit_cards(conn: &mut Connection) -> Result<()> {
+    loop {
+        println!("{}", TEXT_MENU);
+
+        let Some(input) = ui::prompt_nonempty("> ")? else {
+            break;
+        };
+
+        match input.trim() {
+            "1" => {
+                if let Some(id) = select_wort(conn)? {
+                    editor::edit_wort(conn, id)?;
+                }
+            }
+            "2" => {
+                if let Some(id) = select_satz(conn)? {
+                    editor::edit_satz(conn, id)?;
+                }
+            }
+            "3" => restore_worte(conn)?,
+            "4" => restore_setze(conn)?,
+            "exit" => return Ok(()),
+            _ => println!("Comando no reconocido!!"),
+        }
+    }
+
+    Ok(())
+}
+
+/// Busca por id o por texto y deja escoger una de las coincidencias.
+fn select_wort(conn: &Connection) -> Result<Option<i32>> {
+    println!("Escribe el id o parte de la palabra (alemán o español):");
+    let Some(input) = ui::prompt_nonempty("> ")? else {
+        return Ok(None);
+    };
+
+    if let Ok(id) = input.trim().parse::<i32>() {
+        return Ok(Some(id));
+    }
+
+    let options: Vec<String> = WorteRepo::fetch_by_text(conn, &input)?
+        .iter()
+        .map(|w| format!("{} - {} - {}", w.id, w.worte_de, w.worte_es))
+        .collect();
+
+    select_id(options)
+}
+
+fn select_satz(conn: &Connection) -> Result<Option<i32>> {
+    println!("Escribe el id o parte de la oración (alemán o español):");
+    let Some(input) = ui::prompt_nonempty("> ")? else {
+        return Ok(None);
+    };
+
+    if let Ok(id) = input.trim().parse::<i32>() {
+        return Ok(Some(id));
+    }
+
+    let options: Vec<String> = SetzeRepo::fetch_by_text(conn, &input)?
+        .iter()
+        .map(|s| format!("{} - {} - {}", s.id, s.setze_deutsch, s.setze_spanisch))
+        .collect();
+
+    select_id(options)
+}
+
+/// Las opciones tienen el formato "id - ...".
+fn parse_id(option: &str) -> Option<i32> {
+    option.split(" - ").next()?.parse().ok()
+}
+
+fn select_id(options: Vec<String>) -> Result<Option<i32>> {
+    if options.is_empty() {
+        println!("No se encontraron coincidencias.");
+        return Ok(None);
+    }
+
+    let ans = Select::new("Selecciona una opción:", options)
+        .with_page_size(20)
+        .prompt_skippable()?;
+
+    Ok(ans.as_deref().and_then(parse_id))
+}
+
+fn restore_worte(conn: &mut Connection) -> Result<()> {
+    let options: Vec<String> = WorteRepo::fetch_deleted(conn)?
+        .iter()
+        .map(|w| format!("{} - {} - {}", w.id, w.worte_de, w.worte_es))
+        .collect();
+
+    if options.is_empty() {
+        println!("No hay palabras borradas.");
+        return Ok(());
+    }
+
+    let ans = MultiSelect::new("Selecciona las palabras a restaurar:", options)
+        .with_page_size(20)
+        .prompt_skippable()?
+        .unwrap_or_default();
+
+    let ids: Vec<i32> = ans.iter().filter_map(|o| parse_id(o)).collect();
+    let n = WorteRepo::restore(conn, &ids)?;
+    println!("{} palabras restauradas.", n);
+
+    Ok(())
+}
+
+fn restore_setze(conn: &mut Connection) -> Result<()> {
+    let options: Vec<String> = SetzeRepo::fetch_deleted(conn)?
+        .iter()
+        .map(|s| format!("{} - {} - {}", s.id, s.setze_deutsch, s.setze_spanisch))
+        .collect();
+
+    if options.is_empty() {
+        println!("No hay oraciones borradas.");
+        return Ok(());
+    }
+
+    let ans = MultiSelect::new("Selecciona las oraciones a restaurar:", options)
+        .with_page_size(20)
+        .prompt_skippable()?
+        .unwrap_or_default();
+
+    let ids: Vec<i32> = ans.iter().filter_map(|o| parse_id(o)).collect();
+    let n = SetzeRepo::restore(conn, &ids)?;
+    println!("{} oraciones restauradas.", n);
+
+    Ok(())
+}
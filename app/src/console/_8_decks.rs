@@ -0,0 +1,98 @@
+use color_eyre::eyre::Result;
+use inquire::Select;
+use rusqlite::Connection;
+
+use crate::{
+    db::{deck::DeckRepo, schemas::deck::DeckSettings},
+    helpers::{deck, ui},
+};
+
+const TEXT_MENU: &str = r##"¿Qué quieres hacer?
+  1. Escoger el deck para practicar.
+  2. Practicar con todos los decks.
+  3. Crear un deck.
+  4. Configurar un deck.
+
+Los subdecks se separan con "::", ejem: "Goethe B1::Kapitel 4".
+Al escoger un deck también se practican sus subdecks.
+
+Para regresar al menú principal favor de escribir "exit".
+"##;
+
+const TEXT_SETTINGS: &str = r##"
+Para dejar un valor igual presiona enter.
+  - max_neue_worte / max_neue_setze: tarjetas nuevas por día en el estudio diario.
+  - initial_ease: facilidad con la que empieza una tarjeta nueva (mínimo 1.3).
+  - interval_modifier: multiplica los intervalos a partir de la cuarta repetición.
+  - max_interval: intervalo máximo en días.
+"##;
+
+pub fn menu_8_decks(conn: &mut Connection) -> Result<()> {
+    loop {
+        println!("Deck actual: {}", deck::active_deck_name(conn)?);
+        println!("{}", TEXT_MENU);
+
+        let Some(input) = ui::prompt_nonempty("> ")? else {
+            break;
+        };
+
+        match input.trim() {
+            "1" => {
+                if let Some(id) = select_deck(conn, "Deck para practicar:")? {
+                    deck::set_active_deck(Some(id));
+                }
+            }
+            "2" => deck::set_active_deck(None),
+            "3" => {
+                if let Some(d) = deck::prompt_deck(conn, "Escoge \"+ Nuevo deck\":")? {
+                    println!("Deck listo: {}", d.name);
+                }
+            }
+            "4" => {
+                if let Some(id) = select_deck(conn, "Deck a configurar:")? {
+                    configure_deck(conn, id)?;
+                }
+            }
+            "exit" => return Ok(()),
+            _ => println!("Comando no reconocido!!"),
+        }
+    }
+
+    Ok(())
+}
+
+/// Muestra los decks como árbol y regresa el id del escogido.
+fn select_deck(conn: &Connection, title: &str) -> Result<Option<i32>> {
+    let decks = DeckRepo::fetch_all(conn)?;
+    let options: Vec<String> = decks.iter().map(deck::display_name).collect();
+
+    let ans = Select::new(title, options.clone())
+        .with_page_size(20)
+        .prompt_skippable()?;
+
+    Ok(ans
+        .and_then(|a| options.iter().position(|o| *o == a))
+        .map(|i| decks[i].id))
+}
+
+fn configure_deck(conn: &Connection, id: i32) -> Result<()> {
+    let Some(d) = DeckRepo::fetch_by_id(conn, &[id])?.pop() else {
+        return Ok(());
+    };
+    let current = d.settings();
+
+    println!("Configuración de: {}", d.name);
+    println!("{}", TEXT_SETTINGS);
+
+    let settings = DeckSettings {
+        max_neue_worte: ui::prompt_value("max_neue_worte", current.max_neue_worte)?,
+        max_neue_setze: ui::prompt_value("max_neue_setze", current.max_neue_setze)?,
+        initial_ease: ui::prompt_value("initial_ease", current.initial_ease)?.max(1.3),
+        interval_modifier: ui::prompt_value("interval_modifier", current.interval_modifier)?,
+        max_interval: ui::prompt_value("max_interval", current.max_interval)?.max(1),
+    };
+
+    DeckRepo::update_settings(conn, id, &settings)?;
+    println!("Configuración guardada.");
+    Ok(())
+}
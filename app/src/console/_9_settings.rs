@@ -0,0 +1,138 @@
+use color_eyre::eyre::Result;
+use rusqlite::Connection;
+
+use crate::helpers::{
+    profile,
+    settings::{self, Settings},
+    ui,
+};
+
+const TEXT_MENU: &str = r##"¿Qué quieres hacer?
+  1. Ver la configuración.
+  2. Editar tarjetas por bloque.
+  3. Editar el estudio diario.
+  4. Editar el TTS (ElevenLabs).
+  5. Editar la comparación de oraciones.
+  6. Editar el avance por nivel.
+  7. Editar la lista de frecuencia.
+  8. Recargar el archivo.
+  9. Restaurar los valores por defecto.
+
+También puedes editar el archivo directamente y recargarlo.
+
+Para regresar al menú principal favor de escribir "exit".
+"##;
+
+pub fn menu_9_settings(_conn: &mut Connection) -> Result<()> {
+    let path = &profile::paths().config;
+
+    loop {
+        println!("Archivo: {}", path.display());
+        println!("{}", TEXT_MENU);
+
+        let Some(input) = ui::prompt_nonempty("> ")? else {
+            break;
+        };
+
+        let mut current = settings::get();
+        let edited = match input.trim() {
+            "1" => {
+                println!("{}", current.to_toml()?);
+                None
+            }
+            "2" => {
+                println!("Para dejar un valor igual presiona enter.");
+                let s = &mut current.session;
+                s.setze_random = ui::prompt_value("setze_random", s.setze_random)?;
+                s.setze_thema = ui::prompt_value("setze_thema", s.setze_thema)?;
+                s.setze_schwirig = ui::prompt_value("setze_schwirig", s.setze_schwirig)?;
+                s.setze_neue = ui::prompt_value("setze_neue", s.setze_neue)?;
+                s.setze_scramble = ui::prompt_value("setze_scramble", s.setze_scramble)?;
+                s.setze_tags = ui::prompt_value("setze_tags", s.setze_tags)?;
+                s.worte_neue = ui::prompt_value("worte_neue", s.worte_neue)?;
+                s.worte_review = ui::prompt_value("worte_review", s.worte_review)?;
+                s.worte_tags = ui::prompt_value("worte_tags", s.worte_tags)?;
+                s.konjugation = ui::prompt_value("konjugation", s.konjugation)?;
+                s.deklination = ui::prompt_value("deklination", s.deklination)?;
+                s.praeposition = ui::prompt_value("praeposition", s.praeposition)?;
+                s.steigerung = ui::prompt_value("steigerung", s.steigerung)?;
+                s.verwechslung = ui::prompt_value("verwechslung", s.verwechslung)?;
+                s.daily = ui::prompt_value("daily", s.daily)?;
+                Some(current)
+            }
+            "3" => {
+                println!("Para dejar un valor igual presiona enter.");
+                let d = &mut current.daily;
+                d.worte_ratio = ui::prompt_value("worte_ratio", d.worte_ratio)?;
+                d.setze_ratio = ui::prompt_value("setze_ratio", d.setze_ratio)?;
+                Some(current)
+            }
+            "4" => {
+                println!("Para dejar un valor igual presiona enter.");
+                let t = &mut current.tts;
+                t.model_id = ui::prompt_value("model_id", t.model_id.clone())?;
+                t.output_format = ui::prompt_value("output_format", t.output_format.clone())?;
+                t.stability = ui::prompt_value("stability", t.stability)?;
+                t.similarity_boost = ui::prompt_value("similarity_boost", t.similarity_boost)?;
+                t.style = ui::prompt_value("style", t.style)?;
+                t.use_speaker_boost = ui::prompt_value("use_speaker_boost", t.use_speaker_boost)?;
+                t.voice_id_de = ui::prompt_value("voice_id_de", t.voice_id_de.clone())?;
+                t.voice_id_es = ui::prompt_value("voice_id_es", t.voice_id_es.clone())?;
+                t.voice_label_de = ui::prompt_value("voice_label_de", t.voice_label_de.clone())?;
+                t.voice_label_es = ui::prompt_value("voice_label_es", t.voice_label_es.clone())?;
+                Some(current)
+            }
+            "5" => {
+                println!("Para dejar un valor igual presiona enter.");
+                let v = &mut current.vergleich;
+                v.kontraktionen = ui::prompt_value("kontraktionen", v.kontraktionen)?;
+                v.zahlen = ui::prompt_value("zahlen", v.zahlen)?;
+                v.trennbare_verben = ui::prompt_value("trennbare_verben", v.trennbare_verben)?;
+                Some(current)
+            }
+            "6" => {
+                println!("Para dejar un valor igual presiona enter.");
+                let n = &mut current.niveau;
+                n.unlock = ui::prompt_value("unlock", n.unlock)?;
+                n.mastery_percent = ui::prompt_value("mastery_percent", n.mastery_percent)?;
+                Some(current)
+            }
+            "7" => {
+                println!("Para dejar un valor igual presiona enter.");
+                let f = &mut current.frequenz;
+                f.order_neue = ui::prompt_value("order_neue", f.order_neue)?;
+                f.top_n = ui::prompt_value("top_n", f.top_n)?;
+                Some(current)
+            }
+            "8" => {
+                match Settings::load(path) {
+                    Ok(s) => {
+                        settings::set(s);
+                        println!("Configuración recargada.");
+                    }
+                    Err(err) => println!("{:#}", err),
+                }
+                None
+            }
+            "9" => Some(Settings::default()),
+            "exit" => return Ok(()),
+            _ => {
+                println!("Comando no reconocido!!");
+                None
+            }
+        };
+
+        if let Some(s) = edited {
+            // Si no es válida no se guarda y se conserva la anterior
+            match s.save(path) {
+                Ok(()) => {
+                    settings::set(s);
+                    println!("Configuración guardada.");
+                }
+                Err(err) => println!("No se guardó: {:#}", err),
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use color_eyre::eyre::Result;
+use rusqlite::Connection;
 
 use crate::{db::get_conn, helpers::ui};
 
@@ -7,13 +8,26 @@ mod _2_1_random_sentences;
 mod _2_2_select_thema;
 mod _2_3_schwirig_sentences;
 mod _2_4_neue_setze;
+mod _2_7_scramble_setze;
+mod _2_8_tags_setze;
 mod _2_practice_sentences;
 mod _3_add_worte;
 mod _4_1_neue_worte;
 mod _4_2_worte_review;
+mod _4_3_tags_worte;
+mod _4_4_konjugation;
+mod _4_5_deklination;
+mod _4_6_praepositionen;
+mod _4_7_steigerung;
+mod _4_8_verwechslung;
 mod _4_practice_worte;
 mod _5_2_audios_on_worte;
+mod _5_3_audios_on_setze;
 mod _5_manage_audios;
+mod _6_daily_study;
+mod _7_edit_cards;
+mod _8_decks;
+mod _9_settings;
 
 const MENU_MAIN_TEXT: &str = r#"
 Herzliche Willkommen zu meinem Programm.
@@ -23,10 +37,37 @@ Herzliche Willkommen zu meinem Programm.
     3.- Hinzufügen neue Worte.
     4.- Üben Worte.
     5.- Manage Audios.
+    6.- Tägliches Lernen (palabras y oraciones).
+    7.- Bearbeiten Worte und Sätze.
+    8.- Decks.
+    9.- Einstellungen (configuración).
 
 Para salir favor de escribir "exit"
 "#;
 
+pub type ReviewMenu = fn(&mut Connection) -> Result<()>;
+
+/// Sesiones que se pueden abrir directo con `anki_sentences review --mode <modo>`.
+pub const REVIEW_MODES: [(&str, ReviewMenu); 11] = [
+    ("words-due", _4_2_worte_review::menu_4_2_worte_review),
+    ("words-new", _4_1_neue_worte::menu_4_1_neue_worte),
+    ("conjugation", _4_4_konjugation::menu_4_4_konjugation),
+    ("declension", _4_5_deklination::menu_4_5_deklination),
+    ("prepositions", _4_6_praepositionen::menu_4_6_praepositionen),
+    ("comparison", _4_7_steigerung::menu_4_7_steigerung),
+    ("confusables", _4_8_verwechslung::menu_4_8_verwechslung),
+    (
+        "sentences-random",
+        _2_1_random_sentences::menu_2_1_random_sentences,
+    ),
+    (
+        "sentences-schwirig",
+        _2_3_schwirig_sentences::menu_2_3_schwirig_sentences,
+    ),
+    ("sentences-new", _2_4_neue_setze::menu_2_4_neue_sentences),
+    ("daily", _6_daily_study::menu_6_daily_study),
+];
+
 pub fn menu_main() -> Result<()> {
     // clean_screen();
 
@@ -44,6 +85,10 @@ pub fn menu_main() -> Result<()> {
             "3" => _3_add_worte::menu_3_add_worte(&mut conn)?,
             "4" => _4_practice_worte::menu_4_practice_worte(&mut conn)?,
             "5" => _5_manage_audios::menu_5_manage_audios(&mut conn)?,
+            "6" => _6_daily_study::menu_6_daily_study(&mut conn)?,
+            "7" => _7_edit_cards::menu_7_edit_cards(&mut conn)?,
+            "8" => _8_decks::menu_8_decks(&mut conn)?,
+            "9" => _9_settings::menu_9_settings(&mut conn)?,
             "exit" => return Ok(()),
             _ => println!("Comando no reconocido"),
         };
@@ -5,10 +5,12 @@ use color_eyre::eyre::Result;
 use once_cell::sync::Lazy;
 use rusqlite::Connection;
 
-const DB_NAME: &str = "anki_satze.sql";
+use crate::helpers::profile;
 
+/// Conexión a la base del perfil activo (ver `helpers::profile`).
 pub static DB_CONN: Lazy<Mutex<Connection>> = Lazy::new(|| {
-    let conn = Connection::open(DB_NAME).expect("No se puede abrir/crear la base de datos SQLite");
+    let conn = Connection::open(&profile::paths().db)
+        .expect("No se puede abrir/crear la base de datos SQLite");
     Mutex::new(conn)
 });
 
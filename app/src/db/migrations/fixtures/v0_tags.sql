@@ -0,0 +1,268 @@
+-- Base creada después de agregar alternativas y etiquetas.
+BEGIN TRANSACTION;
+CREATE TABLE gram_type(
+    id              INTEGER PRIMARY KEY,
+    code            TEXT UNIQUE NOT NULL,
+    name            TEXT NOT NULL,
+    created_at      TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at      TEXT
+);
+INSERT INTO "gram_type" VALUES(0,'noun_common','Sustantivo comun','2026-10-19 07:21:53',NULL);
+CREATE TABLE niveau_liste (
+        id                  INTEGER PRIMARY KEY AUTOINCREMENT,
+        niveau              TEXT NOT NULL,                         
+
+        -- Generic
+        created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+        deleted_at          TEXT
+    );
+INSERT INTO "niveau_liste" VALUES(0,'A1','2026-10-19 07:21:53',NULL);
+INSERT INTO "niveau_liste" VALUES(1,'A2','2026-10-19 07:21:53',NULL);
+INSERT INTO "niveau_liste" VALUES(2,'B1','2026-10-19 07:21:53',NULL);
+INSERT INTO "niveau_liste" VALUES(3,'B2','2026-10-19 07:21:53',NULL);
+INSERT INTO "niveau_liste" VALUES(4,'C1','2026-10-19 07:21:53',NULL);
+INSERT INTO "niveau_liste" VALUES(5,'C2','2026-10-19 07:21:53',NULL);
+CREATE TABLE setze (
+    id                  INTEGER PRIMARY KEY AUTOINCREMENT,
+    setze_spanisch      TEXT NOT NULL,
+    setze_deutsch       TEXT NOT NULL,
+    thema               TEXT NOT NULL,
+    niveau_id           INTEGER NOT NULL,
+    created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at          TEXT,
+
+    FOREIGN KEY(niveau_id) REFERENCES niveau_liste(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+);
+INSERT INTO "setze" VALUES(1,'Tengo un perro','Ich habe einen Hund','Akkusativ',0,'2026-10-19 07:21:53',NULL);
+INSERT INTO "setze" VALUES(2,'Aunque llueve salgo','Obwohl es regnet, gehe ich raus','Nebensatz',2,'2026-10-19 07:21:53',NULL);
+INSERT INTO "setze" VALUES(3,'Me acuerdo de ti','Ich erinnere mich an dich','Reflexiv',4,'2026-10-19 07:21:53',NULL);
+CREATE TABLE setze_alternativ(
+    id           INTEGER PRIMARY KEY AUTOINCREMENT,
+    satz_id      INTEGER NOT NULL,
+    antwort      TEXT NOT NULL,
+
+    -- Generic
+    created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at          TEXT,
+
+    UNIQUE(satz_id, antwort),
+
+    FOREIGN KEY (satz_id) REFERENCES setze(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+);
+CREATE TABLE setze_audio(
+    satz_id      INTEGER PRIMARY KEY,
+    file_path    TEXT NOT NULL,
+    voice_id     TEXT NOT NULL,
+
+    -- Generic
+    created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at          TEXT,
+
+    FOREIGN KEY (satz_id) REFERENCES setze(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+);
+CREATE TABLE setze_review (
+    id              INTEGER PRIMARY KEY AUTOINCREMENT,
+    satz_id         INTEGER NOT NULL UNIQUE,
+    interval        INTEGER NOT NULL,
+    ease_factor     REAL    NOT NULL,
+    repetitions     INTEGER NOT NULL,
+    last_review     TEXT NOT NULL,
+    next_review     TEXT NOT NULL,
+
+    created_at      TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at      TEXT,
+
+    FOREIGN KEY(satz_id) REFERENCES setze(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+);
+INSERT INTO "setze_review" VALUES(1,2,4,2.36,3,'2025-01-10 12:00:00','2025-01-14 12:00:00','2026-10-19 07:21:53',NULL);
+CREATE TABLE setze_tags(
+    satz_id      INTEGER NOT NULL,
+    tag_id       INTEGER NOT NULL,
+
+    -- Generic
+    created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at          TEXT,
+
+    PRIMARY KEY(satz_id, tag_id),
+
+    FOREIGN KEY (satz_id) REFERENCES setze(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE,
+
+    FOREIGN KEY (tag_id) REFERENCES tags(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+);
+INSERT INTO "setze_tags" VALUES(1,1,'2026-10-19 07:21:53',NULL);
+CREATE TABLE tags(
+    id           INTEGER PRIMARY KEY AUTOINCREMENT,
+    name         TEXT NOT NULL UNIQUE COLLATE NOCASE,
+
+    -- Generic
+    created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at          TEXT
+);
+INSERT INTO "tags" VALUES(1,'Akkusativ','2026-10-19 07:21:53',NULL);
+CREATE TABLE worte(
+    id                  INTEGER PRIMARY KEY AUTOINCREMENT,
+    gender_id           INTEGER,                            -- table worte_gender
+    wort_de             TEXT NOT NULL,                   
+    wort_es             TEXT NOT NULL,                   
+    plural              TEXT,
+    niveau_id           INTEGER NOT NULL,                   -- table niveau_liste
+    example_de          TEXT,                   
+    example_es          TEXT,                   
+
+    -- nur verben
+    verb_aux TEXT,              -- 'sein' / 'haben' / NULL
+    trennbar BOOLEAN,           -- verbo separable
+    reflexiv BOOLEAN,
+
+    -- Generic
+    created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at          TEXT,
+
+    FOREIGN KEY(gender_id) REFERENCES worte_gender(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE,    
+
+    FOREIGN KEY(niveau_id) REFERENCES niveau_liste(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+);
+INSERT INTO "worte" VALUES(1,0,'Hund','perro','Hunde',0,'Der Hund bellt.','El perro ladra.',NULL,NULL,NULL,'2026-10-19 07:21:53',NULL);
+CREATE TABLE worte_alternativ(
+    id           INTEGER PRIMARY KEY AUTOINCREMENT,
+    wort_id      INTEGER NOT NULL,
+    antwort      TEXT NOT NULL,
+
+    -- Generic
+    created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at          TEXT,
+
+    UNIQUE(wort_id, antwort),
+
+    FOREIGN KEY (wort_id) REFERENCES worte(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+);
+CREATE TABLE worte_audio(
+    wort_id      INTEGER PRIMARY KEY,
+    file_path    TEXT NOT NULL,
+    voice_id     TEXT NOT NULL,
+
+    -- Generic
+    created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at          TEXT,
+
+    FOREIGN KEY (wort_id) REFERENCES worte(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+);
+CREATE TABLE worte_gender (
+        id                  INTEGER PRIMARY KEY AUTOINCREMENT,
+        gender              TEXT NOT NULL,                         
+        artikel             TEXT NOT NULL,
+
+        -- Generic
+        created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+        deleted_at          TEXT
+    );
+INSERT INTO "worte_gender" VALUES(0,'Maskuline','der','2026-10-19 07:21:53',NULL);
+INSERT INTO "worte_gender" VALUES(1,'Femenin','die','2026-10-19 07:21:53',NULL);
+INSERT INTO "worte_gender" VALUES(2,'Neutrum','das','2026-10-19 07:21:53',NULL);
+INSERT INTO "worte_gender" VALUES(3,'Plural','die','2026-10-19 07:21:53',NULL);
+CREATE TABLE worte_gram_type(
+    id_worte            INTEGER NOT NULL,
+    id_gram_type        INTEGER NOT NULL,
+
+    -- Generic
+    created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at          TEXT,
+
+    PRIMARY KEY(id_worte,id_gram_type),
+
+    FOREIGN KEY(id_worte) REFERENCES worte(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE,
+
+    FOREIGN KEY(id_gram_type) REFERENCES gram_type(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+);
+INSERT INTO "worte_gram_type" VALUES(1,0,'2026-10-19 07:21:53',NULL);
+CREATE TABLE worte_review (
+    id              INTEGER PRIMARY KEY AUTOINCREMENT,
+    wort_id         INTEGER NOT NULL UNIQUE,
+    interval        INTEGER NOT NULL,
+    ease_factor     REAL    NOT NULL,
+    repetitions     INTEGER NOT NULL,
+    last_review     TEXT NOT NULL,
+    next_review     TEXT NOT NULL,
+    created_at      TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at      TEXT,
+    FOREIGN KEY(wort_id) REFERENCES worte(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+);
+INSERT INTO "worte_review" VALUES(1,1,1,2.5,1,'2025-01-10 12:00:00','2025-01-11 12:00:00','2026-10-19 07:21:53',NULL);
+CREATE TABLE worte_tags(
+    wort_id      INTEGER NOT NULL,
+    tag_id       INTEGER NOT NULL,
+
+    -- Generic
+    created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at          TEXT,
+
+    PRIMARY KEY(wort_id, tag_id),
+
+    FOREIGN KEY (wort_id) REFERENCES worte(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE,
+
+    FOREIGN KEY (tag_id) REFERENCES tags(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+);
+INSERT INTO "worte_tags" VALUES(1,1,'2026-10-19 07:21:53',NULL);
+CREATE INDEX idx_gram_type_code ON gram_type(code)
+;
+CREATE INDEX idx_niveau_liste_created_at ON niveau_liste(created_at);
+CREATE INDEX idx_setze_setze_spanisch ON setze(setze_spanisch);
+CREATE INDEX idx_setze_setze_deutsch ON setze(setze_deutsch);
+CREATE INDEX idx_setze_thema ON setze(thema);
+CREATE INDEX idx_setze_niveau_id ON setze(niveau_id);
+CREATE INDEX idx_setze_alternativ_satz_id ON setze_alternativ(satz_id);
+CREATE INDEX idx_setze_audio_voice_id ON setze_audio(voice_id);
+CREATE UNIQUE INDEX idx_setze_review_satz_id ON setze_review(satz_id);
+CREATE INDEX idx_setze_review_next_review ON setze_review(next_review);
+CREATE INDEX idx_setze_tags_tag_id ON setze_tags(tag_id);
+CREATE INDEX idx_tags_name ON tags(name);
+CREATE INDEX idx_worte_created_at ON worte(created_at);
+CREATE INDEX idx_worte_gender_id ON worte(gender_id);
+CREATE INDEX idx_worte_niveau_id ON worte(niveau_id);
+CREATE INDEX idx_worte_alternativ_wort_id ON worte_alternativ(wort_id);
+CREATE INDEX idx_worte_audio_voice_id ON worte_audio(voice_id);
+CREATE INDEX idx_worte_gender_created_at ON worte_gender(created_at);
+CREATE INDEX idx_worte_gram_type_id_worte ON worte_gram_type(id_worte);
+CREATE INDEX idx_worte_gram_type_id_gram_type ON worte_gram_type(id_gram_type);
+CREATE UNIQUE INDEX idx_worte_review_wort_id ON worte_review(wort_id);
+CREATE INDEX idx_worte_review_next_review ON worte_review(next_review);
+CREATE INDEX idx_worte_tags_tag_id ON worte_tags(tag_id);
+DELETE FROM "sqlite_sequence";
+INSERT INTO "sqlite_sequence" VALUES('niveau_liste',5);
+INSERT INTO "sqlite_sequence" VALUES('worte_gender',3);
+INSERT INTO "sqlite_sequence" VALUES('setze',3);
+INSERT INTO "sqlite_sequence" VALUES('setze_review',1);
+INSERT INTO "sqlite_sequence" VALUES('worte',1);
+INSERT INTO "sqlite_sequence" VALUES('worte_review',1);
+INSERT INTO "sqlite_sequence" VALUES('tags',1);
+COMMIT;
@@ -0,0 +1,389 @@
+-- Base en la versión 3 (worte_setze), creada desde v0_schwirig con las migraciones de entonces.
+PRAGMA foreign_keys=OFF;
+BEGIN TRANSACTION;
+CREATE TABLE deck(
+    id                  INTEGER PRIMARY KEY AUTOINCREMENT,
+    name                TEXT NOT NULL UNIQUE COLLATE NOCASE,   -- ruta completa
+    parent_id           INTEGER,                               -- table deck
+
+    -- Configuración
+    max_neue_worte      INTEGER NOT NULL DEFAULT 10,
+    max_neue_setze      INTEGER NOT NULL DEFAULT 5,
+    initial_ease        REAL NOT NULL DEFAULT 2.5,
+    interval_modifier   REAL NOT NULL DEFAULT 1.0,
+    max_interval        INTEGER NOT NULL DEFAULT 36500,         -- días
+
+    -- Generic
+    created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at          TEXT,
+
+    FOREIGN KEY(parent_id) REFERENCES deck(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+);
+INSERT INTO deck VALUES(1,'Default',NULL,10,5,2.5,1.0,36500,'2026-10-19 07:21:53',NULL);
+INSERT INTO deck VALUES(2,'Goethe B1',NULL,10,5,2.5,1.0,36500,'2026-10-19 07:21:53',NULL);
+CREATE TABLE gram_type(
+    id              INTEGER PRIMARY KEY,
+    code            TEXT UNIQUE NOT NULL,
+    name            TEXT NOT NULL,
+    created_at      TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at      TEXT
+);
+INSERT INTO gram_type VALUES(0,'noun_common','Sustantivo comun','2026-10-19 07:21:53',NULL);
+CREATE TABLE niveau_liste (
+        id                  INTEGER PRIMARY KEY AUTOINCREMENT,
+        niveau              TEXT NOT NULL,                         
+
+        -- Generic
+        created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+        deleted_at          TEXT
+    );
+INSERT INTO niveau_liste VALUES(0,'A1','2026-10-19 07:21:53',NULL);
+INSERT INTO niveau_liste VALUES(1,'A2','2026-10-19 07:21:53',NULL);
+INSERT INTO niveau_liste VALUES(2,'B1','2026-10-19 07:21:53',NULL);
+INSERT INTO niveau_liste VALUES(3,'B2','2026-10-19 07:21:53',NULL);
+INSERT INTO niveau_liste VALUES(4,'C1','2026-10-19 07:21:53',NULL);
+INSERT INTO niveau_liste VALUES(5,'C2','2026-10-19 07:21:53',NULL);
+CREATE TABLE schwirig_liste (
+        id                  INTEGER PRIMARY KEY AUTOINCREMENT,
+        schwirig            TEXT NOT NULL,
+
+        -- Generic
+        created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+        deleted_at          TEXT
+    );
+INSERT INTO schwirig_liste VALUES(0,'Einfach','2026-10-19 07:21:53',NULL);
+INSERT INTO schwirig_liste VALUES(1,'Normal','2026-10-19 07:21:53',NULL);
+INSERT INTO schwirig_liste VALUES(2,'Schwirig','2026-10-19 07:21:53',NULL);
+CREATE TABLE setze (
+    id                  INTEGER PRIMARY KEY AUTOINCREMENT,
+    setze_spanisch      TEXT NOT NULL,
+    setze_deutsch       TEXT NOT NULL,
+    thema               TEXT NOT NULL,
+    niveau_id           INTEGER NOT NULL,                   -- table niveau_liste (CEFR)
+    schwirig_id         INTEGER NOT NULL DEFAULT 1,         -- table schwirig_liste
+    deck_id             INTEGER NOT NULL DEFAULT 1,         -- table deck
+    created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at          TEXT,
+
+    FOREIGN KEY(niveau_id) REFERENCES niveau_liste(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE,
+
+    FOREIGN KEY(schwirig_id) REFERENCES schwirig_liste(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE,
+
+    FOREIGN KEY(deck_id) REFERENCES deck(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+);
+INSERT INTO setze VALUES(1,'Tengo un perro','Ich habe einen Hund','Akkusativ',0,0,1,'2026-10-19 07:21:53',NULL);
+INSERT INTO setze VALUES(2,'Aunque llueve salgo','Obwohl es regnet, gehe ich raus','Nebensatz',2,2,1,'2026-10-19 07:21:53',NULL);
+INSERT INTO setze VALUES(3,'Me acuerdo de ti','Ich erinnere mich an dich','Reflexiv',4,2,2,'2026-10-19 07:21:53',NULL);
+CREATE TABLE setze_alternativ(
+    id           INTEGER PRIMARY KEY AUTOINCREMENT,
+    satz_id      INTEGER NOT NULL,
+    antwort      TEXT NOT NULL,
+
+    -- Generic
+    created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at          TEXT,
+
+    UNIQUE(satz_id, antwort),
+
+    FOREIGN KEY (satz_id) REFERENCES setze(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+);
+CREATE TABLE setze_audio(
+    satz_id      INTEGER PRIMARY KEY,
+    file_path    TEXT NOT NULL,
+    voice_id     TEXT NOT NULL,
+
+    -- Generic
+    created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at          TEXT,
+
+    FOREIGN KEY (satz_id) REFERENCES setze(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+);
+CREATE TABLE setze_review (
+    id              INTEGER PRIMARY KEY AUTOINCREMENT,
+    satz_id         INTEGER NOT NULL UNIQUE,
+    interval        INTEGER NOT NULL,
+    ease_factor     REAL    NOT NULL,
+    repetitions     INTEGER NOT NULL,
+    last_review     TEXT NOT NULL,
+    next_review     TEXT NOT NULL,
+    lapses          INTEGER NOT NULL DEFAULT 0,     -- veces que se falló
+    schwirig_id     INTEGER,                        -- table schwirig_liste, calculada con las revisiones
+
+    created_at      TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at      TEXT,
+
+    FOREIGN KEY(satz_id) REFERENCES setze(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+);
+INSERT INTO setze_review VALUES(1,2,4,2.359999999999999876,3,'2025-01-10 12:00:00','2025-01-14 12:00:00',0,NULL,'2026-10-19 07:21:53',NULL);
+CREATE TABLE setze_tags(
+    satz_id      INTEGER NOT NULL,
+    tag_id       INTEGER NOT NULL,
+
+    -- Generic
+    created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at          TEXT,
+
+    PRIMARY KEY(satz_id, tag_id),
+
+    FOREIGN KEY (satz_id) REFERENCES setze(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE,
+
+    FOREIGN KEY (tag_id) REFERENCES tags(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+);
+INSERT INTO setze_tags VALUES(1,1,'2026-10-19 07:21:53',NULL);
+CREATE TABLE tags(
+    id           INTEGER PRIMARY KEY AUTOINCREMENT,
+    name         TEXT NOT NULL UNIQUE COLLATE NOCASE,
+
+    -- Generic
+    created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at          TEXT
+);
+INSERT INTO tags VALUES(1,'Akkusativ','2026-10-19 07:21:53',NULL);
+CREATE TABLE worte(
+    id                  INTEGER PRIMARY KEY AUTOINCREMENT,
+    gender_id           INTEGER,                            -- table worte_gender
+    wort_de             TEXT NOT NULL,                   
+    wort_es             TEXT NOT NULL,                   
+    plural              TEXT,
+    niveau_id           INTEGER NOT NULL,                   -- table niveau_liste
+    deck_id             INTEGER NOT NULL DEFAULT 1,         -- table deck
+    example_de          TEXT,                   
+    example_es          TEXT,                   
+
+    -- nur verben
+    verb_aux TEXT,              -- 'sein' / 'haben' / NULL
+    trennbar BOOLEAN,           -- verbo separable
+    reflexiv BOOLEAN,
+
+    -- Generic
+    created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at          TEXT,
+
+    FOREIGN KEY(gender_id) REFERENCES worte_gender(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE,    
+
+    FOREIGN KEY(niveau_id) REFERENCES niveau_liste(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE,
+
+    FOREIGN KEY(deck_id) REFERENCES deck(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+);
+INSERT INTO worte VALUES(1,0,'Hund','perro','Hunde',0,1,'Der Hund bellt.','El perro ladra.',NULL,NULL,NULL,'2026-10-19 07:21:53',NULL);
+CREATE TABLE worte_alternativ(
+    id           INTEGER PRIMARY KEY AUTOINCREMENT,
+    wort_id      INTEGER NOT NULL,
+    antwort      TEXT NOT NULL,
+
+    -- Generic
+    created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at          TEXT,
+
+    UNIQUE(wort_id, antwort),
+
+    FOREIGN KEY (wort_id) REFERENCES worte(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+);
+CREATE TABLE worte_audio(
+    wort_id      INTEGER PRIMARY KEY,
+    file_path    TEXT NOT NULL,
+    voice_id     TEXT NOT NULL,
+
+    -- Generic
+    created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at          TEXT,
+
+    FOREIGN KEY (wort_id) REFERENCES worte(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+);
+CREATE TABLE worte_gender (
+        id                  INTEGER PRIMARY KEY AUTOINCREMENT,
+        gender              TEXT NOT NULL,                         
+        artikel             TEXT NOT NULL,
+
+        -- Generic
+        created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+        deleted_at          TEXT
+    );
+INSERT INTO worte_gender VALUES(0,'Maskuline','der','2026-10-19 07:21:53',NULL);
+INSERT INTO worte_gender VALUES(1,'Femenin','die','2026-10-19 07:21:53',NULL);
+INSERT INTO worte_gender VALUES(2,'Neutrum','das','2026-10-19 07:21:53',NULL);
+INSERT INTO worte_gender VALUES(3,'Plural','die','2026-10-19 07:21:53',NULL);
+CREATE TABLE worte_gram_type(
+    id_worte            INTEGER NOT NULL,
+    id_gram_type        INTEGER NOT NULL,
+
+    -- Generic
+    created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at          TEXT,
+
+    PRIMARY KEY(id_worte,id_gram_type),
+
+    FOREIGN KEY(id_worte) REFERENCES worte(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE,
+
+    FOREIGN KEY(id_gram_type) REFERENCES gram_type(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+);
+INSERT INTO worte_gram_type VALUES(1,0,'2026-10-19 07:21:53',NULL);
+CREATE TABLE worte_review (
+    id              INTEGER PRIMARY KEY AUTOINCREMENT,
+    wort_id         INTEGER NOT NULL UNIQUE,
+    interval        INTEGER NOT NULL,
+    ease_factor     REAL    NOT NULL,
+    repetitions     INTEGER NOT NULL,
+    last_review     TEXT NOT NULL,
+    next_review     TEXT NOT NULL,
+    created_at      TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at      TEXT,
+    FOREIGN KEY(wort_id) REFERENCES worte(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+);
+INSERT INTO worte_review VALUES(1,1,1,2.5,1,'2025-01-10 12:00:00','2025-01-11 12:00:00','2026-10-19 07:21:53',NULL);
+CREATE TABLE worte_tags(
+    wort_id      INTEGER NOT NULL,
+    tag_id       INTEGER NOT NULL,
+
+    -- Generic
+    created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at          TEXT,
+
+    PRIMARY KEY(wort_id, tag_id),
+
+    FOREIGN KEY (wort_id) REFERENCES worte(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE,
+
+    FOREIGN KEY (tag_id) REFERENCES tags(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+);
+INSERT INTO worte_tags VALUES(1,1,'2026-10-19 07:21:53',NULL);
+CREATE VIRTUAL TABLE worte_fts USING fts5(
+    wort_de,
+    example_de,
+    content = 'worte',
+    content_rowid = 'id',
+    tokenize = 'unicode61 remove_diacritics 2',
+    prefix = '2 3'
+);
+CREATE VIRTUAL TABLE setze_fts USING fts5(
+    setze_deutsch,
+    content = 'setze',
+    content_rowid = 'id',
+    tokenize = 'unicode61 remove_diacritics 2',
+    prefix = '2 3'
+);
+CREATE TABLE worte_setze(
+    wort_id      INTEGER NOT NULL,
+    satz_id      INTEGER NOT NULL,
+
+    -- Generic
+    created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+
+    PRIMARY KEY(wort_id, satz_id),
+
+    FOREIGN KEY (wort_id) REFERENCES worte(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE,
+
+    FOREIGN KEY (satz_id) REFERENCES setze(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+);
+INSERT INTO worte_setze VALUES(1,1,'2026-10-19 10:20:17');
+DELETE FROM sqlite_sequence;
+INSERT INTO sqlite_sequence VALUES('niveau_liste',5);
+INSERT INTO sqlite_sequence VALUES('worte_gender',3);
+INSERT INTO sqlite_sequence VALUES('deck',2);
+INSERT INTO sqlite_sequence VALUES('setze',3);
+INSERT INTO sqlite_sequence VALUES('setze_review',1);
+INSERT INTO sqlite_sequence VALUES('worte',1);
+INSERT INTO sqlite_sequence VALUES('worte_review',1);
+INSERT INTO sqlite_sequence VALUES('tags',1);
+INSERT INTO sqlite_sequence VALUES('schwirig_liste',2);
+CREATE TRIGGER trg_worte_fts_insert AFTER INSERT ON worte BEGIN
+        INSERT INTO worte_fts (rowid, wort_de, example_de)
+        VALUES (new.id, new.wort_de, new.example_de);
+    END;
+CREATE TRIGGER trg_worte_fts_delete AFTER DELETE ON worte BEGIN
+        INSERT INTO worte_fts (worte_fts, rowid, wort_de, example_de)
+        VALUES ('delete', old.id, old.wort_de, old.example_de);
+    END;
+CREATE TRIGGER trg_worte_fts_update AFTER UPDATE OF wort_de, example_de ON worte BEGIN
+        INSERT INTO worte_fts (worte_fts, rowid, wort_de, example_de)
+        VALUES ('delete', old.id, old.wort_de, old.example_de);
+        INSERT INTO worte_fts (rowid, wort_de, example_de)
+        VALUES (new.id, new.wort_de, new.example_de);
+    END;
+CREATE TRIGGER trg_setze_fts_insert AFTER INSERT ON setze BEGIN
+        INSERT INTO setze_fts (rowid, setze_deutsch) VALUES (new.id, new.setze_deutsch);
+    END;
+CREATE TRIGGER trg_setze_fts_delete AFTER DELETE ON setze BEGIN
+        INSERT INTO setze_fts (setze_fts, rowid, setze_deutsch)
+        VALUES ('delete', old.id, old.setze_deutsch);
+    END;
+CREATE TRIGGER trg_setze_fts_update AFTER UPDATE OF setze_deutsch ON setze BEGIN
+        INSERT INTO setze_fts (setze_fts, rowid, setze_deutsch)
+        VALUES ('delete', old.id, old.setze_deutsch);
+        INSERT INTO setze_fts (rowid, setze_deutsch) VALUES (new.id, new.setze_deutsch);
+    END;
+CREATE INDEX idx_deck_parent_id ON deck(parent_id);
+CREATE INDEX idx_gram_type_code ON gram_type(code)
+;
+CREATE INDEX idx_niveau_liste_created_at ON niveau_liste(created_at);
+CREATE INDEX idx_schwirig_liste_created_at ON schwirig_liste(created_at);
+CREATE INDEX idx_setze_setze_spanisch ON setze(setze_spanisch);
+CREATE INDEX idx_setze_setze_deutsch ON setze(setze_deutsch);
+CREATE INDEX idx_setze_thema ON setze(thema);
+CREATE INDEX idx_setze_niveau_id ON setze(niveau_id);
+CREATE INDEX idx_setze_schwirig_id ON setze(schwirig_id);
+CREATE INDEX idx_setze_deck_id ON setze(deck_id);
+CREATE INDEX idx_setze_alternativ_satz_id ON setze_alternativ(satz_id);
+CREATE INDEX idx_setze_audio_voice_id ON setze_audio(voice_id);
+CREATE UNIQUE INDEX idx_setze_review_satz_id ON setze_review(satz_id);
+CREATE INDEX idx_setze_review_next_review ON setze_review(next_review);
+CREATE INDEX idx_setze_tags_tag_id ON setze_tags(tag_id);
+CREATE INDEX idx_tags_name ON tags(name);
+CREATE INDEX idx_worte_created_at ON worte(created_at);
+CREATE INDEX idx_worte_gender_id ON worte(gender_id);
+CREATE INDEX idx_worte_niveau_id ON worte(niveau_id);
+CREATE INDEX idx_worte_deck_id ON worte(deck_id);
+CREATE INDEX idx_worte_alternativ_wort_id ON worte_alternativ(wort_id);
+CREATE INDEX idx_worte_audio_voice_id ON worte_audio(voice_id);
+CREATE INDEX idx_worte_gender_created_at ON worte_gender(created_at);
+CREATE INDEX idx_worte_gram_type_id_worte ON worte_gram_type(id_worte);
+CREATE INDEX idx_worte_gram_type_id_gram_type ON worte_gram_type(id_gram_type);
+CREATE UNIQUE INDEX idx_worte_review_wort_id ON worte_review(wort_id);
+CREATE INDEX idx_worte_review_next_review ON worte_review(next_review);
+CREATE INDEX idx_worte_tags_tag_id ON worte_tags(tag_id);
+CREATE INDEX idx_worte_setze_satz_id ON worte_setze(satz_id);
+INSERT INTO worte_fts (worte_fts) VALUES ('rebuild');
+INSERT INTO setze_fts (setze_fts) VALUES ('rebuild');
+COMMIT;
+PRAGMA user_version=3;
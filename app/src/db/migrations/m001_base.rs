@@ -0,0 +1,476 @@
+use color_eyre::eyre::Result;
+use rusqlite::Transaction;
+
+use super::add_column_if_missing;
+
+const CREATE_STR_TABLE_WORTE_GENDER: &str = "
+    CREATE TABLE IF NOT EXISTS worte_gender (
+        id                  INTEGER PRIMARY KEY AUTOINCREMENT,
+        gender              TEXT NOT NULL,                         
+        artikel             TEXT NOT NULL,
+
+        -- Generic
+        created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+        deleted_at          TEXT
+    )
+";
+
+const CREATE_STR_INDEX_WORTE_GENDER: &str = "
+    CREATE INDEX IF NOT EXISTS idx_worte_gender_created_at ON worte_gender(created_at);
+";
+
+const CREATE_STR_TABLE_NIVEAU_LISTE: &str = "
+    CREATE TABLE IF NOT EXISTS niveau_liste (
+        id                  INTEGER PRIMARY KEY AUTOINCREMENT,
+        niveau              TEXT NOT NULL,                         
+
+        -- Generic
+        created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+        deleted_at          TEXT
+    )
+";
+
+const CREATE_STR_INDEX_NIVEAU_LISTE: &str = "
+    CREATE INDEX IF NOT EXISTS idx_niveau_liste_created_at ON niveau_liste(created_at);
+";
+
+const CREATE_STR_TABLE_SCHWIRIG_LISTE: &str = "
+    CREATE TABLE IF NOT EXISTS schwirig_liste (
+        id                  INTEGER PRIMARY KEY AUTOINCREMENT,
+        schwirig            TEXT NOT NULL,
+
+        -- Generic
+        created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+        deleted_at          TEXT
+    )
+";
+
+const CREATE_STR_INDEX_SCHWIRIG_LISTE: &str = "
+    CREATE INDEX IF NOT EXISTS idx_schwirig_liste_created_at ON schwirig_liste(created_at);
+";
+
+const CREATE_STR_TABLE_GRAM_TYPE: &str = "
+CREATE TABLE IF NOT EXISTS gram_type(
+    id              INTEGER PRIMARY KEY,
+    code            TEXT UNIQUE NOT NULL,
+    name            TEXT NOT NULL,
+    created_at      TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at      TEXT
+);";
+
+const CREATE_STR_INDEX_GRAM_TYPE: &str = "
+    CREATE INDEX IF NOT EXISTS idx_gram_type_code ON gram_type(code)
+";
+
+const CREATE_STR_TABLE_DECK: &str = "
+CREATE TABLE IF NOT EXISTS deck(
+    id                  INTEGER PRIMARY KEY AUTOINCREMENT,
+    name                TEXT NOT NULL UNIQUE COLLATE NOCASE,   -- ruta completa
+    parent_id           INTEGER,                               -- table deck
+
+    -- Configuración
+    max_neue_worte      INTEGER NOT NULL DEFAULT 10,
+    max_neue_setze      INTEGER NOT NULL DEFAULT 5,
+    initial_ease        REAL NOT NULL DEFAULT 2.5,
+    interval_modifier   REAL NOT NULL DEFAULT 1.0,
+    max_interval        INTEGER NOT NULL DEFAULT 36500,         -- días
+
+    -- Generic
+    created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at          TEXT,
+
+    FOREIGN KEY(parent_id) REFERENCES deck(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+)";
+
+const CREATE_STR_INDEX_DECK: &str = "
+    CREATE INDEX IF NOT EXISTS idx_deck_parent_id ON deck(parent_id);
+";
+
+const CREATE_STR_TABLE_TAGS: &str = "
+CREATE TABLE IF NOT EXISTS tags(
+    id           INTEGER PRIMARY KEY AUTOINCREMENT,
+    name         TEXT NOT NULL UNIQUE COLLATE NOCASE,
+
+    -- Generic
+    created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at          TEXT
+)";
+
+const CREATE_STR_INDEX_TAGS: &str = "
+    CREATE INDEX IF NOT EXISTS idx_tags_name ON tags(name);
+";
+
+const CREATE_STR_TABLE_SETZE: &str = "
+CREATE TABLE IF NOT EXISTS setze (
+    id                  INTEGER PRIMARY KEY AUTOINCREMENT,
+    setze_spanisch      TEXT NOT NULL,
+    setze_deutsch       TEXT NOT NULL,
+    thema               TEXT NOT NULL,
+    niveau_id           INTEGER NOT NULL,                   -- table niveau_liste (CEFR)
+    schwirig_id         INTEGER NOT NULL DEFAULT 1,         -- table schwirig_liste
+    deck_id             INTEGER NOT NULL DEFAULT 1,         -- table deck
+    created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at          TEXT,
+
+    FOREIGN KEY(niveau_id) REFERENCES niveau_liste(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE,
+
+    FOREIGN KEY(schwirig_id) REFERENCES schwirig_liste(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE,
+
+    FOREIGN KEY(deck_id) REFERENCES deck(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+)";
+
+/// Antes la dificultad del CSV (0-2) se guardaba en `niveau_id` y las oraciones "difíciles"
+/// eran las de nivel B2 o superior. Al agregar `schwirig_id` a una base existente se copia
+/// ese valor como dificultad. Un nivel de 0 a 2 era la dificultad y no un nivel CEFR, así
+/// que esas oraciones quedan en A1; de B2 en adelante solo pudo editarse a mano y se conserva.
+const MIGRATE_STR_SETZE_SCHWIRIG: &str = "
+    UPDATE setze SET schwirig_id = MIN(niveau_id, 2);
+    UPDATE setze SET niveau_id = 0 WHERE niveau_id <= 2;
+";
+
+const CREATE_STR_INDEX_SETZE: &str = "
+    CREATE INDEX IF NOT EXISTS idx_setze_setze_spanisch ON setze(setze_spanisch);
+    CREATE INDEX IF NOT EXISTS idx_setze_setze_deutsch ON setze(setze_deutsch);
+    CREATE INDEX IF NOT EXISTS idx_setze_thema ON setze(thema);
+    CREATE INDEX IF NOT EXISTS idx_setze_niveau_id ON setze(niveau_id);
+    CREATE INDEX IF NOT EXISTS idx_setze_schwirig_id ON setze(schwirig_id);
+    CREATE INDEX IF NOT EXISTS idx_setze_deck_id ON setze(deck_id);
+";
+
+const CREATE_STR_TABLE_SETZE_REVIEW: &str = "
+CREATE TABLE IF NOT EXISTS setze_review (
+    id              INTEGER PRIMARY KEY AUTOINCREMENT,
+    satz_id         INTEGER NOT NULL UNIQUE,
+    interval        INTEGER NOT NULL,
+    ease_factor     REAL    NOT NULL,
+    repetitions     INTEGER NOT NULL,
+    last_review     TEXT NOT NULL,
+    next_review     TEXT NOT NULL,
+    lapses          INTEGER NOT NULL DEFAULT 0,     -- veces que se falló
+    schwirig_id     INTEGER,                        -- table schwirig_liste, calculada con las revisiones
+
+    created_at      TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at      TEXT,
+
+    FOREIGN KEY(satz_id) REFERENCES setze(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+);
+";
+
+const CREATE_STR_INDEX_SETZE_REVIEW: &str = "
+    CREATE UNIQUE INDEX IF NOT EXISTS idx_setze_review_satz_id ON setze_review(satz_id);
+    CREATE INDEX IF NOT EXISTS idx_setze_review_next_review ON setze_review(next_review);
+";
+
+const CREATE_STR_TABLE_SETZE_AUDIO: &str = "
+CREATE TABLE IF NOT EXISTS setze_audio(
+    satz_id      INTEGER PRIMARY KEY,
+    file_path    TEXT NOT NULL,
+    voice_id     TEXT NOT NULL,
+
+    -- Generic
+    created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at          TEXT,
+
+    FOREIGN KEY (satz_id) REFERENCES setze(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+)";
+
+const CREATE_STR_INDEX_SETZE_AUDIO: &str = "
+    CREATE INDEX IF NOT EXISTS idx_setze_audio_voice_id ON setze_audio(voice_id);
+";
+
+const CREATE_STR_TABLE_SETZE_ALTERNATIV: &str = "
+CREATE TABLE IF NOT EXISTS setze_alternativ(
+    id           INTEGER PRIMARY KEY AUTOINCREMENT,
+    satz_id      INTEGER NOT NULL,
+    antwort      TEXT NOT NULL,
+
+    -- Generic
+    created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at          TEXT,
+
+    UNIQUE(satz_id, antwort),
+
+    FOREIGN KEY (satz_id) REFERENCES setze(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+)";
+
+const CREATE_STR_INDEX_SETZE_ALTERNATIV: &str = "
+    CREATE INDEX IF NOT EXISTS idx_setze_alternativ_satz_id ON setze_alternativ(satz_id);
+";
+
+const CREATE_STR_TABLE_SETZE_TAGS: &str = "
+CREATE TABLE IF NOT EXISTS setze_tags(
+    satz_id      INTEGER NOT NULL,
+    tag_id       INTEGER NOT NULL,
+
+    -- Generic
+    created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at          TEXT,
+
+    PRIMARY KEY(satz_id, tag_id),
+
+    FOREIGN KEY (satz_id) REFERENCES setze(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE,
+
+    FOREIGN KEY (tag_id) REFERENCES tags(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+)";
+
+const CREATE_STR_INDEX_SETZE_TAGS: &str = "
+    CREATE INDEX IF NOT EXISTS idx_setze_tags_tag_id ON setze_tags(tag_id);
+";
+
+const CREATE_STR_TABLE_WORTE: &str = "
+CREATE TABLE IF NOT EXISTS worte(
+    id                  INTEGER PRIMARY KEY AUTOINCREMENT,
+    gender_id           INTEGER,                            -- table worte_gender
+    wort_de             TEXT NOT NULL,                   
+    wort_es             TEXT NOT NULL,                   
+    plural              TEXT,
+    niveau_id           INTEGER NOT NULL,                   -- table niveau_liste
+    deck_id             INTEGER NOT NULL DEFAULT 1,         -- table deck
+    example_de          TEXT,                   
+    example_es          TEXT,                   
+
+    -- nur verben
+    verb_aux TEXT,              -- 'sein' / 'haben' / NULL
+    trennbar BOOLEAN,           -- verbo separable
+    reflexiv BOOLEAN,
+
+    -- Generic
+    created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at          TEXT,
+
+    FOREIGN KEY(gender_id) REFERENCES worte_gender(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE,    
+
+    FOREIGN KEY(niveau_id) REFERENCES niveau_liste(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE,
+
+    FOREIGN KEY(deck_id) REFERENCES deck(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+)";
+
+const CREATE_STR_INDEX_WORTE: &str = "
+    CREATE INDEX IF NOT EXISTS idx_worte_created_at ON worte(created_at);
+    CREATE INDEX IF NOT EXISTS idx_worte_gender_id ON worte(gender_id);
+    CREATE INDEX IF NOT EXISTS idx_worte_niveau_id ON worte(niveau_id);
+    CREATE INDEX IF NOT EXISTS idx_worte_deck_id ON worte(deck_id);
+";
+
+const CREATE_STR_TABLE_WORTE_TYPE_GRAM: &str = "
+CREATE TABLE IF NOT EXISTS worte_gram_type(
+    id_worte            INTEGER NOT NULL,
+    id_gram_type        INTEGER NOT NULL,
+
+    -- Generic
+    created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at          TEXT,
+
+    PRIMARY KEY(id_worte,id_gram_type),
+
+    FOREIGN KEY(id_worte) REFERENCES worte(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE,
+
+    FOREIGN KEY(id_gram_type) REFERENCES gram_type(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+)";
+
+const CREATE_STR_INDEX_WORTE_TYPE_GRAM: &str = "
+    CREATE INDEX IF NOT EXISTS idx_worte_gram_type_id_worte ON worte_gram_type(id_worte);
+    CREATE INDEX IF NOT EXISTS idx_worte_gram_type_id_gram_type ON worte_gram_type(id_gram_type);
+";
+
+const CREATE_STR_TABLE_WORTE_REVIEW: &str = "
+CREATE TABLE IF NOT EXISTS worte_review (
+    id              INTEGER PRIMARY KEY AUTOINCREMENT,
+    wort_id         INTEGER NOT NULL UNIQUE,
+    interval        INTEGER NOT NULL,
+    ease_factor     REAL    NOT NULL,
+    repetitions     INTEGER NOT NULL,
+    last_review     TEXT NOT NULL,
+    next_review     TEXT NOT NULL,
+    created_at      TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at      TEXT,
+    FOREIGN KEY(wort_id) REFERENCES worte(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+);
+";
+
+const CREATE_STR_INDEX_WORTE_REVIEW: &str = "
+    CREATE UNIQUE INDEX IF NOT EXISTS idx_worte_review_wort_id ON worte_review(wort_id);
+    CREATE INDEX IF NOT EXISTS idx_worte_review_next_review ON worte_review(next_review);
+";
+
+const CREATE_STR_TABLE_WORTE_AUDIO: &str = "
+CREATE TABLE IF NOT EXISTS worte_audio(
+    wort_id      INTEGER PRIMARY KEY,
+    file_path    TEXT NOT NULL,
+    voice_id     TEXT NOT NULL,
+
+    -- Generic
+    created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at          TEXT,
+
+    FOREIGN KEY (wort_id) REFERENCES worte(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+)";
+
+const CREATE_STR_INDEX_WORTE_AUDIO: &str = "
+    CREATE INDEX IF NOT EXISTS idx_worte_audio_voice_id ON worte_audio(voice_id);
+";
+
+const CREATE_STR_TABLE_WORTE_ALTERNATIV: &str = "
+CREATE TABLE IF NOT EXISTS worte_alternativ(
+    id           INTEGER PRIMARY KEY AUTOINCREMENT,
+    wort_id      INTEGER NOT NULL,
+    antwort      TEXT NOT NULL,
+
+    -- Generic
+    created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at          TEXT,
+
+    UNIQUE(wort_id, antwort),
+
+    FOREIGN KEY (wort_id) REFERENCES worte(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+)";
+
+const CREATE_STR_INDEX_WORTE_ALTERNATIV: &str = "
+    CREATE INDEX IF NOT EXISTS idx_worte_alternativ_wort_id ON worte_alternativ(wort_id);
+";
+
+const CREATE_STR_TABLE_WORTE_TAGS: &str = "
+CREATE TABLE IF NOT EXISTS worte_tags(
+    wort_id      INTEGER NOT NULL,
+    tag_id       INTEGER NOT NULL,
+
+    -- Generic
+    created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at          TEXT,
+
+    PRIMARY KEY(wort_id, tag_id),
+
+    FOREIGN KEY (wort_id) REFERENCES worte(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE,
+
+    FOREIGN KEY (tag_id) REFERENCES tags(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+)";
+
+const CREATE_STR_INDEX_WORTE_TAGS: &str = "
+    CREATE INDEX IF NOT EXISTS idx_worte_tags_tag_id ON worte_tags(tag_id);
+";
+
+const SEED_STR_BASE: &str = "
+    INSERT INTO deck (id, name) VALUES (1, 'Default') ON CONFLICT DO NOTHING;
+    INSERT INTO schwirig_liste (id, schwirig) VALUES (0, 'Einfach'), (1, 'Normal'), (2, 'Schwirig')
+        ON CONFLICT(id) DO UPDATE SET schwirig = excluded.schwirig;
+";
+
+// Las migraciones corren sin llaves foráneas, así que las columnas nuevas pueden llevar
+// `REFERENCES` con un valor por defecto distinto de NULL.
+const COLUMN_DECK_ID: &str =
+    "INTEGER NOT NULL DEFAULT 1 REFERENCES deck(id) ON DELETE CASCADE ON UPDATE CASCADE";
+const COLUMN_SCHWIRIG_ID: &str =
+    "INTEGER NOT NULL DEFAULT 1 REFERENCES schwirig_liste(id) ON DELETE CASCADE ON UPDATE CASCADE";
+
+/// Esquema con el que empiezan las versiones. Es idempotente porque las bases creadas antes
+/// de las migraciones (versión 0) pueden tener cualquiera de los esquemas anteriores.
+pub fn up(tx: &Transaction) -> Result<()> {
+    // Gender Worte
+    tx.execute(CREATE_STR_TABLE_WORTE_GENDER, [])?;
+    tx.execute_batch(CREATE_STR_INDEX_WORTE_GENDER)?;
+
+    // Niveau Liste
+    tx.execute(CREATE_STR_TABLE_NIVEAU_LISTE, [])?;
+    tx.execute_batch(CREATE_STR_INDEX_NIVEAU_LISTE)?;
+
+    // Dificultad de las oraciones
+    tx.execute(CREATE_STR_TABLE_SCHWIRIG_LISTE, [])?;
+    tx.execute_batch(CREATE_STR_INDEX_SCHWIRIG_LISTE)?;
+
+    // Gram Type
+    tx.execute(CREATE_STR_TABLE_GRAM_TYPE, [])?;
+    tx.execute_batch(CREATE_STR_INDEX_GRAM_TYPE)?;
+
+    // Decks
+    tx.execute(CREATE_STR_TABLE_DECK, [])?;
+    tx.execute_batch(CREATE_STR_INDEX_DECK)?;
+
+    // Etiquetas
+    tx.execute(CREATE_STR_TABLE_TAGS, [])?;
+    tx.execute_batch(CREATE_STR_INDEX_TAGS)?;
+
+    // Oraciones
+    tx.execute(CREATE_STR_TABLE_SETZE, [])?;
+    add_column_if_missing(tx, "setze", "deck_id", COLUMN_DECK_ID)?;
+    if add_column_if_missing(tx, "setze", "schwirig_id", COLUMN_SCHWIRIG_ID)? {
+        tx.execute_batch(MIGRATE_STR_SETZE_SCHWIRIG)?;
+    }
+    tx.execute_batch(CREATE_STR_INDEX_SETZE)?;
+
+    tx.execute(CREATE_STR_TABLE_SETZE_REVIEW, [])?;
+    add_column_if_missing(tx, "setze_review", "lapses", "INTEGER NOT NULL DEFAULT 0")?;
+    add_column_if_missing(tx, "setze_review", "schwirig_id", "INTEGER")?;
+    tx.execute_batch(CREATE_STR_INDEX_SETZE_REVIEW)?;
+
+    tx.execute(CREATE_STR_TABLE_SETZE_AUDIO, [])?;
+    tx.execute_batch(CREATE_STR_INDEX_SETZE_AUDIO)?;
+
+    tx.execute(CREATE_STR_TABLE_SETZE_ALTERNATIV, [])?;
+    tx.execute_batch(CREATE_STR_INDEX_SETZE_ALTERNATIV)?;
+
+    tx.execute(CREATE_STR_TABLE_SETZE_TAGS, [])?;
+    tx.execute_batch(CREATE_STR_INDEX_SETZE_TAGS)?;
+
+    // Palabras
+    tx.execute(CREATE_STR_TABLE_WORTE, [])?;
+    add_column_if_missing(tx, "worte", "deck_id", COLUMN_DECK_ID)?;
+    tx.execute_batch(CREATE_STR_INDEX_WORTE)?;
+
+    tx.execute(CREATE_STR_TABLE_WORTE_TYPE_GRAM, [])?;
+    tx.execute_batch(CREATE_STR_INDEX_WORTE_TYPE_GRAM)?;
+
+    tx.execute(CREATE_STR_TABLE_WORTE_REVIEW, [])?;
+    tx.execute_batch(CREATE_STR_INDEX_WORTE_REVIEW)?;
+
+    tx.execute(CREATE_STR_TABLE_WORTE_AUDIO, [])?;
+    tx.execute_batch(CREATE_STR_INDEX_WORTE_AUDIO)?;
+
+    tx.execute(CREATE_STR_TABLE_WORTE_ALTERNATIV, [])?;
+    tx.execute_batch(CREATE_STR_INDEX_WORTE_ALTERNATIV)?;
+
+    tx.execute(CREATE_STR_TABLE_WORTE_TAGS, [])?;
+    tx.execute_batch(CREATE_STR_INDEX_WORTE_TAGS)?;
+
+    // Filas a las que apuntan los valores por defecto de las columnas nuevas
+    tx.execute_batch(SEED_STR_BASE)?;
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use color_eyre::eyre::Result;
+use rusqlite::Transaction;
+
+const CREATE_STR_TABLE_WORTE_FTS: &str = "
+CREATE VIRTUAL TABLE IF NOT EXISTS worte_fts USING fts5(
+    wort_de,
+    example_de,
+    content = 'worte',
+    content_rowid = 'id',
+    tokenize = 'unicode61 remove_diacritics 2',
+    prefix = '2 3'
+)";
+
+const CREATE_STR_TRIGGER_WORTE_FTS: &str = "
+    CREATE TRIGGER IF NOT EXISTS trg_worte_fts_insert AFTER INSERT ON worte BEGIN
+        INSERT INTO worte_fts (rowid, wort_de, example_de)
+        VALUES (new.id, new.wort_de, new.example_de);
+    END;
+
+    CREATE TRIGGER IF NOT EXISTS trg_worte_fts_delete AFTER DELETE ON worte BEGIN
+        INSERT INTO worte_fts (worte_fts, rowid, wort_de, example_de)
+        VALUES ('delete', old.id, old.wort_de, old.example_de);
+    END;
+
+    CREATE TRIGGER IF NOT EXISTS trg_worte_fts_update AFTER UPDATE OF wort_de, example_de ON worte BEGIN
+        INSERT INTO worte_fts (worte_fts, rowid, wort_de, example_de)
+        VALUES ('delete', old.id, old.wort_de, old.example_de);
+        INSERT INTO worte_fts (rowid, wort_de, example_de)
+        VALUES (new.id, new.wort_de, new.example_de);
+    END;
+";
+
+const CREATE_STR_TABLE_SETZE_FTS: &str = "
+CREATE VIRTUAL TABLE IF NOT EXISTS setze_fts USING fts5(
+    setze_deutsch,
+    content = 'setze',
+    content_rowid = 'id',
+    tokenize = 'unicode61 remove_diacritics 2',
+    prefix = '2 3'
+)";
+
+const CREATE_STR_TRIGGER_SETZE_FTS: &str = "
+    CREATE TRIGGER IF NOT EXISTS trg_setze_fts_insert AFTER INSERT ON setze BEGIN
+        INSERT INTO setze_fts (rowid, setze_deutsch) VALUES (new.id, new.setze_deutsch);
+    END;
+
+    CREATE TRIGGER IF NOT EXISTS trg_setze_fts_delete AFTER DELETE ON setze BEGIN
+        INSERT INTO setze_fts (setze_fts, rowid, setze_deutsch)
+        VALUES ('delete', old.id, old.setze_deutsch);
+    END;
+
+    CREATE TRIGGER IF NOT EXISTS trg_setze_fts_update AFTER UPDATE OF setze_deutsch ON setze BEGIN
+        INSERT INTO setze_fts (setze_fts, rowid, setze_deutsch)
+        VALUES ('delete', old.id, old.setze_deutsch);
+        INSERT INTO setze_fts (rowid, setze_deutsch) VALUES (new.id, new.setze_deutsch);
+    END;
+";
+
+/// Indexa las filas que ya existían antes de crear las tablas.
+const REBUILD_STR_FTS: &str = "
+    INSERT INTO worte_fts (worte_fts) VALUES ('rebuild');
+    INSERT INTO setze_fts (setze_fts) VALUES ('rebuild');
+";
+
+/// Índices de texto completo del alemán, sincronizados con triggers.
+pub fn up(tx: &Transaction) -> Result<()> {
+    tx.execute(CREATE_STR_TABLE_WORTE_FTS, [])?;
+    tx.execute_batch(CREATE_STR_TRIGGER_WORTE_FTS)?;
+
+    tx.execute(CREATE_STR_TABLE_SETZE_FTS, [])?;
+    tx.execute_batch(CREATE_STR_TRIGGER_SETZE_FTS)?;
+
+    tx.execute_batch(REBUILD_STR_FTS)?;
+
+    Ok(())
+}
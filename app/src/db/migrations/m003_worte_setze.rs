@@ -0,0 +1,34 @@
+use color_eyre::eyre::Result;
+use rusqlite::Transaction;
+
+const CREATE_STR_TABLE_WORTE_SETZE: &str = "
+CREATE TABLE IF NOT EXISTS worte_setze(
+    wort_id      INTEGER NOT NULL,
+    satz_id      INTEGER NOT NULL,
+
+    -- Generic
+    created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+
+    PRIMARY KEY(wort_id, satz_id),
+
+    FOREIGN KEY (wort_id) REFERENCES worte(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE,
+
+    FOREIGN KEY (satz_id) REFERENCES setze(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+)";
+
+const CREATE_STR_INDEX_WORTE_SETZE: &str = "
+    CREATE INDEX IF NOT EXISTS idx_worte_setze_satz_id ON worte_setze(satz_id);
+";
+
+/// Relación entre las palabras y las oraciones que las usan. Los datos que ya existían se
+/// relacionan al terminar de migrar (ver `migrate`), con el esquema más reciente.
+pub fn up(tx: &Transaction) -> Result<()> {
+    tx.execute(CREATE_STR_TABLE_WORTE_SETZE, [])?;
+    tx.execute_batch(CREATE_STR_INDEX_WORTE_SETZE)?;
+
+    Ok(())
+}
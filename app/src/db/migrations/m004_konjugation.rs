@@ -0,0 +1,62 @@
+use color_eyre::eyre::Result;
+use rusqlite::Transaction;
+
+/// Formas conjugadas importadas que reemplazan a las que genera `helpers::konjugation`
+/// (para irregulares que no están en la lista del conjugador).
+///
+/// - tempus: praesens, praeteritum, partizip2, imperativ
+/// - person: ich, du, er, wir, ihr, sie; vacío en el partizip2
+const CREATE_STR_TABLE_VERB_KONJUGATION: &str = "
+CREATE TABLE IF NOT EXISTS verb_konjugation(
+    wort_id      INTEGER NOT NULL,
+    tempus       TEXT NOT NULL,
+    person       TEXT NOT NULL DEFAULT '',
+    form         TEXT NOT NULL,
+
+    -- Generic
+    created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
+
+    PRIMARY KEY(wort_id, tempus, person),
+
+    FOREIGN KEY (wort_id) REFERENCES worte(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+)";
+
+/// Revisiones del drill de conjugación: cada tiempo de un verbo (`tempus`) se programa por
+/// separado.
+const CREATE_STR_TABLE_KONJUGATION_REVIEW: &str = "
+CREATE TABLE IF NOT EXISTS konjugation_review (
+    id              INTEGER PRIMARY KEY AUTOINCREMENT,
+    wort_id         INTEGER NOT NULL,
+    tempus          TEXT NOT NULL,
+    interval        INTEGER NOT NULL,
+    ease_factor     REAL    NOT NULL,
+    repetitions     INTEGER NOT NULL,
+    last_review     TEXT NOT NULL,
+    next_review     TEXT NOT NULL,
+    lapses          INTEGER NOT NULL DEFAULT 0,     -- veces que se falló
+
+    created_at      TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at      TEXT,
+
+    UNIQUE(wort_id, tempus),
+
+    FOREIGN KEY(wort_id) REFERENCES worte(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+);
+";
+
+const CREATE_STR_INDEX_KONJUGATION_REVIEW: &str = "
+    CREATE INDEX IF NOT EXISTS idx_konjugation_review_next_review ON konjugation_review(next_review);
+";
+
+/// Formas conjugadas importadas y revisiones del drill de conjugación.
+pub fn up(tx: &Transaction) -> Result<()> {
+    tx.execute(CREATE_STR_TABLE_VERB_KONJUGATION, [])?;
+    tx.execute(CREATE_STR_TABLE_KONJUGATION_REVIEW, [])?;
+    tx.execute_batch(CREATE_STR_INDEX_KONJUGATION_REVIEW)?;
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use color_eyre::eyre::Result;
+use rusqlite::Transaction;
+
+/// Revisiones del drill de preposiciones, una por preposición.
+const CREATE_STR_TABLE_PRAEPOSITION_REVIEW: &str = "
+CREATE TABLE IF NOT EXISTS praeposition_review (
+    id              INTEGER PRIMARY KEY AUTOINCREMENT,
+    wort_id         INTEGER NOT NULL UNIQUE,
+    interval        INTEGER NOT NULL,
+    ease_factor     REAL    NOT NULL,
+    repetitions     INTEGER NOT NULL,
+    last_review     TEXT NOT NULL,
+    next_review     TEXT NOT NULL,
+    lapses          INTEGER NOT NULL DEFAULT 0,     -- veces que se falló
+
+    created_at      TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at      TEXT,
+
+    FOREIGN KEY(wort_id) REFERENCES worte(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+);
+";
+
+const CREATE_STR_INDEX_PRAEPOSITION_REVIEW: &str = "
+    CREATE INDEX IF NOT EXISTS idx_praeposition_review_next_review ON praeposition_review(next_review);
+";
+
+/// Revisiones del drill de preposiciones.
+pub fn up(tx: &Transaction) -> Result<()> {
+    tx.execute(CREATE_STR_TABLE_PRAEPOSITION_REVIEW, [])?;
+    tx.execute_batch(CREATE_STR_INDEX_PRAEPOSITION_REVIEW)?;
+
+    Ok(())
+}
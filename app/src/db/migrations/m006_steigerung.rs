@@ -0,0 +1,12 @@
+use color_eyre::eyre::Result;
+use rusqlite::Transaction;
+
+use super::add_column_if_missing;
+
+/// Komparativ y Superlativ guardados de los adjetivos irregulares.
+pub fn up(tx: &Transaction) -> Result<()> {
+    add_column_if_missing(tx, "worte", "komparativ", "TEXT")?;
+    add_column_if_missing(tx, "worte", "superlativ", "TEXT")?;
+
+    Ok(())
+}
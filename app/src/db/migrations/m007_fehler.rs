@@ -0,0 +1,41 @@
+use color_eyre::eyre::Result;
+use rusqlite::Transaction;
+
+/// Respuestas incorrectas de las sesiones con su clasificación (ver `helpers::fehler`).
+const CREATE_STR_TABLE_FEHLER: &str = "
+CREATE TABLE IF NOT EXISTS fehler (
+    id              INTEGER PRIMARY KEY AUTOINCREMENT,
+    art             TEXT NOT NULL,          -- tipo de tarjeta: wort, satz, konjugation...
+    kategorie       TEXT NOT NULL,          -- artikel, umlaut, grossschreibung...
+    detail          TEXT,                   -- caso, tiempo o grado del drill: 'Dativ'
+    wort_id         INTEGER,
+    satz_id         INTEGER,
+    expected        TEXT NOT NULL,
+    input           TEXT NOT NULL,
+
+    created_at      TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at      TEXT,
+
+    FOREIGN KEY(wort_id) REFERENCES worte(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE,
+
+    FOREIGN KEY(satz_id) REFERENCES setze(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+);
+";
+
+const CREATE_STR_INDEX_FEHLER: &str = "
+    CREATE INDEX IF NOT EXISTS idx_fehler_kategorie ON fehler(kategorie);
+    CREATE INDEX IF NOT EXISTS idx_fehler_wort_id ON fehler(wort_id);
+    CREATE INDEX IF NOT EXISTS idx_fehler_satz_id ON fehler(satz_id);
+";
+
+/// Respuestas incorrectas clasificadas.
+pub fn up(tx: &Transaction) -> Result<()> {
+    tx.execute(CREATE_STR_TABLE_FEHLER, [])?;
+    tx.execute_batch(CREATE_STR_INDEX_FEHLER)?;
+
+    Ok(())
+}
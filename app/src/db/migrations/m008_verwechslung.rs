@@ -0,0 +1,39 @@
+use color_eyre::eyre::Result;
+use rusqlite::Transaction;
+
+/// Palabras que se respondieron con otra palabra guardada: se esperaba `wort_id` y se escribió
+/// `verwechselt_id` ("gehen" -> "fahren").
+const CREATE_STR_TABLE_VERWECHSLUNG: &str = "
+CREATE TABLE IF NOT EXISTS verwechslung (
+    id              INTEGER PRIMARY KEY AUTOINCREMENT,
+    wort_id         INTEGER NOT NULL,       -- palabra que se preguntó
+    verwechselt_id  INTEGER NOT NULL,       -- palabra que se escribió
+    count           INTEGER NOT NULL DEFAULT 1,
+    last_seen       TEXT NOT NULL,
+
+    created_at      TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at      TEXT,
+
+    UNIQUE(wort_id, verwechselt_id),
+
+    FOREIGN KEY(wort_id) REFERENCES worte(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE,
+
+    FOREIGN KEY(verwechselt_id) REFERENCES worte(id)
+        ON DELETE CASCADE
+        ON UPDATE CASCADE
+);
+";
+
+const CREATE_STR_INDEX_VERWECHSLUNG: &str = "
+    CREATE INDEX IF NOT EXISTS idx_verwechslung_count ON verwechslung(count);
+";
+
+/// Pares de palabras que se confunden.
+pub fn up(tx: &Transaction) -> Result<()> {
+    tx.execute(CREATE_STR_TABLE_VERWECHSLUNG, [])?;
+    tx.execute_batch(CREATE_STR_INDEX_VERWECHSLUNG)?;
+
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use color_eyre::eyre::Result;
+use rusqlite::Transaction;
+
+/// Lista de frecuencia importada: `rank` 1 es el lema más usado. El lema se guarda en
+/// minúsculas y se relaciona con `worte` por el texto en alemán.
+const CREATE_STR_TABLE_WORD_FREQUENCY: &str = "
+CREATE TABLE IF NOT EXISTS word_frequency (
+    id              INTEGER PRIMARY KEY AUTOINCREMENT,
+    lemma           TEXT NOT NULL UNIQUE,
+    rank            INTEGER NOT NULL,
+
+    created_at      TEXT DEFAULT CURRENT_TIMESTAMP,
+    deleted_at      TEXT
+);
+";
+
+const CREATE_STR_INDEX_WORD_FREQUENCY: &str = "
+    CREATE INDEX IF NOT EXISTS idx_word_frequency_rank ON word_frequency(rank);
+";
+
+/// Lista de frecuencia de lemas para introducir primero las palabras más usadas.
+pub fn up(tx: &Transaction) -> Result<()> {
+    tx.execute(CREATE_STR_TABLE_WORD_FREQUENCY, [])?;
+    tx.execute_batch(CREATE_STR_INDEX_WORD_FREQUENCY)?;
+
+    Ok(())
+}
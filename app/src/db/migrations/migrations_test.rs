@@ -0,0 +1,293 @@
+use crate::db::setup_test_db;
+
+#[cfg(test)]
+mod test_migrations {
+    use color_eyre::eyre::{Result, bail};
+    use rusqlite::{Connection, Transaction};
+
+    use crate::db::{
+        migrations::{MIGRATIONS, Migration, current_version, latest_version, migrate, migrate_to},
+        schemas::init_schemas,
+        seeders::init_data,
+    };
+
+    use super::*;
+
+    /// Bases creadas con los esquemas anteriores a las migraciones (versión 0) y con cada
+    /// versión intermedia, con la versión en la que quedaron.
+    const FIXTURES: [(&str, u32, &str); 12] = [
+        ("v0_base", 0, include_str!("fixtures/v0_base.sql")),
+        ("v0_tags", 0, include_str!("fixtures/v0_tags.sql")),
+        ("v0_deck", 0, include_str!("fixtures/v0_deck.sql")),
+        ("v0_schwirig", 0, include_str!("fixtures/v0_schwirig.sql")),
+        ("v1_base", 1, include_str!("fixtures/v1_base.sql")),
+        ("v2_fts", 2, include_str!("fixtures/v2_fts.sql")),
+        (
+            "v3_worte_setze",
+            3,
+            include_str!("fixtures/v3_worte_setze.sql"),
+        ),
+        (
+            "v4_konjugation",
+            4,
+            include_str!("fixtures/v4_konjugation.sql"),
+        ),
+        (
+            "v5_praeposition",
+            5,
+            include_str!("fixtures/v5_praeposition.sql"),
+        ),
+        (
+            "v6_steigerung",
+            6,
+            include_str!("fixtures/v6_steigerung.sql"),
+        ),
+        ("v7_fehler", 7, include_str!("fixtures/v7_fehler.sql")),
+        (
+            "v8_verwechslung",
+            8,
+            include_str!("fixtures/v8_verwechslung.sql"),
+        ),
+    ];
+
+    /// Tablas, columnas, llaves foráneas e índices sin importar el orden de las columnas,
+    /// ya que `ALTER TABLE` agrega las columnas al final.
+    fn schema_of(conn: &Connection) -> Vec<String> {
+        let sql = "
+            SELECT 'column ' || m.name || '.' || c.name || ' ' || c.type || ' notnull=' || c.\"notnull\"
+                || ' default=' || IFNULL(c.dflt_value, 'NULL') || ' pk=' || c.pk
+            FROM sqlite_master m, pragma_table_info(m.name) c
+            WHERE m.type = 'table' AND m.name NOT LIKE 'sqlite_%'
+            UNION ALL
+            SELECT 'fk ' || m.name || '.' || f.\"from\" || ' -> ' || f.\"table\" || '.'
+                || IFNULL(f.\"to\", 'id') || ' ' || f.on_delete || ' ' || f.on_update
+            FROM sqlite_master m, pragma_foreign_key_list(m.name) f
+            WHERE m.type = 'table' AND m.name NOT LIKE 'sqlite_%'
+            UNION ALL
+            SELECT 'index ' || name
+            FROM sqlite_master
+            WHERE type = 'index' AND name NOT LIKE 'sqlite_%'
+            ORDER BY 1;
+        ";
+
+        let mut stmt = conn.prepare(sql).unwrap();
+        stmt.query_map([], |r| r.get(0))
+            .unwrap()
+            .collect::<Result<Vec<String>, _>>()
+            .unwrap()
+    }
+
+    fn fresh_schema() -> Vec<String> {
+        schema_of(&setup_test_db().unwrap())
+    }
+
+    fn query_ids(conn: &Connection, sql: &str) -> Vec<(i32, i32)> {
+        let mut stmt = conn.prepare(sql).unwrap();
+        stmt.query_map([], |r| Ok((r.get(0)?, r.get(1)?)))
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_fresh_db_is_latest() {
+        let mut conn = setup_test_db().unwrap();
+        assert_eq!(current_version(&conn).unwrap(), latest_version());
+
+        // Sin migraciones pendientes no cambia nada
+        let report = migrate(&mut conn).unwrap();
+        assert_eq!(report.from, latest_version());
+        assert_eq!(report.to, latest_version());
+        assert!(report.backup.is_none());
+    }
+
+    #[test]
+    fn test_upgrade_fixtures() {
+        let fresh = fresh_schema();
+
+        for (name, version, fixture) in FIXTURES {
+            let mut conn = Connection::open_in_memory().unwrap();
+            conn.execute_batch(fixture).unwrap();
+            assert_eq!(current_version(&conn).unwrap(), version, "{}", name);
+
+            init_schemas(&mut conn).unwrap_or_else(|e| panic!("{}: {:?}", name, e));
+            init_data(&mut conn).unwrap();
+
+            assert_eq!(
+                current_version(&conn).unwrap(),
+                latest_version(),
+                "{}",
+                name
+            );
+            assert_eq!(schema_of(&conn), fresh, "{}", name);
+
+            let fk: bool = conn
+                .pragma_query_value(None, "foreign_keys", |r| r.get(0))
+                .unwrap();
+            assert!(fk, "{}", name);
+
+            // La dificultad sale del nivel en las bases anteriores a separarlos
+            let setze = query_ids(&conn, "SELECT id, schwirig_id FROM setze ORDER BY id");
+            assert_eq!(setze, vec![(1, 0), (2, 2), (3, 2)], "{}", name);
+
+            // El nivel que era dificultad queda en A1; un nivel editado a mano se conserva
+            let niveau = query_ids(&conn, "SELECT id, niveau_id FROM setze ORDER BY id");
+            let niveau_satz_2 = if name == "v0_base" || name == "v0_tags" || name == "v0_deck" {
+                0
+            } else {
+                2
+            };
+            assert_eq!(niveau, vec![(1, 0), (2, niveau_satz_2), (3, 4)], "{}", name);
+
+            // El historial de repasos se conserva
+            let reviews = query_ids(&conn, "SELECT satz_id, repetitions FROM setze_review");
+            assert_eq!(reviews, vec![(2, 3)], "{}", name);
+            let reviews = query_ids(&conn, "SELECT wort_id, repetitions FROM worte_review");
+            assert_eq!(reviews, vec![(1, 1)], "{}", name);
+
+            // La relación palabra-oración se calcula para los datos que ya existían
+            let links = query_ids(&conn, "SELECT wort_id, satz_id FROM worte_setze");
+            assert_eq!(links, vec![(1, 1)], "{}", name);
+
+            let decks = query_ids(&conn, "SELECT id, deck_id FROM setze ORDER BY id");
+            let deck_satz_3 = if name == "v0_base" || name == "v0_tags" {
+                1
+            } else {
+                2
+            };
+            assert_eq!(decks[2], (3, deck_satz_3), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_upgrade_from_every_version() {
+        let fresh = fresh_schema();
+
+        for version in 0..=latest_version() {
+            let mut conn = Connection::open_in_memory().unwrap();
+            migrate_to(&mut conn, MIGRATIONS, version).unwrap();
+            assert_eq!(current_version(&conn).unwrap(), version);
+
+            init_schemas(&mut conn).unwrap();
+            assert_eq!(current_version(&conn).unwrap(), latest_version());
+            assert_eq!(schema_of(&conn), fresh, "desde v{}", version);
+        }
+    }
+
+    #[test]
+    fn test_backfill_worte_setze() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        migrate_to(&mut conn, MIGRATIONS, 2).unwrap();
+        conn.execute_batch(
+            "
+            INSERT INTO niveau_liste (id, niveau) VALUES (0, 'A1');
+            INSERT INTO gram_type (id, code, name) VALUES
+                (0, 'noun_common', ''), (2, 'verb_main', ''), (7, 'adjective', '');
+            INSERT INTO worte (id, gender_id, wort_de, wort_es, plural, niveau_id, example_de, example_es)
+            VALUES
+                (1, NULL, 'Äpfel', '', NULL, 0, '', ''),
+                (2, NULL, 'sich erinnern', '', NULL, 0, '', ''),
+                (3, NULL, 'klein', '', NULL, 0, '', ''),
+                (4, NULL, 'zum Beispiel', '', NULL, 0, '', ''),
+                (5, NULL, 'Kind', '', 'Kinder', 0, '', '');
+            INSERT INTO worte_gram_type (id_worte, id_gram_type) VALUES
+                (1, 0), (2, 2), (3, 7), (4, 0), (5, 0);
+            INSERT INTO setze (id, setze_spanisch, setze_deutsch, thema, niveau_id) VALUES
+                (1, '', 'Ich esse Äpfel.', '', 0),
+                (2, '', 'Ich erinnere mich an die kleinen Kindern!', '', 0),
+                (3, '', 'Das Kind spielt, zum Beispiel.', '', 0);
+            ",
+        )
+        .unwrap();
+
+        // Al terminar de migrar se relacionan con las mismas formas que usa la aplicación
+        migrate(&mut conn).unwrap();
+        let links = query_ids(
+            &conn,
+            "SELECT wort_id, satz_id FROM worte_setze ORDER BY wort_id, satz_id",
+        );
+        assert_eq!(links, vec![(1, 1), (2, 2), (3, 2), (4, 3), (5, 2), (5, 3)]);
+    }
+
+    fn up_broken(tx: &Transaction) -> Result<()> {
+        tx.execute("CREATE TABLE temporal (id INTEGER)", [])?;
+        bail!("Falla a propósito")
+    }
+
+    #[test]
+    fn test_failed_migration_rolls_back() {
+        let migrations = [
+            Migration {
+                version: 1,
+                name: "base",
+                up: MIGRATIONS[0].up,
+            },
+            Migration {
+                version: 2,
+                name: "broken",
+                up: up_broken,
+            },
+        ];
+
+        let mut conn = Connection::open_in_memory().unwrap();
+        let res = migrate_to(&mut conn, &migrations, 2);
+        assert!(res.is_err());
+
+        // La migración 1 queda aplicada y la 2 se revierte completa
+        assert_eq!(current_version(&conn).unwrap(), 1);
+        let exists: bool = conn
+            .query_row(
+                "SELECT EXISTS (SELECT 1 FROM sqlite_master WHERE name = 'temporal')",
+                [],
+                |r| r.get(0),
+            )
+            .unwrap();
+        assert!(!exists);
+
+        let fk: bool = conn
+            .pragma_query_value(None, "foreign_keys", |r| r.get(0))
+            .unwrap();
+        assert!(fk);
+    }
+
+    #[test]
+    fn test_newer_db_fails() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.pragma_update(None, "user_version", latest_version() + 1)
+            .unwrap();
+
+        assert!(migrate(&mut conn).is_err());
+    }
+
+    #[test]
+    fn test_backup_before_migrate() {
+        let dir = std::env::temp_dir().join(format!("anki_migrations_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        // Una base nueva no se respalda
+        let path = dir.join("nueva.sql");
+        let mut conn = Connection::open(&path).unwrap();
+        let report = migrate(&mut conn).unwrap();
+        assert!(report.backup.is_none());
+
+        let path = dir.join("anterior.sql");
+        let mut conn = Connection::open(&path).unwrap();
+        conn.execute_batch(FIXTURES[0].2).unwrap();
+
+        let report = migrate(&mut conn).unwrap();
+        assert_eq!(report.from, 0);
+        assert_eq!(report.to, latest_version());
+
+        let backup = report.backup.expect("Debe existir el respaldo");
+        let old = Connection::open(&backup).unwrap();
+        assert_eq!(current_version(&old).unwrap(), 0);
+        let n: i32 = old
+            .query_row("SELECT COUNT(*) FROM setze", [], |r| r.get(0))
+            .unwrap();
+        assert_eq!(n, 3);
+
+        drop(old);
+        drop(conn);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,241 @@
+use std::path::PathBuf;
+
+use chrono::Utc;
+use color_eyre::eyre::{Context, Result, bail};
+use rusqlite::{Connection, Transaction};
+
+use crate::db::{seeders::init_data, worte::WorteRepo, worte_setze::WorteSetzeRepo};
+
+mod m001_base;
+mod m002_fts;
+mod m003_worte_setze;
+mod m004_konjugation;
+mod m005_praeposition;
+mod m006_steigerung;
+mod m007_fehler;
+mod m008_verwechslung;
+mod m009_word_frequency;
+
+#[cfg(test)]
+mod migrations_test;
+
+/// Cambio del esquema. Una vez publicada una migración no se modifica: cualquier cambio
+/// posterior va en una migración nueva con la siguiente versión.
+pub struct Migration {
+    pub version: u32,
+    pub name: &'static str,
+    pub up: fn(&Transaction) -> Result<()>,
+}
+
+/// Migraciones en orden. La versión aplicada se guarda en `PRAGMA user_version`.
+pub static MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        name: "base",
+        up: m001_base::up,
+    },
+    Migration {
+        version: 2,
+        name: "fts",
+        up: m002_fts::up,
+    },
+    Migration {
+        version: 3,
+        name: "worte_setze",
+        up: m003_worte_setze::up,
+    },
+    Migration {
+        version: 4,
+        name: "konjugation",
+        up: m004_konjugation::up,
+    },
+    Migration {
+        version: 5,
+        name: "praeposition",
+        up: m005_praeposition::up,
+    },
+    Migration {
+        version: 6,
+        name: "steigerung",
+        up: m006_steigerung::up,
+    },
+    Migration {
+        version: 7,
+        name: "fehler",
+        up: m007_fehler::up,
+    },
+    Migration {
+        version: 8,
+        name: "verwechslung",
+        up: m008_verwechslung::up,
+    },
+    Migration {
+        version: 9,
+        name: "word_frequency",
+        up: m009_word_frequency::up,
+    },
+];
+
+#[derive(Debug)]
+pub struct MigrationReport {
+    pub from: u32,
+    pub to: u32,
+    /// Copia de la base antes de migrar, solo si había datos y la base es un archivo.
+    pub backup: Option<PathBuf>,
+}
+
+pub fn latest_version() -> u32 {
+    MIGRATIONS.last().map_or(0, |m| m.version)
+}
+
+pub fn current_version(conn: &Connection) -> Result<u32> {
+    Ok(conn.pragma_query_value(None, "user_version", |r| r.get(0))?)
+}
+
+/// Versión que crea la relación palabra-oración (`m003_worte_setze`).
+const VERSION_WORTE_SETZE: u32 = 3;
+
+/// Aplica las migraciones pendientes hasta la última versión.
+///
+/// Si la base venía de antes de `worte_setze`, al final se relacionan todas las palabras con
+/// sus oraciones usando `lemma::forms`, igual que al guardarlas desde la aplicación.
+pub fn migrate(conn: &mut Connection) -> Result<MigrationReport> {
+    let report = migrate_to(conn, MIGRATIONS, latest_version())?;
+
+    if report.from < VERSION_WORTE_SETZE && report.to >= VERSION_WORTE_SETZE {
+        // `WorteRepo` resuelve niveles y tipos gramaticales con los catálogos en memoria
+        init_data(conn)?;
+        let tx = conn.transaction()?;
+        let worte = WorteRepo::fetch_by_id(&tx, &WorteRepo::fetch_all_only_ids(&tx)?)?;
+        WorteSetzeRepo::link_worte_tx(&tx, &worte)
+            .context("No se pudieron relacionar las palabras con sus oraciones")?;
+        tx.commit()?;
+    }
+
+    Ok(report)
+}
+
+/// Aplica las migraciones pendientes hasta `target`, cada una en su propia transacción.
+///
+/// Las llaves foráneas se desactivan mientras se migra (SQLite no permite cambiarlas dentro
+/// de una transacción y algunas alteraciones de tablas lo requieren); al terminar cada
+/// migración se verifica que no queden referencias rotas.
+pub fn migrate_to(
+    conn: &mut Connection,
+    migrations: &[Migration],
+    target: u32,
+) -> Result<MigrationReport> {
+    let from = current_version(conn)?;
+    let latest = migrations.last().map_or(0, |m| m.version);
+    if from > latest {
+        bail!(
+            "La base de datos es de una versión más reciente (v{}) que la aplicación (v{})",
+            from,
+            latest
+        );
+    }
+
+    let pending: Vec<&Migration> = migrations
+        .iter()
+        .filter(|m| m.version > from && m.version <= target)
+        .collect();
+
+    let mut report = MigrationReport {
+        from,
+        to: from,
+        backup: None,
+    };
+    if pending.is_empty() {
+        return Ok(report);
+    }
+
+    if has_tables(conn)? {
+        report.backup = backup(conn, from)?;
+    }
+
+    conn.pragma_update(None, "foreign_keys", false)?;
+    let result = apply(conn, &pending);
+    conn.pragma_update(None, "foreign_keys", true)?;
+
+    report.to = result?;
+    Ok(report)
+}
+
+fn apply(conn: &mut Connection, pending: &[&Migration]) -> Result<u32> {
+    let mut version = current_version(conn)?;
+
+    for m in pending {
+        let tx = conn.transaction()?;
+        (m.up)(&tx).with_context(|| format!("Error en la migración {} ({})", m.version, m.name))?;
+
+        let broken: i64 =
+            tx.query_row("SELECT COUNT(*) FROM pragma_foreign_key_check", [], |r| {
+                r.get(0)
+            })?;
+        if broken > 0 {
+            bail!(
+                "La migración {} ({}) deja {} referencias rotas",
+                m.version,
+                m.name,
+                broken
+            );
+        }
+
+        tx.pragma_update(None, "user_version", m.version)?;
+        tx.commit()?;
+        version = m.version;
+    }
+
+    Ok(version)
+}
+
+fn has_tables(conn: &Connection) -> Result<bool> {
+    Ok(conn.query_row(
+        "SELECT EXISTS (SELECT 1 FROM sqlite_master WHERE type = 'table' AND name NOT LIKE 'sqlite_%')",
+        [],
+        |r| r.get(0),
+    )?)
+}
+
+/// Copia la base a `<archivo>.v<versión>-<fecha>.bak`. Las bases en memoria no se respaldan.
+fn backup(conn: &Connection, version: u32) -> Result<Option<PathBuf>> {
+    let Some(path) = conn.path().filter(|p| !p.is_empty()) else {
+        return Ok(None);
+    };
+
+    let backup = PathBuf::from(format!(
+        "{}.v{}-{}.bak",
+        path,
+        version,
+        Utc::now().format("%Y%m%d%H%M%S")
+    ));
+    conn.execute("VACUUM INTO ?1", [backup.to_string_lossy()])
+        .with_context(|| format!("No se pudo respaldar la base en: {}", backup.display()))?;
+
+    Ok(Some(backup))
+}
+
+/// Agrega la columna a una tabla creada con una versión anterior del esquema.
+///
+/// Regresa `true` si la columna se agregó, para poder migrar los datos existentes.
+fn add_column_if_missing(
+    conn: &Connection,
+    table: &str,
+    column: &str,
+    definition: &str,
+) -> Result<bool> {
+    let exists: bool = conn.query_row(
+        "SELECT EXISTS (SELECT 1 FROM pragma_table_info(?1) WHERE name = ?2)",
+        [table, column],
+        |r| r.get(0),
+    )?;
+
+    if !exists {
+        conn.execute(
+            &format!("ALTER TABLE {table} ADD COLUMN {column} {definition}"),
+            [],
+        )?;
+    }
+
+    Ok(!exists)
+}
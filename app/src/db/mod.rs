@@ -1,4 +1,5 @@
 mod connection;
+pub mod migrations;
 pub mod raw;
 mod repositories;
 pub mod schemas;
@@ -0,0 +1,60 @@
+use crate::{
+    db::schemas::deck::{DECK_SEPARATOR, DeckSchema as Schema, DeckSettings},
+    helpers::review_state::SchedulerParams,
+};
+
+impl Schema {
+    /// Último nivel del nombre: "Goethe B1::Kapitel 4" -> "Kapitel 4".
+    pub fn short_name(&self) -> &str {
+        self.name
+            .rsplit(DECK_SEPARATOR)
+            .next()
+            .unwrap_or(&self.name)
+    }
+
+    /// Niveles por encima del deck, 0 para los decks raíz.
+    pub fn depth(&self) -> usize {
+        self.name.matches(DECK_SEPARATOR).count()
+    }
+
+    pub fn settings(&self) -> DeckSettings {
+        DeckSettings {
+            max_neue_worte: self.max_neue_worte,
+            max_neue_setze: self.max_neue_setze,
+            initial_ease: self.initial_ease,
+            interval_modifier: self.interval_modifier,
+            max_interval: self.max_interval,
+        }
+    }
+
+    pub fn scheduler(&self) -> SchedulerParams {
+        SchedulerParams {
+            initial_ease: self.initial_ease,
+            interval_modifier: self.interval_modifier,
+            max_interval: self.max_interval,
+        }
+    }
+}
+
+impl Default for DeckSettings {
+    fn default() -> Self {
+        let scheduler = SchedulerParams::default();
+        Self {
+            max_neue_worte: 10,
+            max_neue_setze: 5,
+            initial_ease: scheduler.initial_ease,
+            interval_modifier: scheduler.interval_modifier,
+            max_interval: scheduler.max_interval,
+        }
+    }
+}
+
+/// Normaliza una ruta de deck: quita espacios y niveles vacíos.
+/// "  Goethe B1 :: Kapitel 4 ::" -> "Goethe B1::Kapitel 4".
+pub fn normalize_deck_path(path: &str) -> String {
+    path.split(DECK_SEPARATOR)
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .collect::<Vec<_>>()
+        .join(DECK_SEPARATOR)
+}
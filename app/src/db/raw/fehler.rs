@@ -0,0 +1,31 @@
+use color_eyre::eyre::Result;
+use sql_model::FromRaw;
+
+use crate::{
+    db::schemas::fehler::{FehlerSchema as Schema, RawFehlerSchema as Raw},
+    helpers::time::string_2_datetime,
+};
+
+impl FromRaw<Raw> for Schema {
+    fn from_raw(r: Raw) -> Result<Self> {
+        let created_at = string_2_datetime(Some(r.created_at)).unwrap();
+        let deleted_at = string_2_datetime(r.deleted_at);
+
+        Ok(Schema {
+            id: r.id,
+
+            art: r.art,
+            kategorie: r.kategorie,
+            detail: r.detail,
+            wort_id: r.wort_id,
+            satz_id: r.satz_id,
+
+            created_at,
+            deleted_at,
+        })
+    }
+
+    fn from_vec_raw(data: Vec<Raw>) -> Result<Vec<Self>> {
+        data.into_iter().map(Self::from_raw).collect()
+    }
+}
@@ -0,0 +1,37 @@
+use color_eyre::eyre::Result;
+use sql_model::FromRaw;
+
+use crate::{
+    db::schemas::konjugation_review::{
+        KonjugationReviewSchema as Schema, RawKonjugationReviewSchema as Raw,
+    },
+    helpers::time::string_2_datetime,
+};
+
+impl FromRaw<Raw> for Schema {
+    fn from_raw(r: Raw) -> Result<Self> {
+        let next_review = string_2_datetime(Some(r.next_review)).unwrap();
+
+        let created_at = string_2_datetime(Some(r.created_at)).unwrap();
+        let deleted_at = string_2_datetime(r.deleted_at);
+
+        Ok(Schema {
+            id: r.id,
+
+            wort_id: r.wort_id,
+            tempus: r.tempus,
+            interval: r.interval,
+            ease_factor: r.ease_factor,
+            repetitions: r.repetitions,
+            next_review,
+            lapses: r.lapses,
+
+            created_at,
+            deleted_at,
+        })
+    }
+
+    fn from_vec_raw(data: Vec<Raw>) -> Result<Vec<Self>> {
+        data.into_iter().map(Self::from_raw).collect()
+    }
+}
@@ -1,7 +1,14 @@
+pub mod deck;
+pub mod fehler;
 pub mod gram_type;
+pub mod konjugation_review;
 pub mod niveau_liste;
+pub mod praeposition_review;
+pub mod schwirig_liste;
 pub mod setze;
 pub mod setze_review;
+pub mod verwechslung;
+pub mod word_frequency;
 pub mod worte;
 pub mod worte_gender;
 pub mod worte_gram_type;
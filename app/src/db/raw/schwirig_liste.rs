@@ -0,0 +1,68 @@
+use color_eyre::eyre::{Result, bail};
+use std::{
+    collections::HashMap,
+    sync::{LazyLock, Mutex},
+};
+
+use crate::db::schemas::schwirig_liste::{
+    NewSchwirigListeSchema as New, SchwirigListeSchema as Schema,
+};
+
+static HASH_VALUES: LazyLock<Mutex<HashMap<i32, Schema>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+impl Schema {
+    pub fn init_data(data: &[Self]) -> Result<()> {
+        let mut hash = HASH_VALUES.lock().unwrap();
+        for d in data {
+            hash.insert(d.id, d.clone());
+        }
+        Ok(())
+    }
+
+    pub fn from_id(id: impl Into<i32>) -> Result<Self> {
+        let id = id.into();
+        let hash = HASH_VALUES.lock().unwrap();
+        let result = hash.get(&id).cloned();
+        match result {
+            Some(v) => Ok(v),
+            None => bail!("No se encontro Schwirig con id: {}", id),
+        }
+    }
+
+    /// Busca la dificultad por nombre sin importar mayúsculas: "schwirig" -> Schwirig.
+    pub fn from_schwirig(schwirig: impl Into<String>) -> Result<Self> {
+        let schwirig = schwirig.into();
+        let hash = HASH_VALUES.lock().unwrap();
+        let result = hash
+            .values()
+            .find(|val| val.schwirig.eq_ignore_ascii_case(schwirig.trim()))
+            .cloned();
+
+        match result {
+            Some(v) => Ok(v),
+            None => bail!("No se encontro Schwirig con el nombre: {}", schwirig),
+        }
+    }
+
+    /// Acepta el id ("2") o el nombre ("Schwirig").
+    pub fn parse(value: &str) -> Result<Self> {
+        match value.trim().parse::<i32>() {
+            Ok(id) => Self::from_id(id),
+            Err(_) => Self::from_schwirig(value),
+        }
+    }
+}
+
+impl New {
+    #[inline]
+    pub fn new<S>(id: impl Into<i32>, schwirig: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            id: id.into(),
+            schwirig: schwirig.into(),
+        }
+    }
+}
@@ -4,6 +4,7 @@ use sql_model::FromRaw;
 use crate::{
     db::schemas::{
         niveau_liste::NiveauListeSchema,
+        schwirig_liste::SchwirigListeSchema,
         setze::{NewSetzeSchema as New, RawSetzeSchema as Raw, SetzeSchema as Schema},
     },
     helpers::time::string_2_datetime,
@@ -15,13 +16,17 @@ impl FromRaw<Raw> for Schema {
         let deleted_at = string_2_datetime(r.deleted_at);
 
         let niveau_id = NiveauListeSchema::from_id(r.niveau_id)?;
+        let schwirig_id = SchwirigListeSchema::from_id(r.schwirig_id)?;
 
         Ok(Schema {
             id: r.id,
             setze_spanisch: r.setze_spanisch,
             setze_deutsch: r.setze_deutsch,
             thema: r.thema,
+            deck_id: r.deck_id,
+            tags: vec![],
             niveau_id,
+            schwirig_id,
             created_at,
             deleted_at,
         })
@@ -38,12 +43,18 @@ impl New {
         setze_deutsch: String,
         thema: String,
         niveau_id: i32,
+        schwirig_id: i32,
+        deck_id: i32,
+        tags: Vec<String>,
     ) -> Self {
         Self {
             setze_spanisch,
             setze_deutsch,
             thema,
             niveau_id,
+            schwirig_id,
+            deck_id,
+            tags,
         }
     }
 }
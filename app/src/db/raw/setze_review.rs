@@ -23,6 +23,8 @@ impl FromRaw<Raw> for Schema {
             repetitions: r.repetitions,
             last_review,
             next_review,
+            lapses: r.lapses,
+            schwirig_id: r.schwirig_id,
 
             created_at,
             deleted_at,
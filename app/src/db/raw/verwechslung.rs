@@ -0,0 +1,32 @@
+use color_eyre::eyre::Result;
+use sql_model::FromRaw;
+
+use crate::{
+    db::schemas::verwechslung::{RawVerwechslungSchema as Raw, VerwechslungSchema as Schema},
+    helpers::time::string_2_datetime,
+};
+
+impl FromRaw<Raw> for Schema {
+    fn from_raw(r: Raw) -> Result<Self> {
+        let last_seen = string_2_datetime(Some(r.last_seen)).unwrap();
+
+        let created_at = string_2_datetime(Some(r.created_at)).unwrap();
+        let deleted_at = string_2_datetime(r.deleted_at);
+
+        Ok(Schema {
+            id: r.id,
+
+            wort_id: r.wort_id,
+            verwechselt_id: r.verwechselt_id,
+            count: r.count,
+            last_seen,
+
+            created_at,
+            deleted_at,
+        })
+    }
+
+    fn from_vec_raw(data: Vec<Raw>) -> Result<Vec<Self>> {
+        data.into_iter().map(Self::from_raw).collect()
+    }
+}
@@ -0,0 +1,28 @@
+use color_eyre::eyre::Result;
+use sql_model::FromRaw;
+
+use crate::{
+    db::schemas::word_frequency::{RawWordFrequencySchema as Raw, WordFrequencySchema as Schema},
+    helpers::time::string_2_datetime,
+};
+
+impl FromRaw<Raw> for Schema {
+    fn from_raw(r: Raw) -> Result<Self> {
+        let created_at = string_2_datetime(Some(r.created_at)).unwrap();
+        let deleted_at = string_2_datetime(r.deleted_at);
+
+        Ok(Schema {
+            id: r.id,
+
+            lemma: r.lemma,
+            rank: r.rank,
+
+            created_at,
+            deleted_at,
+        })
+    }
+
+    fn from_vec_raw(data: Vec<Raw>) -> Result<Vec<Self>> {
+        data.into_iter().map(Self::from_raw).collect()
+    }
+}
@@ -24,16 +24,20 @@ impl FromRaw<Raw> for Schema {
         Ok(Schema {
             id: r.id,
             gram_type_id: vec![],
+            tags: vec![],
             gender_id,
             worte_de: r.worte_de,
             worte_es: r.worte_es,
             plural: r.plural,
             niveau_id,
+            deck_id: r.deck_id,
             example_de: r.example_de,
             example_es: r.example_es,
             verb_aux: r.verb_aux,
             trennbar: r.trennbar,
             reflexiv: r.reflexiv,
+            komparativ: r.komparativ,
+            superlativ: r.superlativ,
             created_at,
             deleted_at,
         })
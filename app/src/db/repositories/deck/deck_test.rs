@@ -0,0 +1,196 @@
+use crate::db::setup_test_db;
+
+#[cfg(test)]
+mod test_deck_repo {
+    use color_eyre::eyre::Result;
+    use rusqlite::Connection;
+
+    use crate::db::{
+        deck::DeckRepo,
+        schemas::{
+            deck::{DEFAULT_DECK_ID, DeckSettings},
+            setze::NewSetzeSchema,
+            worte::NewWorteSchema,
+        },
+        seeders::init_data,
+        setze::SetzeRepo,
+        worte::WorteRepo,
+    };
+
+    use super::*;
+
+    fn new_satz(deutsch: &str, thema: &str, deck_id: i32) -> NewSetzeSchema {
+        NewSetzeSchema {
+            setze_spanisch: "-".into(),
+            setze_deutsch: deutsch.into(),
+            niveau_id: 0,
+            thema: thema.into(),
+            deck_id,
+            schwirig_id: 1,
+            tags: vec![],
+        }
+    }
+
+    fn new_wort(de: &str, deck_id: i32) -> NewWorteSchema {
+        NewWorteSchema {
+            gram_type: vec![0],
+            tags: vec![],
+            gender_id: None,
+            worte_de: de.into(),
+            worte_es: "-".into(),
+            plural: None,
+            niveau_id: 0,
+            example_de: "Beispiel".into(),
+            example_es: "Ejemplo".into(),
+            verb_aux: None,
+            trennbar: None,
+            reflexiv: None,
+            komparativ: None,
+            superlativ: None,
+            deck_id,
+        }
+    }
+
+    fn init_data_local(conn: &mut Connection) -> Result<()> {
+        init_data(conn)?;
+        init_data(conn)?; // El deck por defecto no se duplica
+        Ok(())
+    }
+
+    #[test]
+    fn test_default_deck() {
+        let mut conn = setup_test_db().expect("Error al crear db test");
+        init_data_local(&mut conn).expect("Error al iniciar datos dummy");
+
+        let decks = DeckRepo::fetch_all(&conn).expect("Error al hacer fetch");
+        assert_eq!(decks.len(), 1);
+        assert_eq!(decks[0].id, DEFAULT_DECK_ID);
+        assert_eq!(decks[0].settings(), DeckSettings::default());
+
+        // Las tarjetas sin deck explícito quedan en el deck por defecto
+        conn.execute(
+            "INSERT INTO setze (setze_spanisch, setze_deutsch, niveau_id, thema) VALUES ('a', 'b', 0, 'c')",
+            [],
+        )
+        .unwrap();
+        let satz = SetzeRepo::fetch_by_id(&conn, &[1]).expect("Error al hacer fetch");
+        assert_eq!(satz[0].deck_id, DEFAULT_DECK_ID);
+    }
+
+    #[test]
+    fn test_ensure_path_creates_parents() {
+        let mut conn = setup_test_db().expect("Error al crear db test");
+        init_data_local(&mut conn).expect("Error al iniciar datos dummy");
+
+        let kapitel = DeckRepo::ensure_path(&mut conn, " Goethe B1 :: Kapitel 4 ")
+            .expect("La inserción no debe fallar");
+        assert_eq!(kapitel.name, "Goethe B1::Kapitel 4");
+        assert_eq!(kapitel.short_name(), "Kapitel 4");
+        assert_eq!(kapitel.depth(), 1);
+
+        let goethe = DeckRepo::ensure_path(&mut conn, "goethe b1").expect("No debe fallar");
+        assert_eq!(goethe.name, "Goethe B1");
+        let ids = DeckRepo::fetch_subtree_ids(&conn, goethe.id).expect("No debe fallar");
+        assert_eq!(ids, vec![goethe.id, kapitel.id]);
+
+        // Volver a pedir la misma ruta regresa el deck existente
+        let again =
+            DeckRepo::ensure_path(&mut conn, "Goethe B1::kapitel 4").expect("No debe fallar");
+        assert_eq!(again.id, kapitel.id);
+
+        assert!(DeckRepo::ensure_path(&mut conn, " :: ").is_err());
+
+        let names: Vec<String> = DeckRepo::fetch_all(&conn)
+            .expect("Error al hacer fetch")
+            .into_iter()
+            .map(|d| d.name)
+            .collect();
+        assert_eq!(names, vec!["Default", "Goethe B1", "Goethe B1::Kapitel 4"]);
+    }
+
+    #[test]
+    fn test_fetch_subtree_ids() {
+        let mut conn = setup_test_db().expect("Error al crear db test");
+        init_data_local(&mut conn).expect("Error al iniciar datos dummy");
+
+        let k4 = DeckRepo::ensure_path(&mut conn, "B1::Kapitel 4").unwrap();
+        let k5 = DeckRepo::ensure_path(&mut conn, "B1::Kapitel 5").unwrap();
+        let grammatik = DeckRepo::ensure_path(&mut conn, "B1::Kapitel 5::Grammatik").unwrap();
+        let b1 = DeckRepo::ensure_path(&mut conn, "B1").unwrap().id;
+
+        let ids = DeckRepo::fetch_subtree_ids(&conn, b1).expect("Error al hacer fetch");
+        assert_eq!(ids, vec![b1, k4.id, k5.id, grammatik.id]);
+
+        let ids = DeckRepo::fetch_subtree_ids(&conn, k5.id).expect("Error al hacer fetch");
+        assert_eq!(ids, vec![k5.id, grammatik.id]);
+
+        let ids =
+            DeckRepo::fetch_subtree_ids(&conn, DEFAULT_DECK_ID).expect("Error al hacer fetch");
+        assert_eq!(ids, vec![DEFAULT_DECK_ID]);
+    }
+
+    #[test]
+    fn test_update_settings() {
+        let mut conn = setup_test_db().expect("Error al crear db test");
+        init_data_local(&mut conn).expect("Error al iniciar datos dummy");
+
+        let settings = DeckSettings {
+            max_neue_worte: 3,
+            max_neue_setze: 0,
+            initial_ease: 2.0,
+            interval_modifier: 0.8,
+            max_interval: 60,
+        };
+        let deck = DeckRepo::update_settings(&conn, DEFAULT_DECK_ID, &settings)
+            .expect("La actualización no debe fallar");
+        assert_eq!(deck.settings(), settings);
+        assert_eq!(deck.scheduler().max_interval, 60);
+
+        assert!(DeckRepo::update_settings(&conn, 99, &settings).is_err());
+    }
+
+    #[test]
+    fn test_cards_by_deck() {
+        let mut conn = setup_test_db().expect("Error al crear db test");
+        init_data_local(&mut conn).expect("Error al iniciar datos dummy");
+
+        let k4 = DeckRepo::ensure_path(&mut conn, "B1::Kapitel 4").unwrap();
+        let b1 = DeckRepo::ensure_path(&mut conn, "B1").unwrap().id;
+
+        WorteRepo::bulk_insert(
+            &mut conn,
+            &[
+                new_wort("Hund", DEFAULT_DECK_ID),
+                new_wort("Katze", k4.id),
+                new_wort("Maus", b1),
+            ],
+        )
+        .unwrap();
+        SetzeRepo::bulk_insert(
+            &mut conn,
+            &[
+                new_satz("Ich habe einen Hund", "Akkusativ", DEFAULT_DECK_ID),
+                new_satz("Die Katze schläft", "Nominativ", k4.id),
+            ],
+        )
+        .unwrap();
+
+        let subtree = DeckRepo::fetch_subtree_ids(&conn, b1).unwrap();
+        let ids = WorteRepo::fetch_id_in_decks(&conn, &[1, 2, 3], &subtree).unwrap();
+        assert_eq!(ids, vec![2, 3]);
+        let ids = SetzeRepo::fetch_id_in_decks(&conn, &[1, 2], &subtree).unwrap();
+        assert_eq!(ids, vec![2]);
+
+        let themas = SetzeRepo::fetch_all_themas(&conn, Some(&subtree)).unwrap();
+        assert_eq!(themas, vec!["Nominativ"]);
+        let themas = SetzeRepo::fetch_all_themas(&conn, None).unwrap();
+        assert_eq!(themas.len(), 2);
+
+        let hash = DeckRepo::fetch_by_wort_id(&conn, &[1, 2]).unwrap();
+        assert_eq!(hash[&1].id, DEFAULT_DECK_ID);
+        assert_eq!(hash[&2].id, k4.id);
+
+        let hash = DeckRepo::fetch_by_satz_id(&conn, &[2]).unwrap();
+        assert_eq!(hash[&2].name, "B1::Kapitel 4");
+    }
+}
@@ -0,0 +1,249 @@
+use std::collections::HashMap;
+
+use color_eyre::eyre::{Context, Result, bail};
+use rusqlite::{Connection, Transaction, params, params_from_iter};
+use sql_model::{FromRaw, SqlNew, SqlRaw};
+
+use crate::db::{
+    raw::deck::normalize_deck_path,
+    schemas::deck::{
+        DECK_SEPARATOR, DEFAULT_DECK_ID, DEFAULT_DECK_NAME, DeckSchema as Schema, DeckSettings,
+        NewDeckSchema as New, RawDeckSchema as Raw,
+    },
+};
+
+#[cfg(test)]
+mod deck_test;
+
+const SELECT_DECK: &str = "
+    SELECT
+        d.id, d.name, d.max_neue_worte, d.max_neue_setze, d.initial_ease,
+        d.interval_modifier, d.max_interval, d.created_at, d.deleted_at
+    FROM deck d";
+
+pub struct DeckRepo;
+
+impl DeckRepo {
+    /// Crea el deck por defecto (id 1) al que apuntan las tarjetas sin deck.
+    pub fn init_default_tx(tx: &Transaction) -> Result<()> {
+        let sql = "
+            INSERT INTO deck (id, name)
+                VALUES (?1, ?2)
+            ON CONFLICT DO NOTHING;
+        ";
+        tx.execute(sql, params![DEFAULT_DECK_ID, DEFAULT_DECK_NAME])
+            .context(format!("sql: {}", sql))?;
+        Ok(())
+    }
+
+    /// Inserta los decks; si ya existe uno con el mismo nombre (sin importar mayúsculas)
+    /// regresa el existente.
+    pub fn bulk_insert_tx(tx: &Transaction, data: &[New]) -> Result<Vec<Schema>> {
+        if data.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let sql = r#"
+            INSERT INTO deck (name, parent_id)
+                VALUES (?1, ?2)
+            ON CONFLICT(name) DO UPDATE SET deleted_at = NULL
+            RETURNING id, name, max_neue_worte, max_neue_setze, initial_ease,
+                interval_modifier, max_interval, created_at, deleted_at;
+        "#;
+
+        let mut vec_out = Vec::with_capacity(data.len());
+        let mut stmt = tx.prepare_cached(sql).context(format!("sql: {}", sql))?;
+        for d in data {
+            let raw = stmt
+                .query_one(d.to_params(), Raw::from_sql)
+                .context(format!("sql: {}, params: {:#?}", sql, d))?;
+            vec_out.push(Schema::from_raw(raw)?);
+        }
+
+        Ok(vec_out)
+    }
+
+    pub fn ensure_path(conn: &mut Connection, path: &str) -> Result<Schema> {
+        let tx = conn.transaction()?;
+        let out = Self::ensure_path_tx(&tx, path)?;
+        tx.commit()?;
+        Ok(out)
+    }
+
+    /// Regresa el deck de la ruta "Goethe B1::Kapitel 4", creando los niveles que falten.
+    pub fn ensure_path_tx(tx: &Transaction, path: &str) -> Result<Schema> {
+        let path = normalize_deck_path(path);
+        if path.is_empty() {
+            bail!("El nombre del deck está vacío");
+        }
+
+        let mut name = String::new();
+        let mut parent_id = None;
+        let mut deck = None;
+        for part in path.split(DECK_SEPARATOR) {
+            if !name.is_empty() {
+                name.push_str(DECK_SEPARATOR);
+            }
+            name.push_str(part);
+
+            let d = Self::bulk_insert_tx(
+                tx,
+                &[New {
+                    name: name.clone(),
+                    parent_id,
+                }],
+            )?
+            .remove(0);
+            parent_id = Some(d.id);
+            deck = Some(d);
+        }
+
+        Ok(deck.expect("La ruta tiene al menos un nivel"))
+    }
+
+    /// Decks activos ordenados por ruta, así los subdecks quedan debajo de su padre.
+    pub fn fetch_all(conn: &Connection) -> Result<Vec<Schema>> {
+        let sql = format!(
+            "{SELECT_DECK}
+            WHERE d.deleted_at IS NULL
+            ORDER BY d.name;"
+        );
+
+        let mut stmt = conn.prepare_cached(&sql)?;
+        let raw = stmt
+            .query([])
+            .context(format!("sql: {}", sql))?
+            .mapped(Raw::from_sql)
+            .collect::<Result<Vec<Raw>, _>>()?;
+
+        Schema::from_vec_raw(raw)
+    }
+
+    pub fn fetch_by_id(conn: &Connection, ids: &[i32]) -> Result<Vec<Schema>> {
+        if ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let placeholders = vec!["?"; ids.len()].join(",");
+        let sql = format!(
+            "{SELECT_DECK}
+            WHERE d.id in ({placeholders})
+                AND d.deleted_at IS NULL
+            ORDER BY d.name;"
+        );
+
+        let mut stmt = conn.prepare(&sql)?;
+        let raw = stmt
+            .query(params_from_iter(ids.iter()))
+            .context(format!("sql: {}, params: {:#?}", sql, ids))?
+            .mapped(Raw::from_sql)
+            .collect::<Result<Vec<Raw>, _>>()?;
+
+        Schema::from_vec_raw(raw)
+    }
+
+    /// Ids del deck y de todos sus subdecks.
+    pub fn fetch_subtree_ids(conn: &Connection, id: i32) -> Result<Vec<i32>> {
+        let sql = "
+            WITH RECURSIVE subtree(id) AS (
+                SELECT id FROM deck WHERE id = ?1 AND deleted_at IS NULL
+                UNION
+                SELECT d.id
+                FROM deck d
+                JOIN subtree s ON d.parent_id = s.id
+                WHERE d.deleted_at IS NULL
+            )
+            SELECT id FROM subtree ORDER BY id;
+        ";
+
+        let mut stmt = conn.prepare_cached(sql)?;
+        let ids = stmt
+            .query(params![id])
+            .context(format!("sql: {}", sql))?
+            .mapped(|r| r.get(0))
+            .collect::<Result<Vec<i32>, _>>()?;
+
+        Ok(ids)
+    }
+
+    pub fn update_settings(conn: &Connection, id: i32, settings: &DeckSettings) -> Result<Schema> {
+        let sql = "
+            UPDATE deck SET
+                max_neue_worte = ?1,
+                max_neue_setze = ?2,
+                initial_ease = ?3,
+                interval_modifier = ?4,
+                max_interval = ?5
+            WHERE id = ?6 AND deleted_at IS NULL
+            RETURNING id, name, max_neue_worte, max_neue_setze, initial_ease,
+                interval_modifier, max_interval, created_at, deleted_at;
+        ";
+
+        let raw = conn
+            .query_one(
+                sql,
+                params![
+                    settings.max_neue_worte,
+                    settings.max_neue_setze,
+                    settings.initial_ease,
+                    settings.interval_modifier,
+                    settings.max_interval,
+                    id
+                ],
+                Raw::from_sql,
+            )
+            .context(format!("No existe el deck con id: {}", id))?;
+
+        Schema::from_raw(raw)
+    }
+
+    /// Deck de cada palabra: wort_id -> deck.
+    pub fn fetch_by_wort_id(conn: &Connection, ids: &[i32]) -> Result<HashMap<i32, Schema>> {
+        Self::fetch_by_card_id(conn, "worte", ids)
+    }
+
+    /// Deck de cada oración: satz_id -> deck.
+    pub fn fetch_by_satz_id(conn: &Connection, ids: &[i32]) -> Result<HashMap<i32, Schema>> {
+        Self::fetch_by_card_id(conn, "setze", ids)
+    }
+
+    fn fetch_by_card_id(
+        conn: &Connection,
+        table: &str,
+        ids: &[i32],
+    ) -> Result<HashMap<i32, Schema>> {
+        if ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let placeholders = vec!["?"; ids.len()].join(",");
+        let sql = format!(
+            "
+            SELECT c.id, c.deck_id
+            FROM {table} c
+            WHERE c.id in ({placeholders});
+        "
+        );
+
+        let mut stmt = conn.prepare(&sql)?;
+        let card_deck = stmt
+            .query(params_from_iter(ids.iter()))
+            .context(format!("sql: {}, params: {:#?}", sql, ids))?
+            .mapped(|r| Ok((r.get(0)?, r.get(1)?)))
+            .collect::<Result<Vec<(i32, i32)>, _>>()?;
+
+        let mut deck_ids: Vec<i32> = card_deck.iter().map(|(_, d)| *d).collect();
+        deck_ids.sort();
+        deck_ids.dedup();
+
+        let decks: HashMap<i32, Schema> = Self::fetch_by_id(conn, &deck_ids)?
+            .into_iter()
+            .map(|d| (d.id, d))
+            .collect();
+
+        Ok(card_deck
+            .into_iter()
+            .filter_map(|(card, deck)| decks.get(&deck).map(|d| (card, d.clone())))
+            .collect())
+    }
+}
@@ -0,0 +1,95 @@
+use crate::db::setup_test_db;
+
+#[cfg(test)]
+mod test_fehler_repo {
+    use rusqlite::Connection;
+
+    use crate::db::{
+        fehler::FehlerRepo,
+        schemas::{fehler::NewFehlerSchema as New, setze::NewSetzeSchema, worte::NewWorteSchema},
+        seeders::init_data,
+        setze::SetzeRepo,
+        worte::WorteRepo,
+    };
+
+    use super::*;
+
+    fn init_data_local(conn: &mut Connection) {
+        init_data(conn).unwrap();
+        WorteRepo::bulk_insert(
+            conn,
+            &[NewWorteSchema {
+                gram_type: vec![0],
+                tags: vec![],
+                gender_id: Some(0),
+                worte_de: "Hund".into(),
+                worte_es: "el perro".into(),
+                plural: None,
+                niveau_id: 1,
+                example_de: "".into(),
+                example_es: "".into(),
+                verb_aux: None,
+                trennbar: None,
+                reflexiv: None,
+                komparativ: None,
+                superlativ: None,
+                deck_id: 1,
+            }],
+        )
+        .unwrap();
+        SetzeRepo::bulk_insert(
+            conn,
+            &[NewSetzeSchema {
+                setze_spanisch: "El perro juega".into(),
+                setze_deutsch: "Der Hund spielt".into(),
+                niveau_id: 1,
+                thema: "Tiere".into(),
+                deck_id: 1,
+                schwirig_id: 0,
+                tags: vec![],
+            }],
+        )
+        .unwrap();
+    }
+
+    fn new(wort_id: Option<i32>, satz_id: Option<i32>, kategorie: &str) -> New {
+        New {
+            art: if wort_id.is_some() { "wort" } else { "satz" }.into(),
+            kategorie: kategorie.into(),
+            detail: None,
+            wort_id,
+            satz_id,
+            expected: "der Hund".into(),
+            input: "die Hund".into(),
+        }
+    }
+
+    #[test]
+    fn test_bulk_insert_and_fetch_all() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+
+        let inserted = FehlerRepo::bulk_insert(
+            &mut conn,
+            &[
+                new(Some(1), None, "artikel"),
+                new(None, Some(1), "wortstellung"),
+            ],
+        )
+        .unwrap();
+        assert_eq!(inserted.len(), 2);
+        assert_eq!(inserted[0].kategorie, "artikel");
+        assert_eq!(inserted[0].art, "wort");
+        assert!(FehlerRepo::bulk_insert(&mut conn, &[]).unwrap().is_empty());
+
+        // Los más recientes primero
+        let all = FehlerRepo::fetch_all(&conn).unwrap();
+        assert_eq!(all.iter().map(|f| f.id).collect::<Vec<_>>(), vec![2, 1]);
+
+        // Los errores de tarjetas borradas no cuentan
+        WorteRepo::soft_delete(&mut conn, &[1]).unwrap();
+        let all = FehlerRepo::fetch_all(&conn).unwrap();
+        assert_eq!(all.len(), 1);
+        assert_eq!(all[0].satz_id, Some(1));
+    }
+}
@@ -0,0 +1,69 @@
+use color_eyre::eyre::{Context, Result};
+use rusqlite::{Connection, Transaction};
+use sql_model::{FromRaw, SqlNew, SqlRaw};
+
+use crate::db::schemas::fehler::{
+    FehlerSchema as Schema, NewFehlerSchema as New, RawFehlerSchema as Raw,
+};
+
+#[cfg(test)]
+mod fehler_test;
+
+pub struct FehlerRepo;
+
+impl FehlerRepo {
+    pub fn bulk_insert(conn: &mut Connection, data: &[New]) -> Result<Vec<Schema>> {
+        let tx = conn.transaction()?;
+        let out = Self::bulk_insert_tx(&tx, data)?;
+        tx.commit()?;
+        Ok(out)
+    }
+
+    pub fn bulk_insert_tx(tx: &Transaction, data: &[New]) -> Result<Vec<Schema>> {
+        if data.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let sql = r#"
+            INSERT INTO fehler (art, kategorie, detail, wort_id, satz_id, expected, input)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+            RETURNING id, art, kategorie, detail, wort_id, satz_id, created_at, deleted_at;
+        "#;
+
+        let mut vec_out = Vec::with_capacity(data.len());
+        let mut stmt = tx.prepare_cached(sql).context(format!("sql: {}", sql))?;
+        for d in data {
+            let raw = stmt
+                .query_one(d.to_params(), Raw::from_sql)
+                .context(format!("sql: {}, params: {:#?}", sql, d))?;
+            vec_out.push(Schema::from_raw(raw)?)
+        }
+
+        Ok(vec_out)
+    }
+
+    /// Errores de las palabras y oraciones activas, los más recientes primero.
+    pub fn fetch_all(conn: &Connection) -> Result<Vec<Schema>> {
+        let sql = "
+            SELECT
+                f.id, f.art, f.kategorie, f.detail, f.wort_id, f.satz_id,
+                f.created_at, f.deleted_at
+            FROM fehler f
+            LEFT JOIN worte w ON w.id = f.wort_id
+            LEFT JOIN setze s ON s.id = f.satz_id
+            WHERE f.deleted_at IS NULL
+                AND w.deleted_at IS NULL
+                AND s.deleted_at IS NULL
+            ORDER BY f.created_at DESC, f.id DESC;
+        ";
+
+        let mut stmt = conn.prepare_cached(sql)?;
+        let raw = stmt
+            .query([])
+            .context(format!("sql: {}", sql))?
+            .mapped(Raw::from_sql)
+            .collect::<Result<Vec<Raw>, _>>()?;
+
+        Schema::from_vec_raw(raw)
+    }
+}
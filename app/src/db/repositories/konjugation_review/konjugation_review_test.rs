@@ -0,0 +1,120 @@
+use crate::db::setup_test_db;
+
+#[cfg(test)]
+mod test_konjugation_review_repo {
+    use rusqlite::Connection;
+
+    use crate::{
+        db::{
+            konjugation_review::KonjugationReviewRepo,
+            schemas::{
+                konjugation_review::NewKonjugationReviewSchema as New, worte::NewWorteSchema,
+            },
+            seeders::init_data,
+            worte::WorteRepo,
+        },
+        helpers::konjugation::{KonjugationKey, Tempus},
+    };
+
+    use super::*;
+
+    fn init_data_local(conn: &mut Connection) {
+        init_data(conn).unwrap();
+        let verb = |worte_de: &str| NewWorteSchema {
+            gram_type: vec![2],
+            tags: vec![],
+            gender_id: None,
+            worte_de: worte_de.into(),
+            worte_es: "".into(),
+            plural: None,
+            niveau_id: 1,
+            example_de: "".into(),
+            example_es: "".into(),
+            verb_aux: Some("haben".into()),
+            trennbar: None,
+            reflexiv: None,
+            komparativ: None,
+            superlativ: None,
+            deck_id: 1,
+        };
+        WorteRepo::bulk_insert(conn, &[verb("spielen"), verb("machen")]).unwrap();
+    }
+
+    fn new(wort_id: i32, tempus: &str, next_review: &str, lapses: u32) -> New {
+        New {
+            wort_id,
+            tempus: tempus.into(),
+            interval: 1,
+            ease_factor: 2.5,
+            repetitions: 1,
+            last_review: "2020-01-01 00:00:00".into(),
+            next_review: next_review.into(),
+            lapses,
+        }
+    }
+
+    #[test]
+    fn test_upsert() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+
+        let first = KonjugationReviewRepo::bulk_insert(
+            &mut conn,
+            &[new(1, "praesens", "2020-01-02 00:00:00", 0)],
+        )
+        .unwrap();
+        let second = KonjugationReviewRepo::bulk_insert(
+            &mut conn,
+            &[new(1, "praesens", "2020-01-05 00:00:00", 1)],
+        )
+        .unwrap();
+
+        // Un verbo y tiempo tiene una sola revisión
+        assert_eq!(first[0].id, second[0].id);
+        assert_eq!(second[0].lapses, 1);
+        assert_eq!(second[0].next_review.to_string(), "2020-01-05 00:00:00 UTC");
+        assert_eq!(
+            KonjugationReviewRepo::fetch_by_wort_id(&conn, &[1])
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_fetch_keys() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+
+        KonjugationReviewRepo::bulk_insert(
+            &mut conn,
+            &[
+                new(1, "praeteritum", "2020-01-03 00:00:00", 0),
+                new(1, "praesens", "2020-01-02 00:00:00", 0),
+                new(2, "partizip2", "2999-01-01 00:00:00", 0),
+            ],
+        )
+        .unwrap();
+
+        let key = |wort_id, tempus| KonjugationKey { wort_id, tempus };
+        let due =
+            KonjugationReviewRepo::fetch_review_key_by_day(&conn, "2021-01-01 00:00:00".into())
+                .unwrap();
+        assert_eq!(
+            due,
+            vec![key(1, Tempus::Praesens), key(1, Tempus::Praeteritum)]
+        );
+
+        let keys = KonjugationReviewRepo::fetch_keys_by_wort_id(&conn, &[2]).unwrap();
+        assert_eq!(keys.len(), 1);
+        assert!(keys.contains(&key(2, Tempus::Partizip)));
+
+        // Los verbos borrados no se repasan
+        WorteRepo::soft_delete(&mut conn, &[1]).unwrap();
+        assert!(
+            KonjugationReviewRepo::fetch_review_key_by_day(&conn, "2021-01-01 00:00:00".into())
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
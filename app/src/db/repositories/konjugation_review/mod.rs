@@ -0,0 +1,128 @@
+use std::collections::HashSet;
+
+use color_eyre::eyre::{Context, Result};
+use rusqlite::{Connection, Transaction, params, params_from_iter};
+use sql_model::{FromRaw, SqlNew, SqlRaw};
+
+use crate::{
+    db::schemas::konjugation_review::{
+        KonjugationReviewSchema as Schema, NewKonjugationReviewSchema as New,
+        RawKonjugationReviewSchema as Raw,
+    },
+    helpers::konjugation::{KonjugationKey, Tempus},
+};
+
+#[cfg(test)]
+mod konjugation_review_test;
+
+pub struct KonjugationReviewRepo;
+
+impl KonjugationReviewRepo {
+    pub fn bulk_insert(conn: &mut Connection, data: &[New]) -> Result<Vec<Schema>> {
+        let tx = conn.transaction()?;
+        let out = Self::bulk_insert_tx(&tx, data)?;
+        tx.commit()?;
+        Ok(out)
+    }
+
+    pub fn bulk_insert_tx(tx: &Transaction, data: &[New]) -> Result<Vec<Schema>> {
+        if data.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let sql = r#"
+            INSERT INTO konjugation_review (wort_id, tempus, interval, ease_factor, repetitions, last_review, next_review, lapses)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+
+            ON CONFLICT(wort_id, tempus) DO UPDATE SET
+                interval = ?3,
+                ease_factor = ?4,
+                repetitions = ?5,
+                last_review = ?6,
+                next_review = ?7,
+                lapses = ?8
+
+            RETURNING id, wort_id, tempus, interval, ease_factor, repetitions, next_review, lapses, created_at, deleted_at;
+        "#;
+
+        let mut vec_out = Vec::with_capacity(data.len());
+        let mut stmt = tx.prepare_cached(sql).context(format!("sql: {}", sql))?;
+        for d in data {
+            let raw = stmt
+                .query_one(d.to_params(), Raw::from_sql)
+                .context(format!("sql: {}, params: {:#?}", sql, d))?;
+            vec_out.push(Schema::from_raw(raw)?)
+        }
+
+        Ok(vec_out)
+    }
+
+    pub fn fetch_by_wort_id(conn: &Connection, ids: &[i32]) -> Result<Vec<Schema>> {
+        if ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let placeholders = std::iter::repeat_n("?", ids.len())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        let sql = format!(
+            "
+                SELECT
+                    id, wort_id, tempus, interval, ease_factor, repetitions,
+                    next_review, lapses, created_at, deleted_at
+                FROM konjugation_review kr
+                WHERE kr.deleted_at IS NULL AND
+                kr.wort_id IN ({placeholders})
+            "
+        );
+
+        let mut stmt = conn.prepare(&sql)?;
+        let raw = stmt
+            .query(params_from_iter(ids.iter()))
+            .context(format!("sql: {}", sql))?
+            .mapped(Raw::from_sql)
+            .collect::<Result<Vec<Raw>, _>>()?;
+
+        Schema::from_vec_raw(raw)
+    }
+
+    /// Familias (verbo y tiempo) por repasar antes de `date_review`, las más atrasadas primero.
+    pub fn fetch_review_key_by_day(
+        conn: &Connection,
+        date_review: String,
+    ) -> Result<Vec<KonjugationKey>> {
+        let sql = r#"
+            SELECT kr.wort_id, kr.tempus
+            FROM konjugation_review kr
+            JOIN worte w ON w.id = kr.wort_id
+            WHERE kr.next_review < ?1
+                AND kr.deleted_at IS NULL
+                AND w.deleted_at IS NULL
+            ORDER BY kr.next_review ASC;
+        "#;
+
+        let mut stmt = conn.prepare(sql)?;
+        let rows = stmt
+            .query(params![date_review])?
+            .mapped(|r| Ok((r.get(0)?, r.get(1)?)))
+            .collect::<Result<Vec<(i32, String)>, _>>()?;
+
+        Ok(rows.into_iter().filter_map(Self::to_key).collect())
+    }
+
+    /// Familias de los verbos que ya tienen alguna revisión.
+    pub fn fetch_keys_by_wort_id(
+        conn: &Connection,
+        ids: &[i32],
+    ) -> Result<HashSet<KonjugationKey>> {
+        Ok(Self::fetch_by_wort_id(conn, ids)?
+            .into_iter()
+            .filter_map(|r| Self::to_key((r.wort_id, r.tempus)))
+            .collect())
+    }
+
+    fn to_key((wort_id, tempus): (i32, String)) -> Option<KonjugationKey> {
+        Tempus::parse(&tempus).map(|tempus| KonjugationKey { wort_id, tempus })
+    }
+}
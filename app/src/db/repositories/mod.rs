@@ -1,10 +1,25 @@
+pub mod deck;
+pub mod fehler;
 pub mod gram_type;
+pub mod konjugation_review;
 pub mod niveau_liste;
+pub mod praeposition_review;
+pub mod schwirig_liste;
+pub mod search;
 pub mod setze;
+pub mod setze_alternativ;
 pub mod setze_audio;
 pub mod setze_review;
+pub mod setze_tags;
+pub mod tags;
+pub mod verb_konjugation;
+pub mod verwechslung;
+pub mod word_frequency;
 pub mod worte;
+pub mod worte_alternativ;
 pub mod worte_audio;
 pub mod worte_gender;
 pub mod worte_gram_type;
 pub mod worte_review;
+pub mod worte_setze;
+pub mod worte_tags;
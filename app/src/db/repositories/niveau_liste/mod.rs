@@ -1,4 +1,4 @@
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{Context, Result};
 use rusqlite::{Connection, Transaction};
 use sql_model::{FromRaw, SqlNew, SqlRaw};
 
@@ -43,4 +43,24 @@ impl NiveauListeRepo {
 
         Ok(vec_out)
     }
+
+    /// Niveles activos de A1 a C2.
+    pub fn fetch_all(conn: &Connection) -> Result<Vec<Schema>> {
+        let sql = "
+            SELECT
+                id, niveau, created_at, deleted_at
+            FROM niveau_liste
+            WHERE deleted_at IS NULL
+            ORDER BY id ASC;
+        ";
+
+        let mut stmt = conn.prepare_cached(sql)?;
+        let raw = stmt
+            .query([])
+            .context(format!("sql: {}", sql))?
+            .mapped(Raw::from_sql)
+            .collect::<Result<Vec<Raw>, _>>()?;
+
+        Schema::from_vec_raw(raw)
+    }
 }
@@ -0,0 +1,105 @@
+use color_eyre::eyre::{Context, Result};
+use rusqlite::{Connection, Transaction, params, params_from_iter};
+use sql_model::{FromRaw, SqlNew, SqlRaw};
+
+use crate::db::schemas::praeposition_review::{
+    NewPraepositionReviewSchema as New, PraepositionReviewSchema as Schema,
+    RawPraepositionReviewSchema as Raw,
+};
+
+#[cfg(test)]
+mod praeposition_review_test;
+
+pub struct PraepositionReviewRepo;
+
+impl PraepositionReviewRepo {
+    pub fn bulk_insert(conn: &mut Connection, data: &[New]) -> Result<Vec<Schema>> {
+        let tx = conn.transaction()?;
+        let out = Self::bulk_insert_tx(&tx, data)?;
+        tx.commit()?;
+        Ok(out)
+    }
+
+    pub fn bulk_insert_tx(tx: &Transaction, data: &[New]) -> Result<Vec<Schema>> {
+        if data.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let sql = r#"
+            INSERT INTO praeposition_review (wort_id, interval, ease_factor, repetitions, last_review, next_review, lapses)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+
+            ON CONFLICT(wort_id) DO UPDATE SET
+                interval = ?2,
+                ease_factor = ?3,
+                repetitions = ?4,
+                last_review = ?5,
+                next_review = ?6,
+                lapses = ?7
+
+            RETURNING id, wort_id, interval, ease_factor, repetitions, next_review, lapses, created_at, deleted_at;
+        "#;
+
+        let mut vec_out = Vec::with_capacity(data.len());
+        let mut stmt = tx.prepare_cached(sql).context(format!("sql: {}", sql))?;
+        for d in data {
+            let raw = stmt
+                .query_one(d.to_params(), Raw::from_sql)
+                .context(format!("sql: {}, params: {:#?}", sql, d))?;
+            vec_out.push(Schema::from_raw(raw)?)
+        }
+
+        Ok(vec_out)
+    }
+
+    pub fn fetch_by_wort_id(conn: &Connection, ids: &[i32]) -> Result<Vec<Schema>> {
+        if ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let placeholders = std::iter::repeat_n("?", ids.len())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        let sql = format!(
+            "
+                SELECT
+                    id, wort_id, interval, ease_factor, repetitions,
+                    next_review, lapses, created_at, deleted_at
+                FROM praeposition_review pr
+                WHERE pr.deleted_at IS NULL AND
+                pr.wort_id IN ({placeholders})
+            "
+        );
+
+        let mut stmt = conn.prepare(&sql)?;
+        let raw = stmt
+            .query(params_from_iter(ids.iter()))
+            .context(format!("sql: {}", sql))?
+            .mapped(Raw::from_sql)
+            .collect::<Result<Vec<Raw>, _>>()?;
+
+        Schema::from_vec_raw(raw)
+    }
+
+    /// Preposiciones por repasar antes de `date_review`, las más atrasadas primero.
+    pub fn fetch_review_wort_id_by_day(conn: &Connection, date_review: String) -> Result<Vec<i32>> {
+        let sql = r#"
+            SELECT pr.wort_id
+            FROM praeposition_review pr
+            JOIN worte w ON w.id = pr.wort_id
+            WHERE pr.next_review < ?1
+                AND pr.deleted_at IS NULL
+                AND w.deleted_at IS NULL
+            ORDER BY pr.next_review ASC;
+        "#;
+
+        let mut stmt = conn.prepare(sql)?;
+        let vec_ids = stmt
+            .query(params![date_review])?
+            .mapped(|r| r.get(0))
+            .collect::<Result<Vec<i32>, _>>()?;
+
+        Ok(vec_ids)
+    }
+}
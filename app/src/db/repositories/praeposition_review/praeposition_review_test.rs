@@ -0,0 +1,113 @@
+use crate::db::setup_test_db;
+
+#[cfg(test)]
+mod test_praeposition_review_repo {
+    use rusqlite::Connection;
+
+    use crate::db::{
+        praeposition_review::PraepositionReviewRepo,
+        schemas::{praeposition_review::NewPraepositionReviewSchema as New, worte::NewWorteSchema},
+        seeders::init_data,
+        worte::WorteRepo,
+    };
+
+    use super::*;
+
+    fn init_data_local(conn: &mut Connection) {
+        init_data(conn).unwrap();
+        let praeposition = |worte_de: &str, gram_type: i32| NewWorteSchema {
+            gram_type: vec![gram_type],
+            tags: vec![],
+            gender_id: None,
+            worte_de: worte_de.into(),
+            worte_es: "".into(),
+            plural: None,
+            niveau_id: 1,
+            example_de: "".into(),
+            example_es: "".into(),
+            verb_aux: None,
+            trennbar: None,
+            reflexiv: None,
+            komparativ: None,
+            superlativ: None,
+            deck_id: 1,
+        };
+        WorteRepo::bulk_insert(
+            conn,
+            &[
+                praeposition("mit", 23),
+                praeposition("für", 24),
+                praeposition("in", 26),
+            ],
+        )
+        .unwrap();
+    }
+
+    fn new(wort_id: i32, next_review: &str, lapses: u32) -> New {
+        New {
+            wort_id,
+            interval: 1,
+            ease_factor: 2.5,
+            repetitions: 1,
+            last_review: "2020-01-01 00:00:00".into(),
+            next_review: next_review.into(),
+            lapses,
+        }
+    }
+
+    #[test]
+    fn test_upsert() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+
+        let first =
+            PraepositionReviewRepo::bulk_insert(&mut conn, &[new(1, "2020-01-02 00:00:00", 0)])
+                .unwrap();
+        let second =
+            PraepositionReviewRepo::bulk_insert(&mut conn, &[new(1, "2020-01-05 00:00:00", 1)])
+                .unwrap();
+
+        // Una preposición tiene una sola revisión
+        assert_eq!(first[0].id, second[0].id);
+        assert_eq!(second[0].lapses, 1);
+        assert_eq!(second[0].next_review.to_string(), "2020-01-05 00:00:00 UTC");
+        assert_eq!(
+            PraepositionReviewRepo::fetch_by_wort_id(&conn, &[1, 2])
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_fetch_review_wort_id_by_day() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+
+        PraepositionReviewRepo::bulk_insert(
+            &mut conn,
+            &[
+                new(1, "2020-01-03 00:00:00", 0),
+                new(2, "2020-01-02 00:00:00", 0),
+                new(3, "2999-01-01 00:00:00", 0),
+            ],
+        )
+        .unwrap();
+
+        let due = PraepositionReviewRepo::fetch_review_wort_id_by_day(
+            &conn,
+            "2021-01-01 00:00:00".into(),
+        )
+        .unwrap();
+        assert_eq!(due, vec![2, 1]);
+
+        // Las preposiciones borradas no se repasan
+        WorteRepo::soft_delete(&mut conn, &[2]).unwrap();
+        let due = PraepositionReviewRepo::fetch_review_wort_id_by_day(
+            &conn,
+            "2021-01-01 00:00:00".into(),
+        )
+        .unwrap();
+        assert_eq!(due, vec![1]);
+    }
+}
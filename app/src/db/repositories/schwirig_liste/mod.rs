@@ -0,0 +1,37 @@
+use color_eyre::eyre::Result;
+use rusqlite::Transaction;
+use sql_model::{FromRaw, SqlNew, SqlRaw};
+
+use crate::db::schemas::schwirig_liste::{
+    NewSchwirigListeSchema as New, RawSchwirigListeSchema as Raw, SchwirigListeSchema as Schema,
+};
+
+#[cfg(test)]
+mod schwirig_liste_test;
+
+pub struct SchwirigListeRepo;
+
+impl SchwirigListeRepo {
+    pub fn bulk_insert_tx(tx: &Transaction, data: &[New]) -> Result<Vec<Schema>> {
+        if data.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let sql = r#"
+            INSERT INTO schwirig_liste (id, schwirig)
+                VALUES (?1, ?2)
+            ON CONFLICT(id) DO UPDATE SET schwirig = ?2
+            RETURNING id, schwirig, created_at, deleted_at;
+        "#;
+
+        let mut vec_out = Vec::with_capacity(data.len());
+        let mut stmt = tx.prepare_cached(sql)?;
+
+        for d in data {
+            let raw = stmt.query_one(d.to_params(), Raw::from_sql)?;
+            vec_out.push(Schema::from_raw(raw)?)
+        }
+
+        Ok(vec_out)
+    }
+}
@@ -0,0 +1,56 @@
+use crate::db::setup_test_db;
+
+#[cfg(test)]
+mod test_schwirig_liste_repo {
+
+    use rusqlite::Connection;
+
+    use crate::db::{
+        schemas::schwirig_liste::{NewSchwirigListeSchema as New, SchwirigListeSchema as Schema},
+        schwirig_liste::SchwirigListeRepo,
+        seeders::SEED_SCHWIRIG_LISTE,
+    };
+
+    use super::*;
+
+    fn insert(conn: &mut Connection, data: &[New]) -> Vec<Schema> {
+        let tx = conn.transaction().unwrap();
+        let out =
+            SchwirigListeRepo::bulk_insert_tx(&tx, data).expect("La inserción no debe fallar");
+        tx.commit().unwrap();
+        out
+    }
+
+    #[test]
+    fn test_bulk_insert_and_update() {
+        let mut conn = setup_test_db().unwrap();
+
+        let res = insert(&mut conn, &SEED_SCHWIRIG_LISTE);
+
+        assert_eq!(res.len(), 3);
+        assert_eq!(res[0].id, 0);
+        assert_eq!(res[0].schwirig, "Einfach");
+        assert_eq!(res[2].id, 2);
+        assert_eq!(res[2].schwirig, "Schwirig");
+
+        let res = insert(&mut conn, &[New::new(2, "Sehr schwirig")]);
+
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].id, 2);
+        assert_eq!(res[0].schwirig, "Sehr schwirig");
+    }
+
+    #[test]
+    fn test_parse() {
+        let mut conn = setup_test_db().unwrap();
+        let data = insert(&mut conn, &SEED_SCHWIRIG_LISTE);
+        Schema::init_data(&data).unwrap();
+
+        assert_eq!(Schema::parse("0").unwrap().schwirig, "Einfach");
+        assert_eq!(Schema::parse("normal").unwrap().id, 1);
+        assert_eq!(Schema::parse(" Schwirig ").unwrap().id, 2);
+
+        assert!(Schema::parse("7").is_err());
+        assert!(Schema::parse("B2").is_err());
+    }
+}
@@ -0,0 +1,112 @@
+use color_eyre::eyre::{Context, Result};
+use rusqlite::{Connection, params};
+
+use crate::db::schemas::search::{MARK_END, MARK_START, SearchHit};
+
+#[cfg(test)]
+mod search_test;
+
+pub struct SearchRepo;
+
+impl SearchRepo {
+    /// Convierte lo que escribió el usuario en una consulta FTS5: cada palabra es un prefijo
+    /// y deben aparecer todas. La puntuación se ignora, así que el texto no puede romper la
+    /// sintaxis de la consulta. `None` si no queda ninguna palabra.
+    pub fn match_query(text: &str) -> Option<String> {
+        let terms: Vec<String> = text
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|t| !t.is_empty())
+            .map(|t| format!("\"{}\"*", t))
+            .collect();
+
+        (!terms.is_empty()).then(|| terms.join(" "))
+    }
+
+    /// Palabras activas cuyo alemán o ejemplo coincide con `text`, primero las que coinciden
+    /// en la palabra misma.
+    pub fn fetch_worte(conn: &Connection, text: &str, limit: usize) -> Result<Vec<SearchHit>> {
+        let Some(query) = Self::match_query(text) else {
+            return Ok(vec![]);
+        };
+
+        let sql = "
+            SELECT
+                w.id,
+                bm25(worte_fts, 10.0, 1.0) AS rank,
+                highlight(worte_fts, 0, ?2, ?3),
+                highlight(worte_fts, 1, ?2, ?3)
+            FROM worte_fts
+            JOIN worte w ON w.id = worte_fts.rowid
+            WHERE worte_fts MATCH ?1
+                AND w.deleted_at IS NULL
+            ORDER BY rank, w.id
+            LIMIT ?4;
+        ";
+
+        let mut stmt = conn.prepare_cached(sql)?;
+        let hits = stmt
+            .query(params![
+                query,
+                MARK_START,
+                MARK_END,
+                limit.min(i64::MAX as usize) as i64
+            ])
+            .context(format!("sql: {}, query: {}", sql, query))?
+            .mapped(|r| {
+                Ok(SearchHit {
+                    id: r.get(0)?,
+                    rank: r.get(1)?,
+                    text: r.get(2)?,
+                    example: r.get(3)?,
+                })
+            })
+            .collect::<Result<Vec<SearchHit>, _>>()?;
+
+        Ok(hits)
+    }
+
+    /// Oraciones activas cuyo alemán coincide con `text`.
+    pub fn fetch_setze(conn: &Connection, text: &str, limit: usize) -> Result<Vec<SearchHit>> {
+        let Some(query) = Self::match_query(text) else {
+            return Ok(vec![]);
+        };
+
+        Self::fetch_setze_match(conn, &query, limit)
+    }
+
+    fn fetch_setze_match(conn: &Connection, query: &str, limit: usize) -> Result<Vec<SearchHit>> {
+        let sql = "
+            SELECT
+                s.id,
+                bm25(setze_fts) AS rank,
+                highlight(setze_fts, 0, ?2, ?3)
+            FROM setze_fts
+            JOIN setze s ON s.id = setze_fts.rowid
+            WHERE setze_fts MATCH ?1
+                AND s.deleted_at IS NULL
+            ORDER BY rank, s.id
+            LIMIT ?4;
+        ";
+
+        let mut stmt = conn.prepare_cached(sql)?;
+        let hits = stmt
+            .query(params![
+                query,
+                MARK_START,
+                MARK_END,
+                limit.min(i64::MAX as usize) as i64
+            ])
+            .context(format!("sql: {}, query: {}", sql, query))?
+            .mapped(|r| {
+                Ok(SearchHit {
+                    id: r.get(0)?,
+                    rank: r.get(1)?,
+                    text: r.get(2)?,
+                    example: None,
+                })
+            })
+            .collect::<Result<Vec<SearchHit>, _>>()?;
+
+        Ok(hits)
+    }
+}
@@ -0,0 +1,129 @@
+use crate::db::setup_test_db;
+
+#[cfg(test)]
+mod test_search {
+    use std::fs;
+
+    use rusqlite::Connection;
+
+    use crate::{
+        db::{
+            deck::DeckRepo, schemas::search::SearchHit, search::SearchRepo, seeders::init_data,
+            setze::SetzeRepo, worte::WorteRepo,
+        },
+        helpers::csv,
+    };
+
+    use super::*;
+
+    const WORTE_CSV: &str = "\
+gram_type,gender_id,worte_de,worte_es,plural,niveau_id,example_de,example_es,verb_aux,trennbar,reflexiv
+noun_common,Neutrum,Mädchen,la niña,Mädchen,A1,Das Mädchen liest.,La niña lee.,,,
+noun_common,Maskuline,Hund,el perro,Hunde,A1,Der Hund spielt.,El perro juega.,,,
+numeral_cardinal,,hundert,cien,,A1,Ich habe hundert Euro.,Tengo cien euros.,,,
+";
+
+    const SETZE_CSV: &str = "\
+setze_spanisch,setze_deutsch,thema,schwirig_id
+Tengo un perro,Ich habe einen Hund,Akkusativ,0
+Los perros juegan,Die Hunde spielen im Park,Plural,0
+Cien años,Hundert Jahre,Zahlen,0
+La niña es grande,Das MÄDCHEN ist groß,Adjektive,0
+";
+
+    fn init_data_local(conn: &mut Connection) {
+        init_data(conn).unwrap();
+
+        let dir = std::env::temp_dir().join(format!("anki_fts_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let worte = dir.join("worte.csv");
+        let setze = dir.join("setze.csv");
+        fs::write(&worte, WORTE_CSV).unwrap();
+        fs::write(&setze, SETZE_CSV).unwrap();
+
+        let deck = DeckRepo::ensure_path(conn, "Goethe A1").unwrap();
+        let data = csv::extract_worte_csv(worte.to_str().unwrap(), deck.id).unwrap();
+        WorteRepo::bulk_insert(conn, &data).unwrap();
+        let data = csv::extract_sentences_csv(setze.to_str().unwrap(), deck.id).unwrap();
+        SetzeRepo::bulk_insert(conn, &data).unwrap();
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    fn ids(hits: &[SearchHit]) -> Vec<i32> {
+        hits.iter().map(|h| h.id).collect()
+    }
+
+    #[test]
+    fn test_match_query() {
+        assert_eq!(
+            SearchRepo::match_query("der Hund!"),
+            Some("\"der\"* \"Hund\"*".into())
+        );
+        assert_eq!(
+            SearchRepo::match_query("\"OR\" NEAR("),
+            Some("\"OR\"* \"NEAR\"*".into())
+        );
+        assert_eq!(SearchRepo::match_query(" ¿? "), None);
+    }
+
+    #[test]
+    fn test_fetch_worte() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+
+        // Sin umlauts, sin mayúsculas y por prefijo
+        let hits = SearchRepo::fetch_worte(&conn, "madch", 10).unwrap();
+        assert_eq!(ids(&hits), vec![1]);
+        assert_eq!(hits[0].text, "[Mädchen]");
+        assert_eq!(hits[0].example.as_deref(), Some("Das [Mädchen] liest."));
+
+        // La palabra misma pesa más que el ejemplo
+        let hits = SearchRepo::fetch_worte(&conn, "hund", 10).unwrap();
+        assert_eq!(ids(&hits), vec![2, 3]);
+        assert!(hits[0].rank < hits[1].rank);
+
+        assert!(
+            SearchRepo::fetch_worte(&conn, "katze", 10)
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(SearchRepo::fetch_worte(&conn, "hund", 1).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_fts_stays_in_sync() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+
+        conn.execute(
+            "UPDATE setze SET setze_deutsch = 'Ich habe eine Katze' WHERE id = 1",
+            [],
+        )
+        .unwrap();
+        assert!(
+            SearchRepo::fetch_setze(&conn, "einen", 10)
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(
+            ids(&SearchRepo::fetch_setze(&conn, "katze", 10).unwrap()),
+            vec![1]
+        );
+
+        conn.execute("DELETE FROM setze WHERE id = 1", []).unwrap();
+        assert!(
+            SearchRepo::fetch_setze(&conn, "katze", 10)
+                .unwrap()
+                .is_empty()
+        );
+
+        // Las borradas lógicamente no aparecen
+        SetzeRepo::soft_delete(&mut conn, &[4]).unwrap();
+        assert!(
+            SearchRepo::fetch_setze(&conn, "madchen", 10)
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
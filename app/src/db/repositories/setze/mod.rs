@@ -1,9 +1,20 @@
+use chrono::Utc;
 use color_eyre::eyre::{Context, Result};
-use rusqlite::{Connection, Transaction, params_from_iter};
+use rusqlite::{Connection, Transaction, params, params_from_iter};
 use sql_model::{FromRaw, SqlNew, SqlRaw};
 
 use crate::{
-    db::schemas::setze::{NewSetzeSchema as New, RawSetzeSchema as Raw, SetzeSchema as Schema},
+    db::{
+        schemas::{
+            schwirig_liste::SCHWIRIG_ID_SCHWIRIG,
+            setze::{NewSetzeSchema as New, RawSetzeSchema as Raw, SetzeSchema as Schema},
+        },
+        search::SearchRepo,
+        setze_audio::SetzeAudioRepo,
+        setze_tags::SetzeTagsRepo,
+        worte_setze::WorteSetzeRepo,
+    },
+    helpers::{search::SearchFilter, tags::TagExpr, time},
     with_ctx,
 };
 
@@ -13,19 +24,26 @@ mod setze_test;
 pub struct SetzeRepo;
 
 impl SetzeRepo {
-    pub fn fetch_all_themas(conn: &Connection) -> Result<Vec<String>> {
-        let sql: &'static str = r#"
+    /// Temas de las oraciones activas, solo de los decks indicados si se pasan.
+    pub fn fetch_all_themas(conn: &Connection, decks: Option<&[i32]>) -> Result<Vec<String>> {
+        let filter_decks = match decks {
+            Some(decks) => format!("AND s.deck_id in ({})", vec!["?"; decks.len()].join(",")),
+            None => String::new(),
+        };
+        let sql = format!(
+            r#"
             SELECT
                 DISTINCT(thema)
             FROM setze s
-            WHERE s.deleted_at IS NULL
+            WHERE s.deleted_at IS NULL {filter_decks}
             ORDER BY s.id
-        "#;
+        "#
+        );
 
-        let mut stmt = conn.prepare_cached(sql)?;
+        let mut stmt = conn.prepare_cached(&sql)?;
 
         let rows = stmt
-            .query([])
+            .query(params_from_iter(decks.unwrap_or_default().iter()))
             .context(with_ctx!(format!("Error query - {}", sql)))?
             .mapped(|row| row.get(0))
             .collect::<Result<Vec<String>, _>>()
@@ -53,44 +71,33 @@ impl SetzeRepo {
         Ok(rows)
     }
 
+    /// Oraciones difíciles. La dificultad calculada con las revisiones (`setze_review`)
+    /// tiene prioridad sobre la que se asignó al registrar la oración.
     pub fn fetch_id_schwirig_thema(
         conn: &Connection,
         titles: Option<&[String]>,
     ) -> Result<Vec<i32>> {
-        let (sql, params) = if titles.is_some() && !titles.unwrap().is_empty() {
-            let Some(titles) = titles else { panic!() };
-            let placeholders = std::iter::repeat_n("?", titles.len())
-                .collect::<Vec<_>>()
-                .join(",");
-
-            let sql = format!(
-                "SELECT
-                    id
-                FROM setze
-                WHERE thema in ({placeholders}) AND niveau_id >= 3 AND deleted_at IS NULL
-                ORDER BY id"
-            );
-
-            let params: Vec<&dyn rusqlite::ToSql> =
-                titles.iter().map(|t| t as &dyn rusqlite::ToSql).collect();
-
-            (sql, params)
+        let titles = titles.unwrap_or_default();
+        let filter_thema = if titles.is_empty() {
+            String::new()
         } else {
-            let sql = "SELECT
-                    id
-                FROM setze
-                WHERE niveau_id >= 3 AND deleted_at IS NULL
-                ORDER BY id"
-                .to_string();
-
-            let params: Vec<&dyn rusqlite::ToSql> = vec![];
-            (sql, params)
+            format!("AND s.thema in ({})", vec!["?"; titles.len()].join(","))
         };
 
+        let sql = format!(
+            "SELECT
+                s.id
+            FROM setze s
+            LEFT JOIN setze_review sr ON sr.satz_id = s.id AND sr.deleted_at IS NULL
+            WHERE COALESCE(sr.schwirig_id, s.schwirig_id) = {SCHWIRIG_ID_SCHWIRIG}
+                AND s.deleted_at IS NULL {filter_thema}
+            ORDER BY s.id"
+        );
+
         let mut stmt = conn.prepare_cached(&sql)?;
 
         let ids = stmt
-            .query(params_from_iter(params))
+            .query(params_from_iter(titles.iter()))
             .context(with_ctx!(format!("Sql - {}", sql)))?
             .mapped(|r| r.get(0))
             .collect::<Result<Vec<i32>, _>>()?;
@@ -124,6 +131,28 @@ impl SetzeRepo {
         Ok(ids)
     }
 
+    /// (id, niveau_id, intervalo) de las oraciones activas; las nuevas tienen intervalo 0.
+    pub fn fetch_niveau_interval(conn: &Connection) -> Result<Vec<(i32, i32, u32)>> {
+        let sql = "
+            SELECT
+                s.id, s.niveau_id, COALESCE(sr.interval, 0)
+            FROM setze s
+            LEFT JOIN setze_review sr ON sr.satz_id = s.id
+            WHERE s.deleted_at IS NULL
+            ORDER BY s.id ASC;
+            ";
+
+        let mut stmt = conn.prepare_cached(sql)?;
+
+        let rows = stmt
+            .query([])
+            .context(with_ctx!(format!("Sql - {}", sql)))?
+            .mapped(|r| Ok((r.get(0)?, r.get(1)?, r.get(2)?)))
+            .collect::<Result<Vec<(i32, i32, u32)>, _>>()?;
+
+        Ok(rows)
+    }
+
     pub fn bulk_insert(conn: &mut Connection, data: &[New]) -> Result<Vec<Schema>> {
         let tx = conn.transaction()?;
         let result = Self::bulk_insert_tx(&tx, data)?;
@@ -137,9 +166,9 @@ impl SetzeRepo {
         }
 
         let sql = r#"
-            INSERT INTO setze (setze_spanisch, setze_deutsch, niveau_id, thema)
-                VALUES (?1,?2,?3,?4)
-            RETURNING id, setze_spanisch, setze_deutsch, niveau_id, thema, created_at, deleted_at;
+            INSERT INTO setze (setze_spanisch, setze_deutsch, niveau_id, thema, deck_id, schwirig_id)
+                VALUES (?1,?2,?3,?4,?5,?6)
+            RETURNING id, setze_spanisch, setze_deutsch, niveau_id, schwirig_id, thema, deck_id, created_at, deleted_at;
         "#;
 
         let mut out: Vec<Schema> = Vec::with_capacity(data.len());
@@ -152,6 +181,14 @@ impl SetzeRepo {
             out.push(Schema::from_raw(raw)?);
         }
 
+        for (satz, new) in out.iter_mut().zip(data.iter()) {
+            if !new.tags.is_empty() {
+                satz.tags = SetzeTagsRepo::replace_by_satz_id_tx(tx, satz.id, &new.tags)?;
+            }
+        }
+
+        WorteSetzeRepo::link_setze_tx(tx, &out)?;
+
         Ok(out)
     }
 
@@ -196,11 +233,14 @@ impl SetzeRepo {
                 setze_spanisch,
                 setze_deutsch,
                 niveau_id,
+                schwirig_id,
                 thema,
+                deck_id,
                 created_at,
                 deleted_at
             FROM setze
-            WHERE id in ({placeholders})
+            WHERE deleted_at IS NULL
+                AND id in ({placeholders})
             ORDER BY setze_deutsch"
         );
 
@@ -214,7 +254,8 @@ impl SetzeRepo {
             .mapped(Raw::from_sql)
             .collect::<Result<Vec<Raw>, _>>()?;
 
-        let result = Schema::from_vec_raw(rows)?;
+        let mut result = Schema::from_vec_raw(rows)?;
+        Self::fill_tags(conn, &mut result)?;
         Ok(result)
     }
 
@@ -225,11 +266,13 @@ impl SetzeRepo {
                 s.setze_spanisch,
                 s.setze_deutsch,
                 s.niveau_id,
+                s.schwirig_id,
                 s.thema,
+                s.deck_id,
                 s.created_at,
                 s.deleted_at
             FROM setze s
-            LEFT JOIN setze_audio sa ON s.id = sa.wort_id 
+            LEFT JOIN setze_audio sa ON s.id = sa.satz_id AND sa.deleted_at IS NULL
             WHERE s.deleted_at IS NULL AND sa.satz_id is NULL
             ORDER BY s.id ASC;
         "
@@ -246,4 +289,281 @@ impl SetzeRepo {
         let vec_out = Schema::from_vec_raw(raws)?;
         Ok(vec_out)
     }
+
+    /// Ids de las oraciones activas que cumplen con la expresión de etiquetas.
+    pub fn fetch_id_where_tags(conn: &Connection, expr: &TagExpr) -> Result<Vec<i32>> {
+        let exists = "EXISTS (
+                SELECT 1
+                FROM setze_tags lt
+                JOIN tags t ON t.id = lt.tag_id
+                WHERE lt.satz_id = s.id
+                    AND lt.deleted_at IS NULL
+                    AND t.deleted_at IS NULL
+                    AND t.name = ?
+            )";
+        let (condition, params) = expr.to_sql(exists);
+
+        let sql = format!(
+            "
+            SELECT s.id
+            FROM setze s
+            WHERE s.deleted_at IS NULL
+                AND {condition}
+            ORDER BY s.id ASC;
+        "
+        );
+
+        let mut stmt = conn.prepare(&sql)?;
+        let ids = stmt
+            .query(params_from_iter(params.iter()))
+            .context(format!("sql: {}, params: {:#?}", sql, params))?
+            .mapped(|r| r.get(0))
+            .collect::<Result<Vec<i32>, _>>()?;
+
+        Ok(ids)
+    }
+
+    /// De los ids dados, los de las oraciones que pertenecen a alguno de los decks.
+    pub fn fetch_id_in_decks(conn: &Connection, ids: &[i32], decks: &[i32]) -> Result<Vec<i32>> {
+        if ids.is_empty() || decks.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let placeholders_ids = vec!["?"; ids.len()].join(",");
+        let placeholders_decks = vec!["?"; decks.len()].join(",");
+        let sql = format!(
+            "
+            SELECT s.id
+            FROM setze s
+            WHERE s.id in ({placeholders_ids})
+                AND s.deck_id in ({placeholders_decks})
+            ORDER BY s.id ASC;
+        "
+        );
+
+        let mut stmt = conn.prepare(&sql)?;
+        let ids = stmt
+            .query(params_from_iter(ids.iter().chain(decks.iter())))
+            .context(format!("sql: {}", sql))?
+            .mapped(|r| r.get(0))
+            .collect::<Result<Vec<i32>, _>>()?;
+
+        Ok(ids)
+    }
+
+    pub fn update(conn: &mut Connection, id: i32, data: &New) -> Result<Schema> {
+        let tx = conn.transaction()?;
+        let out = Self::update_tx(&tx, id, data)?;
+        tx.commit()?;
+        Ok(out)
+    }
+
+    /// Actualiza una oración activa. Si cambia alguno de sus textos el audio se invalida
+    /// para volver a generarlo.
+    pub fn update_tx(tx: &Transaction, id: i32, data: &New) -> Result<Schema> {
+        let sql_old = "
+            SELECT setze_spanisch, setze_deutsch
+            FROM setze
+            WHERE id = ?1 AND deleted_at IS NULL;
+        ";
+        let old: (String, String) = tx
+            .query_one(sql_old, params![id], |r| Ok((r.get(0)?, r.get(1)?)))
+            .context(with_ctx!(format!("No existe la oración con id: {}", id)))?;
+
+        let sql = r#"
+            UPDATE setze SET
+                setze_spanisch = ?1,
+                setze_deutsch = ?2,
+                niveau_id = ?3,
+                thema = ?4,
+                deck_id = ?5,
+                schwirig_id = ?6
+            WHERE id = ?7 AND deleted_at IS NULL
+            RETURNING id, setze_spanisch, setze_deutsch, niveau_id, schwirig_id, thema, deck_id, created_at, deleted_at;
+        "#;
+
+        let raw = tx
+            .query_one(
+                sql,
+                params![
+                    data.setze_spanisch,
+                    data.setze_deutsch,
+                    data.niveau_id,
+                    data.thema,
+                    data.deck_id,
+                    data.schwirig_id,
+                    id
+                ],
+                Raw::from_sql,
+            )
+            .context(format!("sql: {}, params: {:#?}", sql, data))?;
+        let mut out = Schema::from_raw(raw)?;
+        out.tags = SetzeTagsRepo::replace_by_satz_id_tx(tx, id, &data.tags)?;
+
+        if old != (out.setze_spanisch.clone(), out.setze_deutsch.clone()) {
+            SetzeAudioRepo::invalidate_tx(tx, &[id])?;
+        }
+
+        if old.1 != out.setze_deutsch {
+            WorteSetzeRepo::link_setze_tx(tx, std::slice::from_ref(&out))?;
+        }
+
+        Ok(out)
+    }
+
+    /// Marca las oraciones como borradas.
+    pub fn soft_delete(conn: &mut Connection, ids: &[i32]) -> Result<usize> {
+        if ids.is_empty() {
+            return Ok(0);
+        }
+
+        let placeholders = vec!["?"; ids.len()].join(",");
+        let now = time::datetime_2_string(Utc::now());
+
+        let mut params: Vec<&dyn rusqlite::ToSql> = vec![&now];
+        params.extend(ids.iter().map(|t| t as &dyn rusqlite::ToSql));
+
+        let sql = format!(
+            "
+            UPDATE setze SET deleted_at = ?
+            WHERE deleted_at IS NULL AND id in ({placeholders});
+        "
+        );
+
+        let n = conn
+            .execute(&sql, params_from_iter(params))
+            .context(format!("sql: {}", sql))?;
+        Ok(n)
+    }
+
+    pub fn restore(conn: &mut Connection, ids: &[i32]) -> Result<usize> {
+        if ids.is_empty() {
+            return Ok(0);
+        }
+
+        let placeholders = vec!["?"; ids.len()].join(",");
+        let sql = format!(
+            "
+            UPDATE setze SET deleted_at = NULL
+            WHERE deleted_at IS NOT NULL AND id in ({placeholders});
+        "
+        );
+
+        let tx = conn.transaction()?;
+        let n = tx
+            .execute(&sql, params_from_iter(ids.iter()))
+            .context(format!("sql: {}", sql))?;
+        // Mientras estuvieron borradas no se relacionaron con las palabras nuevas
+        WorteSetzeRepo::link_setze_tx(&tx, &Self::fetch_by_id(&tx, ids)?)?;
+        tx.commit()?;
+
+        Ok(n)
+    }
+
+    /// Ids de las oraciones activas que cumplen con los filtros de búsqueda.
+    pub fn fetch_id_search(
+        conn: &Connection,
+        filter: &SearchFilter,
+        date_review: &str,
+    ) -> Result<Vec<i32>> {
+        let (mut conditions, mut params) = filter.to_sql("s", "sr", date_review);
+
+        if let Some(text) = &filter.text {
+            // El texto completo del alemán no distingue umlauts: "madchen" encuentra "Mädchen"
+            let mut condition =
+                "s.setze_deutsch LIKE '%' || ? || '%' OR s.setze_spanisch LIKE '%' || ? || '%'"
+                    .to_string();
+            params.push(text.clone().into());
+            params.push(text.clone().into());
+            if let Some(query) = SearchRepo::match_query(text) {
+                condition
+                    .push_str(" OR s.id IN (SELECT rowid FROM setze_fts WHERE setze_fts MATCH ?)");
+                params.push(query.into());
+            }
+            conditions.push(format!("({condition})"));
+        }
+        if let Some(thema) = &filter.thema {
+            conditions.push("s.thema LIKE ?".to_string());
+            params.push(thema.clone().into());
+        }
+
+        let condition = conditions
+            .iter()
+            .map(|c| format!("AND {c}"))
+            .collect::<Vec<_>>()
+            .join("\n                ");
+        let sql = format!(
+            "
+            SELECT s.id
+            FROM setze s
+            LEFT JOIN setze_review sr ON sr.satz_id = s.id AND sr.deleted_at IS NULL
+            WHERE s.deleted_at IS NULL
+                {condition}
+            ORDER BY s.id ASC;
+        "
+        );
+
+        let mut stmt = conn.prepare(&sql)?;
+        let ids = stmt
+            .query(params_from_iter(params.iter()))
+            .context(format!("sql: {}, params: {:#?}", sql, params))?
+            .mapped(|r| r.get(0))
+            .collect::<Result<Vec<i32>, _>>()?;
+
+        Ok(ids)
+    }
+
+    /// Oraciones activas cuyo texto en alemán o español contiene `text`.
+    pub fn fetch_by_text(conn: &Connection, text: &str) -> Result<Vec<Schema>> {
+        let sql = "
+            SELECT
+                id, setze_spanisch, setze_deutsch, niveau_id, schwirig_id, thema, deck_id, created_at, deleted_at
+            FROM setze s
+            WHERE s.deleted_at IS NULL
+                AND (s.setze_deutsch LIKE '%' || ?1 || '%' OR s.setze_spanisch LIKE '%' || ?1 || '%')
+            ORDER BY s.id ASC;
+        ";
+
+        let mut stmt = conn.prepare_cached(sql)?;
+        let raws = stmt
+            .query(params![text.trim()])
+            .context(format!("Sql - {}", sql))?
+            .mapped(Raw::from_sql)
+            .collect::<Result<Vec<Raw>, _>>()?;
+
+        let mut out = Schema::from_vec_raw(raws)?;
+        Self::fill_tags(conn, &mut out)?;
+        Ok(out)
+    }
+
+    pub fn fetch_deleted(conn: &Connection) -> Result<Vec<Schema>> {
+        let sql = "
+            SELECT
+                id, setze_spanisch, setze_deutsch, niveau_id, schwirig_id, thema, deck_id, created_at, deleted_at
+            FROM setze s
+            WHERE s.deleted_at IS NOT NULL
+            ORDER BY s.deleted_at DESC, s.id ASC;
+        ";
+
+        let mut stmt = conn.prepare_cached(sql)?;
+        let raws = stmt
+            .query([])
+            .context(format!("Sql - {}", sql))?
+            .mapped(Raw::from_sql)
+            .collect::<Result<Vec<Raw>, _>>()?;
+
+        Schema::from_vec_raw(raws)
+    }
+
+    /// Llena las etiquetas de cada oración.
+    fn fill_tags(conn: &Connection, data: &mut [Schema]) -> Result<()> {
+        let ids: Vec<i32> = data.iter().map(|s| s.id).collect();
+        let mut hash_tags = SetzeTagsRepo::fetch_tags_by_satz_id(conn, &ids)?;
+
+        for satz in data.iter_mut() {
+            satz.tags = hash_tags.remove(&satz.id).unwrap_or_default();
+        }
+
+        Ok(())
+    }
 }
@@ -100,12 +100,18 @@ mod test_setze_repo {
                     setze_deutsch: "Hallo".into(),
                     niveau_id: 1,
                     thema: "Thema 1".into(),
+                    deck_id: 1,
+                    schwirig_id: 1,
+                    tags: vec![],
                 },
                 New {
                     setze_spanisch: "Adios".into(),
                     setze_deutsch: "Tschüss".into(),
                     niveau_id: 2,
                     thema: "Thema 2".into(),
+                    deck_id: 1,
+                    schwirig_id: 1,
+                    tags: vec![],
                 },
             ];
             run_bulk_insert_update_scenario(|conn| SetzeRepo::bulk_insert(conn, &data_1));
@@ -119,12 +125,18 @@ mod test_setze_repo {
                     setze_deutsch: "Hallo".into(),
                     niveau_id: 1,
                     thema: "Thema 1".into(),
+                    deck_id: 1,
+                    schwirig_id: 1,
+                    tags: vec![],
                 },
                 New {
                     setze_spanisch: "Adios".into(),
                     setze_deutsch: "Tschüss".into(),
                     niveau_id: 2,
                     thema: "Thema 2".into(),
+                    deck_id: 1,
+                    schwirig_id: 1,
+                    tags: vec![],
                 },
             ];
             run_bulk_insert_update_scenario(|conn| {
@@ -142,8 +154,11 @@ mod test_setze_repo {
 
         use super::*;
         use crate::db::{
-            schemas::setze_review::NewSetzeReviewSchema, seeders::init_data, setup_test_db,
-            setze::SetzeRepo, setze_review::SetzeReviewRepo,
+            schemas::{init_schemas, setze_review::NewSetzeReviewSchema},
+            seeders::init_data,
+            setup_test_db,
+            setze::SetzeRepo,
+            setze_review::SetzeReviewRepo,
         };
 
         fn init_data_local(conn: &mut Connection) -> Result<()> {
@@ -154,12 +169,18 @@ mod test_setze_repo {
                     setze_deutsch: "Hallo".into(),
                     niveau_id: 1,
                     thema: "Thema 1".into(),
+                    deck_id: 1,
+                    schwirig_id: 1,
+                    tags: vec![],
                 },
                 New {
                     setze_spanisch: "Adios".into(),
                     setze_deutsch: "Tschüss".into(),
                     niveau_id: 3,
                     thema: "Thema 2".into(),
+                    deck_id: 1,
+                    schwirig_id: 2,
+                    tags: vec![],
                 },
             ];
             SetzeRepo::bulk_insert(conn, &data_1)?;
@@ -250,6 +271,114 @@ mod test_setze_repo {
             insta::assert_debug_snapshot!(res);
         }
 
+        #[test]
+        fn test_fetch_id_schwirig_independent_of_niveau() {
+            let mut conn = setup_test_db().expect("Error al crear db test");
+            init_data_local(&mut conn).expect("Error al iniciar data test");
+
+            // Nivel C1 pero dificultad normal: no es difícil
+            SetzeRepo::bulk_insert(
+                &mut conn,
+                &[New {
+                    setze_spanisch: "Aunque".into(),
+                    setze_deutsch: "Obwohl".into(),
+                    niveau_id: 4,
+                    thema: "Thema 3".into(),
+                    deck_id: 1,
+                    schwirig_id: 1,
+                    tags: vec![],
+                }],
+            )
+            .expect("Error al insertar");
+
+            let res =
+                SetzeRepo::fetch_id_schwirig_thema(&conn, None).expect("Error al hacer fetch");
+            assert_eq!(res, vec![2]);
+        }
+
+        #[test]
+        fn test_fetch_id_schwirig_by_review() {
+            let mut conn = setup_test_db().expect("Error al crear db test");
+            init_data_local(&mut conn).expect("Error al iniciar data test");
+
+            let review = |satz_id: i32, schwirig_id: Option<i32>| NewSetzeReviewSchema {
+                satz_id,
+                repetitions: 1,
+                ease_factor: 2.0,
+                interval: 1,
+                last_review: "2025-01-10 12:00:00".into(),
+                next_review: "2025-01-10 12:00:00".into(),
+                lapses: 0,
+                schwirig_id,
+            };
+
+            // Sin veredicto de las revisiones se usa la dificultad registrada
+            SetzeReviewRepo::bulk_insert(&mut conn, &[review(1, None), review(2, None)])
+                .expect("Error al guardar las revisiones");
+            let res =
+                SetzeRepo::fetch_id_schwirig_thema(&conn, None).expect("Error al hacer fetch");
+            assert_eq!(res, vec![2]);
+
+            // El desempeño tiene prioridad
+            SetzeReviewRepo::bulk_insert(&mut conn, &[review(1, Some(2)), review(2, Some(0))])
+                .expect("Error al guardar las revisiones");
+            let res =
+                SetzeRepo::fetch_id_schwirig_thema(&conn, None).expect("Error al hacer fetch");
+            assert_eq!(res, vec![1]);
+        }
+
+        #[test]
+        fn test_migrate_schwirig_from_niveau() {
+            // Base creada antes de separar la dificultad del nivel
+            let mut conn = Connection::open_in_memory().expect("Error al crear db test");
+            conn.execute_batch(
+                "CREATE TABLE setze (
+                    id              INTEGER PRIMARY KEY AUTOINCREMENT,
+                    setze_spanisch  TEXT NOT NULL,
+                    setze_deutsch   TEXT NOT NULL,
+                    thema           TEXT NOT NULL,
+                    niveau_id       INTEGER NOT NULL,
+                    created_at      TEXT DEFAULT CURRENT_TIMESTAMP,
+                    deleted_at      TEXT
+                );
+                INSERT INTO setze (setze_spanisch, setze_deutsch, thema, niveau_id) VALUES
+                    ('a', 'a', 'T', 0),
+                    ('b', 'b', 'T', 2),
+                    ('c', 'c', 'T', 4);",
+            )
+            .expect("Error al crear la tabla anterior");
+
+            init_schemas(&mut conn).expect("Error al migrar");
+            init_data(&mut conn).expect("Error al iniciar data test");
+
+            let res = SetzeRepo::fetch_by_id(&conn, &[1, 2, 3]).expect("Error al hacer fetch");
+            let res: Vec<(i32, i32, i32)> = res
+                .iter()
+                .map(|s| (s.id, s.niveau_id.id, s.schwirig_id.id))
+                .collect();
+            assert_eq!(res, vec![(1, 0, 0), (2, 0, 2), (3, 4, 2)]);
+
+            // Volver a iniciar los esquemas no repite la migración
+            SetzeRepo::update(
+                &mut conn,
+                1,
+                &New {
+                    setze_spanisch: "a".into(),
+                    setze_deutsch: "a".into(),
+                    niveau_id: 0,
+                    thema: "T".into(),
+                    deck_id: 1,
+                    schwirig_id: 2,
+                    tags: vec![],
+                },
+            )
+            .expect("Error al actualizar");
+            init_schemas(&mut conn).expect("Error al iniciar esquemas");
+
+            let res = SetzeRepo::fetch_by_id(&conn, &[1]).expect("Error al hacer fetch");
+            assert_eq!(res[0].schwirig_id.id, 2);
+        }
+
         #[test]
         fn test_fetch_all_only_ids() {
             let mut conn = setup_test_db().expect("Error al crear db test");
@@ -269,7 +398,7 @@ mod test_setze_repo {
             let mut conn = setup_test_db().expect("Error al crear db test");
             init_data_local(&mut conn).expect("Error al iniciar data test");
 
-            let res = SetzeRepo::fetch_all_themas(&conn).expect("Error al hacer fetch");
+            let res = SetzeRepo::fetch_all_themas(&conn, None).expect("Error al hacer fetch");
 
             assert_eq!(res.len(), 2);
             assert_eq!(res[0], "Thema 1");
@@ -300,6 +429,8 @@ mod test_setze_repo {
                     interval: 1,
                     last_review: "2025-01-10 12:00:00".into(),
                     next_review: "2025-01-10 12:00:00".into(),
+                    lapses: 0,
+                    schwirig_id: None,
                 }],
             )
             .expect("Error al guardar los resultados del historial");
@@ -312,4 +443,136 @@ mod test_setze_repo {
             insta::assert_debug_snapshot!(res);
         }
     }
+
+    mod update_delete {
+        use color_eyre::eyre::Result;
+        use rusqlite::Connection;
+
+        use super::*;
+        use crate::db::{
+            schemas::setze_audio::NewSetzeAudioSchema, seeders::init_data,
+            setze_audio::SetzeAudioRepo,
+        };
+
+        fn init_data_local(conn: &mut Connection) -> Result<()> {
+            init_data(conn)?;
+            let data_1 = vec![
+                New {
+                    setze_spanisch: "Hola".into(),
+                    setze_deutsch: "Hallo".into(),
+                    niveau_id: 1,
+                    thema: "Thema 1".into(),
+                    deck_id: 1,
+                    schwirig_id: 1,
+                    tags: vec![],
+                },
+                New {
+                    setze_spanisch: "Adios".into(),
+                    setze_deutsch: "Tschüss".into(),
+                    niveau_id: 3,
+                    thema: "Thema 2".into(),
+                    deck_id: 1,
+                    schwirig_id: 1,
+                    tags: vec![],
+                },
+            ];
+            SetzeRepo::bulk_insert(conn, &data_1)?;
+            SetzeAudioRepo::bulk_insert(
+                conn,
+                &[NewSetzeAudioSchema {
+                    satz_id: 1,
+                    file_path: "satz_000001.mp3".into(),
+                    voice_id: "voice".into(),
+                }],
+            )?;
+            Ok(())
+        }
+
+        #[test]
+        fn test_update() {
+            let mut conn = setup_test_db().expect("Error al crear db test");
+            init_data_local(&mut conn).expect("Error al iniciar data test");
+
+            // Cambiar solo el tema no invalida el audio
+            let res = SetzeRepo::update(
+                &mut conn,
+                1,
+                &New {
+                    setze_spanisch: "Hola".into(),
+                    setze_deutsch: "Hallo".into(),
+                    niveau_id: 2,
+                    thema: "Thema 3".into(),
+                    deck_id: 1,
+                    schwirig_id: 1,
+                    tags: vec![],
+                },
+            )
+            .expect("Error al actualizar");
+            assert_eq!(res.thema, "Thema 3");
+            assert_eq!(res.niveau_id.id, 2);
+            assert_eq!(SetzeAudioRepo::fetch_by_id(&conn, &[1]).unwrap().len(), 1);
+
+            let res = SetzeRepo::update(
+                &mut conn,
+                1,
+                &New {
+                    setze_spanisch: "Hola a todos".into(),
+                    setze_deutsch: "Hallo zusammen".into(),
+                    niveau_id: 2,
+                    thema: "Thema 3".into(),
+                    deck_id: 1,
+                    schwirig_id: 1,
+                    tags: vec![],
+                },
+            )
+            .expect("Error al actualizar");
+            assert_eq!(res.setze_deutsch, "Hallo zusammen");
+            assert!(SetzeAudioRepo::fetch_by_id(&conn, &[1]).unwrap().is_empty());
+
+            let res = placeholder_dates(vec![res]);
+            insta::assert_debug_snapshot!(res);
+
+            // No existe
+            assert!(
+                SetzeRepo::update(
+                    &mut conn,
+                    99,
+                    &New {
+                        setze_spanisch: "".into(),
+                        setze_deutsch: "".into(),
+                        niveau_id: 1,
+                        thema: "".into(),
+                        deck_id: 1,
+                        schwirig_id: 1,
+                        tags: vec![],
+                    }
+                )
+                .is_err()
+            );
+        }
+
+        #[test]
+        fn test_soft_delete_and_restore() {
+            let mut conn = setup_test_db().expect("Error al crear db test");
+            init_data_local(&mut conn).expect("Error al iniciar data test");
+
+            let n = SetzeRepo::soft_delete(&mut conn, &[1]).expect("Error al borrar");
+            assert_eq!(n, 1);
+
+            assert_eq!(SetzeRepo::fetch_all_only_ids(&conn).unwrap(), vec![2]);
+            assert!(SetzeRepo::fetch_by_id(&conn, &[1]).unwrap().is_empty());
+            assert!(SetzeRepo::fetch_by_text(&conn, "hal").unwrap().is_empty());
+
+            let deleted = SetzeRepo::fetch_deleted(&conn).unwrap();
+            assert_eq!(deleted.len(), 1);
+            assert!(deleted[0].deleted_at.is_some());
+
+            let n = SetzeRepo::restore(&mut conn, &[1, 2]).expect("Error al restaurar");
+            assert_eq!(n, 1);
+
+            assert_eq!(SetzeRepo::fetch_by_id(&conn, &[1]).unwrap().len(), 1);
+            assert_eq!(SetzeRepo::fetch_by_text(&conn, "hal").unwrap()[0].id, 1);
+            assert!(SetzeRepo::fetch_deleted(&conn).unwrap().is_empty());
+        }
+    }
 }
@@ -0,0 +1,124 @@
+use crate::db::setup_test_db;
+
+#[cfg(test)]
+mod test_setze_alternativ_repo {
+
+    use color_eyre::eyre::Result;
+    use rusqlite::Connection;
+
+    use crate::db::{
+        schemas::{
+            setze::NewSetzeSchema,
+            setze_alternativ::{NewSetzeAlternativSchema as New, SetzeAlternativSchema as Schema},
+        },
+        seeders::init_data,
+        setze::SetzeRepo,
+        setze_alternativ::SetzeAlternativRepo,
+    };
+
+    use super::*;
+
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    struct Snapshot {
+        id: i32,
+        satz_id: i32,
+        antwort: String,
+        created_at: String,
+        deleted_at: String,
+    }
+
+    fn placeholder_dates(data: Vec<Schema>) -> Vec<Snapshot> {
+        data.into_iter()
+            .map(|d| Snapshot {
+                id: d.id,
+                satz_id: d.satz_id,
+                antwort: d.antwort,
+                created_at: "<created_at>".into(),
+                deleted_at: "<deleted_at>".into(),
+            })
+            .collect()
+    }
+
+    fn init_data_local(conn: &mut Connection) -> Result<()> {
+        init_data(conn)?;
+        let data = vec![
+            NewSetzeSchema {
+                setze_spanisch: "Voy a la escuela".into(),
+                setze_deutsch: "Ich gehe zur Schule".into(),
+                niveau_id: 0,
+                thema: "Dativ".into(),
+                deck_id: 1,
+                schwirig_id: 1,
+                tags: vec![],
+            },
+            NewSetzeSchema {
+                setze_spanisch: "Tengo un perro".into(),
+                setze_deutsch: "Ich habe einen Hund".into(),
+                niveau_id: 0,
+                thema: "Akkusativ".into(),
+                deck_id: 1,
+                schwirig_id: 1,
+                tags: vec![],
+            },
+        ];
+        SetzeRepo::bulk_insert(conn, &data)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_bulk_insert_is_idempotent() {
+        let mut conn = setup_test_db().expect("Error al crear db test");
+        init_data_local(&mut conn).expect("Error al iniciar datos dummy");
+
+        let data = vec![
+            New {
+                satz_id: 1,
+                antwort: "Ich gehe in die Schule".into(),
+            },
+            New {
+                satz_id: 2,
+                antwort: "Ich besitze einen Hund".into(),
+            },
+        ];
+
+        let res_1 = SetzeAlternativRepo::bulk_insert(&mut conn, &data)
+            .expect("La inserción no debe fallar");
+        assert_eq!(res_1.len(), 2);
+        assert_eq!(res_1[0].satz_id, 1);
+        assert_eq!(res_1[1].satz_id, 2);
+
+        // Volver a insertar la misma alternativa no debe duplicarla
+        let res_2 = SetzeAlternativRepo::bulk_insert(&mut conn, &data[..1])
+            .expect("La inserción no debe fallar");
+        assert_eq!(res_2.len(), 1);
+        assert_eq!(res_2[0].id, res_1[0].id);
+
+        let res_1 = placeholder_dates(res_1);
+        insta::assert_debug_snapshot!(res_1);
+    }
+
+    #[test]
+    fn test_fetch_by_satz_id() {
+        let mut conn = setup_test_db().expect("Error al crear db test");
+        init_data_local(&mut conn).expect("Error al iniciar datos dummy");
+
+        SetzeAlternativRepo::bulk_insert(
+            &mut conn,
+            &[New {
+                satz_id: 2,
+                antwort: "Ich besitze einen Hund".into(),
+            }],
+        )
+        .expect("La inserción no debe fallar");
+
+        let res = SetzeAlternativRepo::fetch_by_satz_id(&conn, &[]).expect("Error al hacer fetch");
+        assert_eq!(res.len(), 0);
+
+        let res =
+            SetzeAlternativRepo::fetch_by_satz_id(&conn, &[1, 2]).expect("Error al hacer fetch");
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].satz_id, 2);
+        assert_eq!(res[0].antwort, "Ich besitze einen Hund");
+    }
+}
@@ -25,17 +25,19 @@ impl SetzeReviewRepo {
         }
 
         let sql = r#"
-            INSERT INTO setze_review (satz_id, interval, ease_factor, repetitions, last_review, next_review)
-                VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+            INSERT INTO setze_review (satz_id, interval, ease_factor, repetitions, last_review, next_review, lapses, schwirig_id)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
 
             ON CONFLICT(satz_id) DO UPDATE SET 
                 interval = ?2,
                 ease_factor = ?3,
                 repetitions = ?4,
                 last_review = ?5,
-                next_review = ?6
+                next_review = ?6,
+                lapses = ?7,
+                schwirig_id = ?8
             
-            RETURNING id, satz_id, interval, ease_factor, repetitions, last_review, next_review, created_at, deleted_at;
+            RETURNING id, satz_id, interval, ease_factor, repetitions, last_review, next_review, lapses, schwirig_id, created_at, deleted_at;
         "#;
 
         let mut vec_out = Vec::with_capacity(data.len());
@@ -62,7 +64,7 @@ impl SetzeReviewRepo {
             "
                 SELECT 
                     id, satz_id, interval, ease_factor, repetitions,
-                    last_review, next_review, created_at, deleted_at
+                    last_review, next_review, lapses, schwirig_id, created_at, deleted_at
                 FROM setze_review sr
                 WHERE sr.deleted_at is NULL AND
                 sr.satz_id in ({placeholders})
@@ -80,17 +82,38 @@ impl SetzeReviewRepo {
     }
 
     pub fn fetch_review_satz_id_by_day(conn: &Connection, date_review: String) -> Result<Vec<i32>> {
+        let sql = r#"
+            SELECT sr.satz_id
+            FROM setze_review sr
+            JOIN setze s ON s.id = sr.satz_id
+            WHERE sr.next_review < ?1
+                AND sr.deleted_at IS NULL
+                AND s.deleted_at IS NULL
+            ORDER BY sr.next_review ASC;
+        "#;
+
+        let mut stmt = conn.prepare(sql)?;
+        let vec_ids = stmt
+            .query(params![date_review])?
+            .mapped(|r| r.get(0))
+            .collect::<Result<Vec<i32>, _>>()?;
+
+        Ok(vec_ids)
+    }
+
+    /// Ids con una revisión desde `since` (UTC, "YYYY-MM-DD HH:MM:SS").
+    pub fn fetch_satz_id_reviewed_since(conn: &Connection, since: String) -> Result<Vec<i32>> {
         let sql = r#"
             SELECT satz_id
             FROM setze_review
-            WHERE next_review < ?1
+            WHERE last_review >= ?1
                 AND deleted_at IS NULL
-            ORDER BY next_review ASC;
+            ORDER BY satz_id ASC;
         "#;
 
         let mut stmt = conn.prepare(sql)?;
         let vec_ids = stmt
-            .query(params![date_review])?
+            .query(params![since])?
             .mapped(|r| r.get(0))
             .collect::<Result<Vec<i32>, _>>()?;
 
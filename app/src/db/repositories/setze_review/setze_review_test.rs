@@ -0,0 +1,61 @@
+use crate::db::setup_test_db;
+
+#[cfg(test)]
+mod test_setze_review_repo {
+
+    use crate::db::{
+        schemas::{setze::NewSetzeSchema, setze_review::NewSetzeReviewSchema as New},
+        seeders::init_data,
+        setze::SetzeRepo,
+        setze_review::SetzeReviewRepo,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_fetch_review_satz_id_by_day_ignora_borradas() {
+        let mut conn = setup_test_db().unwrap();
+        init_data(&mut conn).expect("Error al iniciar datos dummy");
+
+        let satz = |deutsch: &str, spanisch: &str| NewSetzeSchema {
+            setze_spanisch: spanisch.into(),
+            setze_deutsch: deutsch.into(),
+            niveau_id: 1,
+            thema: "Tiere".into(),
+            deck_id: 1,
+            schwirig_id: 0,
+            tags: vec![],
+        };
+        SetzeRepo::bulk_insert(
+            &mut conn,
+            &[
+                satz("Der Hund spielt im Garten", "El perro juega en el jardín"),
+                satz("Die Katze schläft", "El gato duerme"),
+            ],
+        )
+        .unwrap();
+
+        let review = |satz_id: i32| New {
+            satz_id,
+            interval: 1,
+            ease_factor: 2.5,
+            repetitions: 1,
+            last_review: "2025-01-10 12:00:00".into(),
+            next_review: "2025-01-11 12:00:00".into(),
+            lapses: 0,
+            schwirig_id: None,
+        };
+        SetzeReviewRepo::bulk_insert(&mut conn, &[review(1), review(2)]).unwrap();
+
+        let date = "2026-01-01 00:00:00".to_string();
+        let res = SetzeReviewRepo::fetch_review_satz_id_by_day(&conn, date.clone())
+            .expect("La consulta no debe fallar");
+        assert_eq!(res.len(), 2);
+
+        SetzeRepo::soft_delete(&mut conn, &[1]).expect("El borrado no debe fallar");
+
+        let res = SetzeReviewRepo::fetch_review_satz_id_by_day(&conn, date)
+            .expect("La consulta no debe fallar");
+        assert_eq!(res, vec![2]);
+    }
+}
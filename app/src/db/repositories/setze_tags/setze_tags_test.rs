@@ -0,0 +1,110 @@
+use crate::db::setup_test_db;
+
+#[cfg(test)]
+mod test_setze_tags_repo {
+    use color_eyre::eyre::Result;
+    use rusqlite::Connection;
+
+    use crate::db::{
+        schemas::setze::NewSetzeSchema, seeders::init_data, setze::SetzeRepo,
+        setze_tags::SetzeTagsRepo,
+    };
+
+    use super::*;
+
+    fn init_data_local(conn: &mut Connection) -> Result<()> {
+        init_data(conn)?;
+        let data = vec![
+            NewSetzeSchema {
+                setze_spanisch: "Voy a la escuela".into(),
+                setze_deutsch: "Ich gehe zur Schule".into(),
+                niveau_id: 0,
+                thema: "Dativ".into(),
+                deck_id: 1,
+                schwirig_id: 1,
+                tags: vec!["Schule".into(), "Kapitel 1".into()],
+            },
+            NewSetzeSchema {
+                setze_spanisch: "Tengo un perro".into(),
+                setze_deutsch: "Ich habe einen Hund".into(),
+                niveau_id: 0,
+                thema: "Akkusativ".into(),
+                deck_id: 1,
+                schwirig_id: 1,
+                tags: vec!["Kapitel 1".into()],
+            },
+        ];
+        SetzeRepo::bulk_insert(conn, &data)?;
+        Ok(())
+    }
+
+    fn tag_names(conn: &Connection, satz_id: i32) -> Vec<String> {
+        SetzeTagsRepo::fetch_tags_by_satz_id(conn, &[satz_id])
+            .expect("Error al hacer fetch")
+            .remove(&satz_id)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|t| t.name)
+            .collect()
+    }
+
+    #[test]
+    fn test_bulk_insert_links_tags() {
+        let mut conn = setup_test_db().expect("Error al crear db test");
+        init_data_local(&mut conn).expect("Error al iniciar datos dummy");
+
+        assert_eq!(tag_names(&conn, 1), vec!["Kapitel 1", "Schule"]);
+        assert_eq!(tag_names(&conn, 2), vec!["Kapitel 1"]);
+
+        // La etiqueta compartida solo se crea una vez
+        let links = SetzeTagsRepo::fetch_by_satz_id(&conn, &[1, 2]).expect("Error al hacer fetch");
+        assert_eq!(links.len(), 3);
+        assert_eq!(links[1].tag_id, links[2].tag_id);
+
+        let satz = SetzeRepo::fetch_by_id(&conn, &[1]).expect("Error al hacer fetch");
+        assert_eq!(satz[0].tags.len(), 2);
+    }
+
+    #[test]
+    fn test_replace_by_satz_id_tx() {
+        let mut conn = setup_test_db().expect("Error al crear db test");
+        init_data_local(&mut conn).expect("Error al iniciar datos dummy");
+
+        let tx = conn.transaction().unwrap();
+        let tags = SetzeTagsRepo::replace_by_satz_id_tx(&tx, 1, &["Dativ".into()])
+            .expect("El reemplazo no debe fallar");
+        tx.commit().unwrap();
+
+        assert_eq!(tags.len(), 1);
+        assert_eq!(tag_names(&conn, 1), vec!["Dativ"]);
+        // No afecta a las demás oraciones
+        assert_eq!(tag_names(&conn, 2), vec!["Kapitel 1"]);
+
+        let tx = conn.transaction().unwrap();
+        SetzeTagsRepo::replace_by_satz_id_tx(&tx, 1, &["schule".into()])
+            .expect("El reemplazo no debe fallar");
+        tx.commit().unwrap();
+        assert_eq!(tag_names(&conn, 1), vec!["Schule"]);
+    }
+
+    #[test]
+    fn test_fetch_id_where_tags() {
+        use crate::helpers::tags::TagExpr;
+
+        let mut conn = setup_test_db().expect("Error al crear db test");
+        init_data_local(&mut conn).expect("Error al iniciar datos dummy");
+
+        let fetch = |conn: &Connection, expr: &str| {
+            SetzeRepo::fetch_id_where_tags(conn, &TagExpr::parse(expr).unwrap())
+                .expect("Error al hacer fetch")
+        };
+
+        assert_eq!(fetch(&conn, r#""kapitel 1""#), vec![1, 2]);
+        assert_eq!(fetch(&conn, r#""Kapitel 1" AND NOT Schule"#), vec![2]);
+        assert_eq!(fetch(&conn, "Schule OR Dativ"), vec![1]);
+        assert!(fetch(&conn, "Dativ").is_empty());
+
+        SetzeRepo::soft_delete(&mut conn, &[2]).expect("El borrado no debe fallar");
+        assert_eq!(fetch(&conn, r#""Kapitel 1""#), vec![1]);
+    }
+}
@@ -0,0 +1,82 @@
+use color_eyre::eyre::{Context, Result};
+use rusqlite::{Connection, Transaction, params_from_iter};
+use sql_model::{FromRaw, SqlNew, SqlRaw};
+
+use crate::db::schemas::tags::{NewTagsSchema as New, RawTagsSchema as Raw, TagsSchema as Schema};
+
+#[cfg(test)]
+mod tags_test;
+
+pub struct TagsRepo;
+
+impl TagsRepo {
+    /// Inserta las etiquetas; si ya existen (sin importar mayúsculas) regresa la existente.
+    pub fn bulk_insert_tx(tx: &Transaction, data: &[New]) -> Result<Vec<Schema>> {
+        if data.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let sql = r#"
+            INSERT INTO tags (name)
+                VALUES (?1)
+            ON CONFLICT(name) DO UPDATE SET deleted_at = NULL
+            RETURNING id, name, created_at, deleted_at;
+        "#;
+
+        let mut vec_out = Vec::with_capacity(data.len());
+        let mut stmt = tx.prepare_cached(sql).context(format!("sql: {}", sql))?;
+        for d in data {
+            let raw = stmt
+                .query_one(d.to_params(), Raw::from_sql)
+                .context(format!("sql: {}, params: {:#?}", sql, d))?;
+            vec_out.push(Schema::from_raw(raw)?);
+        }
+
+        Ok(vec_out)
+    }
+
+    pub fn fetch_all(conn: &Connection) -> Result<Vec<Schema>> {
+        let sql = "
+            SELECT id, name, created_at, deleted_at
+            FROM tags
+            WHERE deleted_at is NULL
+            ORDER BY name;
+        ";
+
+        let mut stmt = conn.prepare_cached(sql)?;
+        let raw = stmt
+            .query([])
+            .context(format!("sql: {}", sql))?
+            .mapped(Raw::from_sql)
+            .collect::<Result<Vec<Raw>, _>>()?;
+
+        let vec_out = Schema::from_vec_raw(raw)?;
+        Ok(vec_out)
+    }
+
+    pub fn fetch_by_id(conn: &Connection, ids: &[i32]) -> Result<Vec<Schema>> {
+        if ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let placeholders = vec!["?"; ids.len()].join(",");
+        let sql = format!(
+            "
+            SELECT id, name, created_at, deleted_at
+            FROM tags
+            WHERE id in ({placeholders})
+                AND deleted_at is NULL
+            ORDER BY name;
+        "
+        );
+
+        let mut stmt = conn.prepare(&sql)?;
+        let raw = stmt
+            .query(params_from_iter(ids.iter()))?
+            .mapped(Raw::from_sql)
+            .collect::<Result<Vec<Raw>, _>>()?;
+
+        let vec_out = Schema::from_vec_raw(raw)?;
+        Ok(vec_out)
+    }
+}
@@ -0,0 +1,57 @@
+use crate::db::setup_test_db;
+
+#[cfg(test)]
+mod test_tags_repo {
+    use rusqlite::Connection;
+
+    use crate::db::{
+        schemas::tags::{NewTagsSchema as New, TagsSchema as Schema},
+        tags::TagsRepo,
+    };
+
+    use super::*;
+
+    fn new(name: &str) -> New {
+        New { name: name.into() }
+    }
+
+    fn insert(conn: &mut Connection, data: &[New]) -> Vec<Schema> {
+        let tx = conn.transaction().unwrap();
+        let out = TagsRepo::bulk_insert_tx(&tx, data).expect("La inserción no debe fallar");
+        tx.commit().unwrap();
+        out
+    }
+
+    #[test]
+    fn test_bulk_insert_is_case_insensitive() {
+        let mut conn = setup_test_db().expect("Error al crear db test");
+
+        let res_1 = insert(&mut conn, &[new("Genitiv"), new("Arbeit")]);
+        assert_eq!(res_1.len(), 2);
+
+        // La misma etiqueta con otras mayúsculas regresa la existente
+        let res_2 = insert(&mut conn, &[new("genitiv")]);
+        assert_eq!(res_2.len(), 1);
+        assert_eq!(res_2[0].id, res_1[0].id);
+        assert_eq!(res_2[0].name, "Genitiv");
+
+        let all = TagsRepo::fetch_all(&conn).expect("Error al hacer fetch");
+        let names: Vec<&str> = all.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["Arbeit", "Genitiv"]);
+    }
+
+    #[test]
+    fn test_fetch_by_id() {
+        let mut conn = setup_test_db().expect("Error al crear db test");
+
+        let res = insert(&mut conn, &[new("b"), new("a"), new("c")]);
+
+        let out = TagsRepo::fetch_by_id(&conn, &[]).expect("Error al hacer fetch");
+        assert!(out.is_empty());
+
+        let out =
+            TagsRepo::fetch_by_id(&conn, &[res[0].id, res[1].id]).expect("Error al hacer fetch");
+        let names: Vec<&str> = out.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["a", "b"]);
+    }
+}
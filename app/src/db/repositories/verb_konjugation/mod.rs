@@ -0,0 +1,78 @@
+use color_eyre::eyre::{Context, Result};
+use rusqlite::{Connection, Transaction, params_from_iter};
+use sql_model::{FromRaw, SqlNew, SqlRaw};
+
+use crate::db::schemas::verb_konjugation::{
+    NewVerbKonjugationSchema as New, RawVerbKonjugationSchema as Raw,
+    VerbKonjugationSchema as Schema,
+};
+
+#[cfg(test)]
+mod verb_konjugation_test;
+
+pub struct VerbKonjugationRepo;
+
+impl VerbKonjugationRepo {
+    pub fn bulk_insert(conn: &mut Connection, data: &[New]) -> Result<Vec<Schema>> {
+        let tx = conn.transaction()?;
+        let out = Self::bulk_insert_tx(&tx, data)?;
+        tx.commit()?;
+        Ok(out)
+    }
+
+    /// Inserta las formas; si la forma ya existía para la misma persona y tiempo se reemplaza.
+    pub fn bulk_insert_tx(tx: &Transaction, data: &[New]) -> Result<Vec<Schema>> {
+        if data.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let sql = r#"
+            INSERT INTO verb_konjugation (wort_id, tempus, person, form)
+                VALUES (?1, ?2, ?3, ?4)
+
+            ON CONFLICT(wort_id, tempus, person) DO UPDATE SET
+                form = ?4
+
+            RETURNING wort_id, tempus, person, form, created_at;
+        "#;
+
+        let mut vec_out = Vec::with_capacity(data.len());
+        let mut stmt = tx.prepare_cached(sql).context(format!("sql: {}", sql))?;
+        for d in data {
+            let raw = stmt
+                .query_one(d.to_params(), Raw::from_sql)
+                .context(format!("sql: {}, params: {:#?}", sql, d))?;
+            vec_out.push(Schema::from_raw(raw)?)
+        }
+
+        Ok(vec_out)
+    }
+
+    pub fn fetch_by_wort_id(conn: &Connection, ids: &[i32]) -> Result<Vec<Schema>> {
+        if ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let placeholders = std::iter::repeat_n("?", ids.len())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        let sql = format!(
+            "
+                SELECT wort_id, tempus, person, form, created_at
+                FROM verb_konjugation
+                WHERE wort_id IN ({placeholders})
+                ORDER BY wort_id, tempus, person
+            "
+        );
+
+        let mut stmt = conn.prepare(&sql)?;
+        let raw = stmt
+            .query(params_from_iter(ids.iter()))
+            .context(format!("sql: {}", sql))?
+            .mapped(Raw::from_sql)
+            .collect::<Result<Vec<Raw>, _>>()?;
+
+        Schema::from_vec_raw(raw)
+    }
+}
@@ -0,0 +1,93 @@
+use crate::db::setup_test_db;
+
+#[cfg(test)]
+mod test_verb_konjugation_repo {
+    use rusqlite::Connection;
+
+    use crate::db::{
+        schemas::{verb_konjugation::NewVerbKonjugationSchema as New, worte::NewWorteSchema},
+        seeders::init_data,
+        verb_konjugation::VerbKonjugationRepo,
+        worte::WorteRepo,
+    };
+
+    use super::*;
+
+    fn init_data_local(conn: &mut Connection) {
+        init_data(conn).unwrap();
+        WorteRepo::bulk_insert(
+            conn,
+            &[NewWorteSchema {
+                gram_type: vec![2],
+                tags: vec![],
+                gender_id: None,
+                worte_de: "backen".into(),
+                worte_es: "hornear".into(),
+                plural: None,
+                niveau_id: 1,
+                example_de: "".into(),
+                example_es: "".into(),
+                verb_aux: Some("haben".into()),
+                trennbar: None,
+                reflexiv: None,
+                komparativ: None,
+                superlativ: None,
+                deck_id: 1,
+            }],
+        )
+        .unwrap();
+    }
+
+    fn new(tempus: &str, person: &str, form: &str) -> New {
+        New {
+            wort_id: 1,
+            tempus: tempus.into(),
+            person: person.into(),
+            form: form.into(),
+        }
+    }
+
+    #[test]
+    fn test_upsert_and_fetch() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+
+        VerbKonjugationRepo::bulk_insert(
+            &mut conn,
+            &[
+                new("praeteritum", "ich", "backte"),
+                new("partizip2", "", "gebacken"),
+            ],
+        )
+        .unwrap();
+        // La misma persona y tiempo reemplaza la forma
+        VerbKonjugationRepo::bulk_insert(&mut conn, &[new("praeteritum", "ich", "buk")]).unwrap();
+
+        let saved: Vec<(String, String, String)> =
+            VerbKonjugationRepo::fetch_by_wort_id(&conn, &[1])
+                .unwrap()
+                .into_iter()
+                .map(|k| (k.tempus, k.person, k.form))
+                .collect();
+        assert_eq!(
+            saved,
+            vec![
+                ("partizip2".into(), "".into(), "gebacken".into()),
+                ("praeteritum".into(), "ich".into(), "buk".into()),
+            ]
+        );
+        assert!(
+            VerbKonjugationRepo::fetch_by_wort_id(&conn, &[2])
+                .unwrap()
+                .is_empty()
+        );
+
+        // Se borran con la palabra
+        conn.execute("DELETE FROM worte WHERE id = 1", []).unwrap();
+        assert!(
+            VerbKonjugationRepo::fetch_by_wort_id(&conn, &[1])
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,75 @@
+use color_eyre::eyre::{Context, Result};
+use rusqlite::{Connection, Transaction};
+use sql_model::{FromRaw, SqlNew, SqlRaw};
+
+use crate::db::schemas::verwechslung::{
+    NewVerwechslungSchema as New, RawVerwechslungSchema as Raw, VerwechslungSchema as Schema,
+};
+
+#[cfg(test)]
+mod verwechslung_test;
+
+pub struct VerwechslungRepo;
+
+impl VerwechslungRepo {
+    pub fn bulk_insert(conn: &mut Connection, data: &[New]) -> Result<Vec<Schema>> {
+        let tx = conn.transaction()?;
+        let out = Self::bulk_insert_tx(&tx, data)?;
+        tx.commit()?;
+        Ok(out)
+    }
+
+    /// Registra cada confusión; si el par ya existe suma una vez más.
+    pub fn bulk_insert_tx(tx: &Transaction, data: &[New]) -> Result<Vec<Schema>> {
+        if data.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let sql = r#"
+            INSERT INTO verwechslung (wort_id, verwechselt_id, last_seen)
+                VALUES (?1, ?2, ?3)
+
+            ON CONFLICT(wort_id, verwechselt_id) DO UPDATE SET
+                count = count + 1,
+                last_seen = ?3
+
+            RETURNING id, wort_id, verwechselt_id, count, last_seen, created_at, deleted_at;
+        "#;
+
+        let mut vec_out = Vec::with_capacity(data.len());
+        let mut stmt = tx.prepare_cached(sql).context(format!("sql: {}", sql))?;
+        for d in data {
+            let raw = stmt
+                .query_one(d.to_params(), Raw::from_sql)
+                .context(format!("sql: {}, params: {:#?}", sql, d))?;
+            vec_out.push(Schema::from_raw(raw)?)
+        }
+
+        Ok(vec_out)
+    }
+
+    /// Pares de palabras activas, los más frecuentes primero.
+    pub fn fetch_all(conn: &Connection) -> Result<Vec<Schema>> {
+        let sql = "
+            SELECT
+                v.id, v.wort_id, v.verwechselt_id, v.count, v.last_seen,
+                v.created_at, v.deleted_at
+            FROM verwechslung v
+            JOIN worte a ON a.id = v.wort_id
+            JOIN worte b ON b.id = v.verwechselt_id
+            WHERE v.deleted_at IS NULL
+                AND a.deleted_at IS NULL
+                AND b.deleted_at IS NULL
+            ORDER BY v.count DESC, v.last_seen DESC, v.id ASC;
+        ";
+
+        let mut stmt = conn.prepare_cached(sql)?;
+        let raw = stmt
+            .query([])
+            .context(format!("sql: {}", sql))?
+            .mapped(Raw::from_sql)
+            .collect::<Result<Vec<Raw>, _>>()?;
+
+        Schema::from_vec_raw(raw)
+    }
+}
@@ -0,0 +1,97 @@
+use crate::db::setup_test_db;
+
+#[cfg(test)]
+mod test_verwechslung_repo {
+    use rusqlite::Connection;
+
+    use crate::db::{
+        schemas::{verwechslung::NewVerwechslungSchema as New, worte::NewWorteSchema},
+        seeders::init_data,
+        verwechslung::VerwechslungRepo,
+        worte::WorteRepo,
+    };
+
+    use super::*;
+
+    fn init_data_local(conn: &mut Connection) {
+        init_data(conn).unwrap();
+        let verb = |worte_de: &str| NewWorteSchema {
+            gram_type: vec![2],
+            tags: vec![],
+            gender_id: None,
+            worte_de: worte_de.into(),
+            worte_es: "".into(),
+            plural: None,
+            niveau_id: 1,
+            example_de: "".into(),
+            example_es: "".into(),
+            verb_aux: None,
+            trennbar: None,
+            reflexiv: None,
+            komparativ: None,
+            superlativ: None,
+            deck_id: 1,
+        };
+        WorteRepo::bulk_insert(conn, &[verb("gehen"), verb("fahren"), verb("liegen")]).unwrap();
+    }
+
+    fn new(wort_id: i32, verwechselt_id: i32, last_seen: &str) -> New {
+        New {
+            wort_id,
+            verwechselt_id,
+            last_seen: last_seen.into(),
+        }
+    }
+
+    #[test]
+    fn test_upsert_counts() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+
+        let first =
+            VerwechslungRepo::bulk_insert(&mut conn, &[new(1, 2, "2020-01-01 00:00:00")]).unwrap();
+        let second =
+            VerwechslungRepo::bulk_insert(&mut conn, &[new(1, 2, "2020-01-05 00:00:00")]).unwrap();
+
+        // Un par se guarda una vez y cuenta las repeticiones
+        assert_eq!(first[0].id, second[0].id);
+        assert_eq!(first[0].count, 1);
+        assert_eq!(second[0].count, 2);
+        assert_eq!(second[0].last_seen.to_string(), "2020-01-05 00:00:00 UTC");
+
+        // El orden importa: "fahren" por "gehen" no es "gehen" por "fahren"
+        let other =
+            VerwechslungRepo::bulk_insert(&mut conn, &[new(2, 1, "2020-01-02 00:00:00")]).unwrap();
+        assert_ne!(other[0].id, first[0].id);
+    }
+
+    #[test]
+    fn test_fetch_all() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+
+        VerwechslungRepo::bulk_insert(
+            &mut conn,
+            &[
+                new(3, 1, "2020-01-01 00:00:00"),
+                new(1, 2, "2020-01-01 00:00:00"),
+                new(1, 2, "2020-01-02 00:00:00"),
+            ],
+        )
+        .unwrap();
+
+        // Los más frecuentes primero
+        let all = VerwechslungRepo::fetch_all(&conn).unwrap();
+        let pairs: Vec<(i32, i32, u32)> = all
+            .iter()
+            .map(|v| (v.wort_id, v.verwechselt_id, v.count))
+            .collect();
+        assert_eq!(pairs, vec![(1, 2, 2), (3, 1, 1)]);
+
+        // Los pares con una palabra borrada no cuentan
+        WorteRepo::soft_delete(&mut conn, &[2]).unwrap();
+        let all = VerwechslungRepo::fetch_all(&conn).unwrap();
+        assert_eq!(all.len(), 1);
+        assert_eq!(all[0].wort_id, 3);
+    }
+}
@@ -0,0 +1,71 @@
+use color_eyre::eyre::{Context, Result};
+use rusqlite::{Connection, Transaction};
+use sql_model::{FromRaw, SqlNew, SqlRaw};
+
+use crate::db::schemas::word_frequency::{
+    NewWordFrequencySchema as New, RawWordFrequencySchema as Raw, WordFrequencySchema as Schema,
+};
+
+#[cfg(test)]
+mod word_frequency_test;
+
+pub struct WordFrequencyRepo;
+
+impl WordFrequencyRepo {
+    /// Cambia la lista completa por `data`: una lista nueva no se mezcla con la anterior.
+    pub fn replace_all(conn: &mut Connection, data: &[New]) -> Result<Vec<Schema>> {
+        let tx = conn.transaction()?;
+        tx.execute("DELETE FROM word_frequency;", [])?;
+        let out = Self::bulk_insert_tx(&tx, data)?;
+        tx.commit()?;
+        Ok(out)
+    }
+
+    /// Guarda los lemas; si uno ya existe se queda con su nuevo rango.
+    pub fn bulk_insert_tx(tx: &Transaction, data: &[New]) -> Result<Vec<Schema>> {
+        if data.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let sql = r#"
+            INSERT INTO word_frequency (lemma, rank)
+                VALUES (?1, ?2)
+
+            ON CONFLICT(lemma) DO UPDATE SET
+                rank = ?2
+
+            RETURNING id, lemma, rank, created_at, deleted_at;
+        "#;
+
+        let mut vec_out = Vec::with_capacity(data.len());
+        let mut stmt = tx.prepare_cached(sql).context(format!("sql: {}", sql))?;
+        for d in data {
+            let raw = stmt
+                .query_one(d.to_params(), Raw::from_sql)
+                .context(format!("sql: {}, params: {:#?}", sql, d))?;
+            vec_out.push(Schema::from_raw(raw)?)
+        }
+
+        Ok(vec_out)
+    }
+
+    /// Lemas activos, del más frecuente al menos frecuente.
+    pub fn fetch_all(conn: &Connection) -> Result<Vec<Schema>> {
+        let sql = "
+            SELECT
+                id, lemma, rank, created_at, deleted_at
+            FROM word_frequency
+            WHERE deleted_at IS NULL
+            ORDER BY rank ASC, id ASC;
+        ";
+
+        let mut stmt = conn.prepare_cached(sql)?;
+        let raw = stmt
+            .query([])
+            .context(format!("sql: {}", sql))?
+            .mapped(Raw::from_sql)
+            .collect::<Result<Vec<Raw>, _>>()?;
+
+        Schema::from_vec_raw(raw)
+    }
+}
@@ -0,0 +1,36 @@
+use crate::db::setup_test_db;
+
+#[cfg(test)]
+mod test_word_frequency_repo {
+    use crate::db::{
+        schemas::word_frequency::NewWordFrequencySchema as New, word_frequency::WordFrequencyRepo,
+    };
+
+    use super::*;
+
+    fn new(lemma: &str, rank: u32) -> New {
+        New {
+            lemma: lemma.into(),
+            rank,
+        }
+    }
+
+    #[test]
+    fn test_replace_all() {
+        let mut conn = setup_test_db().unwrap();
+
+        let saved =
+            WordFrequencyRepo::replace_all(&mut conn, &[new("sein", 2), new("der", 1)]).unwrap();
+        assert_eq!(saved.len(), 2);
+
+        let all = WordFrequencyRepo::fetch_all(&conn).unwrap();
+        let list: Vec<(&str, u32)> = all.iter().map(|f| (f.lemma.as_str(), f.rank)).collect();
+        assert_eq!(list, [("der", 1), ("sein", 2)]);
+
+        // La lista anterior se reemplaza completa
+        WordFrequencyRepo::replace_all(&mut conn, &[new("haben", 1), new("sein", 5)]).unwrap();
+        let all = WordFrequencyRepo::fetch_all(&conn).unwrap();
+        let list: Vec<(&str, u32)> = all.iter().map(|f| (f.lemma.as_str(), f.rank)).collect();
+        assert_eq!(list, [("haben", 1), ("sein", 5)]);
+    }
+}
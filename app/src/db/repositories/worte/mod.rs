@@ -1,16 +1,24 @@
 use std::collections::HashMap;
 
+use chrono::Utc;
 use color_eyre::eyre::{Context, Result};
 use rusqlite::{Connection, Transaction, params, params_from_iter};
 use sql_model::{FromRaw, SqlNew, SqlRaw};
 
-use crate::db::{
-    schemas::{
-        gram_type::GramTypeSchema,
-        worte::{NewWorteSchema as New, RawWorteSchema as Raw, WorteSchema as Schema},
-        worte_gram_type::{NewWorteGramTypeSchema, WorteGramTypeSchema},
+use crate::{
+    db::{
+        schemas::{
+            gram_type::GramTypeSchema,
+            worte::{NewWorteSchema as New, RawWorteSchema as Raw, WorteSchema as Schema},
+            worte_gram_type::{NewWorteGramTypeSchema, WorteGramTypeSchema},
+        },
+        search::SearchRepo,
+        worte_audio::WorteAudioRepo,
+        worte_gram_type::WorteGramTypeRepo,
+        worte_setze::WorteSetzeRepo,
+        worte_tags::WorteTagsRepo,
     },
-    worte_gram_type::WorteGramTypeRepo,
+    helpers::{search::SearchFilter, tags::TagExpr, time},
 };
 
 #[cfg(test)]
@@ -34,9 +42,9 @@ impl WorteRepo {
 
         let sql = r#"
             INSERT INTO 
-                worte (gender_id,wort_de,wort_es,plural,niveau_id,example_de,example_es,verb_aux,trennbar,reflexiv)
-            VALUES (?1,?2,?3,?4,?5,?6,?7,?8,?9,?10)
-            RETURNING id,gender_id,wort_de,wort_es,plural,niveau_id,example_de,example_es,verb_aux,trennbar,reflexiv,created_at,deleted_at;
+                worte (gender_id,wort_de,wort_es,plural,niveau_id,example_de,example_es,verb_aux,trennbar,reflexiv,deck_id,komparativ,superlativ)
+            VALUES (?1,?2,?3,?4,?5,?6,?7,?8,?9,?10,?11,?12,?13)
+            RETURNING id,gender_id,wort_de,wort_es,plural,niveau_id,example_de,example_es,verb_aux,trennbar,reflexiv,komparativ,superlativ,deck_id,created_at,deleted_at;
         "#;
 
         let mut stmt = tx.prepare_cached(sql)?;
@@ -66,9 +74,37 @@ impl WorteRepo {
 
         WorteGramTypeRepo::bulk_insert_tx(tx, &vec_mn)?;
 
+        for (wort, new) in vec_out.iter_mut().zip(data.iter()) {
+            if !new.tags.is_empty() {
+                wort.tags = WorteTagsRepo::replace_by_wort_id_tx(tx, wort.id, &new.tags)?;
+            }
+        }
+
+        WorteSetzeRepo::link_worte_tx(tx, &vec_out)?;
+
         Ok(vec_out)
     }
 
+    pub fn fetch_all_only_ids(conn: &Connection) -> Result<Vec<i32>> {
+        let sql: &'static str = r#"
+            SELECT
+                id
+            FROM worte w
+            WHERE w.deleted_at IS NULL
+            ORDER BY w.id ASC
+        "#;
+
+        let mut stmt = conn.prepare_cached(sql)?;
+
+        let rows = stmt
+            .query([])
+            .context(format!("sql: {}", sql))?
+            .mapped(|row| row.get(0))
+            .collect::<Result<Vec<i32>, _>>()?;
+
+        Ok(rows)
+    }
+
     pub fn fetch_id_neue_worte(conn: &Connection) -> Result<Vec<i32>> {
         let sql = "
             SELECT
@@ -95,6 +131,108 @@ impl WorteRepo {
         Ok(ids)
     }
 
+    /// (id, niveau_id, intervalo) de las palabras activas; las nuevas tienen intervalo 0.
+    pub fn fetch_niveau_interval(conn: &Connection) -> Result<Vec<(i32, i32, u32)>> {
+        let sql = "
+            SELECT
+                w.id, w.niveau_id, COALESCE(wr.interval, 0)
+            FROM worte w
+            LEFT JOIN worte_review wr ON wr.wort_id = w.id
+            WHERE w.deleted_at IS NULL
+            ORDER BY w.id ASC;
+        ";
+
+        let mut stmt = conn.prepare_cached(sql)?;
+
+        let rows = stmt
+            .query([])
+            .context(format!("Sql - {}", sql))?
+            .mapped(|r| Ok((r.get(0)?, r.get(1)?, r.get(2)?)))
+            .collect::<Result<Vec<(i32, i32, u32)>, _>>()?;
+
+        Ok(rows)
+    }
+
+    /// Verbos activos: por su tipo gramatical o porque tienen auxiliar.
+    pub fn fetch_id_verbs(conn: &Connection) -> Result<Vec<i32>> {
+        let sql = "
+            SELECT
+                w.id
+            FROM worte w
+            WHERE w.deleted_at IS NULL
+                AND (
+                    TRIM(COALESCE(w.verb_aux, '')) <> ''
+                    OR EXISTS (
+                        SELECT 1
+                        FROM worte_gram_type wgt
+                        JOIN gram_type gt ON gt.id = wgt.id_gram_type
+                        WHERE wgt.id_worte = w.id
+                            AND wgt.deleted_at IS NULL
+                            AND gt.code LIKE 'verb%'
+                    )
+                )
+            ORDER BY w.id ASC;
+        ";
+
+        let mut stmt = conn.prepare_cached(sql)?;
+        let ids = stmt
+            .query([])
+            .context(format!("Sql - {}", sql))?
+            .mapped(|r| r.get(0))
+            .collect::<Result<Vec<i32>, _>>()?;
+
+        Ok(ids)
+    }
+
+    /// Sustantivos activos: las palabras con género.
+    pub fn fetch_id_nouns(conn: &Connection) -> Result<Vec<i32>> {
+        let sql = "
+            SELECT
+                w.id
+            FROM worte w
+            WHERE w.deleted_at IS NULL
+                AND w.gender_id IS NOT NULL
+            ORDER BY w.id ASC;
+        ";
+
+        let mut stmt = conn.prepare_cached(sql)?;
+        let ids = stmt
+            .query([])
+            .context(format!("Sql - {}", sql))?
+            .mapped(|r| r.get(0))
+            .collect::<Result<Vec<i32>, _>>()?;
+
+        Ok(ids)
+    }
+
+    /// Palabras activas con un tipo gramatical cuyo código coincide con `code` (LIKE).
+    pub fn fetch_id_by_gram_code(conn: &Connection, code: &str) -> Result<Vec<i32>> {
+        let sql = "
+            SELECT
+                w.id
+            FROM worte w
+            WHERE w.deleted_at IS NULL
+                AND EXISTS (
+                    SELECT 1
+                    FROM worte_gram_type wgt
+                    JOIN gram_type gt ON gt.id = wgt.id_gram_type
+                    WHERE wgt.id_worte = w.id
+                        AND wgt.deleted_at IS NULL
+                        AND gt.code LIKE ?1
+                )
+            ORDER BY w.id ASC;
+        ";
+
+        let mut stmt = conn.prepare_cached(sql)?;
+        let ids = stmt
+            .query(params![code])
+            .context(format!("Sql - {}", sql))?
+            .mapped(|r| r.get(0))
+            .collect::<Result<Vec<i32>, _>>()?;
+
+        Ok(ids)
+    }
+
     pub fn fetch_by_id(conn: &Connection, ids: &[i32]) -> Result<Vec<Schema>> {
         if ids.is_empty() {
             return Ok(vec![]);
@@ -106,7 +244,8 @@ impl WorteRepo {
             "
             SELECT 
                 id, gender_id, wort_de, wort_es, plural, niveau_id, example_de,
-                example_es, verb_aux, trennbar, reflexiv, created_at, deleted_at
+                example_es, verb_aux, trennbar, reflexiv, komparativ, superlativ, deck_id,
+                created_at, deleted_at
             FROM worte w
             WHERE w.deleted_at is NULL AND
             w.id in ({placeholders})
@@ -133,9 +272,12 @@ impl WorteRepo {
         };
 
         let mut vec_out = Schema::from_vec_raw(raw)?;
+        let mut hash_tags = WorteTagsRepo::fetch_tags_by_wort_id(conn, ids)?;
 
         // Llenamos el schema de Worte con la info de GramType
         for wort in vec_out.iter_mut() {
+            wort.tags = hash_tags.remove(&wort.id).unwrap_or_default();
+
             if let Some(vec_wgt) = hash_worte_gram_type.get(&wort.id) {
                 for wgt in vec_wgt {
                     // Llenamos arreglo para la información del Schema para el regreso
@@ -162,10 +304,13 @@ impl WorteRepo {
                 w.verb_aux,
                 w.trennbar,
                 w.reflexiv,
+                w.komparativ,
+                w.superlativ,
+                w.deck_id,
                 w.created_at,
                 w.deleted_at
             FROM worte w
-            LEFT JOIN worte_audio wa ON w.id = wa.wort_id 
+            LEFT JOIN worte_audio wa ON w.id = wa.wort_id AND wa.deleted_at IS NULL
             WHERE w.deleted_at IS NULL AND wa.wort_id is NULL
             ORDER BY w.id ASC;
         "
@@ -182,4 +327,347 @@ impl WorteRepo {
         let vec_out = Schema::from_vec_raw(raws)?;
         Ok(vec_out)
     }
+
+    /// Ids de las palabras activas que cumplen con la expresión de etiquetas.
+    pub fn fetch_id_where_tags(conn: &Connection, expr: &TagExpr) -> Result<Vec<i32>> {
+        let exists = "EXISTS (
+                SELECT 1
+                FROM worte_tags lt
+                JOIN tags t ON t.id = lt.tag_id
+                WHERE lt.wort_id = w.id
+                    AND lt.deleted_at IS NULL
+                    AND t.deleted_at IS NULL
+                    AND t.name = ?
+            )";
+        let (condition, params) = expr.to_sql(exists);
+
+        let sql = format!(
+            "
+            SELECT w.id
+            FROM worte w
+            WHERE w.deleted_at IS NULL
+                AND {condition}
+            ORDER BY w.id ASC;
+        "
+        );
+
+        let mut stmt = conn.prepare(&sql)?;
+        let ids = stmt
+            .query(params_from_iter(params.iter()))
+            .context(format!("sql: {}, params: {:#?}", sql, params))?
+            .mapped(|r| r.get(0))
+            .collect::<Result<Vec<i32>, _>>()?;
+
+        Ok(ids)
+    }
+
+    /// De los ids dados, los de las palabras que pertenecen a alguno de los decks.
+    pub fn fetch_id_in_decks(conn: &Connection, ids: &[i32], decks: &[i32]) -> Result<Vec<i32>> {
+        if ids.is_empty() || decks.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let placeholders_ids = vec!["?"; ids.len()].join(",");
+        let placeholders_decks = vec!["?"; decks.len()].join(",");
+        let sql = format!(
+            "
+            SELECT w.id
+            FROM worte w
+            WHERE w.id in ({placeholders_ids})
+                AND w.deck_id in ({placeholders_decks})
+            ORDER BY w.id ASC;
+        "
+        );
+
+        let mut stmt = conn.prepare(&sql)?;
+        let ids = stmt
+            .query(params_from_iter(ids.iter().chain(decks.iter())))
+            .context(format!("sql: {}", sql))?
+            .mapped(|r| r.get(0))
+            .collect::<Result<Vec<i32>, _>>()?;
+
+        Ok(ids)
+    }
+
+    pub fn update(conn: &mut Connection, id: i32, data: &New) -> Result<Schema> {
+        let tx = conn.transaction()?;
+        let out = Self::update_tx(&tx, id, data)?;
+        tx.commit()?;
+        Ok(out)
+    }
+
+    /// Actualiza una palabra activa junto con sus tipos gramaticales.
+    ///
+    /// Si cambia `wort_es` (el texto del TTS) el audio se invalida para volver a generarlo.
+    pub fn update_tx(tx: &Transaction, id: i32, data: &New) -> Result<Schema> {
+        let sql_old = "SELECT wort_es FROM worte WHERE id = ?1 AND deleted_at IS NULL;";
+        let old_wort_es: String = tx
+            .query_one(sql_old, params![id], |r| r.get(0))
+            .context(format!("No existe la palabra con id: {}", id))?;
+
+        let sql = r#"
+            UPDATE worte SET
+                gender_id = ?1,
+                wort_de = ?2,
+                wort_es = ?3,
+                plural = ?4,
+                niveau_id = ?5,
+                example_de = ?6,
+                example_es = ?7,
+                verb_aux = ?8,
+                trennbar = ?9,
+                reflexiv = ?10,
+                deck_id = ?11,
+                komparativ = ?12,
+                superlativ = ?13
+            WHERE id = ?14 AND deleted_at IS NULL
+            RETURNING id,gender_id,wort_de,wort_es,plural,niveau_id,example_de,example_es,verb_aux,trennbar,reflexiv,komparativ,superlativ,deck_id,created_at,deleted_at;
+        "#;
+
+        let raw = tx
+            .query_one(
+                sql,
+                params![
+                    data.gender_id,
+                    data.worte_de,
+                    data.worte_es,
+                    data.plural,
+                    data.niveau_id,
+                    data.example_de,
+                    data.example_es,
+                    data.verb_aux,
+                    data.trennbar,
+                    data.reflexiv,
+                    data.deck_id,
+                    data.komparativ,
+                    data.superlativ,
+                    id
+                ],
+                Raw::from_sql,
+            )
+            .context(format!("sql: {}, params: {:#?}", sql, data))?;
+        let mut out = Schema::from_raw(raw)?;
+
+        for wgt in WorteGramTypeRepo::replace_by_wort_id_tx(tx, id, &data.gram_type)? {
+            out.gram_type_id
+                .push(GramTypeSchema::from_id(wgt.id_gram_type)?);
+        }
+
+        out.tags = WorteTagsRepo::replace_by_wort_id_tx(tx, id, &data.tags)?;
+
+        if old_wort_es != out.worte_es {
+            WorteAudioRepo::invalidate_tx(tx, &[id])?;
+        }
+
+        WorteSetzeRepo::link_worte_tx(tx, std::slice::from_ref(&out))?;
+
+        Ok(out)
+    }
+
+    /// Marca las palabras (y sus tipos gramaticales) como borradas.
+    pub fn soft_delete(conn: &mut Connection, ids: &[i32]) -> Result<usize> {
+        if ids.is_empty() {
+            return Ok(0);
+        }
+
+        let placeholders = vec!["?"; ids.len()].join(",");
+        let now = time::datetime_2_string(Utc::now());
+
+        let mut params: Vec<&dyn rusqlite::ToSql> = vec![&now];
+        params.extend(ids.iter().map(|t| t as &dyn rusqlite::ToSql));
+
+        // Usamos la misma fecha para poder restaurar solo los tipos borrados junto con la palabra
+        let sql_gram_type = format!(
+            "
+            UPDATE worte_gram_type SET deleted_at = ?
+            WHERE deleted_at IS NULL AND id_worte in ({placeholders});
+        "
+        );
+        let sql = format!(
+            "
+            UPDATE worte SET deleted_at = ?
+            WHERE deleted_at IS NULL AND id in ({placeholders});
+        "
+        );
+
+        let tx = conn.transaction()?;
+        tx.execute(&sql_gram_type, params_from_iter(params.iter()))
+            .context(format!("sql: {}", sql_gram_type))?;
+        let n = tx
+            .execute(&sql, params_from_iter(params.iter()))
+            .context(format!("sql: {}", sql))?;
+        tx.commit()?;
+
+        Ok(n)
+    }
+
+    /// Restaura palabras borradas junto con los tipos gramaticales que se borraron con ellas.
+    pub fn restore(conn: &mut Connection, ids: &[i32]) -> Result<usize> {
+        if ids.is_empty() {
+            return Ok(0);
+        }
+
+        let placeholders = vec!["?"; ids.len()].join(",");
+
+        let sql_gram_type = format!(
+            "
+            UPDATE worte_gram_type SET deleted_at = NULL
+            WHERE id_worte in ({placeholders})
+                AND deleted_at = (
+                    SELECT w.deleted_at FROM worte w WHERE w.id = worte_gram_type.id_worte
+                );
+        "
+        );
+        let sql = format!(
+            "
+            UPDATE worte SET deleted_at = NULL
+            WHERE deleted_at IS NOT NULL AND id in ({placeholders});
+        "
+        );
+
+        let tx = conn.transaction()?;
+        tx.execute(&sql_gram_type, params_from_iter(ids.iter()))
+            .context(format!("sql: {}", sql_gram_type))?;
+        let n = tx
+            .execute(&sql, params_from_iter(ids.iter()))
+            .context(format!("sql: {}", sql))?;
+        // Mientras estuvieron borradas no se relacionaron con las oraciones nuevas
+        WorteSetzeRepo::link_worte_tx(&tx, &Self::fetch_by_id(&tx, ids)?)?;
+        tx.commit()?;
+
+        Ok(n)
+    }
+
+    /// Ids de las palabras activas que cumplen con los filtros de búsqueda.
+    pub fn fetch_id_search(
+        conn: &Connection,
+        filter: &SearchFilter,
+        date_review: &str,
+    ) -> Result<Vec<i32>> {
+        let (mut conditions, mut params) = filter.to_sql("w", "wr", date_review);
+
+        if let Some(text) = &filter.text {
+            // El texto completo del alemán no distingue umlauts: "madchen" encuentra "Mädchen"
+            let mut condition =
+                "w.wort_de LIKE '%' || ? || '%' OR w.wort_es LIKE '%' || ? || '%'".to_string();
+            params.push(text.clone().into());
+            params.push(text.clone().into());
+            if let Some(query) = SearchRepo::match_query(text) {
+                condition
+                    .push_str(" OR w.id IN (SELECT rowid FROM worte_fts WHERE worte_fts MATCH ?)");
+                params.push(query.into());
+            }
+            conditions.push(format!("({condition})"));
+        }
+        if let Some(gram_type) = &filter.gram_type {
+            conditions.push(
+                "EXISTS (
+                    SELECT 1
+                    FROM worte_gram_type wgt
+                    JOIN gram_type gt ON gt.id = wgt.id_gram_type
+                    WHERE wgt.id_worte = w.id
+                        AND wgt.deleted_at IS NULL
+                        AND (gt.code LIKE ? OR gt.name LIKE ?)
+                )"
+                .to_string(),
+            );
+            params.push(gram_type.clone().into());
+            params.push(gram_type.clone().into());
+        }
+        if let Some(gender) = &filter.gender {
+            conditions.push(
+                "w.gender_id IN (SELECT id FROM worte_gender WHERE gender LIKE ? OR artikel LIKE ?)"
+                    .to_string(),
+            );
+            params.push(gender.clone().into());
+            params.push(gender.clone().into());
+        }
+
+        let condition = conditions
+            .iter()
+            .map(|c| format!("AND {c}"))
+            .collect::<Vec<_>>()
+            .join("\n                ");
+        let sql = format!(
+            "
+            SELECT w.id
+            FROM worte w
+            LEFT JOIN worte_review wr ON wr.wort_id = w.id AND wr.deleted_at IS NULL
+            WHERE w.deleted_at IS NULL
+                {condition}
+            ORDER BY w.id ASC;
+        "
+        );
+
+        let mut stmt = conn.prepare(&sql)?;
+        let ids = stmt
+            .query(params_from_iter(params.iter()))
+            .context(format!("sql: {}, params: {:#?}", sql, params))?
+            .mapped(|r| r.get(0))
+            .collect::<Result<Vec<i32>, _>>()?;
+
+        Ok(ids)
+    }
+
+    /// Palabras activas cuyo texto en alemán es exactamente `wort_de`.
+    pub fn fetch_id_by_wort_de(conn: &Connection, wort_de: &str) -> Result<Vec<i32>> {
+        let sql = "
+            SELECT id
+            FROM worte w
+            WHERE w.deleted_at IS NULL
+                AND TRIM(w.wort_de) = ?1
+            ORDER BY w.id ASC;
+        ";
+
+        let mut stmt = conn.prepare_cached(sql)?;
+        let ids = stmt
+            .query(params![wort_de.trim()])
+            .context(format!("Sql - {}", sql))?
+            .mapped(|r| r.get(0))
+            .collect::<Result<Vec<i32>, _>>()?;
+
+        Ok(ids)
+    }
+
+    /// Palabras activas cuyo texto en alemán o español contiene `text`.
+    pub fn fetch_by_text(conn: &Connection, text: &str) -> Result<Vec<Schema>> {
+        let sql = "
+            SELECT id
+            FROM worte w
+            WHERE w.deleted_at IS NULL
+                AND (w.wort_de LIKE '%' || ?1 || '%' OR w.wort_es LIKE '%' || ?1 || '%')
+            ORDER BY w.id ASC;
+        ";
+
+        let mut stmt = conn.prepare_cached(sql)?;
+        let ids = stmt
+            .query(params![text.trim()])
+            .context(format!("Sql - {}", sql))?
+            .mapped(|r| r.get(0))
+            .collect::<Result<Vec<i32>, _>>()?;
+
+        Self::fetch_by_id(conn, &ids)
+    }
+
+    /// Palabras borradas, sin sus tipos gramaticales.
+    pub fn fetch_deleted(conn: &Connection) -> Result<Vec<Schema>> {
+        let sql = "
+            SELECT 
+                id, gender_id, wort_de, wort_es, plural, niveau_id, example_de,
+                example_es, verb_aux, trennbar, reflexiv, komparativ, superlativ, deck_id,
+                created_at, deleted_at
+            FROM worte w
+            WHERE w.deleted_at IS NOT NULL
+            ORDER BY w.deleted_at DESC, w.id ASC;
+        ";
+
+        let mut stmt = conn.prepare_cached(sql)?;
+        let raws = stmt
+            .query([])
+            .context(format!("Sql - {}", sql))?
+            .mapped(Raw::from_sql)
+            .collect::<Result<Vec<Raw>, _>>()?;
+
+        Schema::from_vec_raw(raws)
+    }
 }
@@ -148,6 +148,7 @@ mod test_worte_repo {
             let data_1 = vec![
                 New {
                     gram_type: vec![1],
+                    tags: vec![],
                     gender_id: Some(1),
                     worte_de: "Hund".into(),
                     worte_es: "Perro".into(),
@@ -158,9 +159,13 @@ mod test_worte_repo {
                     verb_aux: None,
                     trennbar: None,
                     reflexiv: None,
+                    komparativ: None,
+                    superlativ: None,
+                    deck_id: 1,
                 },
                 New {
                     gram_type: vec![2, 3],
+                    tags: vec![],
                     gender_id: None,
                     worte_de: "laufen".into(),
                     worte_es: "correr".into(),
@@ -171,6 +176,9 @@ mod test_worte_repo {
                     verb_aux: Some("sein".into()),
                     trennbar: Some(false),
                     reflexiv: Some(false),
+                    komparativ: None,
+                    superlativ: None,
+                    deck_id: 1,
                 },
             ];
             run_bulk_insert_update_scenario(|conn| WorteRepo::bulk_insert(conn, &data_1));
@@ -181,6 +189,7 @@ mod test_worte_repo {
             let data_1 = vec![
                 New {
                     gram_type: vec![1],
+                    tags: vec![],
                     gender_id: Some(1),
                     worte_de: "Hund".into(),
                     worte_es: "Perro".into(),
@@ -191,9 +200,13 @@ mod test_worte_repo {
                     verb_aux: None,
                     trennbar: None,
                     reflexiv: None,
+                    komparativ: None,
+                    superlativ: None,
+                    deck_id: 1,
                 },
                 New {
                     gram_type: vec![2, 3],
+                    tags: vec![],
                     gender_id: None,
                     worte_de: "laufen".into(),
                     worte_es: "correr".into(),
@@ -204,6 +217,9 @@ mod test_worte_repo {
                     verb_aux: Some("sein".into()),
                     trennbar: Some(false),
                     reflexiv: Some(false),
+                    komparativ: None,
+                    superlativ: None,
+                    deck_id: 1,
                 },
             ];
 
@@ -222,12 +238,9 @@ mod test_worte_repo {
         use color_eyre::eyre::Result;
         use rusqlite::Connection;
 
-        use crate::{
-            db::{
-                schemas::worte_review::NewWorteReviewSchema, seeders::init_data, setup_test_db,
-                worte_review::WorteReviewRepo,
-            },
-            helpers::time::fixed_date,
+        use crate::db::{
+            schemas::worte_review::NewWorteReviewSchema, seeders::init_data, setup_test_db,
+            worte_review::WorteReviewRepo,
         };
 
         fn init_data_local(conn: &mut Connection) -> Result<()> {
@@ -235,6 +248,7 @@ mod test_worte_repo {
             let data = [
                 New {
                     gram_type: vec![1],
+                    tags: vec![],
                     gender_id: Some(1),
                     worte_de: "Hund".into(),
                     worte_es: "Perro".into(),
@@ -245,9 +259,13 @@ mod test_worte_repo {
                     verb_aux: None,
                     trennbar: None,
                     reflexiv: None,
+                    komparativ: None,
+                    superlativ: None,
+                    deck_id: 1,
                 },
                 New {
                     gram_type: vec![2, 3],
+                    tags: vec![],
                     gender_id: None,
                     worte_de: "laufen".into(),
                     worte_es: "correr".into(),
@@ -258,6 +276,9 @@ mod test_worte_repo {
                     verb_aux: Some("sein".into()),
                     trennbar: Some(false),
                     reflexiv: Some(false),
+                    komparativ: None,
+                    superlativ: None,
+                    deck_id: 1,
                 },
             ];
             WorteRepo::bulk_insert(conn, &data)?;
@@ -314,4 +335,147 @@ mod test_worte_repo {
             insta::assert_debug_snapshot!(res_2);
         }
     }
+
+    mod update_delete {
+        use color_eyre::eyre::Result;
+        use rusqlite::Connection;
+
+        use super::*;
+        use crate::db::{
+            schemas::worte_audio::NewWorteAudioSchema, seeders::init_data,
+            worte_audio::WorteAudioRepo,
+        };
+
+        fn new_hund() -> New {
+            New {
+                gram_type: vec![1],
+                tags: vec![],
+                gender_id: Some(1),
+                worte_de: "Hund".into(),
+                worte_es: "Perro".into(),
+                plural: Some("Hunde".into()),
+                niveau_id: 1,
+                example_de: "Beispiel".into(),
+                example_es: "Ejemplo".into(),
+                verb_aux: None,
+                trennbar: None,
+                reflexiv: None,
+                komparativ: None,
+                superlativ: None,
+                deck_id: 1,
+            }
+        }
+
+        fn init_data_local(conn: &mut Connection) -> Result<()> {
+            init_data(conn)?;
+            WorteRepo::bulk_insert(conn, &[new_hund()])?;
+            WorteAudioRepo::bulk_insert(
+                conn,
+                &[NewWorteAudioSchema {
+                    wort_id: 1,
+                    file_path: "wort_000001.mp3".into(),
+                    voice_id: "voice".into(),
+                }],
+            )?;
+            Ok(())
+        }
+
+        #[test]
+        fn test_update() {
+            let mut conn = setup_test_db().unwrap();
+            init_data_local(&mut conn).expect("Error al iniciar datos dummy");
+
+            // Cambiar el alemán y los tipos no invalida el audio (el TTS es en español)
+            let res = WorteRepo::update(
+                &mut conn,
+                1,
+                &New {
+                    gram_type: vec![2, 3],
+                    worte_de: "Hündin".into(),
+                    ..new_hund()
+                },
+            )
+            .expect("Error al actualizar");
+            assert_eq!(res.worte_de, "Hündin");
+            assert_eq!(
+                res.gram_type_id.iter().map(|g| g.id).collect::<Vec<_>>(),
+                vec![2, 3]
+            );
+            assert_eq!(WorteAudioRepo::fetch_by_id(&conn, &[1]).unwrap().len(), 1);
+            assert!(
+                WorteRepo::fetch_worte_without_audio(&conn)
+                    .unwrap()
+                    .is_empty()
+            );
+
+            let res = WorteRepo::update(
+                &mut conn,
+                1,
+                &New {
+                    gram_type: vec![1, 3],
+                    worte_es: "Perra".into(),
+                    plural: None,
+                    ..new_hund()
+                },
+            )
+            .expect("Error al actualizar");
+            assert!(WorteAudioRepo::fetch_by_id(&conn, &[1]).unwrap().is_empty());
+            assert_eq!(
+                WorteRepo::fetch_worte_without_audio(&conn).unwrap().len(),
+                1
+            );
+
+            // Los tipos quitados no regresan al consultar
+            let fetched = WorteRepo::fetch_by_id(&conn, &[1]).unwrap();
+            assert_eq!(
+                fetched[0]
+                    .gram_type_id
+                    .iter()
+                    .map(|g| g.id)
+                    .collect::<Vec<_>>(),
+                vec![1, 3]
+            );
+
+            let res = placeholder_dates(vec![res]);
+            insta::assert_debug_snapshot!(res);
+
+            assert!(WorteRepo::update(&mut conn, 99, &new_hund()).is_err());
+        }
+
+        #[test]
+        fn test_soft_delete_and_restore() {
+            let mut conn = setup_test_db().unwrap();
+            init_data_local(&mut conn).expect("Error al iniciar datos dummy");
+
+            // El tipo 2 se quitó antes de borrar: no debe restaurarse
+            WorteRepo::update(
+                &mut conn,
+                1,
+                &New {
+                    gram_type: vec![1],
+                    ..new_hund()
+                },
+            )
+            .unwrap();
+
+            let n = WorteRepo::soft_delete(&mut conn, &[1]).expect("Error al borrar");
+            assert_eq!(n, 1);
+
+            assert!(WorteRepo::fetch_by_id(&conn, &[1]).unwrap().is_empty());
+            assert!(WorteRepo::fetch_id_neue_worte(&conn).unwrap().is_empty());
+            assert!(WorteRepo::fetch_by_text(&conn, "hun").unwrap().is_empty());
+            assert_eq!(WorteRepo::fetch_deleted(&conn).unwrap().len(), 1);
+
+            let n = WorteRepo::restore(&mut conn, &[1]).expect("Error al restaurar");
+            assert_eq!(n, 1);
+
+            let res = WorteRepo::fetch_by_text(&conn, "hun").unwrap();
+            assert_eq!(res.len(), 1);
+            assert_eq!(
+                res[0].gram_type_id.iter().map(|g| g.id).collect::<Vec<_>>(),
+                vec![1]
+            );
+            assert!(WorteRepo::fetch_deleted(&conn).unwrap().is_empty());
+        }
+    }
 }
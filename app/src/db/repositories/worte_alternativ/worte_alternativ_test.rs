@@ -0,0 +1,151 @@
+use crate::db::setup_test_db;
+
+#[cfg(test)]
+mod test_worte_alternativ_repo {
+
+    use color_eyre::eyre::Result;
+    use rusqlite::Connection;
+
+    use crate::db::{
+        schemas::{
+            worte::NewWorteSchema,
+            worte_alternativ::{NewWorteAlternativSchema as New, WorteAlternativSchema as Schema},
+        },
+        seeders::init_data,
+        worte::WorteRepo,
+        worte_alternativ::WorteAlternativRepo,
+    };
+
+    use super::*;
+
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    struct Snapshot {
+        id: i32,
+        wort_id: i32,
+        antwort: String,
+        created_at: String,
+        deleted_at: String,
+    }
+
+    fn placeholder_dates(data: Vec<Schema>) -> Vec<Snapshot> {
+        data.into_iter()
+            .map(|d| Snapshot {
+                id: d.id,
+                wort_id: d.wort_id,
+                antwort: d.antwort,
+                created_at: "<created_at>".into(),
+                deleted_at: "<deleted_at>".into(),
+            })
+            .collect()
+    }
+
+    fn init_data_local(conn: &mut Connection) -> Result<()> {
+        init_data(conn)?;
+        let data = vec![
+            NewWorteSchema {
+                gram_type: vec![0],
+                tags: vec![],
+                gender_id: Some(0),
+                worte_de: "Hund".into(),
+                worte_es: "Perro".into(),
+                plural: Some("Hunde".into()),
+                niveau_id: 0,
+                example_de: "Beispiel".into(),
+                example_es: "Ejemplo".into(),
+                verb_aux: None,
+                trennbar: None,
+                reflexiv: None,
+                komparativ: None,
+                superlativ: None,
+                deck_id: 1,
+            },
+            NewWorteSchema {
+                gram_type: vec![2],
+                tags: vec![],
+                gender_id: None,
+                worte_de: "laufen".into(),
+                worte_es: "correr".into(),
+                plural: None,
+                niveau_id: 0,
+                example_de: "Beispiel".into(),
+                example_es: "Ejemplo".into(),
+                verb_aux: Some("sein".into()),
+                trennbar: Some(false),
+                reflexiv: Some(false),
+                komparativ: None,
+                superlativ: None,
+                deck_id: 1,
+            },
+        ];
+        WorteRepo::bulk_insert(conn, &data)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_bulk_insert_is_idempotent() {
+        let mut conn = setup_test_db().expect("Error al crear db test");
+        init_data_local(&mut conn).expect("Error al iniciar datos dummy");
+
+        let data = vec![
+            New {
+                wort_id: 1,
+                antwort: "der Köter".into(),
+            },
+            New {
+                wort_id: 2,
+                antwort: "rennen".into(),
+            },
+        ];
+
+        let res_1 = WorteAlternativRepo::bulk_insert(&mut conn, &data)
+            .expect("La inserción no debe fallar");
+        assert_eq!(res_1.len(), 2);
+        assert_eq!(res_1[0].wort_id, 1);
+        assert_eq!(res_1[0].antwort, "der Köter");
+        assert_eq!(res_1[1].wort_id, 2);
+        assert_eq!(res_1[1].antwort, "rennen");
+
+        // Volver a insertar la misma alternativa no debe duplicarla
+        let res_2 = WorteAlternativRepo::bulk_insert(&mut conn, &data[..1])
+            .expect("La inserción no debe fallar");
+        assert_eq!(res_2.len(), 1);
+        assert_eq!(res_2[0].id, res_1[0].id);
+
+        let res_1 = placeholder_dates(res_1);
+        insta::assert_debug_snapshot!(res_1);
+    }
+
+    #[test]
+    fn test_fetch_by_wort_id() {
+        let mut conn = setup_test_db().expect("Error al crear db test");
+        init_data_local(&mut conn).expect("Error al iniciar datos dummy");
+
+        WorteAlternativRepo::bulk_insert(
+            &mut conn,
+            &[
+                New {
+                    wort_id: 1,
+                    antwort: "der Köter".into(),
+                },
+                New {
+                    wort_id: 1,
+                    antwort: "die Töle".into(),
+                },
+            ],
+        )
+        .expect("La inserción no debe fallar");
+
+        let res = WorteAlternativRepo::fetch_by_wort_id(&conn, &[]).expect("Error al hacer fetch");
+        assert_eq!(res.len(), 0);
+
+        let res =
+            WorteAlternativRepo::fetch_by_wort_id(&conn, &[1, 2]).expect("Error al hacer fetch");
+        assert_eq!(res.len(), 2);
+        assert_eq!(res[0].antwort, "der Köter");
+        assert_eq!(res[1].antwort, "die Töle");
+
+        let res = WorteAlternativRepo::fetch_by_wort_id(&conn, &[2]).expect("Error al hacer fetch");
+        assert_eq!(res.len(), 0);
+    }
+}
@@ -29,7 +29,7 @@ impl WorteAudioRepo {
         let sql = r#"
             INSERT INTO worte_audio (wort_id, file_path, voice_id)
                 VALUES (?1, ?2, ?3)
-            ON CONFLICT(wort_id) DO UPDATE SET file_path = ?2, voice_id = ?3
+            ON CONFLICT(wort_id) DO UPDATE SET file_path = ?2, voice_id = ?3, deleted_at = NULL
             RETURNING wort_id, file_path, voice_id, created_at, deleted_at;
             "#;
 
@@ -69,4 +69,24 @@ impl WorteAudioRepo {
         let vec_out = Schema::from_vec_raw(raw)?;
         Ok(vec_out)
     }
+
+    /// Marca los audios como borrados para que se vuelvan a generar (ejem: cambió el texto).
+    pub fn invalidate_tx(tx: &Transaction, ids: &[i32]) -> Result<usize> {
+        if ids.is_empty() {
+            return Ok(0);
+        }
+
+        let placeholders = vec!["?"; ids.len()].join(",");
+        let sql = format!(
+            "
+            UPDATE worte_audio
+                SET deleted_at = CURRENT_TIMESTAMP
+            WHERE wort_id in ({placeholders})
+                AND deleted_at is NULL;
+        "
+        );
+
+        let n = tx.execute(&sql, params_from_iter(ids.iter()))?;
+        Ok(n)
+    }
 }
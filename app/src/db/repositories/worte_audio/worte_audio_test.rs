@@ -47,6 +47,7 @@ mod test_worte_audio_repo {
             let data = vec![
                 NewWorteSchema {
                     gram_type: vec![1],
+                    tags: vec![],
                     gender_id: Some(1),
                     worte_de: "Hund".into(),
                     worte_es: "Perro".into(),
@@ -57,9 +58,13 @@ mod test_worte_audio_repo {
                     verb_aux: None,
                     trennbar: None,
                     reflexiv: None,
+                    komparativ: None,
+                    superlativ: None,
+                    deck_id: 1,
                 },
                 NewWorteSchema {
                     gram_type: vec![2, 3],
+                    tags: vec![],
                     gender_id: None,
                     worte_de: "laufen".into(),
                     worte_es: "correr".into(),
@@ -70,6 +75,9 @@ mod test_worte_audio_repo {
                     verb_aux: Some("sein".into()),
                     trennbar: Some(false),
                     reflexiv: Some(false),
+                    komparativ: None,
+                    superlativ: None,
+                    deck_id: 1,
                 },
             ];
             WorteRepo::bulk_insert(conn, &data)?;
@@ -76,4 +76,54 @@ impl WorteGramTypeRepo {
         let out = Schema::from_vec_raw(raw)?;
         Ok(out)
     }
+
+    /// Deja a la palabra solo con los tipos gramaticales indicados: los que sobran se marcan
+    /// como borrados y los que faltan se insertan (o se restauran).
+    pub fn replace_by_wort_id_tx(
+        tx: &Transaction,
+        wort_id: i32,
+        gram_types: &[i32],
+    ) -> Result<Vec<Schema>> {
+        let placeholders = std::iter::repeat_n("?", gram_types.len())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        let sql = format!(
+            "
+            UPDATE worte_gram_type
+                SET deleted_at = CURRENT_TIMESTAMP
+            WHERE id_worte = ?
+                AND deleted_at IS NULL
+                AND id_gram_type NOT IN ({placeholders});
+        "
+        );
+
+        let mut params: Vec<&dyn rusqlite::ToSql> = vec![&wort_id];
+        params.extend(gram_types.iter().map(|t| t as &dyn rusqlite::ToSql));
+
+        tx.execute(&sql, params_from_iter(params))
+            .context(format!("sql: {}", sql))?;
+
+        let sql = r#"
+            INSERT INTO worte_gram_type (id_worte, id_gram_type)
+                VALUES (?1, ?2)
+            ON CONFLICT(id_worte, id_gram_type) DO UPDATE SET deleted_at = NULL
+            RETURNING id_worte, id_gram_type, created_at, deleted_at;
+        "#;
+
+        let mut vec_out = Vec::with_capacity(gram_types.len());
+        let mut stmt = tx.prepare_cached(sql).context(format!("sql: {}", sql))?;
+        for id_gram_type in gram_types {
+            let d = New {
+                id_worte: wort_id,
+                id_gram_type: *id_gram_type,
+            };
+            let raw = stmt
+                .query_one(d.to_params(), Raw::from_sql)
+                .context(format!("sql: {}, params: {:#?}", sql, d))?;
+            vec_out.push(Schema::from_raw(raw)?)
+        }
+
+        Ok(vec_out)
+    }
 }
@@ -45,6 +45,7 @@ mod test_worte_gram_type_repo {
                 &[
                     NewWorteSchema {
                         gram_type: vec![],
+                        tags: vec![],
                         gender_id: Some(1),
                         worte_de: "Hund".into(),
                         worte_es: "Perro".into(),
@@ -55,9 +56,13 @@ mod test_worte_gram_type_repo {
                         verb_aux: None,
                         trennbar: None,
                         reflexiv: None,
+                        komparativ: None,
+                        superlativ: None,
+                        deck_id: 1,
                     },
                     NewWorteSchema {
                         gram_type: vec![],
+                        tags: vec![],
                         gender_id: None,
                         worte_de: "laufen".into(),
                         worte_es: "correr".into(),
@@ -68,6 +73,9 @@ mod test_worte_gram_type_repo {
                         verb_aux: Some("sein".into()),
                         trennbar: Some(false),
                         reflexiv: Some(false),
+                        komparativ: None,
+                        superlativ: None,
+                        deck_id: 1,
                     },
                 ],
             )?;
@@ -80,17 +80,38 @@ impl WorteReviewRepo {
     }
 
     pub fn fetch_review_wort_id_by_day(conn: &Connection, date_review: String) -> Result<Vec<i32>> {
+        let sql = r#"
+            SELECT wr.wort_id
+            FROM worte_review wr
+            JOIN worte w ON w.id = wr.wort_id
+            WHERE wr.next_review < ?1
+                AND wr.deleted_at IS NULL
+                AND w.deleted_at IS NULL
+            ORDER BY wr.next_review ASC;
+        "#;
+
+        let mut stmt = conn.prepare(sql)?;
+        let vec_ids = stmt
+            .query(params![date_review])?
+            .mapped(|r| r.get(0))
+            .collect::<Result<Vec<i32>, _>>()?;
+
+        Ok(vec_ids)
+    }
+
+    /// Ids con una revisión desde `since` (UTC, "YYYY-MM-DD HH:MM:SS").
+    pub fn fetch_wort_id_reviewed_since(conn: &Connection, since: String) -> Result<Vec<i32>> {
         let sql = r#"
             SELECT wort_id
             FROM worte_review
-            WHERE next_review < ?1
+            WHERE last_review >= ?1
                 AND deleted_at IS NULL
-            ORDER BY next_review ASC;
+            ORDER BY wort_id ASC;
         "#;
 
         let mut stmt = conn.prepare(sql)?;
         let vec_ids = stmt
-            .query(params![date_review])?
+            .query(params![since])?
             .mapped(|r| r.get(0))
             .collect::<Result<Vec<i32>, _>>()?;
 
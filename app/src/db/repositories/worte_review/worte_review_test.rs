@@ -62,6 +62,7 @@ mod test_worte_review_repo {
             let data = vec![
                 NewWorteSchema {
                     gram_type: vec![1],
+                    tags: vec![],
                     gender_id: Some(1),
                     worte_de: "Hund".into(),
                     worte_es: "Perro".into(),
@@ -72,9 +73,13 @@ mod test_worte_review_repo {
                     verb_aux: None,
                     trennbar: None,
                     reflexiv: None,
+                    komparativ: None,
+                    superlativ: None,
+                    deck_id: 1,
                 },
                 NewWorteSchema {
                     gram_type: vec![2, 3],
+                    tags: vec![],
                     gender_id: None,
                     worte_de: "laufen".into(),
                     worte_es: "correr".into(),
@@ -85,6 +90,9 @@ mod test_worte_review_repo {
                     verb_aux: Some("sein".into()),
                     trennbar: Some(false),
                     reflexiv: Some(false),
+                    komparativ: None,
+                    superlativ: None,
+                    deck_id: 1,
                 },
             ];
             WorteRepo::bulk_insert(conn, &data)?;
@@ -207,6 +215,7 @@ mod test_worte_review_repo {
             let data = vec![
                 NewWorteSchema {
                     gram_type: vec![1],
+                    tags: vec![],
                     gender_id: Some(1),
                     worte_de: "Hund".into(),
                     worte_es: "Perro".into(),
@@ -217,9 +226,13 @@ mod test_worte_review_repo {
                     verb_aux: None,
                     trennbar: None,
                     reflexiv: None,
+                    komparativ: None,
+                    superlativ: None,
+                    deck_id: 1,
                 },
                 NewWorteSchema {
                     gram_type: vec![2, 3],
+                    tags: vec![],
                     gender_id: None,
                     worte_de: "laufen".into(),
                     worte_es: "correr".into(),
@@ -230,6 +243,9 @@ mod test_worte_review_repo {
                     verb_aux: Some("sein".into()),
                     trennbar: Some(false),
                     reflexiv: Some(false),
+                    komparativ: None,
+                    superlativ: None,
+                    deck_id: 1,
                 },
             ];
             WorteRepo::bulk_insert(conn, &data)?;
@@ -291,5 +307,22 @@ mod test_worte_review_repo {
             let res = placeholder_dates(res);
             insta::assert_debug_snapshot!(res);
         }
+
+        #[test]
+        fn test_fetch_review_wort_id_by_day_ignora_borradas() {
+            let mut conn = setup_test_db().unwrap();
+            init_data_local(&mut conn).expect("Error al iniciar datos dummy");
+
+            let date = "2026-01-01 00:00:00".to_string();
+            let res = WorteReviewRepo::fetch_review_wort_id_by_day(&conn, date.clone())
+                .expect("La consulta no debe fallar");
+            assert_eq!(res, vec![1, 2]);
+
+            WorteRepo::soft_delete(&mut conn, &[1]).expect("El borrado no debe fallar");
+
+            let res = WorteReviewRepo::fetch_review_wort_id_by_day(&conn, date)
+                .expect("La consulta no debe fallar");
+            assert_eq!(res, vec![2]);
+        }
     }
 }
@@ -0,0 +1,211 @@
+use std::collections::HashMap;
+
+use color_eyre::eyre::{Context, Result};
+use rusqlite::{Connection, Transaction, params_from_iter};
+use sql_model::SqlNew;
+
+use crate::{
+    db::{
+        schemas::{
+            setze::SetzeSchema, worte::WorteSchema, worte_setze::NewWorteSetzeSchema as New,
+        },
+        worte::WorteRepo,
+    },
+    helpers::lemma::{self, SetzeIndex},
+};
+
+#[cfg(test)]
+mod worte_setze_test;
+
+/// Intervalo (días) a partir del cual una palabra se considera madura.
+pub const MATURE_INTERVAL: u32 = 21;
+
+pub struct WorteSetzeRepo;
+
+impl WorteSetzeRepo {
+    /// Vuelve a calcular en qué oraciones activas aparece cada una de las palabras.
+    pub fn link_worte_tx(tx: &Transaction, worte: &[WorteSchema]) -> Result<()> {
+        if worte.is_empty() {
+            return Ok(());
+        }
+
+        let ids: Vec<i32> = worte.iter().map(|w| w.id).collect();
+        let placeholders = vec!["?"; ids.len()].join(",");
+        let sql = format!("DELETE FROM worte_setze WHERE wort_id IN ({placeholders});");
+        tx.execute(&sql, params_from_iter(ids.iter()))
+            .context(format!("sql: {}", sql))?;
+
+        let setze = Self::fetch_setze_deutsch(tx)?;
+        let index = SetzeIndex::new(setze.iter().map(|(id, de)| (*id, de.as_str())));
+        Self::insert_tx(tx, worte, &index)
+    }
+
+    /// Vuelve a calcular qué palabras activas aparecen en cada una de las oraciones.
+    pub fn link_setze_tx(tx: &Transaction, setze: &[SetzeSchema]) -> Result<()> {
+        if setze.is_empty() {
+            return Ok(());
+        }
+
+        let ids: Vec<i32> = setze.iter().map(|s| s.id).collect();
+        let placeholders = vec!["?"; ids.len()].join(",");
+        let sql = format!("DELETE FROM worte_setze WHERE satz_id IN ({placeholders});");
+        tx.execute(&sql, params_from_iter(ids.iter()))
+            .context(format!("sql: {}", sql))?;
+
+        let index = SetzeIndex::new(setze.iter().map(|s| (s.id, s.setze_deutsch.as_str())));
+        let worte = WorteRepo::fetch_by_id(tx, &WorteRepo::fetch_all_only_ids(tx)?)?;
+        Self::insert_tx(tx, &worte, &index)
+    }
+
+    /// Número de oraciones activas en las que aparece cada palabra: wort_id -> oraciones.
+    pub fn count_by_wort_id(conn: &Connection, ids: &[i32]) -> Result<HashMap<i32, usize>> {
+        if ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let placeholders = vec!["?"; ids.len()].join(",");
+        let sql = format!(
+            "
+            SELECT ws.wort_id, COUNT(*)
+            FROM worte_setze ws
+            JOIN setze s ON s.id = ws.satz_id
+            WHERE ws.wort_id IN ({placeholders})
+                AND s.deleted_at IS NULL
+            GROUP BY ws.wort_id;
+        "
+        );
+
+        let mut stmt = conn.prepare(&sql)?;
+        let rows = stmt
+            .query(params_from_iter(ids.iter()))
+            .context(format!("sql: {}", sql))?
+            .mapped(|r| Ok((r.get(0)?, r.get::<_, i64>(1)? as usize)))
+            .collect::<Result<HashMap<i32, usize>, _>>()?;
+
+        Ok(rows)
+    }
+
+    /// Alemán de las primeras `limit` oraciones activas (por id) en las que aparece cada
+    /// palabra: wort_id -> oraciones.
+    pub fn fetch_setze_by_wort_id(
+        conn: &Connection,
+        ids: &[i32],
+        limit: usize,
+    ) -> Result<HashMap<i32, Vec<String>>> {
+        if ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let placeholders = vec!["?"; ids.len()].join(",");
+        let sql = format!(
+            "
+            SELECT wort_id, setze_deutsch
+            FROM (
+                SELECT
+                    ws.wort_id,
+                    s.setze_deutsch,
+                    ROW_NUMBER() OVER (PARTITION BY ws.wort_id ORDER BY s.id) AS n
+                FROM worte_setze ws
+                JOIN setze s ON s.id = ws.satz_id
+                WHERE ws.wort_id IN ({placeholders})
+                    AND s.deleted_at IS NULL
+            )
+            WHERE n <= ?
+            ORDER BY wort_id, n;
+        "
+        );
+
+        let mut params: Vec<&dyn rusqlite::ToSql> =
+            ids.iter().map(|id| id as &dyn rusqlite::ToSql).collect();
+        let limit = limit as i64;
+        params.push(&limit);
+
+        let mut stmt = conn.prepare(&sql)?;
+        let mut rows = stmt
+            .query(params_from_iter(params))
+            .context(format!("sql: {}", sql))?;
+
+        let mut out: HashMap<i32, Vec<String>> = HashMap::new();
+        while let Some(r) = rows.next()? {
+            out.entry(r.get(0)?).or_default().push(r.get(1)?);
+        }
+
+        Ok(out)
+    }
+
+    /// Fracción (0 a 1) de las palabras activas de cada oración que ya están maduras
+    /// (intervalo de al menos `MATURE_INTERVAL` días). Las oraciones sin palabras
+    /// relacionadas no aparecen.
+    pub fn fetch_mature_ratio(conn: &Connection, ids: &[i32]) -> Result<HashMap<i32, f64>> {
+        if ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let placeholders = vec!["?"; ids.len()].join(",");
+        let sql = format!(
+            "
+            SELECT
+                ws.satz_id,
+                AVG(CASE WHEN wr.interval >= ? THEN 1.0 ELSE 0.0 END)
+            FROM worte_setze ws
+            JOIN worte w ON w.id = ws.wort_id
+            LEFT JOIN worte_review wr ON wr.wort_id = w.id AND wr.deleted_at IS NULL
+            WHERE ws.satz_id IN ({placeholders})
+                AND w.deleted_at IS NULL
+            GROUP BY ws.satz_id;
+        "
+        );
+
+        let mut params: Vec<&dyn rusqlite::ToSql> = vec![&MATURE_INTERVAL];
+        params.extend(ids.iter().map(|id| id as &dyn rusqlite::ToSql));
+
+        let mut stmt = conn.prepare(&sql)?;
+        let rows = stmt
+            .query(params_from_iter(params))
+            .context(format!("sql: {}", sql))?
+            .mapped(|r| Ok((r.get(0)?, r.get(1)?)))
+            .collect::<Result<HashMap<i32, f64>, _>>()?;
+
+        Ok(rows)
+    }
+
+    fn insert_tx(tx: &Transaction, worte: &[WorteSchema], index: &SetzeIndex) -> Result<()> {
+        let sql = "
+            INSERT INTO worte_setze (wort_id, satz_id)
+                VALUES (?1, ?2)
+            ON CONFLICT(wort_id, satz_id) DO NOTHING;
+        ";
+
+        let mut stmt = tx.prepare_cached(sql)?;
+        for wort in worte {
+            for satz_id in index.find(&lemma::forms(wort)) {
+                let d = New {
+                    wort_id: wort.id,
+                    satz_id,
+                };
+                stmt.execute(d.to_params())
+                    .context(format!("sql: {}, params: {:#?}", sql, d))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn fetch_setze_deutsch(conn: &Connection) -> Result<Vec<(i32, String)>> {
+        let sql = "
+            SELECT id, setze_deutsch
+            FROM setze
+            WHERE deleted_at IS NULL
+            ORDER BY id;
+        ";
+
+        let mut stmt = conn.prepare_cached(sql)?;
+        let rows = stmt
+            .query([])
+            .context(format!("sql: {}", sql))?
+            .mapped(|r| Ok((r.get(0)?, r.get(1)?)))
+            .collect::<Result<Vec<(i32, String)>, _>>()?;
+
+        Ok(rows)
+    }
+}
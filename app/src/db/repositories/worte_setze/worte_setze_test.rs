@@ -0,0 +1,175 @@
+use crate::db::setup_test_db;
+
+#[cfg(test)]
+mod test_worte_setze_repo {
+    use std::fs;
+
+    use chrono::Utc;
+    use rusqlite::Connection;
+
+    use crate::{
+        db::{
+            deck::DeckRepo,
+            schemas::{setze::NewSetzeSchema, worte_review::NewWorteReviewSchema},
+            seeders::init_data,
+            setze::SetzeRepo,
+            worte::WorteRepo,
+            worte_review::WorteReviewRepo,
+            worte_setze::{MATURE_INTERVAL, WorteSetzeRepo},
+        },
+        helpers::{csv, time},
+    };
+
+    use super::*;
+
+    const WORTE_CSV: &str = "\
+gram_type,gender_id,worte_de,worte_es,plural,niveau_id,example_de,example_es,verb_aux,trennbar,reflexiv
+noun_common,Maskuline,Hund,el perro,Hunde,A1,Der Hund spielt.,El perro juega.,,,
+verb_separable,,anrufen,llamar por teléfono,,A2,Ich rufe dich an.,Te llamo.,haben,Si,No
+verb_main,,gehen,ir,,A1,Ich gehe.,Voy.,sein,No,No
+";
+
+    const SETZE_CSV: &str = "\
+setze_spanisch,setze_deutsch,thema,schwirig_id
+Los perros juegan,Die Hunde spielen,Plural,0
+Te llamo mañana,Ich rufe dich morgen an,Trennbare Verben,0
+El perro fue a casa,Der Hund ging nach Hause,Präteritum,0
+";
+
+    fn init_data_local(conn: &mut Connection) {
+        init_data(conn).unwrap();
+
+        let dir = std::env::temp_dir().join(format!("anki_worte_setze_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let worte = dir.join("worte.csv");
+        let setze = dir.join("setze.csv");
+        fs::write(&worte, WORTE_CSV).unwrap();
+        fs::write(&setze, SETZE_CSV).unwrap();
+
+        let deck = DeckRepo::ensure_path(conn, "Goethe A1").unwrap();
+        let data = csv::extract_worte_csv(worte.to_str().unwrap(), deck.id).unwrap();
+        WorteRepo::bulk_insert(conn, &data).unwrap();
+        let data = csv::extract_sentences_csv(setze.to_str().unwrap(), deck.id).unwrap();
+        SetzeRepo::bulk_insert(conn, &data).unwrap();
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    fn links(conn: &Connection) -> Vec<(i32, i32)> {
+        let mut stmt = conn
+            .prepare("SELECT wort_id, satz_id FROM worte_setze ORDER BY wort_id, satz_id")
+            .unwrap();
+        stmt.query_map([], |r| Ok((r.get(0)?, r.get(1)?)))
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_link_on_insert() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+
+        // Plural, verbo separable partido y pasado irregular
+        assert_eq!(links(&conn), vec![(1, 1), (1, 3), (2, 2), (3, 3)]);
+
+        let count = WorteSetzeRepo::count_by_wort_id(&conn, &[1, 2, 3]).unwrap();
+        assert_eq!(count.get(&1), Some(&2));
+        assert_eq!(count.get(&2), Some(&1));
+
+        // Una oración nueva se relaciona con las palabras que ya existían
+        let satz = SetzeRepo::fetch_by_id(&conn, &[1]).unwrap().remove(0);
+        let new = NewSetzeSchema {
+            setze_spanisch: "Vamos".into(),
+            setze_deutsch: "Wir gehen mit dem Hund".into(),
+            niveau_id: satz.niveau_id.id,
+            thema: "Dativ".into(),
+            deck_id: satz.deck_id,
+            schwirig_id: satz.schwirig_id.id,
+            tags: vec![],
+        };
+        SetzeRepo::bulk_insert(&mut conn, &[new]).unwrap();
+        assert_eq!(
+            links(&conn),
+            vec![(1, 1), (1, 3), (1, 4), (2, 2), (3, 3), (3, 4)]
+        );
+    }
+
+    #[test]
+    fn test_link_on_update_and_delete() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+
+        let satz = SetzeRepo::fetch_by_id(&conn, &[2]).unwrap().remove(0);
+        let new = NewSetzeSchema {
+            setze_spanisch: satz.setze_spanisch,
+            setze_deutsch: "Ich gehe morgen".into(),
+            niveau_id: satz.niveau_id.id,
+            thema: satz.thema,
+            deck_id: satz.deck_id,
+            schwirig_id: satz.schwirig_id.id,
+            tags: vec![],
+        };
+        SetzeRepo::update(&mut conn, 2, &new).unwrap();
+        assert_eq!(links(&conn), vec![(1, 1), (1, 3), (3, 2), (3, 3)]);
+
+        // Las oraciones borradas no cuentan, pero conservan su relación
+        SetzeRepo::soft_delete(&mut conn, &[1]).unwrap();
+        let count = WorteSetzeRepo::count_by_wort_id(&conn, &[1]).unwrap();
+        assert_eq!(count.get(&1), Some(&1));
+
+        SetzeRepo::restore(&mut conn, &[1]).unwrap();
+        let count = WorteSetzeRepo::count_by_wort_id(&conn, &[1]).unwrap();
+        assert_eq!(count.get(&1), Some(&2));
+    }
+
+    #[test]
+    fn test_fetch_setze_by_wort_id() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+
+        let usages = WorteSetzeRepo::fetch_setze_by_wort_id(&conn, &[1, 2], 1).unwrap();
+        assert_eq!(
+            usages.get(&1).unwrap(),
+            &vec!["Die Hunde spielen".to_string()]
+        );
+        assert_eq!(
+            usages.get(&2).unwrap(),
+            &vec!["Ich rufe dich morgen an".to_string()]
+        );
+
+        // Las oraciones borradas no se muestran
+        SetzeRepo::soft_delete(&mut conn, &[1]).unwrap();
+        let usages = WorteSetzeRepo::fetch_setze_by_wort_id(&conn, &[1], 3).unwrap();
+        assert_eq!(
+            usages.get(&1).unwrap(),
+            &vec!["Der Hund ging nach Hause".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_fetch_mature_ratio() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+
+        let now = time::datetime_2_string(Utc::now());
+        WorteReviewRepo::bulk_insert(
+            &mut conn,
+            &[NewWorteReviewSchema {
+                wort_id: 1,
+                interval: MATURE_INTERVAL,
+                ease_factor: 2.5,
+                repetitions: 5,
+                last_review: now.clone(),
+                next_review: now,
+            }],
+        )
+        .unwrap();
+
+        let ratio = WorteSetzeRepo::fetch_mature_ratio(&conn, &[1, 2, 3]).unwrap();
+        assert_eq!(ratio.get(&1), Some(&1.0));
+        assert_eq!(ratio.get(&2), Some(&0.0));
+        // "Hund" madura, "gehen" nueva
+        assert_eq!(ratio.get(&3), Some(&0.5));
+    }
+}
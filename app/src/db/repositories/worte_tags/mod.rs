@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+
+use color_eyre::eyre::{Context, Result};
+use rusqlite::{Connection, Transaction, params_from_iter};
+use sql_model::{FromRaw, SqlNew, SqlRaw};
+
+use crate::db::{
+    schemas::{
+        tags::{NewTagsSchema, TagsSchema},
+        worte_tags::{
+            NewWorteTagsSchema as New, RawWorteTagsSchema as Raw, WorteTagsSchema as Schema,
+        },
+    },
+    tags::TagsRepo,
+};
+
+#[cfg(test)]
+mod worte_tags_test;
+
+pub struct WorteTagsRepo;
+
+impl WorteTagsRepo {
+    pub fn bulk_insert_tx(tx: &Transaction, data: &[New]) -> Result<Vec<Schema>> {
+        if data.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let sql = r#"
+            INSERT INTO worte_tags (wort_id, tag_id)
+                VALUES (?1, ?2)
+            ON CONFLICT(wort_id, tag_id) DO UPDATE SET deleted_at = NULL
+            RETURNING wort_id, tag_id, created_at, deleted_at;
+        "#;
+
+        let mut vec_out = Vec::with_capacity(data.len());
+        let mut stmt = tx.prepare_cached(sql).context(format!("sql: {}", sql))?;
+        for d in data {
+            let raw = stmt
+                .query_one(d.to_params(), Raw::from_sql)
+                .context(format!("sql: {}, params: {:#?}", sql, d))?;
+            vec_out.push(Schema::from_raw(raw)?);
+        }
+
+        Ok(vec_out)
+    }
+
+    /// Deja a la palabra solo con las etiquetas indicadas (por nombre), creándolas si no existen.
+    pub fn replace_by_wort_id_tx(
+        tx: &Transaction,
+        wort_id: i32,
+        tags: &[String],
+    ) -> Result<Vec<TagsSchema>> {
+        let new_tags: Vec<NewTagsSchema> = tags
+            .iter()
+            .map(|name| NewTagsSchema {
+                name: name.trim().to_string(),
+            })
+            .filter(|t| !t.name.is_empty())
+            .collect();
+        let mut tags = TagsRepo::bulk_insert_tx(tx, &new_tags)?;
+        tags.sort_by_key(|t| t.id);
+        tags.dedup_by_key(|t| t.id);
+
+        let placeholders = vec!["?"; tags.len()].join(",");
+        let sql = format!(
+            "
+            UPDATE worte_tags
+                SET deleted_at = CURRENT_TIMESTAMP
+            WHERE wort_id = ?
+                AND deleted_at IS NULL
+                AND tag_id NOT IN ({placeholders});
+        "
+        );
+
+        let mut params: Vec<&dyn rusqlite::ToSql> = vec![&wort_id];
+        params.extend(tags.iter().map(|t| &t.id as &dyn rusqlite::ToSql));
+        tx.execute(&sql, params_from_iter(params))
+            .context(format!("sql: {}", sql))?;
+
+        let links: Vec<New> = tags
+            .iter()
+            .map(|t| New {
+                wort_id,
+                tag_id: t.id,
+            })
+            .collect();
+        Self::bulk_insert_tx(tx, &links)?;
+
+        tags.sort_by_key(|t| t.name.to_lowercase());
+        Ok(tags)
+    }
+
+    pub fn fetch_by_wort_id(conn: &Connection, ids: &[i32]) -> Result<Vec<Schema>> {
+        if ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let placeholders = vec!["?"; ids.len()].join(",");
+        let sql = format!(
+            "
+            SELECT wort_id, tag_id, created_at, deleted_at
+            FROM worte_tags
+            WHERE wort_id in ({placeholders})
+                AND deleted_at is NULL
+            ORDER BY wort_id, tag_id;
+        "
+        );
+
+        let mut stmt = conn.prepare(&sql)?;
+        let raw = stmt
+            .query(params_from_iter(ids.iter()))?
+            .mapped(Raw::from_sql)
+            .collect::<Result<Vec<Raw>, _>>()?;
+
+        let vec_out = Schema::from_vec_raw(raw)?;
+        Ok(vec_out)
+    }
+
+    /// Etiquetas de cada palabra: wort_id -> etiquetas ordenadas por nombre.
+    pub fn fetch_tags_by_wort_id(
+        conn: &Connection,
+        ids: &[i32],
+    ) -> Result<HashMap<i32, Vec<TagsSchema>>> {
+        let links = Self::fetch_by_wort_id(conn, ids)?;
+
+        let mut tag_ids: Vec<i32> = links.iter().map(|l| l.tag_id).collect();
+        tag_ids.sort();
+        tag_ids.dedup();
+
+        let tags = TagsRepo::fetch_by_id(conn, &tag_ids)?;
+
+        let mut hash: HashMap<i32, Vec<TagsSchema>> = HashMap::new();
+        for tag in tags {
+            for link in links.iter().filter(|l| l.tag_id == tag.id) {
+                hash.entry(link.wort_id).or_default().push(tag.clone());
+            }
+        }
+
+        Ok(hash)
+    }
+}
@@ -0,0 +1,130 @@
+use crate::db::setup_test_db;
+
+#[cfg(test)]
+mod test_worte_tags_repo {
+    use color_eyre::eyre::Result;
+    use rusqlite::Connection;
+
+    use crate::db::{
+        schemas::worte::NewWorteSchema, seeders::init_data, worte::WorteRepo,
+        worte_tags::WorteTagsRepo,
+    };
+
+    use super::*;
+
+    fn init_data_local(conn: &mut Connection) -> Result<()> {
+        init_data(conn)?;
+        let data = vec![
+            NewWorteSchema {
+                gram_type: vec![0],
+                tags: vec!["Tiere".into(), "A1".into()],
+                gender_id: Some(0),
+                worte_de: "Hund".into(),
+                worte_es: "Perro".into(),
+                plural: Some("Hunde".into()),
+                niveau_id: 0,
+                example_de: "Beispiel".into(),
+                example_es: "Ejemplo".into(),
+                verb_aux: None,
+                trennbar: None,
+                reflexiv: None,
+                komparativ: None,
+                superlativ: None,
+                deck_id: 1,
+            },
+            NewWorteSchema {
+                gram_type: vec![2],
+                tags: vec![],
+                gender_id: None,
+                worte_de: "laufen".into(),
+                worte_es: "correr".into(),
+                plural: None,
+                niveau_id: 0,
+                example_de: "Beispiel".into(),
+                example_es: "Ejemplo".into(),
+                verb_aux: Some("sein".into()),
+                trennbar: Some(false),
+                reflexiv: Some(false),
+                komparativ: None,
+                superlativ: None,
+                deck_id: 1,
+            },
+        ];
+        WorteRepo::bulk_insert(conn, &data)?;
+        Ok(())
+    }
+
+    fn tag_names(conn: &Connection, wort_id: i32) -> Vec<String> {
+        WorteTagsRepo::fetch_tags_by_wort_id(conn, &[wort_id])
+            .expect("Error al hacer fetch")
+            .remove(&wort_id)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|t| t.name)
+            .collect()
+    }
+
+    #[test]
+    fn test_bulk_insert_links_tags() {
+        let mut conn = setup_test_db().expect("Error al crear db test");
+        init_data_local(&mut conn).expect("Error al iniciar datos dummy");
+
+        assert_eq!(tag_names(&conn, 1), vec!["A1", "Tiere"]);
+        assert!(tag_names(&conn, 2).is_empty());
+
+        let links = WorteTagsRepo::fetch_by_wort_id(&conn, &[1, 2]).expect("Error al hacer fetch");
+        assert_eq!(links.len(), 2);
+        assert!(links.iter().all(|l| l.wort_id == 1));
+    }
+
+    #[test]
+    fn test_replace_by_wort_id_tx() {
+        let mut conn = setup_test_db().expect("Error al crear db test");
+        init_data_local(&mut conn).expect("Error al iniciar datos dummy");
+
+        // Quita "A1", agrega "Haus" (duplicada con otras mayúsculas) y conserva "Tiere"
+        let tx = conn.transaction().unwrap();
+        let tags = WorteTagsRepo::replace_by_wort_id_tx(
+            &tx,
+            1,
+            &["tiere".into(), "Haus".into(), "haus".into(), " ".into()],
+        )
+        .expect("El reemplazo no debe fallar");
+        tx.commit().unwrap();
+
+        let names: Vec<&str> = tags.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["Haus", "Tiere"]);
+        assert_eq!(tag_names(&conn, 1), vec!["Haus", "Tiere"]);
+
+        // Volver a poner "A1" restaura el vínculo borrado
+        let tx = conn.transaction().unwrap();
+        WorteTagsRepo::replace_by_wort_id_tx(&tx, 1, &["A1".into()])
+            .expect("El reemplazo no debe fallar");
+        tx.commit().unwrap();
+        assert_eq!(tag_names(&conn, 1), vec!["A1"]);
+
+        // Sin etiquetas se quitan todas
+        let tx = conn.transaction().unwrap();
+        WorteTagsRepo::replace_by_wort_id_tx(&tx, 1, &[]).expect("El reemplazo no debe fallar");
+        tx.commit().unwrap();
+        assert!(tag_names(&conn, 1).is_empty());
+    }
+
+    #[test]
+    fn test_fetch_id_where_tags() {
+        use crate::helpers::tags::TagExpr;
+
+        let mut conn = setup_test_db().expect("Error al crear db test");
+        init_data_local(&mut conn).expect("Error al iniciar datos dummy");
+
+        let fetch = |expr: &str| {
+            WorteRepo::fetch_id_where_tags(&conn, &TagExpr::parse(expr).unwrap())
+                .expect("Error al hacer fetch")
+        };
+
+        assert_eq!(fetch("tiere"), vec![1]);
+        assert_eq!(fetch("NOT Tiere"), vec![2]);
+        assert_eq!(fetch("Tiere AND NOT A1"), Vec::<i32>::new());
+        assert_eq!(fetch("Tiere OR NOT A1"), vec![1, 2]);
+    }
+}
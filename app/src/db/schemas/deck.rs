@@ -0,0 +1,83 @@
+use chrono::{DateTime, Utc};
+use sql_model::SqlModel;
+
+/// Deck al que pertenecen las palabras y oraciones que no se asignaron a otro.
+pub const DEFAULT_DECK_ID: i32 = 1;
+pub const DEFAULT_DECK_NAME: &str = "Default";
+
+/// Separador de los niveles en el nombre de un deck: "Goethe B1::Kapitel 4".
+pub const DECK_SEPARATOR: &str = "::";
+
+#[derive(Debug, Clone, SqlModel)]
+#[sql(raw_type = "RawDeckSchema")]
+#[sql(raw(
+    id,
+    name,
+    max_neue_worte,
+    max_neue_setze,
+    initial_ease,
+    interval_modifier,
+    max_interval,
+    created_at,
+    deleted_at
+))]
+pub struct DeckSchema {
+    pub id: i32,
+    pub name: String,
+
+    // Configuración
+    pub max_neue_worte: u32,
+    pub max_neue_setze: u32,
+    pub initial_ease: f32,
+    pub interval_modifier: f32,
+    pub max_interval: u32,
+
+    // Generic
+    pub created_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, SqlModel)]
+#[sql(insert(name, parent_id))]
+pub struct NewDeckSchema {
+    pub name: String,
+    pub parent_id: Option<i32>,
+}
+
+/// Configuración editable de un deck.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeckSettings {
+    pub max_neue_worte: u32,
+    pub max_neue_setze: u32,
+    pub initial_ease: f32,
+    pub interval_modifier: f32,
+    pub max_interval: u32,
+}
+
+#[derive(Debug, SqlModel)]
+#[sql(raw(
+    id,
+    name,
+    max_neue_worte,
+    max_neue_setze,
+    initial_ease,
+    interval_modifier,
+    max_interval,
+    created_at,
+    deleted_at
+))]
+pub struct RawDeckSchema {
+    pub id: i32,
+    pub name: String,
+
+    // Configuración
+    pub max_neue_worte: u32,
+    pub max_neue_setze: u32,
+    pub initial_ease: f32,
+    pub interval_modifier: f32,
+    pub max_interval: u32,
+
+    // Generic
+    pub created_at: String,
+    pub deleted_at: Option<String>,
+}
@@ -0,0 +1,44 @@
+use chrono::{DateTime, Utc};
+use sql_model::SqlModel;
+
+#[derive(Debug, Clone)]
+pub struct FehlerSchema {
+    pub id: i32,
+
+    pub art: String,
+    pub kategorie: String,
+    pub detail: Option<String>,
+    pub wort_id: Option<i32>,
+    pub satz_id: Option<i32>,
+
+    // Generic
+    pub created_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, SqlModel)]
+#[sql(insert(art, kategorie, detail, wort_id, satz_id, expected, input))]
+pub struct NewFehlerSchema {
+    pub art: String,
+    pub kategorie: String,
+    pub detail: Option<String>,
+    pub wort_id: Option<i32>,
+    pub satz_id: Option<i32>,
+    pub expected: String,
+    pub input: String,
+}
+
+#[derive(Debug, SqlModel)]
+#[sql(raw(id, art, kategorie, detail, wort_id, satz_id, created_at, deleted_at))]
+pub struct RawFehlerSchema {
+    pub id: i32,
+    pub art: String,
+    pub kategorie: String,
+    pub detail: Option<String>,
+    pub wort_id: Option<i32>,
+    pub satz_id: Option<i32>,
+
+    // Generic
+    pub created_at: String,
+    pub deleted_at: Option<String>,
+}
@@ -1,55 +1,48 @@
 use color_eyre::eyre::Result;
 use rusqlite::Connection;
 
+use crate::db::migrations;
+
+pub mod deck;
+pub mod fehler;
 pub mod gram_type;
+pub mod konjugation_review;
 pub mod niveau_liste;
+pub mod praeposition_review;
+pub mod schwirig_liste;
+pub mod search;
 pub mod setze;
+pub mod setze_alternativ;
 pub mod setze_audio;
 pub mod setze_review;
+pub mod setze_tags;
+pub mod tags;
+pub mod verb_konjugation;
+pub mod verwechslung;
+pub mod word_frequency;
 pub mod worte;
+pub mod worte_alternativ;
 pub mod worte_audio;
 pub mod worte_gender;
 pub mod worte_gram_type;
 pub mod worte_review;
+pub mod worte_setze;
+pub mod worte_tags;
 
+/// Crea o actualiza las tablas aplicando las migraciones pendientes.
 pub fn init_schemas(conn: &mut Connection) -> Result<()> {
+    let report = migrations::migrate(conn)?;
+    if let Some(backup) = report.backup {
+        eprintln!(
+            "Base de datos actualizada de la versión {} a la {}. Respaldo: {}",
+            report.from,
+            report.to,
+            backup.display()
+        );
+    }
+
     // Activar las llaves foráneas
     conn.execute("PRAGMA foreign_keys = ON", [])?;
 
-    // Gender Worte
-    conn.execute(worte_gender::CREATE_STR_TABLE_WORTE_GENDER, [])?;
-    conn.execute_batch(worte_gender::CREATE_STR_INDEX_WORTE_GENDER)?;
-
-    // Niveau Liste
-    conn.execute(niveau_liste::CREATE_STR_TABLE_NIVEAU_LISTE, [])?;
-    conn.execute_batch(niveau_liste::CREATE_STR_INDEX_NIVEAU_LISTE)?;
-
-    // Gram Type
-    conn.execute(gram_type::CREATE_STR_TABLE_GRAM_TYPE, [])?;
-    conn.execute_batch(gram_type::CREATE_STR_INDEX_GRAM_TYPE)?;
-
-    // Oraciones
-    conn.execute(setze::CREATE_STR_TABLE_SETZE, [])?;
-    conn.execute_batch(setze::CREATE_STR_INDEX_SETZE)?;
-
-    conn.execute(setze_review::CREATE_STR_TABLE_SETZE_REVIEW, [])?;
-    conn.execute_batch(setze_review::CREATE_STR_INDEX_SETZE_REVIEW)?;
-
-    conn.execute(setze_audio::CREATE_STR_TABLE_SETZE_AUDIO, [])?;
-    conn.execute_batch(setze_audio::CREATE_STR_INDEX_SETZE_AUDIO)?;
-
-    // Palabras
-    conn.execute(worte::CREATE_STR_TABLE_WORTE, [])?;
-    conn.execute_batch(worte::CREATE_STR_INDEX_WORTE)?;
-
-    conn.execute(worte_gram_type::CREATE_STR_TABLE_WORTE_TYPE_GRAM, [])?;
-    conn.execute_batch(worte_gram_type::CREATE_STR_INDEX_WORTE_TYPE_GRAM)?;
-
-    conn.execute(worte_review::CREATE_STR_TABLE_WORTE_REVIEW, [])?;
-    conn.execute_batch(worte_review::CREATE_STR_INDEX_WORTE_REVIEW)?;
-
-    conn.execute(worte_audio::CREATE_STR_TABLE_WORTE_AUDIO, [])?;
-    conn.execute_batch(worte_audio::CREATE_STR_INDEX_WORTE_AUDIO)?;
-
     Ok(())
 }
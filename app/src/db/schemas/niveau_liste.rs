@@ -1,21 +1,6 @@
 use chrono::{DateTime, Utc};
 use sql_model::SqlModel;
 
-pub const CREATE_STR_TABLE_NIVEAU_LISTE: &str = "
-    CREATE TABLE IF NOT EXISTS niveau_liste (
-        id                  INTEGER PRIMARY KEY AUTOINCREMENT,
-        niveau              TEXT NOT NULL,                         
-
-        -- Generic
-        created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
-        deleted_at          TEXT
-    )
-";
-
-pub const CREATE_STR_INDEX_NIVEAU_LISTE: &str = "
-    CREATE INDEX IF NOT EXISTS idx_niveau_liste_created_at ON niveau_liste(created_at);
-";
-
 // 0 - A1
 // 1 - A2
 // 2 - B1
@@ -0,0 +1,64 @@
+use chrono::{DateTime, Utc};
+use sql_model::SqlModel;
+
+#[derive(Debug, Clone)]
+pub struct PraepositionReviewSchema {
+    pub id: i32,
+
+    pub wort_id: i32,
+    pub interval: u32,
+    pub ease_factor: f32,
+    pub repetitions: u32,
+    pub next_review: DateTime<Utc>,
+    pub lapses: u32,
+
+    // Generic
+    pub created_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, SqlModel)]
+#[sql(insert(
+    wort_id,
+    interval,
+    ease_factor,
+    repetitions,
+    last_review,
+    next_review,
+    lapses
+))]
+pub struct NewPraepositionReviewSchema {
+    pub wort_id: i32,
+    pub interval: u32,
+    pub ease_factor: f32,
+    pub repetitions: u32,
+    pub last_review: String, // DateTime<Utc>
+    pub next_review: String, // DateTime<Utc>
+    pub lapses: u32,
+}
+
+#[derive(Debug, SqlModel)]
+#[sql(raw(
+    id,
+    wort_id,
+    interval,
+    ease_factor,
+    repetitions,
+    next_review,
+    lapses,
+    created_at,
+    deleted_at
+))]
+pub struct RawPraepositionReviewSchema {
+    pub id: i32,
+    pub wort_id: i32,
+    pub interval: u32,
+    pub ease_factor: f32,
+    pub repetitions: u32,
+    pub next_review: String,
+    pub lapses: u32,
+
+    // Generic
+    pub created_at: String,
+    pub deleted_at: Option<String>,
+}
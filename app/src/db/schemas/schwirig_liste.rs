@@ -0,0 +1,40 @@
+use chrono::{DateTime, Utc};
+use sql_model::SqlModel;
+
+/// Oraciones que el usuario ya domina según sus revisiones.
+pub const SCHWIRIG_ID_EINFACH: i32 = 0;
+/// Dificultad con la que se registra una oración si no se indica otra.
+pub const SCHWIRIG_ID_NORMAL: i32 = 1;
+/// Oraciones que se practican en el menú de oraciones difíciles.
+pub const SCHWIRIG_ID_SCHWIRIG: i32 = 2;
+
+// 0 - Einfach
+// 1 - Normal
+// 2 - Schwirig
+#[derive(Debug, Clone, SqlModel)]
+#[sql(raw_type = "RawSchwirigListeSchema")]
+#[sql(raw(id, schwirig, created_at, deleted_at))]
+pub struct SchwirigListeSchema {
+    pub id: i32,
+    pub schwirig: String,
+
+    // Generic
+    pub created_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, SqlModel)]
+#[sql(insert(id, schwirig))]
+pub struct NewSchwirigListeSchema {
+    pub id: i32,
+    pub schwirig: String,
+}
+
+#[derive(Debug, SqlModel)]
+#[sql(raw(id, schwirig, created_at, deleted_at))]
+pub struct RawSchwirigListeSchema {
+    pub id: i32,
+    pub schwirig: String,
+    pub created_at: String,
+    pub deleted_at: Option<String>,
+}
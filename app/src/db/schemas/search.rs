@@ -0,0 +1,22 @@
+// Índices de texto completo (FTS5) del alemán de las palabras y oraciones.
+//
+// Son tablas de contenido externo: el texto vive en `worte`/`setze` y los triggers mantienen
+// el índice al día. `remove_diacritics 2` hace que "madchen" encuentre "Mädchen" y el
+// tokenizador ya ignora mayúsculas; `prefix` acelera las búsquedas por prefijo.
+
+/// Marcas con las que se resaltan las coincidencias en `SearchHit`.
+pub const MARK_START: &str = "[";
+pub const MARK_END: &str = "]";
+
+/// Resultado de una búsqueda de texto completo, del más al menos relevante.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchHit {
+    /// Id de la palabra u oración
+    pub id: i32,
+    /// Relevancia (bm25), entre más negativo más relevante
+    pub rank: f64,
+    /// Texto en alemán con las coincidencias resaltadas
+    pub text: String,
+    /// Ejemplo resaltado, solo palabras
+    pub example: Option<String>,
+}
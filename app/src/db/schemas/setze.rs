@@ -1,29 +1,9 @@
 use chrono::{DateTime, Utc};
 use sql_model::SqlModel;
 
-use crate::db::schemas::niveau_liste::NiveauListeSchema;
-
-pub const CREATE_STR_TABLE_SETZE: &str = "
-CREATE TABLE IF NOT EXISTS setze (
-    id                  INTEGER PRIMARY KEY AUTOINCREMENT,
-    setze_spanisch      TEXT NOT NULL,
-    setze_deutsch       TEXT NOT NULL,
-    thema               TEXT NOT NULL,
-    niveau_id           INTEGER NOT NULL,
-    created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
-    deleted_at          TEXT,
-
-    FOREIGN KEY(niveau_id) REFERENCES niveau_liste(id)
-        ON DELETE CASCADE
-        ON UPDATE CASCADE
-)";
-
-pub const CREATE_STR_INDEX_SETZE: &str = "
-    CREATE INDEX IF NOT EXISTS idx_setze_setze_spanisch ON setze(setze_spanisch);
-    CREATE INDEX IF NOT EXISTS idx_setze_setze_deutsch ON setze(setze_deutsch);
-    CREATE INDEX IF NOT EXISTS idx_setze_thema ON setze(thema);
-    CREATE INDEX IF NOT EXISTS idx_setze_niveau_id ON setze(niveau_id);
-";
+use crate::db::schemas::{
+    niveau_liste::NiveauListeSchema, schwirig_liste::SchwirigListeSchema, tags::TagsSchema,
+};
 
 #[derive(Debug, Clone)]
 pub struct SetzeSchema {
@@ -31,19 +11,26 @@ pub struct SetzeSchema {
     pub setze_spanisch: String,
     pub setze_deutsch: String,
     pub niveau_id: NiveauListeSchema,
+    pub schwirig_id: SchwirigListeSchema,
     pub thema: String,
+    pub deck_id: i32,
+    pub tags: Vec<TagsSchema>,
 
     pub created_at: DateTime<Utc>,
     pub deleted_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Clone, SqlModel)]
-#[sql(insert(setze_spanisch, setze_deutsch, niveau_id, thema))]
+#[sql(insert(setze_spanisch, setze_deutsch, niveau_id, thema, deck_id, schwirig_id))]
 pub struct NewSetzeSchema {
     pub setze_spanisch: String,
     pub setze_deutsch: String,
     pub niveau_id: i32,
     pub thema: String,
+    pub deck_id: i32,
+    pub schwirig_id: i32,
+    /// Nombres de las etiquetas (table tags), se crean si no existen
+    pub tags: Vec<String>,
 }
 
 #[derive(Debug, SqlModel)]
@@ -52,7 +39,9 @@ pub struct NewSetzeSchema {
     setze_spanisch,
     setze_deutsch,
     niveau_id,
+    schwirig_id,
     thema,
+    deck_id,
     created_at,
     deleted_at
 ))]
@@ -61,7 +50,9 @@ pub struct RawSetzeSchema {
     pub setze_spanisch: String,
     pub setze_deutsch: String,
     pub niveau_id: i32,
+    pub schwirig_id: i32,
     pub thema: String,
+    pub deck_id: i32,
     pub created_at: String,
     pub deleted_at: Option<String>,
 }
@@ -0,0 +1,34 @@
+use chrono::{DateTime, Utc};
+use sql_model::SqlModel;
+
+#[derive(Debug, Clone, SqlModel)]
+#[sql(raw_type = "RawSetzeAlternativSchema")]
+#[sql(raw(id, satz_id, antwort, created_at, deleted_at))]
+pub struct SetzeAlternativSchema {
+    pub id: i32,
+    pub satz_id: i32,
+    pub antwort: String,
+
+    // Generic
+    pub created_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, SqlModel)]
+#[sql(insert(satz_id, antwort))]
+pub struct NewSetzeAlternativSchema {
+    pub satz_id: i32,
+    pub antwort: String,
+}
+
+#[derive(Debug, SqlModel)]
+#[sql(raw(id, satz_id, antwort, created_at, deleted_at))]
+pub struct RawSetzeAlternativSchema {
+    pub id: i32,
+    pub satz_id: i32,
+    pub antwort: String,
+
+    // Generic
+    pub created_at: String,
+    pub deleted_at: Option<String>,
+}
@@ -1,30 +1,6 @@
 use chrono::{DateTime, Utc};
 use sql_model::SqlModel;
 
-pub const CREATE_STR_TABLE_SETZE_REVIEW: &str = "
-CREATE TABLE IF NOT EXISTS setze_review (
-    id              INTEGER PRIMARY KEY AUTOINCREMENT,
-    satz_id         INTEGER NOT NULL UNIQUE,
-    interval        INTEGER NOT NULL,
-    ease_factor     REAL    NOT NULL,
-    repetitions     INTEGER NOT NULL,
-    last_review     TEXT NOT NULL,
-    next_review     TEXT NOT NULL,
-
-    created_at      TEXT DEFAULT CURRENT_TIMESTAMP,
-    deleted_at      TEXT,
-
-    FOREIGN KEY(satz_id) REFERENCES setze(id)
-        ON DELETE CASCADE
-        ON UPDATE CASCADE
-);
-";
-
-pub const CREATE_STR_INDEX_SETZE_REVIEW: &str = "
-    CREATE UNIQUE INDEX IF NOT EXISTS idx_setze_review_satz_id ON setze_review(satz_id);
-    CREATE INDEX IF NOT EXISTS idx_setze_review_next_review ON setze_review(next_review);
-";
-
 #[derive(Debug, Clone)]
 pub struct SetzeReviewSchema {
     pub id: i32,
@@ -35,6 +11,9 @@ pub struct SetzeReviewSchema {
     pub repetitions: u32,
     pub last_review: DateTime<Utc>,
     pub next_review: DateTime<Utc>,
+    pub lapses: u32,
+    /// Dificultad según el desempeño, `None` mientras no hay suficientes revisiones.
+    pub schwirig_id: Option<i32>,
 
     // Generic
     pub created_at: DateTime<Utc>,
@@ -42,7 +21,16 @@ pub struct SetzeReviewSchema {
 }
 
 #[derive(Debug, SqlModel)]
-#[sql(insert(satz_id, interval, ease_factor, repetitions, last_review, next_review))]
+#[sql(insert(
+    satz_id,
+    interval,
+    ease_factor,
+    repetitions,
+    last_review,
+    next_review,
+    lapses,
+    schwirig_id
+))]
 pub struct NewSetzeReviewSchema {
     pub satz_id: i32,
     pub interval: u32,
@@ -50,6 +38,8 @@ pub struct NewSetzeReviewSchema {
     pub repetitions: u32,
     pub last_review: String, // DateTime<Utc>
     pub next_review: String, // DateTime<Utc>
+    pub lapses: u32,
+    pub schwirig_id: Option<i32>,
 }
 
 #[derive(Debug, SqlModel)]
@@ -61,6 +51,8 @@ pub struct NewSetzeReviewSchema {
     repetitions,
     last_review,
     next_review,
+    lapses,
+    schwirig_id,
     created_at,
     deleted_at
 ))]
@@ -72,6 +64,8 @@ pub struct RawWorteReviewSchema {
     pub repetitions: u32,
     pub last_review: String,
     pub next_review: String,
+    pub lapses: u32,
+    pub schwirig_id: Option<i32>,
 
     // Generic
     pub created_at: String,
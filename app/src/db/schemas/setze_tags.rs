@@ -0,0 +1,32 @@
+use chrono::{DateTime, Utc};
+use sql_model::SqlModel;
+
+#[derive(Debug, Clone, SqlModel)]
+#[sql(raw_type = "RawSetzeTagsSchema")]
+#[sql(raw(satz_id, tag_id, created_at, deleted_at))]
+pub struct SetzeTagsSchema {
+    pub satz_id: i32,
+    pub tag_id: i32,
+
+    // Generic
+    pub created_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, SqlModel)]
+#[sql(insert(satz_id, tag_id))]
+pub struct NewSetzeTagsSchema {
+    pub satz_id: i32,
+    pub tag_id: i32,
+}
+
+#[derive(Debug, SqlModel)]
+#[sql(raw(satz_id, tag_id, created_at, deleted_at))]
+pub struct RawSetzeTagsSchema {
+    pub satz_id: i32,
+    pub tag_id: i32,
+
+    // Generic
+    pub created_at: String,
+    pub deleted_at: Option<String>,
+}
@@ -0,0 +1,31 @@
+use chrono::{DateTime, Utc};
+use sql_model::SqlModel;
+
+#[derive(Debug, Clone, SqlModel)]
+#[sql(raw_type = "RawTagsSchema")]
+#[sql(raw(id, name, created_at, deleted_at))]
+pub struct TagsSchema {
+    pub id: i32,
+    pub name: String,
+
+    // Generic
+    pub created_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, SqlModel)]
+#[sql(insert(name))]
+pub struct NewTagsSchema {
+    pub name: String,
+}
+
+#[derive(Debug, SqlModel)]
+#[sql(raw(id, name, created_at, deleted_at))]
+pub struct RawTagsSchema {
+    pub id: i32,
+    pub name: String,
+
+    // Generic
+    pub created_at: String,
+    pub deleted_at: Option<String>,
+}
@@ -0,0 +1,36 @@
+use chrono::{DateTime, Utc};
+use sql_model::SqlModel;
+
+#[derive(Debug, Clone, SqlModel)]
+#[sql(raw_type = "RawVerbKonjugationSchema")]
+#[sql(raw(wort_id, tempus, person, form, created_at))]
+pub struct VerbKonjugationSchema {
+    pub wort_id: i32,
+    pub tempus: String,
+    pub person: String,
+    pub form: String,
+
+    // Generic
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, SqlModel)]
+#[sql(insert(wort_id, tempus, person, form))]
+pub struct NewVerbKonjugationSchema {
+    pub wort_id: i32,
+    pub tempus: String,
+    pub person: String,
+    pub form: String,
+}
+
+#[derive(Debug, SqlModel)]
+#[sql(raw(wort_id, tempus, person, form, created_at))]
+pub struct RawVerbKonjugationSchema {
+    pub wort_id: i32,
+    pub tempus: String,
+    pub person: String,
+    pub form: String,
+
+    // Generic
+    pub created_at: String,
+}
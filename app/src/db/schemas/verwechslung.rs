@@ -0,0 +1,38 @@
+use chrono::{DateTime, Utc};
+use sql_model::SqlModel;
+
+#[derive(Debug, Clone)]
+pub struct VerwechslungSchema {
+    pub id: i32,
+
+    pub wort_id: i32,
+    pub verwechselt_id: i32,
+    pub count: u32,
+    pub last_seen: DateTime<Utc>,
+
+    // Generic
+    pub created_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, SqlModel)]
+#[sql(insert(wort_id, verwechselt_id, last_seen))]
+pub struct NewVerwechslungSchema {
+    pub wort_id: i32,
+    pub verwechselt_id: i32,
+    pub last_seen: String, // DateTime<Utc>
+}
+
+#[derive(Debug, SqlModel)]
+#[sql(raw(id, wort_id, verwechselt_id, count, last_seen, created_at, deleted_at))]
+pub struct RawVerwechslungSchema {
+    pub id: i32,
+    pub wort_id: i32,
+    pub verwechselt_id: i32,
+    pub count: u32,
+    pub last_seen: String,
+
+    // Generic
+    pub created_at: String,
+    pub deleted_at: Option<String>,
+}
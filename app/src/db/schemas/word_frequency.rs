@@ -0,0 +1,33 @@
+use chrono::{DateTime, Utc};
+use sql_model::SqlModel;
+
+#[derive(Debug, Clone)]
+pub struct WordFrequencySchema {
+    pub id: i32,
+
+    pub lemma: String,
+    pub rank: u32,
+
+    // Generic
+    pub created_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, PartialEq, SqlModel)]
+#[sql(insert(lemma, rank))]
+pub struct NewWordFrequencySchema {
+    pub lemma: String,
+    pub rank: u32,
+}
+
+#[derive(Debug, SqlModel)]
+#[sql(raw(id, lemma, rank, created_at, deleted_at))]
+pub struct RawWordFrequencySchema {
+    pub id: i32,
+    pub lemma: String,
+    pub rank: u32,
+
+    // Generic
+    pub created_at: String,
+    pub deleted_at: Option<String>,
+}
@@ -2,53 +2,21 @@ use chrono::{DateTime, Utc};
 use sql_model::SqlModel;
 
 use crate::db::schemas::{
-    gram_type::GramTypeSchema, niveau_liste::NiveauListeSchema, worte_gender::WorteGenderSchema,
+    gram_type::GramTypeSchema, niveau_liste::NiveauListeSchema, tags::TagsSchema,
+    worte_gender::WorteGenderSchema,
 };
 
-pub const CREATE_STR_TABLE_WORTE: &str = "
-CREATE TABLE IF NOT EXISTS worte(
-    id                  INTEGER PRIMARY KEY AUTOINCREMENT,
-    gender_id           INTEGER,                            -- table worte_gender
-    wort_de             TEXT NOT NULL,                   
-    wort_es             TEXT NOT NULL,                   
-    plural              TEXT,
-    niveau_id           INTEGER NOT NULL,                   -- table niveau_liste
-    example_de          TEXT,                   
-    example_es          TEXT,                   
-
-    -- nur verben
-    verb_aux TEXT,              -- 'sein' / 'haben' / NULL
-    trennbar BOOLEAN,           -- verbo separable
-    reflexiv BOOLEAN,
-
-    -- Generic
-    created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
-    deleted_at          TEXT,
-
-    FOREIGN KEY(gender_id) REFERENCES worte_gender(id)
-        ON DELETE CASCADE
-        ON UPDATE CASCADE,    
-
-    FOREIGN KEY(niveau_id) REFERENCES niveau_liste(id)
-        ON DELETE CASCADE
-        ON UPDATE CASCADE
-)";
-
-pub const CREATE_STR_INDEX_WORTE: &str = "
-    CREATE INDEX IF NOT EXISTS idx_worte_created_at ON worte(created_at);
-    CREATE INDEX IF NOT EXISTS idx_worte_gender_id ON worte(gender_id);
-    CREATE INDEX IF NOT EXISTS idx_worte_niveau_id ON worte(niveau_id);
-";
-
 #[derive(Debug, Clone)]
 pub struct WorteSchema {
     pub id: i32,
     pub gram_type_id: Vec<GramTypeSchema>,
+    pub tags: Vec<TagsSchema>,
     pub gender_id: Option<WorteGenderSchema>,
     pub worte_de: String,
     pub worte_es: String,
     pub plural: Option<String>,
     pub niveau_id: NiveauListeSchema,
+    pub deck_id: i32,
     pub example_de: String,
     pub example_es: String,
 
@@ -57,6 +25,10 @@ pub struct WorteSchema {
     pub trennbar: Option<bool>,
     pub reflexiv: Option<bool>,
 
+    // nur adjektive
+    pub komparativ: Option<String>,
+    pub superlativ: Option<String>,
+
     // Generic
     pub created_at: DateTime<Utc>,
     pub deleted_at: Option<DateTime<Utc>>,
@@ -65,10 +37,12 @@ pub struct WorteSchema {
 #[derive(Debug, SqlModel)]
 #[sql(insert(
     gender_id, worte_de, worte_es, plural, niveau_id, example_de, example_es, verb_aux, trennbar,
-    reflexiv
+    reflexiv, deck_id, komparativ, superlativ
 ))]
 pub struct NewWorteSchema {
     pub gram_type: Vec<i32>,
+    /// Nombres de las etiquetas (table tags), se crean si no existen
+    pub tags: Vec<String>,
     pub gender_id: Option<i32>,
     pub worte_de: String,
     pub worte_es: String,
@@ -81,12 +55,18 @@ pub struct NewWorteSchema {
     pub verb_aux: Option<String>,
     pub trennbar: Option<bool>,
     pub reflexiv: Option<bool>,
+
+    // nur adjektive
+    pub komparativ: Option<String>,
+    pub superlativ: Option<String>,
+
+    pub deck_id: i32,
 }
 
 #[derive(Debug, SqlModel)]
 #[sql(raw(
     id, gender_id, worte_de, worte_es, plural, niveau_id, example_de, example_es, verb_aux,
-    trennbar, reflexiv, created_at, deleted_at
+    trennbar, reflexiv, komparativ, superlativ, deck_id, created_at, deleted_at
 ))]
 pub struct RawWorteSchema {
     pub id: i32,
@@ -103,6 +83,12 @@ pub struct RawWorteSchema {
     pub trennbar: Option<bool>,
     pub reflexiv: Option<bool>,
 
+    // nur adjektive
+    pub komparativ: Option<String>,
+    pub superlativ: Option<String>,
+
+    pub deck_id: i32,
+
     // Generic
     pub created_at: String,
     pub deleted_at: Option<String>,
@@ -0,0 +1,34 @@
+use chrono::{DateTime, Utc};
+use sql_model::SqlModel;
+
+#[derive(Debug, Clone, SqlModel)]
+#[sql(raw_type = "RawWorteAlternativSchema")]
+#[sql(raw(id, wort_id, antwort, created_at, deleted_at))]
+pub struct WorteAlternativSchema {
+    pub id: i32,
+    pub wort_id: i32,
+    pub antwort: String,
+
+    // Generic
+    pub created_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, SqlModel)]
+#[sql(insert(wort_id, antwort))]
+pub struct NewWorteAlternativSchema {
+    pub wort_id: i32,
+    pub antwort: String,
+}
+
+#[derive(Debug, SqlModel)]
+#[sql(raw(id, wort_id, antwort, created_at, deleted_at))]
+pub struct RawWorteAlternativSchema {
+    pub id: i32,
+    pub wort_id: i32,
+    pub antwort: String,
+
+    // Generic
+    pub created_at: String,
+    pub deleted_at: Option<String>,
+}
@@ -1,22 +1,6 @@
 use chrono::{DateTime, Utc};
 use sql_model::SqlModel;
 
-pub const CREATE_STR_TABLE_WORTE_GENDER: &str = "
-    CREATE TABLE IF NOT EXISTS worte_gender (
-        id                  INTEGER PRIMARY KEY AUTOINCREMENT,
-        gender              TEXT NOT NULL,                         
-        artikel             TEXT NOT NULL,
-
-        -- Generic
-        created_at          TEXT DEFAULT CURRENT_TIMESTAMP,
-        deleted_at          TEXT
-    )
-";
-
-pub const CREATE_STR_INDEX_WORTE_GENDER: &str = "
-    CREATE INDEX IF NOT EXISTS idx_worte_gender_created_at ON worte_gender(created_at);
-";
-
 // 0 - Maskuline - der
 // 1 - Femenin - die
 // 2 - Neutrum - das
@@ -1,28 +1,6 @@
 use chrono::{DateTime, Utc};
 use sql_model::SqlModel;
 
-pub const CREATE_STR_TABLE_WORTE_REVIEW: &str = "
-CREATE TABLE IF NOT EXISTS worte_review (
-    id              INTEGER PRIMARY KEY AUTOINCREMENT,
-    wort_id         INTEGER NOT NULL UNIQUE,
-    interval        INTEGER NOT NULL,
-    ease_factor     REAL    NOT NULL,
-    repetitions     INTEGER NOT NULL,
-    last_review     TEXT NOT NULL,
-    next_review     TEXT NOT NULL,
-    created_at      TEXT DEFAULT CURRENT_TIMESTAMP,
-    deleted_at      TEXT,
-    FOREIGN KEY(wort_id) REFERENCES worte(id)
-        ON DELETE CASCADE
-        ON UPDATE CASCADE
-);
-";
-
-pub const CREATE_STR_INDEX_WORTE_REVIEW: &str = "
-    CREATE UNIQUE INDEX IF NOT EXISTS idx_worte_review_wort_id ON worte_review(wort_id);
-    CREATE INDEX IF NOT EXISTS idx_worte_review_next_review ON worte_review(next_review);
-";
-
 #[derive(Debug, Clone)]
 pub struct WorteReviewSchema {
     pub id: i32,
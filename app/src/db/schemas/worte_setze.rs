@@ -0,0 +1,10 @@
+use sql_model::SqlModel;
+
+/// Relación palabra-oración: qué palabras aparecen (en alguna de sus formas) en cada oración.
+/// Se calcula con `helpers::lemma` al guardar palabras u oraciones.
+#[derive(Debug, SqlModel)]
+#[sql(insert(wort_id, satz_id))]
+pub struct NewWorteSetzeSchema {
+    pub wort_id: i32,
+    pub satz_id: i32,
+}
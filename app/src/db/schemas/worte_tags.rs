@@ -0,0 +1,32 @@
+use chrono::{DateTime, Utc};
+use sql_model::SqlModel;
+
+#[derive(Debug, Clone, SqlModel)]
+#[sql(raw_type = "RawWorteTagsSchema")]
+#[sql(raw(wort_id, tag_id, created_at, deleted_at))]
+pub struct WorteTagsSchema {
+    pub wort_id: i32,
+    pub tag_id: i32,
+
+    // Generic
+    pub created_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, SqlModel)]
+#[sql(insert(wort_id, tag_id))]
+pub struct NewWorteTagsSchema {
+    pub wort_id: i32,
+    pub tag_id: i32,
+}
+
+#[derive(Debug, SqlModel)]
+#[sql(raw(wort_id, tag_id, created_at, deleted_at))]
+pub struct RawWorteTagsSchema {
+    pub wort_id: i32,
+    pub tag_id: i32,
+
+    // Generic
+    pub created_at: String,
+    pub deleted_at: Option<String>,
+}
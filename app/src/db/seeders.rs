@@ -3,13 +3,16 @@ use once_cell::sync::Lazy;
 use rusqlite::Connection;
 
 use crate::db::{
+    deck::DeckRepo,
     gram_type::GramTypeRepo,
     niveau_liste::NiveauListeRepo,
     schemas::{
         gram_type::{GramTypeSchema, NewGramTypeSchema},
         niveau_liste::{NewNiveauListeSchema, NiveauListeSchema},
+        schwirig_liste::{NewSchwirigListeSchema, SchwirigListeSchema},
         worte_gender::{NewWorteGenderSchema, WorteGenderSchema},
     },
+    schwirig_liste::SchwirigListeRepo,
     worte_gender::WorteGenderRepo,
 };
 
@@ -33,6 +36,14 @@ pub static SEED_NIVEAU_LISTE: Lazy<Vec<NewNiveauListeSchema>> = Lazy::new(|| {
     ])
 });
 
+pub static SEED_SCHWIRIG_LISTE: Lazy<Vec<NewSchwirigListeSchema>> = Lazy::new(|| {
+    Vec::from([
+        NewSchwirigListeSchema::new(0, "Einfach"),
+        NewSchwirigListeSchema::new(1, "Normal"),
+        NewSchwirigListeSchema::new(2, "Schwirig"),
+    ])
+});
+
 pub static SEED_GRAM_TYPE_LISTE: Lazy<Vec<NewGramTypeSchema>> = Lazy::new(|| {
     Vec::from([
         NewGramTypeSchema::new(0, "noun_common", "Sustantivo comun"),
@@ -90,10 +101,17 @@ pub fn init_data(conn: &mut Connection) -> Result<()> {
     let data = NiveauListeRepo::bulk_insert_tx(&tx, &SEED_NIVEAU_LISTE)?;
     NiveauListeSchema::init_data(&data)?;
 
+    // Dificultad de las oraciones
+    let data = SchwirigListeRepo::bulk_insert_tx(&tx, &SEED_SCHWIRIG_LISTE)?;
+    SchwirigListeSchema::init_data(&data)?;
+
     // GramType
     let data = GramTypeRepo::bulk_insert_tx(&tx, &SEED_GRAM_TYPE_LISTE)?;
     GramTypeSchema::init_data(&data)?;
 
+    // Deck por defecto
+    DeckRepo::init_default_tx(&tx)?;
+
     tx.commit()?;
 
     Ok(())
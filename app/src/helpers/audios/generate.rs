@@ -0,0 +1,136 @@
+use color_eyre::{Report, eyre::Result};
+use rusqlite::Connection;
+use serde::Serialize;
+
+use crate::{
+    db::{
+        schemas::{
+            setze::SetzeSchema, setze_audio::NewSetzeAudioSchema, worte::WorteSchema,
+            worte_audio::NewWorteAudioSchema,
+        },
+        setze_audio::SetzeAudioRepo,
+        worte_audio::WorteAudioRepo,
+    },
+    helpers::audios::ManageAudios,
+    services::tts::{self, eleven_labs::LanguageVoice},
+};
+
+/// Resultado de generar audios; un error en una tarjeta no detiene a las demás.
+#[derive(Debug, Default, Serialize)]
+pub struct AudioReport {
+    pub generated: usize,
+    pub failed: Vec<AudioFailure>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AudioFailure {
+    pub id: i32,
+    pub text: String,
+    pub error: String,
+}
+
+impl AudioReport {
+    fn fail(&mut self, id: i32, text: &str, msg: &str, err: Report, verbose: bool) {
+        if verbose {
+            println!("{}: {}", msg, text);
+            println!("{:#?}", err);
+        }
+        self.failed.push(AudioFailure {
+            id,
+            text: text.to_string(),
+            error: format!("{}: {:#}", msg, err),
+        });
+    }
+}
+
+/// Genera con TTS el audio en español de cada palabra y lo registra en `worte_audio`.
+pub fn generate_worte(
+    conn: &mut Connection,
+    worte: &[WorteSchema],
+    verbose: bool,
+) -> Result<AudioReport> {
+    let mut report = AudioReport::default();
+
+    for (i, wort) in worte.iter().enumerate() {
+        let audio_bytes =
+            match tts::eleven_labs::generate_tts(&wort.worte_es, LanguageVoice::Spanisch) {
+                Ok(v) => v,
+                Err(err) => {
+                    let msg = "Error al generar el TTS de la palabra";
+                    report.fail(wort.id, &wort.worte_es, msg, err, verbose);
+                    continue;
+                }
+            };
+
+        let audio_path = match ManageAudios::save_audio_worte(audio_bytes, wort.id) {
+            Ok(v) => v,
+            Err(err) => {
+                let msg = "Error al guardar el archivo";
+                report.fail(wort.id, &wort.worte_es, msg, err, verbose);
+                continue;
+            }
+        };
+
+        WorteAudioRepo::bulk_insert(
+            conn,
+            &[NewWorteAudioSchema {
+                wort_id: wort.id,
+                voice_id: LanguageVoice::Spanisch.label(),
+                file_path: audio_path,
+            }],
+        )?;
+        report.generated += 1;
+
+        if verbose {
+            println!("Audio procesado {}/{}.", i + 1, worte.len());
+        }
+    }
+
+    Ok(report)
+}
+
+/// Genera con TTS el audio en alemán de cada oración y lo registra en `setze_audio`.
+pub fn generate_setze(
+    conn: &mut Connection,
+    setze: &[SetzeSchema],
+    verbose: bool,
+) -> Result<AudioReport> {
+    let mut report = AudioReport::default();
+
+    for (i, satz) in setze.iter().enumerate() {
+        let audio_bytes =
+            match tts::eleven_labs::generate_tts(&satz.setze_deutsch, LanguageVoice::Deutsch) {
+                Ok(v) => v,
+                Err(err) => {
+                    let msg = "Error al generar el TTS de la oración";
+                    report.fail(satz.id, &satz.setze_deutsch, msg, err, verbose);
+                    continue;
+                }
+            };
+
+        let audio_path = match ManageAudios::save_audio_setze(audio_bytes, satz.id) {
+            Ok(v) => v,
+            Err(err) => {
+                let msg = "Error al guardar el archivo";
+                report.fail(satz.id, &satz.setze_deutsch, msg, err, verbose);
+                continue;
+            }
+        };
+
+        SetzeAudioRepo::bulk_insert(
+            conn,
+            &[NewSetzeAudioSchema {
+                satz_id: satz.id,
+                voice_id: LanguageVoice::Deutsch.label(),
+                file_path: audio_path,
+            }],
+        )?;
+        report.generated += 1;
+
+        if verbose {
+            println!("Audio procesado {}/{}.", i + 1, setze.len());
+        }
+    }
+
+    Ok(report)
+}
@@ -1,43 +1,24 @@
 use std::{
     fs::{self, File},
-    path::Path,
+    path::PathBuf,
 };
 
 use color_eyre::eyre::Result;
 
-pub mod audio_player;
+use crate::helpers::profile;
 
-pub const PATH_FOLDER: &str = "assets/audios";
-pub const PATH_AUDIOS_WORTE: &str = "assets/audios/worte";
-pub const PATH_AUDIOS_SETZE: &str = "assets/audios/setze";
+pub mod audio_player;
+pub mod generate;
 
 enum TypeFile {
     AudioWort,
     AudioSatz,
 }
 
+/// Audios del perfil activo, las carpetas las crea `AppPaths::prepare`.
 pub struct ManageAudios {}
 
 impl ManageAudios {
-    pub fn init_dir() -> Result<()> {
-        let folder_path = Path::new(PATH_FOLDER);
-        if !folder_path.exists() {
-            fs::create_dir_all(folder_path)?;
-        }
-
-        let worte_path = Path::new(PATH_AUDIOS_WORTE);
-        if !worte_path.exists() {
-            fs::create_dir_all(worte_path)?;
-        }
-
-        let setze_path = Path::new(PATH_AUDIOS_SETZE);
-        if !setze_path.exists() {
-            fs::create_dir_all(setze_path)?;
-        }
-
-        Ok(())
-    }
-
     pub fn save_audio_setze(bytes: Vec<u8>, id: i32) -> Result<String> {
         Self::save_file(bytes, id, TypeFile::AudioSatz)
     }
@@ -46,15 +27,20 @@ impl ManageAudios {
         Self::save_file(bytes, id, TypeFile::AudioWort)
     }
 
+    fn file_path(id: i32, type_file: TypeFile) -> PathBuf {
+        let paths = profile::paths();
+        match type_file {
+            TypeFile::AudioWort => paths.audios_worte().join(format!("wort_{:06}.mp3", id)),
+            TypeFile::AudioSatz => paths.audios_setze().join(format!("satz_{:06}.mp3", id)),
+        }
+    }
+
     fn save_file(bytes: Vec<u8>, id: i32, type_file: TypeFile) -> Result<String> {
-        let path_final = match type_file {
-            TypeFile::AudioWort => format!("{}/wort_{:06}.mp3", PATH_AUDIOS_WORTE, id),
-            TypeFile::AudioSatz => format!("{}/satz_{:06}.mp3", PATH_AUDIOS_SETZE, id),
-        };
+        let path_final = Self::file_path(id, type_file);
 
         fs::write(&path_final, bytes)?;
 
-        Ok(path_final)
+        Ok(path_final.to_string_lossy().into_owned())
     }
 
     pub fn get_audio_setze(id: i32) -> Result<Option<File>> {
@@ -65,10 +51,7 @@ impl ManageAudios {
     }
 
     fn get_file(id: i32, type_file: TypeFile) -> Result<Option<File>> {
-        let path = match type_file {
-            TypeFile::AudioWort => format!("{}/wort_{:06}.mp3", PATH_AUDIOS_WORTE, id),
-            TypeFile::AudioSatz => format!("{}/wort_{:06}.mp3", PATH_AUDIOS_SETZE, id),
-        };
+        let path = Self::file_path(id, type_file);
 
         let file = File::open(path);
         match file {
@@ -1,49 +1,49 @@
 use std::collections::{HashMap, HashSet};
 
+use chrono::Utc;
 use color_eyre::eyre::Result;
 use rusqlite::Connection;
 
 use crate::{
     db::{
-        schemas::{setze::SetzeSchema, worte::WorteSchema},
-        setze::SetzeRepo,
-        worte::WorteRepo,
+        deck::DeckRepo,
+        konjugation_review::KonjugationReviewRepo,
+        praeposition_review::PraepositionReviewRepo,
+        schemas::{
+            konjugation_review::{KonjugationReviewSchema, NewKonjugationReviewSchema},
+            praeposition_review::{NewPraepositionReviewSchema, PraepositionReviewSchema},
+            setze::SetzeSchema,
+            setze_alternativ::NewSetzeAlternativSchema,
+            setze_review::{NewSetzeReviewSchema, SetzeReviewSchema},
+            worte::WorteSchema,
+            worte_alternativ::NewWorteAlternativSchema,
+            worte_review::{NewWorteReviewSchema, WorteReviewSchema},
+        },
+        setze_alternativ::SetzeAlternativRepo,
+        setze_review::SetzeReviewRepo,
+        tags::TagsRepo,
+        worte_alternativ::WorteAlternativRepo,
+        worte_review::WorteReviewRepo,
     },
     helpers::{
         audios::{ManageAudios, audio_player::AudioPlayer},
-        ui,
+        editor, fehler,
+        konjugation::KonjugationKey,
+        praeposition::PraepositionKey,
+        review_state::{ReviewState, schwirig_from_performance},
+        session::{Outcome, Session, SessionCard},
+        tags::TagExpr,
+        time, ui,
     },
+    traits::string::StringConvertion,
     utils,
 };
 
-#[derive(Debug)]
-struct ManageRepetitions {
-    once_mistake: bool,
-    repetition: u8,
-}
-
-impl ManageRepetitions {
-    fn new_error() -> Self {
-        Self {
-            once_mistake: true,
-            repetition: 0,
-        }
-    }
-
-    fn new() -> Self {
-        Self {
-            once_mistake: false,
-            repetition: 1,
-        }
-    }
-
-    fn add_repetition(&mut self) {
-        self.repetition += 1;
-    }
-}
+pub const TEXT_COMMANDS: &str = r##"Comandos: "undo" deshace la última respuesta, "override" marca tu última respuesta incorrecta como correcta, "edit" edita o borra la tarjeta."##;
 
 const TEXT_SETZE_ONCE: &str = r##"
 Para salir pon la palara "exit".                 Faltantes: {remainding}
+{commands}
 Algunas letras que te pueden ayudar. :)
           - ß ẞ ä ö ü Ä Ö Ü 
 
@@ -53,138 +53,242 @@ Algunas letras que te pueden ayudar. :)
 Por favor traducela...
 "##;
 
-/// return:
-/// - Seguir o no seguir con el proceso:
-///   - 0 Finishing sentences
-///   - 1 User typed "exit"
-/// - Vec<(i32, u8)>:
-///   - 1° -> satz_id
-///   - 2° -> resultado usuario
-///     - 0 -> No se la sabe
-///     - 1 -> Se la sabe pero dificil
-///     - 2 -> Se la sabe facil
-pub fn make_setze_exercise_repeat(
-    conn: &Connection,
-    ids_setze: Vec<i32>,
-    hash_audios: HashSet<i32>,
-    offset: usize,
-) -> Result<(i32, Vec<(i32, u8)>)> {
-    let mut ids_setze = ids_setze;
+const TEXT_TAG_EXPR: &str = r##"
+Escribe una expresión de etiquetas, por ejemplo: verbos AND (a1 OR a2) AND NOT irregular
+También puedes usar & | ! y comillas para etiquetas con espacios.
 
-    let mut vec_out: Vec<(i32, u8)> = vec![];
-    let mut val_out = 0;
-    let mut already_studied: HashMap<i32, ManageRepetitions> = HashMap::new();
+Para regresar presiona enter.
+"##;
 
-    let take = ids_setze.len().min(offset);
-    let aux_ids: Vec<i32> = ids_setze.drain(..take).collect();
+enum SessionCommand {
+    Exit,
+    Handled,
+    None,
+}
 
-    // Obtenemos toda la info del bloque de palabras que vamos a usar
-    let mut setze_correct = SetzeRepo::fetch_by_id(conn, &aux_ids)?;
+/// Atiende los comandos que el usuario puede escribir en lugar de una respuesta.
+fn handle_session_command<T, F, E>(
+    conn: &mut Connection,
+    session: &mut Session<T>,
+    card: &T,
+    input: &str,
+    save_alternative: &F,
+    edit: &E,
+) -> Result<SessionCommand>
+where
+    T: SessionCard,
+    F: Fn(&mut Connection, T::Key, String) -> Result<()>,
+    E: Fn(&mut Connection, &T) -> Result<()>,
+{
+    match input.trim() {
+        "exit" => Ok(SessionCommand::Exit),
+        "edit" => {
+            edit(conn, card)?;
+            session.reload_card(conn, card.key())?;
+            Ok(SessionCommand::Handled)
+        }
+        "undo" => {
+            if session.undo() {
+                println!("Se deshizo la última respuesta.");
+            } else {
+                println!("No hay respuestas por deshacer.");
+            }
+            Ok(SessionCommand::Handled)
+        }
+        "override" => {
+            let Some((key, wrong_input)) = session.override_last(conn)? else {
+                println!("No hay una respuesta incorrecta que corregir.");
+                return Ok(SessionCommand::Handled);
+            };
 
-    let player = AudioPlayer::new();
-    while !setze_correct.is_empty() {
-        let s = setze_correct[0].clone();
+            println!("Respuesta marcada como correcta.");
+            println!(
+                "¿Quieres guardar \"{}\" como respuesta alternativa? (si/no)",
+                wrong_input
+            );
+
+            #[allow(clippy::collapsible_if)]
+            if let Some(answer) = ui::prompt_nonempty("> ")? {
+                if answer.to_bool() {
+                    save_alternative(conn, key, wrong_input.clone())?;
+                    session.add_alternative(key, &wrong_input);
+                }
+            }
+            Ok(SessionCommand::Handled)
+        }
+        _ => Ok(SessionCommand::None),
+    }
+}
 
-        utils::clean_screen();
-        let setze_remaining = setze_correct.len() + ids_setze.len();
-        println!(
-            "{}",
-            TEXT_SETZE_ONCE
-                .replace("{satz}", &s.setze_spanisch)
-                .replace("{thema}", &s.thema)
-                .replace("{remainding}", &setze_remaining.to_string())
-        );
+/// Ciclo de consola de una sesión de estudio. Al terminar guarda las respuestas incorrectas
+/// con su clasificación, también si el usuario sale antes.
+///
+/// return:
+/// - 0 Finishing cards
+/// - 1 User typed "exit"
+pub fn run_session<T, R, W, F, E>(
+    conn: &mut Connection,
+    session: &mut Session<T>,
+    render: R,
+    show_wrong: W,
+    save_alternative: F,
+    edit: E,
+) -> Result<i32>
+where
+    T: SessionCard,
+    R: Fn(&T, usize) -> Result<()>,
+    W: Fn(&T, &str),
+    F: Fn(&mut Connection, T::Key, String) -> Result<()>,
+    E: Fn(&mut Connection, &T) -> Result<()>,
+{
+    let val_out = study_session(conn, session, render, show_wrong, save_alternative, edit)?;
+    fehler::save(conn, session.take_mistakes())?;
+    Ok(val_out)
+}
 
-        #[allow(clippy::collapsible_if)]
-        if let Some(audio) = hash_audios.get(&s.id) {
-            if let Ok(Some(path)) = ManageAudios::get_audio_setze(*audio) {
-                player.play(path)?;
-            }
-        };
+fn study_session<T, R, W, F, E>(
+    conn: &mut Connection,
+    session: &mut Session<T>,
+    render: R,
+    show_wrong: W,
+    save_alternative: F,
+    edit: E,
+) -> Result<i32>
+where
+    T: SessionCard,
+    R: Fn(&T, usize) -> Result<()>,
+    W: Fn(&T, &str),
+    F: Fn(&mut Connection, T::Key, String) -> Result<()>,
+    E: Fn(&mut Connection, &T) -> Result<()>,
+{
+    while let Some(card) = session.current().cloned() {
+        utils::clean_screen();
+        render(&card, session.remaining())?;
 
         let Some(input) = ui::prompt_nonempty("> ")? else {
             continue;
         };
 
-        if input == "exit" {
-            val_out = 1;
-            break;
+        match handle_session_command(conn, session, &card, &input, &save_alternative, &edit)? {
+            SessionCommand::Exit => return Ok(1),
+            SessionCommand::Handled => continue,
+            SessionCommand::None => {}
         }
 
-        let correct_answer = utils::string::clean_sentences(&s.setze_deutsch);
-        let input = utils::string::clean_sentences(&input);
-        if input == correct_answer {
-            if let Some(rep) = already_studied.get_mut(&s.id) {
-                if rep.repetition < 1 {
-                    // Primera vez que la acierta: subimos contador pero aún no la graduamos
-                    rep.add_repetition();
-                    setze_correct.rotate_left(1); // mueve el primer elemento al final del vector
-                } else {
-                    // Si la bandera de once_mistake esta en true, quiere decir que se equivoco con la
-                    // palabra por lo menos una vez
-                    let easy = if rep.once_mistake { 1 } else { 2 };
-                    vec_out.push((s.id, easy));
-                    setze_correct.remove(0);
-
-                    if !ids_setze.is_empty() {
-                        // Consultamos una nueva palabra y la añadimos al arreglo para su estudio
-                        let id_new = ids_setze.remove(0);
-                        let satz_new = SetzeRepo::fetch_by_id(conn, &[id_new])?;
-                        setze_correct.push(satz_new[0].clone());
-                    }
-
-                    // limpiamos el hashmap de la palabra que ya no se va a repetir
-                    already_studied.remove(&s.id);
-                }
-            } else {
-                // La tuvo correcta a la primera
-                let easy = 2;
-                vec_out.push((s.id, easy));
-                setze_correct.remove(0);
-
-                if !ids_setze.is_empty() {
-                    // Consultamos una nueva palabra y la añadimos al arreglo para su estudio
-                    let id_new = ids_setze.remove(0);
-                    let satz_new = SetzeRepo::fetch_by_id(conn, &[id_new])?;
-                    setze_correct.push(satz_new[0].clone());
+        match session.answer(conn, &input)? {
+            Outcome::Correct => continue,
+            Outcome::Partial => {
+                println!();
+                println!("Casi correcta, se acepta: {}", card.expected());
+                for hint in card.hints(&input) {
+                    println!("  - {}", hint);
                 }
+                println!();
+                ui::prompt("Presiona enter para continuar... ")?;
+                continue;
             }
-
-            continue;
+            Outcome::Wrong => {}
         }
 
-        already_studied
-            .entry(s.id)
-            .and_modify(|r| *r = ManageRepetitions::new_error())
-            .or_insert(ManageRepetitions::new_error());
-
         println!();
-        println!("Palabra incorrecta");
-        println!("La palabra correcta es: {}", correct_answer);
+        println!("Respuesta incorrecta");
+        println!("La respuesta correcta es: {}", card.expected());
+        println!(
+            "Tipo de error: {}",
+            fehler::classify_card(&card, &input).label()
+        );
+        for hint in card.hints(&input) {
+            println!("  - {}", hint);
+        }
+        show_wrong(&card, &input);
         println!();
 
         loop {
             let Some(input) = ui::prompt_nonempty("> ")? else {
                 break;
             };
-            if input == "exit" {
-                val_out = 1;
-                break;
+
+            match handle_session_command(conn, session, &card, &input, &save_alternative, &edit)? {
+                SessionCommand::Exit => return Ok(1),
+                SessionCommand::Handled => break,
+                SessionCommand::None => {}
             }
 
-            let input = input.trim();
-            if input == correct_answer {
-                setze_correct.rotate_left(1); // mueve el primer elemento al final del vector
+            if session.is_correct(&card, &input) {
                 break;
             }
         }
     }
 
-    Ok((val_out, vec_out))
+    Ok(0)
+}
+
+/// return:
+/// - Seguir o no seguir con el proceso:
+///   - 0 Finishing sentences
+///   - 1 User typed "exit"
+/// - Vec<(i32, u8)>:
+///   - 1° -> satz_id
+///   - 2° -> resultado usuario
+///     - 0 -> No se la sabe
+///     - 1 -> Se la sabe pero dificil
+///     - 2 -> Se la sabe facil
+pub fn make_setze_exercise_repeat(
+    conn: &mut Connection,
+    ids_setze: Vec<i32>,
+    hash_audios: HashSet<i32>,
+    offset: usize,
+) -> Result<(i32, Vec<(i32, u8)>)> {
+    let mut alternatives: HashMap<i32, Vec<String>> = HashMap::new();
+    for alt in SetzeAlternativRepo::fetch_by_satz_id(conn, &ids_setze)? {
+        alternatives
+            .entry(alt.satz_id)
+            .or_default()
+            .push(alt.antwort);
+    }
+
+    let mut session: Session<SetzeSchema> =
+        Session::new(conn, ids_setze, offset)?.with_alternatives(alternatives);
+
+    let player = AudioPlayer::new();
+    let val_out = run_session(
+        conn,
+        &mut session,
+        |s, remaining| {
+            println!(
+                "{}",
+                TEXT_SETZE_ONCE
+                    .replace("{commands}", TEXT_COMMANDS)
+                    .replace("{satz}", &s.setze_spanisch)
+                    .replace("{thema}", &s.thema)
+                    .replace("{remainding}", &remaining.to_string())
+            );
+
+            #[allow(clippy::collapsible_if)]
+            if hash_audios.contains(&s.id) {
+                if let Ok(Some(path)) = ManageAudios::get_audio_setze(s.id) {
+                    player.play(path)?;
+                }
+            };
+            Ok(())
+        },
+        |_, _| {},
+        |conn, satz_id, antwort| {
+            SetzeAlternativRepo::bulk_insert(
+                conn,
+                &[NewSetzeAlternativSchema { satz_id, antwort }],
+            )?;
+            Ok(())
+        },
+        |conn, s| editor::edit_satz(conn, s.id),
+    )?;
+
+    Ok((val_out, session.into_results()))
 }
 
 const TEXT_WORTE_ONCE: &str = r##"
 Para salir pon la palara "exit".                 Faltantes: {remainding}
+{commands}
 Algunas letras que te pueden ayudar. :)
           - ß ẞ ä ö ü Ä Ö Ü 
 
@@ -194,6 +298,7 @@ Algunas letras que te pueden ayudar. :)
 Consideraciones:
   - Se esta contemplando el uso de Mayusculas y minusculas.
   - Para los sustantivos, favor de poner el articulo y el sustantivo. Ejem: "der Hund"
+  - Un sustantivo en minúscula con el artículo correcto se acepta, pero se guarda como error.
 
 Por favor traducela...
 "##;
@@ -209,136 +314,317 @@ Por favor traducela...
 ///     - 1 -> Se la sabe pero dificil
 ///     - 2 -> Se la sabe facil
 pub fn make_worte_exercise_repeat(
-    conn: &Connection,
+    conn: &mut Connection,
     ids_worte: Vec<i32>,
     hash_audios: HashSet<i32>,
     offset: usize,
 ) -> Result<(i32, Vec<(i32, u8)>)> {
-    let mut ids_worte = ids_worte;
+    let mut alternatives: HashMap<i32, Vec<String>> = HashMap::new();
+    for alt in WorteAlternativRepo::fetch_by_wort_id(conn, &ids_worte)? {
+        alternatives
+            .entry(alt.wort_id)
+            .or_default()
+            .push(alt.antwort);
+    }
 
-    let mut vec_out: Vec<(i32, u8)> = vec![];
-    let mut val_out = 0;
-    let mut already_studied: HashMap<i32, ManageRepetitions> = HashMap::new();
+    let mut session: Session<WorteSchema> =
+        Session::new(conn, ids_worte, offset)?.with_alternatives(alternatives);
 
-    let take = ids_worte.len().min(offset);
-    let aux_ids: Vec<i32> = ids_worte.drain(..take).collect();
+    let player = AudioPlayer::new();
+    let val_out = run_session(
+        conn,
+        &mut session,
+        |w, remaining| {
+            println!(
+                "{}",
+                TEXT_WORTE_ONCE
+                    .replace("{commands}", TEXT_COMMANDS)
+                    .replace("{wort}", &w.worte_es)
+                    .replace("{remainding}", &remaining.to_string())
+                    .replace(
+                        "{gram_type}",
+                        &w.gram_type_id
+                            .iter()
+                            .map(|r| format!("{} ", r.name))
+                            .collect::<Vec<_>>()
+                            .join(",")
+                    )
+            );
+
+            #[allow(clippy::collapsible_if)]
+            if hash_audios.contains(&w.id) {
+                if let Ok(Some(path)) = ManageAudios::get_audio_worte(w.id) {
+                    player.play(path)?;
+                }
+            };
+            Ok(())
+        },
+        |w, _| {
+            println!();
+            println!("Ejemplo: {}", w.example_de);
+            println!("Traducción: {}", w.example_es);
+        },
+        |conn, wort_id, antwort| {
+            WorteAlternativRepo::bulk_insert(
+                conn,
+                &[NewWorteAlternativSchema { wort_id, antwort }],
+            )?;
+            Ok(())
+        },
+        |conn, w| editor::edit_wort(conn, w.id),
+    )?;
+
+    Ok((val_out, session.into_results()))
+}
 
-    // Obtenemos toda la info del bloque de palabras que vamos a usar
-    let mut worte_correct = WorteRepo::fetch_by_id(conn, &aux_ids)?;
+/// Calcula y guarda la siguiente revisión de cada palabra respondida (wort_id, calidad).
+pub fn save_worte_review(conn: &mut Connection, results: Vec<(i32, u8)>) -> Result<()> {
+    let wort_ids: Vec<i32> = results.iter().map(|(id, _)| *id).collect();
 
-    let player = AudioPlayer::new();
-    while !worte_correct.is_empty() && val_out == 0 {
-        let w = worte_correct[0].clone();
+    let mut hash_worte_review: HashMap<i32, WorteReviewSchema> = HashMap::new();
+    for wr in WorteReviewRepo::fetch_by_wort_id(conn, &wort_ids)? {
+        hash_worte_review.insert(wr.wort_id, wr);
+    }
 
-        utils::clean_screen();
-        let worte_remaining = worte_correct.len() + ids_worte.len();
-        println!(
-            "{}",
-            TEXT_WORTE_ONCE
-                .replace("{wort}", &w.worte_es)
-                .replace("{remainding}", &worte_remaining.to_string())
-                .replace(
-                    "{gram_type}",
-                    &w.gram_type_id
-                        .into_iter()
-                        .map(|r| format!("{} ", r.name))
-                        .collect::<Vec<_>>()
-                        .join(",")
-                )
-        );
+    // Cada tarjeta usa los parámetros del scheduler de su deck
+    let hash_decks = DeckRepo::fetch_by_wort_id(conn, &wort_ids)?;
 
-        #[allow(clippy::collapsible_if)]
-        if let Some(audio) = hash_audios.get(&w.id) {
-            if let Ok(Some(path)) = ManageAudios::get_audio_worte(*audio) {
-                player.play(path)?;
-            }
+    let mut vec_new_worte_review: Vec<NewWorteReviewSchema> = vec![];
+    let now = Utc::now();
+
+    for (wort_id, quality) in results {
+        let params = hash_decks
+            .get(&wort_id)
+            .map(|d| d.scheduler())
+            .unwrap_or_default();
+
+        let review_state = if let Some(val) = hash_worte_review.get(&wort_id) {
+            ReviewState::from(val.interval, val.ease_factor, val.repetitions)
+        } else {
+            ReviewState::new_with(&params)
         };
 
-        let Some(input) = ui::prompt_nonempty("> ")? else {
-            continue;
+        let review_state = review_state.review_with(quality, &params);
+        let next = review_state.next_review_date_from(now);
+        vec_new_worte_review.push(NewWorteReviewSchema {
+            wort_id,
+            interval: review_state.interval,
+            ease_factor: review_state.ease_factor,
+            repetitions: review_state.repetitions,
+            last_review: time::datetime_2_string(now),
+            next_review: time::datetime_2_string(next),
+        })
+    }
+
+    WorteReviewRepo::bulk_insert(conn, &vec_new_worte_review)?;
+    Ok(())
+}
+
+/// Calcula y guarda la siguiente revisión de cada oración respondida (satz_id, calidad).
+pub fn save_setze_review(conn: &mut Connection, results: Vec<(i32, u8)>) -> Result<()> {
+    save_setze_review_with(conn, results, true)
+}
+
+/// Igual que `save_setze_review` para el ejercicio de ordenar palabras: un acierto vuelve a
+/// programar la oración con el mismo intervalo, sin avanzarla ni contarla como fallo.
+pub fn save_scramble_review(conn: &mut Connection, results: Vec<(i32, u8)>) -> Result<()> {
+    save_setze_review_with(conn, results, false)
+}
+
+fn save_setze_review_with(
+    conn: &mut Connection,
+    results: Vec<(i32, u8)>,
+    avanzar: bool,
+) -> Result<()> {
+    let satz_ids: Vec<i32> = results.iter().map(|(id, _)| *id).collect();
+
+    let mut hash_setze_review: HashMap<i32, SetzeReviewSchema> = HashMap::new();
+    for sr in SetzeReviewRepo::fetch_by_satz_id(conn, &satz_ids)? {
+        hash_setze_review.insert(sr.satz_id, sr);
+    }
+
+    // Cada tarjeta usa los parámetros del scheduler de su deck
+    let hash_decks = DeckRepo::fetch_by_satz_id(conn, &satz_ids)?;
+
+    let mut vec_new_setze_review: Vec<NewSetzeReviewSchema> = vec![];
+    let now = Utc::now();
+
+    for (satz_id, quality) in results {
+        let params = hash_decks
+            .get(&satz_id)
+            .map(|d| d.scheduler())
+            .unwrap_or_default();
+
+        let review_state = if let Some(val) = hash_setze_review.get(&satz_id) {
+            ReviewState::from(val.interval, val.ease_factor, val.repetitions)
+        } else {
+            ReviewState::new_with(&params)
         };
 
-        if input == "exit" {
-            val_out = 1;
-            break;
-        }
+        let lapses = hash_setze_review.get(&satz_id).map_or(0, |v| v.lapses)
+            + ReviewState::is_lapse(quality) as u32;
 
-        let correct_answer = match w.gender_id {
-            Some(v) => format!("{} {}", v.artikel.to_lowercase(), w.worte_de),
-            None => w.worte_de.clone(),
+        let review_state = if avanzar {
+            review_state.review_with(quality, &params)
+        } else {
+            review_state.review_sin_avance(quality, &params)
         };
+        let next = review_state.next_review_date_from(now);
+        vec_new_setze_review.push(NewSetzeReviewSchema {
+            satz_id,
+            interval: review_state.interval,
+            ease_factor: review_state.ease_factor,
+            repetitions: review_state.repetitions,
+            last_review: time::datetime_2_string(now),
+            next_review: time::datetime_2_string(next),
+            lapses,
+            schwirig_id: schwirig_from_performance(
+                review_state.ease_factor,
+                lapses,
+                review_state.repetitions,
+            ),
+        })
+    }
 
-        let input = input.trim();
-        if input == correct_answer {
-            if let Some(rep) = already_studied.get_mut(&w.id) {
-                if rep.repetition < 1 {
-                    // Primera vez que la acierta: subimos contador pero aún no la graduamos
-                    rep.add_repetition();
-                    worte_correct.rotate_left(1); // mueve el primer elemento al final del vector
-                } else {
-                    // Si la bandera de once_mistake esta en true, quiere decir que se equivoco con la
-                    // palabra por lo menos una vez
-                    let easy = if rep.once_mistake { 1 } else { 2 };
-                    vec_out.push((w.id, easy));
-                    worte_correct.remove(0);
-
-                    if !ids_worte.is_empty() {
-                        // Consultamos una nueva palabra y la añadimos al arreglo para su estudio
-                        let id_new = ids_worte.remove(0);
-                        let wort_new = WorteRepo::fetch_by_id(conn, &[id_new])?;
-                        worte_correct.push(wort_new[0].clone());
-                    }
-
-                    // limpiamos el hashmap de la palabra que ya no se va a repetir
-                    already_studied.remove(&w.id);
-                }
-            } else {
-                // La tuvo correcta a la primera
-                let easy = 2;
-                vec_out.push((w.id, easy));
-                worte_correct.remove(0);
-
-                if !ids_worte.is_empty() {
-                    // Consultamos una nueva palabra y la añadimos al arreglo para su estudio
-                    let id_new = ids_worte.remove(0);
-                    let wort_new = WorteRepo::fetch_by_id(conn, &[id_new])?;
-                    worte_correct.push(wort_new[0].clone());
-                }
-            }
+    SetzeReviewRepo::bulk_insert(conn, &vec_new_setze_review)?;
+    Ok(())
+}
 
-            continue;
-        }
+/// Calcula y guarda la siguiente revisión de cada familia de formas respondida
+/// (verbo y tiempo, calidad).
+pub fn save_konjugation_review(
+    conn: &mut Connection,
+    results: Vec<(KonjugationKey, u8)>,
+) -> Result<()> {
+    let mut wort_ids: Vec<i32> = results.iter().map(|(k, _)| k.wort_id).collect();
+    wort_ids.sort();
+    wort_ids.dedup();
+
+    let mut hash_konjugation_review: HashMap<(i32, String), KonjugationReviewSchema> =
+        HashMap::new();
+    for kr in KonjugationReviewRepo::fetch_by_wort_id(conn, &wort_ids)? {
+        hash_konjugation_review.insert((kr.wort_id, kr.tempus.clone()), kr);
+    }
 
-        already_studied
-            .entry(w.id)
-            .and_modify(|r| *r = ManageRepetitions::new_error())
-            .or_insert(ManageRepetitions::new_error());
+    // Cada tarjeta usa los parámetros del scheduler del deck de su verbo
+    let hash_decks = DeckRepo::fetch_by_wort_id(conn, &wort_ids)?;
 
-        println!();
-        println!("Palabra incorrecta");
-        println!("La palabra correcta es: {}", correct_answer);
+    let mut vec_new_konjugation_review: Vec<NewKonjugationReviewSchema> = vec![];
+    let now = Utc::now();
 
-        println!();
-        println!("Ejemplo: {}", w.example_de);
-        println!("Traducción: {}", w.example_es);
-        println!();
+    for (key, quality) in results {
+        let params = hash_decks
+            .get(&key.wort_id)
+            .map(|d| d.scheduler())
+            .unwrap_or_default();
 
-        loop {
-            let Some(input) = ui::prompt_nonempty("> ")? else {
-                break;
-            };
-            if input == "exit" {
-                val_out = 1;
-                break;
-            }
+        let tempus = key.tempus.code().to_string();
+        let previous = hash_konjugation_review.get(&(key.wort_id, tempus.clone()));
+        let review_state = if let Some(val) = previous {
+            ReviewState::from(val.interval, val.ease_factor, val.repetitions)
+        } else {
+            ReviewState::new_with(&params)
+        };
 
-            let input = input.trim();
-            if input == correct_answer {
-                worte_correct.rotate_left(1); // mueve el primer elemento al final del vector
-                break;
-            }
-        }
+        let lapses = previous.map_or(0, |v| v.lapses) + ReviewState::is_lapse(quality) as u32;
+
+        let review_state = review_state.review_with(quality, &params);
+        let next = review_state.next_review_date_from(now);
+        vec_new_konjugation_review.push(NewKonjugationReviewSchema {
+            wort_id: key.wort_id,
+            tempus,
+            interval: review_state.interval,
+            ease_factor: review_state.ease_factor,
+            repetitions: review_state.repetitions,
+            last_review: time::datetime_2_string(now),
+            next_review: time::datetime_2_string(next),
+            lapses,
+        })
+    }
+
+    KonjugationReviewRepo::bulk_insert(conn, &vec_new_konjugation_review)?;
+    Ok(())
+}
+
+/// Calcula y guarda la siguiente revisión de cada preposición respondida
+/// (pregunta del drill, calidad).
+pub fn save_praeposition_review(
+    conn: &mut Connection,
+    results: Vec<(PraepositionKey, u8)>,
+) -> Result<()> {
+    let wort_ids: Vec<i32> = results.iter().map(|(k, _)| k.wort_id).collect();
+
+    let mut hash_praeposition_review: HashMap<i32, PraepositionReviewSchema> = HashMap::new();
+    for pr in PraepositionReviewRepo::fetch_by_wort_id(conn, &wort_ids)? {
+        hash_praeposition_review.insert(pr.wort_id, pr);
+    }
+
+    let hash_decks = DeckRepo::fetch_by_wort_id(conn, &wort_ids)?;
+
+    let mut vec_new_praeposition_review: Vec<NewPraepositionReviewSchema> = vec![];
+    let now = Utc::now();
+
+    // La revisión es de la preposición, sin importar el sustantivo o la pregunta
+    for (key, quality) in results {
+        let params = hash_decks
+            .get(&key.wort_id)
+            .map(|d| d.scheduler())
+            .unwrap_or_default();
+
+        let previous = hash_praeposition_review.get(&key.wort_id);
+        let review_state = if let Some(val) = previous {
+            ReviewState::from(val.interval, val.ease_factor, val.repetitions)
+        } else {
+            ReviewState::new_with(&params)
+        };
+
+        let lapses = previous.map_or(0, |v| v.lapses) + ReviewState::is_lapse(quality) as u32;
+
+        let review_state = review_state.review_with(quality, &params);
+        let next = review_state.next_review_date_from(now);
+        vec_new_praeposition_review.push(NewPraepositionReviewSchema {
+            wort_id: key.wort_id,
+            interval: review_state.interval,
+            ease_factor: review_state.ease_factor,
+            repetitions: review_state.repetitions,
+            last_review: time::datetime_2_string(now),
+            next_review: time::datetime_2_string(next),
+            lapses,
+        })
     }
 
-    Ok((val_out, vec_out))
+    PraepositionReviewRepo::bulk_insert(conn, &vec_new_praeposition_review)?;
+    Ok(())
+}
+
+/// Muestra las etiquetas existentes y pide una expresión hasta que sea válida.
+/// Regresa `None` si el usuario no escribe nada o no hay etiquetas.
+pub fn prompt_tag_expr(conn: &Connection) -> Result<Option<TagExpr>> {
+    let tags = TagsRepo::fetch_all(conn)?;
+    if tags.is_empty() {
+        println!("No hay etiquetas registradas.");
+        return Ok(None);
+    }
+
+    let names = tags
+        .iter()
+        .map(|t| t.name.as_str())
+        .collect::<Vec<_>>()
+        .join(", ");
+    println!("Etiquetas: {}", names);
+    println!("{}", TEXT_TAG_EXPR);
+
+    loop {
+        let Some(input) = ui::prompt_nonempty("> ")? else {
+            return Ok(None);
+        };
+        if input.trim() == "exit" {
+            return Ok(None);
+        }
+
+        match TagExpr::parse(&input) {
+            Ok(expr) => return Ok(Some(expr)),
+            Err(err) => println!("Expresión no válida: {}", err),
+        }
+    }
 }
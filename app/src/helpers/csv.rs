@@ -1,11 +1,21 @@
 use color_eyre::eyre::{Context, Result, bail, eyre};
-use csv::ReaderBuilder;
-use std::fs::File;
+use csv::{ReaderBuilder, StringRecord, Writer};
+use std::{fs::File, io::Write};
 
 use crate::{
     db::schemas::{
-        gram_type::GramTypeSchema, niveau_liste::NiveauListeSchema, setze::NewSetzeSchema,
-        worte::NewWorteSchema, worte_gender::WorteGenderSchema,
+        gram_type::GramTypeSchema,
+        niveau_liste::NiveauListeSchema,
+        schwirig_liste::{SCHWIRIG_ID_NORMAL, SchwirigListeSchema},
+        setze::{NewSetzeSchema, SetzeSchema},
+        tags::TagsSchema,
+        word_frequency::NewWordFrequencySchema,
+        worte::{NewWorteSchema, WorteSchema},
+        worte_gender::WorteGenderSchema,
+    },
+    helpers::{
+        konjugation::{Person, Tempus},
+        tags,
     },
     traits::string::StringConvertion,
 };
@@ -13,9 +23,31 @@ use crate::{
 pub enum CsvType {
     Setze,
     Worte,
+    Konjugation,
+    Frequenz,
 }
 
 static HEADER_SETZE_CSV: [&str; 4] = ["setze_spanisch", "setze_deutsch", "thema", "schwirig_id"];
+/// Columna opcional con el nivel CEFR (A1…C2) de la oración.
+static HEADER_NIVEAU_CSV: &str = "niveau";
+/// Columna opcional de cualquier CSV con las etiquetas separadas por coma.
+static HEADER_TAGS_CSV: &str = "tags";
+/// Columnas opcionales que pueden ir, en cualquier orden, después de las obligatorias.
+static HEADER_SETZE_OPTIONAL_CSV: [&str; 2] = [HEADER_NIVEAU_CSV, HEADER_TAGS_CSV];
+/// Columnas opcionales de los adjetivos; vacías se generan con reglas.
+static HEADER_KOMPARATIV_CSV: &str = "komparativ";
+static HEADER_SUPERLATIV_CSV: &str = "superlativ";
+static HEADER_WORTE_OPTIONAL_CSV: [&str; 3] = [
+    HEADER_TAGS_CSV,
+    HEADER_KOMPARATIV_CSV,
+    HEADER_SUPERLATIV_CSV,
+];
+/// Nivel de las oraciones cuando el CSV no tiene la columna `niveau`.
+static DEFAULT_NIVEAU_SETZE: &str = "A1";
+/// Formas conjugadas que reemplazan a las generadas; `person` va vacío en el partizip2.
+static HEADER_KONJUGATION_CSV: [&str; 4] = ["worte_de", "tempus", "person", "form"];
+/// Lista de frecuencia: lema y rango (1 es el más frecuente).
+static HEADER_FREQUENZ_CSV: [&str; 2] = ["lemma", "rank"];
 static HEADER_WORTE_CSV: [&str; 11] = [
     "gram_type",
     "gender_id",
@@ -41,9 +73,11 @@ pub fn is_csv_valid(path: &str, type_file: CsvType) -> Result<()> {
     let file = File::open(path)
         .with_context(|| format!("[is_csv_valid] - No se puede abrir el archivo: {}", path))?;
 
-    let header_csv: &'static [&'static str] = match type_file {
-        CsvType::Setze => &HEADER_SETZE_CSV,
-        CsvType::Worte => &HEADER_WORTE_CSV,
+    let (header_csv, header_optional): (&[&str], &[&str]) = match type_file {
+        CsvType::Setze => (&HEADER_SETZE_CSV, &HEADER_SETZE_OPTIONAL_CSV),
+        CsvType::Worte => (&HEADER_WORTE_CSV, &HEADER_WORTE_OPTIONAL_CSV),
+        CsvType::Konjugation => (&HEADER_KONJUGATION_CSV, &[]),
+        CsvType::Frequenz => (&HEADER_FREQUENZ_CSV, &[]),
     };
 
     let mut reader = ReaderBuilder::new().has_headers(true).from_reader(file);
@@ -51,7 +85,8 @@ pub fn is_csv_valid(path: &str, type_file: CsvType) -> Result<()> {
         .headers()
         .context("[is_csv_valid] - Error en encabezados")?;
 
-    if headers.len() != header_csv.len() {
+    if headers.len() < header_csv.len() || headers.len() > header_csv.len() + header_optional.len()
+    {
         return Err(eyre!(
             "[is_csv_valid] - Número de columnas inválido esperado {}, recibido {}",
             header_csv.len(),
@@ -59,7 +94,7 @@ pub fn is_csv_valid(path: &str, type_file: CsvType) -> Result<()> {
         ));
     }
 
-    for (i, h) in headers.iter().enumerate() {
+    for (i, h) in headers.iter().take(header_csv.len()).enumerate() {
         if h != header_csv[i] {
             return Err(eyre!(
                 "[is_csv_valid] - La cabera {} no corresponde con {} (pos {})",
@@ -70,6 +105,17 @@ pub fn is_csv_valid(path: &str, type_file: CsvType) -> Result<()> {
         }
     }
 
+    let extra: Vec<&str> = headers.iter().skip(header_csv.len()).collect();
+    for (i, h) in extra.iter().enumerate() {
+        if !header_optional.contains(h) || extra[..i].contains(h) {
+            return Err(eyre!(
+                "[is_csv_valid] - La cabecera opcional {} no es válida, se esperaba una de {:?}",
+                h,
+                header_optional
+            ));
+        }
+    }
+
     for (i, result) in reader.records().enumerate() {
         result.with_context(|| format!("[is_csv_valid] - Error en la línea {}", i + 1))?;
     }
@@ -77,7 +123,8 @@ pub fn is_csv_valid(path: &str, type_file: CsvType) -> Result<()> {
     Ok(())
 }
 
-pub fn extract_sentences_csv(path: &str) -> Result<Vec<NewSetzeSchema>> {
+/// Lee las oraciones del CSV asignándolas al deck `deck_id`.
+pub fn extract_sentences_csv(path: &str, deck_id: i32) -> Result<Vec<NewSetzeSchema>> {
     let file = File::open(path).with_context(|| {
         format!(
             "[extract_sentences_from] - No se puede abrir el archivo: {}",
@@ -86,32 +133,72 @@ pub fn extract_sentences_csv(path: &str) -> Result<Vec<NewSetzeSchema>> {
     })?;
     let mut reader = ReaderBuilder::new().has_headers(true).from_reader(file);
 
+    let headers = reader
+        .headers()
+        .context("[extract_sentences_from] - Error en encabezados")?;
+    let col_niveau = headers.iter().position(|h| h == HEADER_NIVEAU_CSV);
+    let col_tags = headers.iter().position(|h| h == HEADER_TAGS_CSV);
+
     let mut r: Vec<NewSetzeSchema> = Vec::new();
     for (i, result) in reader.records().enumerate() {
         let value = result
             .with_context(|| format!("[extract_sentences_from] - Error en la línea {}", i + 1))?;
 
-        let col3_value = value.get(3).unwrap_or("1").to_string();
-        let schwirig_id = if let Ok(col3) = col3_value.parse::<i32>() {
-            NiveauListeSchema::from_id(col3)
-        } else {
-            NiveauListeSchema::from_niveau(&col3_value)
+        let schwirig_id = match value.get(3) {
+            Some(v) if !v.trim().is_empty() => {
+                SchwirigListeSchema::parse(v)
+                    .with_context(|| format!("schwirig_id no válido. Línea del CSV: {}", i + 1))?
+                    .id
+            }
+            _ => SCHWIRIG_ID_NORMAL,
         };
 
+        let niveau = col_niveau
+            .and_then(|c| value.get(c))
+            .filter(|v| !v.trim().is_empty())
+            .unwrap_or(DEFAULT_NIVEAU_SETZE);
+        let niveau_id = NiveauListeSchema::from_niveau(niveau.trim())
+            .with_context(|| format!("niveau no válido. Línea del CSV: {}", i + 1))?
+            .id;
+
         let span = value.get(0).unwrap_or("").to_string();
         let deut = value.get(1).unwrap_or("").to_string();
         let them = value.get(2).unwrap_or("").to_string();
+        let tags = col_tags
+            .and_then(|c| value.get(c))
+            .map(tags::parse_tags)
+            .unwrap_or_default();
 
-        r.push(NewSetzeSchema::new(span, deut, them, schwirig_id?.id));
+        r.push(NewSetzeSchema::new(
+            span,
+            deut,
+            them,
+            niveau_id,
+            schwirig_id,
+            deck_id,
+            tags,
+        ));
     }
 
     Ok(r)
 }
 
-pub fn extract_worte_csv(path: &str) -> Result<Vec<NewWorteSchema>> {
+/// Lee las palabras del CSV asignándolas al deck `deck_id`.
+pub fn extract_worte_csv(path: &str, deck_id: i32) -> Result<Vec<NewWorteSchema>> {
     let file = File::open(path)?;
     let mut reader = ReaderBuilder::new().has_headers(true).from_reader(file);
 
+    let headers = reader.headers()?;
+    let col_tags = headers.iter().position(|h| h == HEADER_TAGS_CSV);
+    let col_komparativ = headers.iter().position(|h| h == HEADER_KOMPARATIV_CSV);
+    let col_superlativ = headers.iter().position(|h| h == HEADER_SUPERLATIV_CSV);
+    let optional = |value: &StringRecord, col: Option<usize>| {
+        col.and_then(|c| value.get(c))
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+            .map(|s| s.to_string())
+    };
+
     let mut vec_result: Vec<NewWorteSchema> = Vec::new();
     for (i, result) in reader.records().enumerate() {
         let value = result.with_context(|| format!("Error en la línea del CSV: {}", i + 1))?;
@@ -172,9 +259,16 @@ pub fn extract_worte_csv(path: &str) -> Result<Vec<NewWorteSchema>> {
         let verb_aux = value.get(8).map(|s| s.to_string());
         let trennbar = value.get(9).map(|s| s.to_bool());
         let reflexiv = value.get(10).map(|s| s.to_bool());
+        let tags = col_tags
+            .and_then(|c| value.get(c))
+            .map(tags::parse_tags)
+            .unwrap_or_default();
+        let komparativ = optional(&value, col_komparativ);
+        let superlativ = optional(&value, col_superlativ);
 
         vec_result.push(NewWorteSchema {
             gram_type: vec_gram_type,
+            tags,
             gender_id,
             worte_de,
             worte_es,
@@ -185,8 +279,167 @@ pub fn extract_worte_csv(path: &str) -> Result<Vec<NewWorteSchema>> {
             verb_aux,
             trennbar,
             reflexiv,
+            komparativ,
+            superlativ,
+            deck_id,
         });
     }
 
     Ok(vec_result)
 }
+
+/// Renglón del CSV de conjugaciones; el verbo se busca por su texto al importar.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KonjugationCsv {
+    pub worte_de: String,
+    pub tempus: Tempus,
+    pub person: Option<Person>,
+    pub form: String,
+}
+
+/// Lee las formas conjugadas del CSV validando el tiempo y la persona.
+pub fn extract_konjugation_csv(path: &str) -> Result<Vec<KonjugationCsv>> {
+    let file = File::open(path)?;
+    let mut reader = ReaderBuilder::new().has_headers(true).from_reader(file);
+
+    let mut vec_result: Vec<KonjugationCsv> = Vec::new();
+    for (i, result) in reader.records().enumerate() {
+        let value = result.with_context(|| format!("Error en la línea del CSV: {}", i + 1))?;
+
+        let worte_de = value.get(0).unwrap_or("").trim().to_string();
+        if worte_de.is_empty() {
+            bail!("worte_de no puede ser vacio. Línea del CSV: {}", i + 1);
+        }
+
+        let tempus = value.get(1).unwrap_or("");
+        let Some(tempus) = Tempus::parse(tempus) else {
+            bail!("tempus no válido: {}. Línea del CSV: {}", tempus, i + 1);
+        };
+
+        let person = value.get(2).unwrap_or("").trim();
+        let person = match Person::parse(person) {
+            Some(p) if tempus.persons().contains(&p) => Some(p),
+            None if person.is_empty() && tempus.persons().is_empty() => None,
+            _ => bail!(
+                "person no válida para {}: {}. Línea del CSV: {}",
+                tempus.code(),
+                person,
+                i + 1
+            ),
+        };
+
+        let form = value.get(3).unwrap_or("").trim().to_string();
+        if form.is_empty() {
+            bail!("form no puede ser vacio. Línea del CSV: {}", i + 1);
+        }
+
+        vec_result.push(KonjugationCsv {
+            worte_de,
+            tempus,
+            person,
+            form,
+        });
+    }
+
+    Ok(vec_result)
+}
+
+/// Lee la lista de frecuencia; el lema se guarda en minúsculas.
+pub fn extract_frequenz_csv(path: &str) -> Result<Vec<NewWordFrequencySchema>> {
+    let file = File::open(path)?;
+    let mut reader = ReaderBuilder::new().has_headers(true).from_reader(file);
+
+    let mut vec_result: Vec<NewWordFrequencySchema> = Vec::new();
+    for (i, result) in reader.records().enumerate() {
+        let value = result.with_context(|| format!("Error en la línea del CSV: {}", i + 1))?;
+
+        let lemma = value.get(0).unwrap_or("").trim().to_lowercase();
+        if lemma.is_empty() {
+            bail!("lemma no puede ser vacio. Línea del CSV: {}", i + 1);
+        }
+
+        let rank = value.get(1).unwrap_or("").trim();
+        let rank = match rank.parse::<u32>() {
+            Ok(r) if r > 0 => r,
+            _ => bail!("rank no válido: {}. Línea del CSV: {}", rank, i + 1),
+        };
+
+        vec_result.push(NewWordFrequencySchema { lemma, rank });
+    }
+
+    Ok(vec_result)
+}
+
+/// Escribe las oraciones con el mismo formato que lee `extract_sentences_csv`.
+pub fn write_setze_csv<W: Write>(out: W, setze: &[SetzeSchema]) -> Result<()> {
+    let mut writer = Writer::from_writer(out);
+
+    let mut header = HEADER_SETZE_CSV.to_vec();
+    header.extend(HEADER_SETZE_OPTIONAL_CSV);
+    writer.write_record(&header)?;
+
+    for s in setze {
+        writer.write_record([
+            s.setze_spanisch.as_str(),
+            s.setze_deutsch.as_str(),
+            s.thema.as_str(),
+            s.schwirig_id.schwirig.as_str(),
+            s.niveau_id.niveau.as_str(),
+            &join_tags(&s.tags),
+        ])?;
+    }
+
+    writer.flush()?;
+    Ok(())
+}
+
+/// Escribe las palabras con el mismo formato que lee `extract_worte_csv`.
+pub fn write_worte_csv<W: Write>(out: W, worte: &[WorteSchema]) -> Result<()> {
+    let mut writer = Writer::from_writer(out);
+
+    let mut header = HEADER_WORTE_CSV.to_vec();
+    header.extend(HEADER_WORTE_OPTIONAL_CSV);
+    writer.write_record(&header)?;
+
+    let bool_2_str = |b: Option<bool>| match b {
+        Some(true) => "Si",
+        Some(false) => "No",
+        None => "",
+    };
+
+    for w in worte {
+        let gram_type = w
+            .gram_type_id
+            .iter()
+            .map(|g| g.code.as_str())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        writer.write_record([
+            gram_type.as_str(),
+            w.gender_id.as_ref().map_or("", |g| g.gender.as_str()),
+            w.worte_de.as_str(),
+            w.worte_es.as_str(),
+            w.plural.as_deref().unwrap_or(""),
+            w.niveau_id.niveau.as_str(),
+            w.example_de.as_str(),
+            w.example_es.as_str(),
+            w.verb_aux.as_deref().unwrap_or(""),
+            bool_2_str(w.trennbar),
+            bool_2_str(w.reflexiv),
+            &join_tags(&w.tags),
+            w.komparativ.as_deref().unwrap_or(""),
+            w.superlativ.as_deref().unwrap_or(""),
+        ])?;
+    }
+
+    writer.flush()?;
+    Ok(())
+}
+
+fn join_tags(tags: &[TagsSchema]) -> String {
+    tags.iter()
+        .map(|t| t.name.as_str())
+        .collect::<Vec<_>>()
+        .join(",")
+}
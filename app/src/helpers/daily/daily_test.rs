@@ -0,0 +1,80 @@
+#[cfg(test)]
+mod test_daily {
+    use std::collections::HashMap;
+
+    use crate::helpers::daily::{
+        DailyPlan, StudyKey::Satz, StudyKey::Wort, interleave, order_by_mature_worte,
+        order_setze_after_worte, wort_in_satz,
+    };
+
+    #[test]
+    fn interleave_respects_ratio_and_appends_rest() {
+        assert_eq!(
+            interleave(&[1, 2, 3, 4, 5], &[10, 11], 2, 1),
+            vec![
+                Wort(1),
+                Wort(2),
+                Satz(10),
+                Wort(3),
+                Wort(4),
+                Satz(11),
+                Wort(5)
+            ]
+        );
+
+        assert_eq!(
+            interleave(&[1], &[10, 11, 12], 1, 1),
+            vec![Wort(1), Satz(10), Satz(11), Satz(12)]
+        );
+
+        // Proporción 0: solo al final
+        assert_eq!(
+            interleave(&[1, 2], &[10], 0, 1),
+            vec![Satz(10), Wort(1), Wort(2)]
+        );
+    }
+
+    #[test]
+    fn plan_parses_ratio() {
+        let plan = DailyPlan::default().with_ratio(" 3 : 1 ").unwrap();
+        assert_eq!((plan.worte_ratio, plan.setze_ratio), (3, 1));
+        assert_eq!(plan.max_neue_worte, DailyPlan::default().max_neue_worte);
+
+        assert!(DailyPlan::default().with_ratio("3").is_none());
+        assert!(DailyPlan::default().with_ratio("a:1").is_none());
+        assert!(DailyPlan::default().with_ratio("0:0").is_none());
+    }
+
+    #[test]
+    fn wort_is_found_by_token() {
+        assert!(wort_in_satz("Hund", "Der Hund spielt im Garten."));
+        assert!(wort_in_satz(
+            "Hause",
+            "Ich bleibe zu hause, weil ich krank bin."
+        ));
+        assert!(!wort_in_satz("Hund", "Die Hunde spielen."));
+        assert!(!wort_in_satz("", "Die Hunde spielen."));
+    }
+
+    #[test]
+    fn setze_move_after_their_worte() {
+        let queue = vec![Satz(10), Wort(1), Wort(2), Satz(11), Wort(3)];
+        let contains: HashMap<i32, Vec<i32>> = [(10, vec![1, 2]), (11, vec![1])].into();
+
+        assert_eq!(
+            order_setze_after_worte(queue, &contains),
+            vec![Wort(1), Wort(2), Satz(10), Satz(11), Wort(3)]
+        );
+    }
+
+    #[test]
+    fn setze_with_mature_worte_go_first() {
+        let mature: HashMap<i32, f64> = [(11, 0.5), (12, 1.0), (13, 0.5)].into();
+
+        // Entre iguales se conserva el orden
+        assert_eq!(
+            order_by_mature_worte(vec![10, 13, 11, 12], &mature),
+            vec![12, 13, 11, 10]
+        );
+    }
+}
@@ -0,0 +1,218 @@
+use std::collections::{HashMap, HashSet};
+
+use color_eyre::eyre::Result;
+use rusqlite::Connection;
+
+use crate::{
+    db::{
+        schemas::{deck::DeckSettings, setze::SetzeSchema, worte::WorteSchema},
+        setze::SetzeRepo,
+        setze_review::SetzeReviewRepo,
+        worte::WorteRepo,
+        worte_review::WorteReviewRepo,
+        worte_setze::WorteSetzeRepo,
+    },
+    helpers::{deck, frequenz, niveau, scramble, time},
+};
+
+#[cfg(test)]
+mod daily_test;
+
+/// Identificador de una tarjeta dentro del estudio diario.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StudyKey {
+    Wort(i32),
+    Satz(i32),
+}
+
+/// Tarjeta del estudio diario: puede ser una palabra o una oración.
+#[derive(Debug, Clone)]
+pub enum StudyCard {
+    Wort(WorteSchema),
+    Satz(SetzeSchema),
+}
+
+/// Configuración para armar la cola del estudio diario.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DailyPlan {
+    /// Palabras seguidas antes de intercalar oraciones
+    pub worte_ratio: usize,
+    /// Oraciones seguidas antes de volver a las palabras
+    pub setze_ratio: usize,
+    pub max_neue_worte: usize,
+    pub max_neue_setze: usize,
+    /// Decks de los que se toman las tarjetas, `None` para todos
+    pub decks: Option<Vec<i32>>,
+}
+
+impl Default for DailyPlan {
+    fn default() -> Self {
+        Self {
+            worte_ratio: 2,
+            setze_ratio: 1,
+            max_neue_worte: 10,
+            max_neue_setze: 5,
+            decks: None,
+        }
+    }
+}
+
+impl DailyPlan {
+    /// Toma los límites de tarjetas nuevas del deck y limita la cola a `decks`.
+    pub fn with_deck(self, settings: &DeckSettings, decks: Option<Vec<i32>>) -> Self {
+        Self {
+            max_neue_worte: settings.max_neue_worte as usize,
+            max_neue_setze: settings.max_neue_setze as usize,
+            decks,
+            ..self
+        }
+    }
+
+    /// Cambia la proporción palabras:oraciones a partir de un texto como "3:1".
+    /// Regresa None si el texto no es válido.
+    pub fn with_ratio(self, input: &str) -> Option<Self> {
+        let (w, s) = input.trim().split_once(':')?;
+        let worte_ratio: usize = w.trim().parse().ok()?;
+        let setze_ratio: usize = s.trim().parse().ok()?;
+        if worte_ratio == 0 && setze_ratio == 0 {
+            return None;
+        }
+
+        Some(Self {
+            worte_ratio,
+            setze_ratio,
+            ..self
+        })
+    }
+}
+
+/// Intercala palabras y oraciones en la proporción indicada, respetando el orden de cada lista.
+///
+/// Si una de las listas se termina, el resto de la otra se agrega al final.
+pub fn interleave(
+    worte: &[i32],
+    setze: &[i32],
+    worte_ratio: usize,
+    setze_ratio: usize,
+) -> Vec<StudyKey> {
+    let mut out = Vec::with_capacity(worte.len() + setze.len());
+    let mut w = worte.iter();
+    let mut s = setze.iter();
+
+    loop {
+        let before = out.len();
+        out.extend(w.by_ref().take(worte_ratio).map(|id| StudyKey::Wort(*id)));
+        out.extend(s.by_ref().take(setze_ratio).map(|id| StudyKey::Satz(*id)));
+
+        if out.len() == before {
+            break;
+        }
+    }
+
+    // Con proporción 0 de un lado, lo que quede va al final
+    out.extend(w.map(|id| StudyKey::Wort(*id)));
+    out.extend(s.map(|id| StudyKey::Satz(*id)));
+    out
+}
+
+/// Indica si la palabra aparece dentro de la oración (ignorando mayúsculas y puntuación).
+pub fn wort_in_satz(wort_de: &str, satz_de: &str) -> bool {
+    let satz: HashSet<String> = scramble::tokenize(satz_de)
+        .iter()
+        .map(|t| t.to_lowercase())
+        .collect();
+
+    let wort = scramble::tokenize(wort_de);
+    !wort.is_empty() && wort.iter().all(|t| satz.contains(&t.to_lowercase()))
+}
+
+/// Mueve cada oración justo después de la última palabra que contiene, si esa palabra
+/// aparece más adelante en la cola.
+///
+/// `contains` relaciona satz_id -> wort_ids que aparecen en la oración.
+pub fn order_setze_after_worte(
+    mut queue: Vec<StudyKey>,
+    contains: &HashMap<i32, Vec<i32>>,
+) -> Vec<StudyKey> {
+    let setze: Vec<StudyKey> = queue
+        .iter()
+        .filter(|k| matches!(k, StudyKey::Satz(_)))
+        .copied()
+        .collect();
+
+    for key in setze {
+        let StudyKey::Satz(satz_id) = key else {
+            continue;
+        };
+        let Some(worte) = contains.get(&satz_id) else {
+            continue;
+        };
+
+        let pos = queue.iter().position(|k| *k == key).unwrap();
+        let last_wort = queue
+            .iter()
+            .rposition(|k| matches!(k, StudyKey::Wort(id) if worte.contains(id)));
+
+        if let Some(last) = last_wort.filter(|last| *last > pos) {
+            queue.remove(pos);
+            queue.insert(last, key);
+        }
+    }
+
+    queue
+}
+
+/// Ordena las oraciones nuevas poniendo primero las que tienen más vocabulario maduro, para
+/// que al estudiarlas solo haya que fijarse en lo nuevo. Con la misma proporción se respeta
+/// el orden recibido.
+///
+/// `mature` relaciona satz_id -> fracción de sus palabras que ya están maduras.
+pub fn order_by_mature_worte(mut ids: Vec<i32>, mature: &HashMap<i32, f64>) -> Vec<i32> {
+    let ratio = |id: &i32| mature.get(id).copied().unwrap_or(0.0);
+    ids.sort_by(|a, b| ratio(b).total_cmp(&ratio(a)));
+    ids
+}
+
+/// Arma la cola del estudio diario: palabras y oraciones por repasar, más un número limitado
+/// de tarjetas nuevas, intercaladas según `plan`.
+pub fn build_queue(conn: &Connection, plan: &DailyPlan) -> Result<Vec<StudyKey>> {
+    let date_review = time::today_local_string(1);
+
+    let decks = plan.decks.as_deref();
+
+    let ids_worte = WorteReviewRepo::fetch_review_wort_id_by_day(conn, date_review.clone())?;
+    let mut ids_worte = deck::filter_worte(conn, ids_worte, decks)?;
+    let neue_worte = deck::filter_worte(conn, WorteRepo::fetch_id_neue_worte(conn)?, decks)?;
+    let neue_worte = frequenz::order_neue_worte(conn, neue_worte)?;
+    ids_worte.extend(
+        niveau::unlock_worte(conn, neue_worte, decks)?
+            .into_iter()
+            .take(plan.max_neue_worte),
+    );
+
+    let ids_setze = SetzeReviewRepo::fetch_review_satz_id_by_day(conn, date_review)?;
+    let mut ids_setze = deck::filter_setze(conn, ids_setze, decks)?;
+    let neue_setze = deck::filter_setze(conn, SetzeRepo::fetch_id_neue_sentences(conn)?, decks)?;
+    let mature = WorteSetzeRepo::fetch_mature_ratio(conn, &neue_setze)?;
+    let neue_setze = order_by_mature_worte(neue_setze, &mature);
+    ids_setze.extend(
+        niveau::unlock_setze(conn, neue_setze, decks)?
+            .into_iter()
+            .take(plan.max_neue_setze),
+    );
+
+    let worte = WorteRepo::fetch_by_id(conn, &ids_worte)?;
+    let setze = SetzeRepo::fetch_by_id(conn, &ids_setze)?;
+
+    let mut contains: HashMap<i32, Vec<i32>> = HashMap::new();
+    for s in &setze {
+        for w in &worte {
+            if wort_in_satz(&w.worte_de, &s.setze_deutsch) {
+                contains.entry(s.id).or_default().push(w.id);
+            }
+        }
+    }
+
+    let queue = interleave(&ids_worte, &ids_setze, plan.worte_ratio, plan.setze_ratio);
+    Ok(order_setze_after_worte(queue, &contains))
+}
@@ -0,0 +1,67 @@
+#[cfg(test)]
+mod test_deck {
+    use crate::{
+        db::{
+            deck::DeckRepo, raw::deck::normalize_deck_path, schemas::worte::NewWorteSchema,
+            seeders::init_data, setup_test_db, worte::WorteRepo,
+        },
+        helpers::deck::{display_name, filter_worte, find_deck},
+    };
+
+    #[test]
+    fn normalize_deck_path_trims_levels() {
+        assert_eq!(normalize_deck_path("  A :: B ::"), "A::B");
+        assert_eq!(normalize_deck_path("A::::B"), "A::B");
+        assert_eq!(normalize_deck_path(" :: "), "");
+    }
+
+    #[test]
+    fn filter_worte_keeps_order() {
+        let mut conn = setup_test_db().expect("Error al crear db test");
+        init_data(&mut conn).expect("Error al iniciar datos dummy");
+
+        let deck = DeckRepo::ensure_path(&mut conn, "Goethe B1::Kapitel 4").unwrap();
+        assert_eq!(display_name(&deck), "  Kapitel 4");
+
+        let data: Vec<NewWorteSchema> = [1, deck.id, deck.id]
+            .into_iter()
+            .map(|deck_id| NewWorteSchema {
+                gram_type: vec![0],
+                tags: vec![],
+                gender_id: None,
+                worte_de: "Hund".into(),
+                worte_es: "Perro".into(),
+                plural: None,
+                niveau_id: 0,
+                example_de: "Beispiel".into(),
+                example_es: "Ejemplo".into(),
+                verb_aux: None,
+                trennbar: None,
+                reflexiv: None,
+                komparativ: None,
+                superlativ: None,
+                deck_id,
+            })
+            .collect();
+        WorteRepo::bulk_insert(&mut conn, &data).unwrap();
+
+        let ids = filter_worte(&conn, vec![3, 1, 2], Some(&[deck.id])).unwrap();
+        assert_eq!(ids, vec![3, 2]);
+
+        // Sin decks no se filtra
+        let ids = filter_worte(&conn, vec![3, 1, 2], None).unwrap();
+        assert_eq!(ids, vec![3, 1, 2]);
+    }
+
+    #[test]
+    fn find_deck_ignores_case() {
+        let mut conn = setup_test_db().expect("Error al crear db test");
+        init_data(&mut conn).expect("Error al iniciar datos dummy");
+
+        let deck = DeckRepo::ensure_path(&mut conn, "Goethe B1::Kapitel 4").unwrap();
+
+        let found = find_deck(&conn, " goethe b1 :: KAPITEL 4").unwrap();
+        assert_eq!(found.map(|d| d.id), Some(deck.id));
+        assert!(find_deck(&conn, "Goethe B2").unwrap().is_none());
+    }
+}
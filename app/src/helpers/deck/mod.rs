@@ -0,0 +1,157 @@
+use std::{collections::HashSet, sync::Mutex};
+
+use color_eyre::eyre::Result;
+use inquire::Select;
+use once_cell::sync::Lazy;
+use rusqlite::Connection;
+
+use crate::{
+    db::{
+        deck::DeckRepo,
+        raw::deck::normalize_deck_path,
+        schemas::deck::{DEFAULT_DECK_ID, DeckSchema, DeckSettings},
+        setze::SetzeRepo,
+        worte::WorteRepo,
+    },
+    helpers::ui,
+};
+
+#[cfg(test)]
+mod deck_test;
+
+/// Deck con el que se practica. `None` practica con todos los decks.
+static ACTIVE_DECK: Lazy<Mutex<Option<i32>>> = Lazy::new(|| Mutex::new(None));
+
+const OPTION_NEW_DECK: &str = "+ Nuevo deck";
+
+pub fn active_deck() -> Option<i32> {
+    *ACTIVE_DECK.lock().expect("Mutex envenenado en ACTIVE_DECK")
+}
+
+pub fn set_active_deck(id: Option<i32>) {
+    *ACTIVE_DECK.lock().expect("Mutex envenenado en ACTIVE_DECK") = id;
+}
+
+/// Busca un deck existente por su ruta, ejem: "Goethe B1::Kapitel 4". Sin importar
+/// mayúsculas, igual que el `COLLATE NOCASE` de `deck.name`.
+pub fn find_deck(conn: &Connection, path: &str) -> Result<Option<DeckSchema>> {
+    let path = normalize_deck_path(path);
+    Ok(DeckRepo::fetch_all(conn)?
+        .into_iter()
+        .find(|d| d.name.eq_ignore_ascii_case(&path)))
+}
+
+/// Nombre del deck activo para mostrarlo en los menús.
+pub fn active_deck_name(conn: &Connection) -> Result<String> {
+    let name = match active_deck() {
+        Some(id) => DeckRepo::fetch_by_id(conn, &[id])?.pop().map(|d| d.name),
+        None => None,
+    };
+    Ok(name.unwrap_or_else(|| "Todos".into()))
+}
+
+/// Ids del deck activo y sus subdecks; `None` si no hay deck activo.
+pub fn active_scope(conn: &Connection) -> Result<Option<Vec<i32>>> {
+    match active_deck() {
+        Some(id) => Ok(Some(DeckRepo::fetch_subtree_ids(conn, id)?)),
+        None => Ok(None),
+    }
+}
+
+/// Configuración del deck activo, o la de por defecto si se practica con todos.
+pub fn active_settings(conn: &Connection) -> Result<DeckSettings> {
+    let deck = match active_deck() {
+        Some(id) => DeckRepo::fetch_by_id(conn, &[id])?.pop(),
+        None => None,
+    };
+    Ok(deck.map(|d| d.settings()).unwrap_or_default())
+}
+
+/// Deja solo las palabras del deck activo, respetando el orden.
+pub fn scope_worte(conn: &Connection, ids: Vec<i32>) -> Result<Vec<i32>> {
+    filter_worte(conn, ids, active_scope(conn)?.as_deref())
+}
+
+/// Deja solo las oraciones del deck activo, respetando el orden.
+pub fn scope_setze(conn: &Connection, ids: Vec<i32>) -> Result<Vec<i32>> {
+    filter_setze(conn, ids, active_scope(conn)?.as_deref())
+}
+
+/// Deja solo las palabras de `decks`, respetando el orden. Sin decks no filtra.
+pub fn filter_worte(conn: &Connection, ids: Vec<i32>, decks: Option<&[i32]>) -> Result<Vec<i32>> {
+    let Some(decks) = decks else {
+        return Ok(ids);
+    };
+    let keep: HashSet<i32> = WorteRepo::fetch_id_in_decks(conn, &ids, decks)?
+        .into_iter()
+        .collect();
+    Ok(retain_ids(ids, &keep))
+}
+
+/// Deja solo las oraciones de `decks`, respetando el orden. Sin decks no filtra.
+pub fn filter_setze(conn: &Connection, ids: Vec<i32>, decks: Option<&[i32]>) -> Result<Vec<i32>> {
+    let Some(decks) = decks else {
+        return Ok(ids);
+    };
+    let keep: HashSet<i32> = SetzeRepo::fetch_id_in_decks(conn, &ids, decks)?
+        .into_iter()
+        .collect();
+    Ok(retain_ids(ids, &keep))
+}
+
+fn retain_ids(mut ids: Vec<i32>, keep: &HashSet<i32>) -> Vec<i32> {
+    ids.retain(|id| keep.contains(id));
+    ids
+}
+
+/// Nombre con sangría según el nivel: "  Kapitel 4".
+pub fn display_name(deck: &DeckSchema) -> String {
+    format!("{}{}", "  ".repeat(deck.depth()), deck.short_name())
+}
+
+/// Pide un deck de la lista o el nombre de uno nuevo ("Goethe B1::Kapitel 4").
+/// Regresa `None` si el usuario cancela.
+pub fn prompt_deck(conn: &mut Connection, title: &str) -> Result<Option<DeckSchema>> {
+    let decks = DeckRepo::fetch_all(conn)?;
+
+    let mut options: Vec<String> = decks
+        .iter()
+        .map(|d| format!("{} - {}", d.id, d.name))
+        .collect();
+    options.push(OPTION_NEW_DECK.into());
+
+    let start = decks
+        .iter()
+        .position(|d| Some(d.id) == active_deck())
+        .or_else(|| decks.iter().position(|d| d.id == DEFAULT_DECK_ID))
+        .unwrap_or(0);
+
+    let Some(ans) = Select::new(title, options)
+        .with_starting_cursor(start)
+        .with_page_size(20)
+        .prompt_skippable()?
+    else {
+        return Ok(None);
+    };
+
+    if ans != OPTION_NEW_DECK {
+        let id = ans
+            .split(" - ")
+            .next()
+            .and_then(|id| id.parse::<i32>().ok());
+        return Ok(decks.into_iter().find(|d| Some(d.id) == id));
+    }
+
+    println!("Nombre del deck, usa \"::\" para subdecks. Ejem: Goethe B1::Kapitel 4");
+    let Some(path) = ui::prompt_nonempty("> ")? else {
+        return Ok(None);
+    };
+
+    match DeckRepo::ensure_path(conn, &path) {
+        Ok(deck) => Ok(Some(deck)),
+        Err(err) => {
+            println!("No se pudo crear el deck: {}", err);
+            Ok(None)
+        }
+    }
+}
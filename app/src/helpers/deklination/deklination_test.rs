@@ -0,0 +1,299 @@
+use crate::db::setup_test_db;
+
+#[cfg(test)]
+mod test_deklination {
+    use chrono::Utc;
+    use rusqlite::Connection;
+
+    use crate::{
+        db::{
+            schemas::{
+                niveau_liste::NiveauListeSchema,
+                worte::{NewWorteSchema, WorteSchema},
+                worte_gender::WorteGenderSchema,
+            },
+            seeders::init_data,
+            worte::WorteRepo,
+        },
+        helpers::{
+            deklination::{
+                Artikel, DeklinationCard, Kasus, build_drill, decline, decline_all, is_weak_noun,
+            },
+            session::SessionCard,
+        },
+    };
+
+    use super::*;
+
+    fn noun(gender: &str, worte_de: &str, plural: Option<&str>) -> WorteSchema {
+        WorteSchema {
+            id: 1,
+            gram_type_id: vec![],
+            tags: vec![],
+            gender_id: Some(WorteGenderSchema {
+                id: 0,
+                gender: gender.into(),
+                artikel: "".into(),
+                created_at: Utc::now(),
+                deleted_at: None,
+            }),
+            worte_de: worte_de.into(),
+            worte_es: "".into(),
+            plural: plural.map(|p| p.into()),
+            niveau_id: NiveauListeSchema {
+                id: 0,
+                niveau: "A1".into(),
+                created_at: Utc::now(),
+                deleted_at: None,
+            },
+            deck_id: 1,
+            example_de: "".into(),
+            example_es: "".into(),
+            verb_aux: None,
+            trennbar: None,
+            reflexiv: None,
+            komparativ: None,
+            superlativ: None,
+            created_at: Utc::now(),
+            deleted_at: None,
+        }
+    }
+
+    fn forms(
+        noun: &WorteSchema,
+        adjective: Option<&str>,
+        artikel: Artikel,
+        plural: bool,
+    ) -> Vec<String> {
+        decline_all(noun, adjective, artikel, plural)
+            .into_iter()
+            .map(|d| d.form)
+            .collect()
+    }
+
+    #[test]
+    fn test_articles_and_adjectives() {
+        let hund = noun("Maskuline", "Hund", Some("Hunde"));
+        assert_eq!(
+            forms(&hund, Some("alt"), Artikel::Bestimmt, false),
+            vec![
+                "der alte Hund",
+                "den alten Hund",
+                "dem alten Hund",
+                "des alten Hundes"
+            ]
+        );
+        assert_eq!(
+            forms(&hund, Some("alt"), Artikel::Bestimmt, true),
+            vec![
+                "die alten Hunde",
+                "die alten Hunde",
+                "den alten Hunden",
+                "der alten Hunde"
+            ]
+        );
+
+        let frau = noun("Femenin", "die Frau", Some("Frauen"));
+        assert_eq!(
+            forms(&frau, Some("schön"), Artikel::Unbestimmt, false),
+            vec![
+                "eine schöne Frau",
+                "eine schöne Frau",
+                "einer schönen Frau",
+                "einer schönen Frau"
+            ]
+        );
+
+        let kind = noun("Neutrum", "Kind", Some("Kinder"));
+        assert_eq!(
+            forms(&kind, Some("klein"), Artikel::Ohne, false),
+            vec![
+                "kleines Kind",
+                "kleines Kind",
+                "kleinem Kind",
+                "kleinen Kindes"
+            ]
+        );
+        assert_eq!(
+            forms(&kind, Some("klein"), Artikel::Kein, true),
+            vec![
+                "keine kleinen Kinder",
+                "keine kleinen Kinder",
+                "keinen kleinen Kindern",
+                "keiner kleinen Kinder"
+            ]
+        );
+        assert_eq!(
+            forms(&kind, None, Artikel::Unbestimmt, false),
+            vec!["ein Kind", "ein Kind", "einem Kind", "eines Kindes"]
+        );
+
+        // Raíces de adjetivo que cambian
+        let zimmer = noun("Neutrum", "Zimmer", Some("Zimmer"));
+        let nom = |adjective| {
+            decline(
+                &zimmer,
+                Some(adjective),
+                Artikel::Bestimmt,
+                false,
+                Kasus::Nominativ,
+            )
+            .unwrap()
+            .form
+        };
+        assert_eq!(nom("dunkel"), "das dunkle Zimmer");
+        assert_eq!(nom("teuer"), "das teure Zimmer");
+        assert_eq!(nom("hoch"), "das hohe Zimmer");
+        assert_eq!(nom("hell"), "das helle Zimmer");
+        assert_eq!(nom("leise"), "das leise Zimmer");
+    }
+
+    #[test]
+    fn test_nouns() {
+        let genitiv = |gender, worte_de| {
+            decline(
+                &noun(gender, worte_de, None),
+                None,
+                Artikel::Bestimmt,
+                false,
+                Kasus::Genitiv,
+            )
+            .unwrap()
+            .form
+        };
+        assert_eq!(genitiv("Maskuline", "Vater"), "des Vaters");
+        assert_eq!(genitiv("Neutrum", "Haus"), "des Hauses");
+        assert_eq!(genitiv("Neutrum", "Ergebnis"), "des Ergebnisses");
+        assert_eq!(genitiv("Maskuline", "Moment"), "des Moments");
+        assert_eq!(genitiv("Maskuline", "Name"), "des Namens");
+        assert_eq!(genitiv("Femenin", "Arbeitszeit"), "der Arbeitszeit");
+
+        // n-Deklination
+        assert!(is_weak_noun("Student") && is_weak_noun("der Junge") && is_weak_noun("Herr"));
+        assert!(!is_weak_noun("Monat") && !is_weak_noun("Hund"));
+        let student = noun("Maskuline", "Student", Some("Studenten"));
+        assert_eq!(
+            forms(&student, None, Artikel::Bestimmt, false),
+            vec![
+                "der Student",
+                "den Studenten",
+                "dem Studenten",
+                "des Studenten"
+            ]
+        );
+        let herr = noun("Maskuline", "Herr", None);
+        assert_eq!(
+            decline(&herr, None, Artikel::Bestimmt, false, Kasus::Dativ)
+                .unwrap()
+                .form,
+            "dem Herrn"
+        );
+        let nachbar = noun("Maskuline", "Nachbar", None);
+        assert_eq!(
+            decline(&nachbar, None, Artikel::Unbestimmt, false, Kasus::Akkusativ)
+                .unwrap()
+                .form,
+            "einen Nachbarn"
+        );
+
+        // Sin plural guardado, "ein" en plural o sin género no hay forma
+        assert!(decline(&herr, None, Artikel::Bestimmt, true, Kasus::Nominativ).is_none());
+        assert!(decline(&student, None, Artikel::Unbestimmt, true, Kasus::Nominativ).is_none());
+        let leute = noun("Plural", "Leute", None);
+        assert_eq!(
+            decline(&leute, None, Artikel::Bestimmt, false, Kasus::Dativ)
+                .unwrap()
+                .form,
+            "den Leuten"
+        );
+        let mut sin_genero = noun("Maskuline", "schnell", None);
+        sin_genero.gender_id = None;
+        assert!(decline_all(&sin_genero, None, Artikel::Bestimmt, false).is_empty());
+    }
+
+    #[test]
+    fn test_rule() {
+        let mann = noun("Maskuline", "Mann", Some("Männer"));
+        let rule = |artikel, kasus| {
+            decline(&mann, Some("alt"), artikel, false, kasus)
+                .unwrap()
+                .rule
+        };
+
+        let r = rule(Artikel::Unbestimmt, Kasus::Nominativ);
+        assert!(
+            r.starts_with("Nominativ masculino con artículo indefinido: «ein»."),
+            "{}",
+            r
+        );
+        assert!(r.contains("«ein» no muestra el género, así que el adjetivo lo marca con -er."));
+
+        let r = rule(Artikel::Bestimmt, Kasus::Genitiv);
+        assert!(r.contains("terminación débil -en"), "{}", r);
+        assert!(
+            r.contains("En genitivo el sustantivo masculino o neutro añade -s"),
+            "{}",
+            r
+        );
+
+        let r = rule(Artikel::Ohne, Kasus::Genitiv);
+        assert!(r.contains("el adjetivo toma -en"), "{}", r);
+    }
+
+    fn init_data_local(conn: &mut Connection) {
+        init_data(conn).unwrap();
+        let wort =
+            |gram_type: i32, gender_id: Option<i32>, worte_de: &str, plural: Option<&str>| {
+                NewWorteSchema {
+                    gram_type: vec![gram_type],
+                    tags: vec![],
+                    gender_id,
+                    worte_de: worte_de.into(),
+                    worte_es: "".into(),
+                    plural: plural.map(|p| p.into()),
+                    niveau_id: 1,
+                    example_de: "".into(),
+                    example_es: "".into(),
+                    verb_aux: None,
+                    trennbar: None,
+                    reflexiv: None,
+                    komparativ: None,
+                    superlativ: None,
+                    deck_id: 1,
+                }
+            };
+        WorteRepo::bulk_insert(
+            conn,
+            &[
+                wort(0, Some(0), "Hund", Some("Hunde")),
+                wort(7, None, "alt", None),
+                wort(0, Some(1), "Katze", Some("Katzen")),
+                wort(2, None, "gehen", None),
+                wort(7, None, "rosa", None),
+            ],
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_build_drill() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+
+        let keys = build_drill(&conn, None, 10).unwrap();
+        assert!(!keys.is_empty() && keys.len() <= 10);
+        for (i, key) in keys.iter().enumerate() {
+            assert!([1, 3].contains(&key.noun_id));
+            // "rosa" no se declina
+            assert!(key.adjective_id.is_none_or(|id| id == 2));
+            assert!(!keys[..i].contains(key));
+        }
+
+        let cards = DeklinationCard::fetch(&conn, &keys).unwrap();
+        assert_eq!(cards.len(), keys.len());
+        assert_eq!(cards[0].key(), keys[0]);
+        assert_eq!(cards[0].normalize(" der  alte Hund "), "der alte Hund");
+
+        assert!(build_drill(&conn, Some(&[99]), 10).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,586 @@
+use std::collections::HashMap;
+
+use color_eyre::eyre::Result;
+use rand::{Rng, seq::IndexedRandom};
+use rusqlite::Connection;
+
+use crate::{
+    db::{schemas::worte::WorteSchema, worte::WorteRepo},
+    helpers::{
+        deck,
+        fehler::{Art, Quelle},
+        lemma,
+        session::SessionCard,
+    },
+};
+
+#[cfg(test)]
+mod deklination_test;
+
+/// Sustantivos masculinos de la n-Deklination que no se reconocen por su terminación.
+const WEAK_NOUNS: [&str; 10] = [
+    "Mensch", "Herr", "Held", "Bär", "Nachbar", "Bauer", "Prinz", "Graf", "Fürst", "Narr",
+];
+
+/// Terminaciones de los masculinos de la n-Deklination: "Student", "Polizist", "Junge".
+const WEAK_SUFFIXES: [&str; 5] = ["ent", "ant", "ist", "at", "e"];
+
+/// Masculinos con esas terminaciones que se declinan normal.
+const NOT_WEAK_NOUNS: [&str; 8] = [
+    "Moment",
+    "Monat",
+    "Salat",
+    "Apparat",
+    "Käse",
+    "Kontinent",
+    "Akzent",
+    "Zement",
+];
+
+/// Los que en genitivo llevan -ns: "des Namens".
+const WEAK_NOUNS_NS: [&str; 6] = ["Name", "Gedanke", "Buchstabe", "Glaube", "Wille", "Friede"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Kasus {
+    Nominativ,
+    Akkusativ,
+    Dativ,
+    Genitiv,
+}
+
+impl Kasus {
+    pub const ALL: [Kasus; 4] = [
+        Kasus::Nominativ,
+        Kasus::Akkusativ,
+        Kasus::Dativ,
+        Kasus::Genitiv,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Kasus::Nominativ => "Nominativ",
+            Kasus::Akkusativ => "Akkusativ",
+            Kasus::Dativ => "Dativ",
+            Kasus::Genitiv => "Genitiv",
+        }
+    }
+
+    fn index(&self) -> usize {
+        Self::ALL.iter().position(|k| k == self).unwrap()
+    }
+}
+
+/// Género del grupo nominal; en plural los tres géneros se declinan igual.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Genus {
+    Maskulin,
+    Feminin,
+    Neutrum,
+    Plural,
+}
+
+impl Genus {
+    /// Género de la palabra según la tabla worte_gender.
+    pub fn from_wort(wort: &WorteSchema) -> Option<Self> {
+        match wort.gender_id.as_ref()?.gender.as_str() {
+            "Maskuline" => Some(Genus::Maskulin),
+            "Femenin" => Some(Genus::Feminin),
+            "Neutrum" => Some(Genus::Neutrum),
+            "Plural" => Some(Genus::Plural),
+            _ => None,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Genus::Maskulin => "masculino",
+            Genus::Feminin => "femenino",
+            Genus::Neutrum => "neutro",
+            Genus::Plural => "plural",
+        }
+    }
+
+    fn index(&self) -> usize {
+        match self {
+            Genus::Maskulin => 0,
+            Genus::Feminin => 1,
+            Genus::Neutrum => 2,
+            Genus::Plural => 3,
+        }
+    }
+}
+
+/// Tipo de determinante delante del adjetivo y el sustantivo.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Artikel {
+    /// der, die, das
+    Bestimmt,
+    /// ein, eine; no tiene plural
+    Unbestimmt,
+    /// kein, keine
+    Kein,
+    /// Sin determinante: "kaltes Wasser"
+    Ohne,
+}
+
+impl Artikel {
+    pub const ALL: [Artikel; 4] = [
+        Artikel::Bestimmt,
+        Artikel::Unbestimmt,
+        Artikel::Kein,
+        Artikel::Ohne,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Artikel::Bestimmt => "artículo definido",
+            Artikel::Unbestimmt => "artículo indefinido",
+            Artikel::Kein => "kein",
+            Artikel::Ohne => "sin artículo",
+        }
+    }
+
+    /// Artículo declinado; vacío sin determinante. `None` si no existe (ein en plural).
+    pub fn form(&self, genus: Genus, kasus: Kasus) -> Option<String> {
+        const BESTIMMT: [[&str; 4]; 4] = [
+            ["der", "den", "dem", "des"],
+            ["die", "die", "der", "der"],
+            ["das", "das", "dem", "des"],
+            ["die", "die", "den", "der"],
+        ];
+        const EIN: [[&str; 4]; 4] = [
+            ["", "en", "em", "es"],
+            ["e", "e", "er", "er"],
+            ["", "", "em", "es"],
+            ["e", "e", "en", "er"],
+        ];
+
+        let (g, k) = (genus.index(), kasus.index());
+        match self {
+            Artikel::Bestimmt => Some(BESTIMMT[g][k].to_string()),
+            Artikel::Unbestimmt if genus == Genus::Plural => None,
+            Artikel::Unbestimmt => Some(format!("ein{}", EIN[g][k])),
+            Artikel::Kein => Some(format!("kein{}", EIN[g][k])),
+            Artikel::Ohne => Some(String::new()),
+        }
+    }
+}
+
+/// Declinación del adjetivo según lo que muestre el determinante.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Adjektiv {
+    /// Después de der/die/das
+    Schwach,
+    /// Después de ein/kein
+    Gemischt,
+    /// Sin determinante
+    Stark,
+}
+
+impl Adjektiv {
+    fn from_artikel(artikel: Artikel) -> Self {
+        match artikel {
+            Artikel::Bestimmt => Adjektiv::Schwach,
+            Artikel::Unbestimmt | Artikel::Kein => Adjektiv::Gemischt,
+            Artikel::Ohne => Adjektiv::Stark,
+        }
+    }
+
+    fn ending(&self, genus: Genus, kasus: Kasus) -> &'static str {
+        const SCHWACH: [[&str; 4]; 4] = [
+            ["e", "en", "en", "en"],
+            ["e", "e", "en", "en"],
+            ["e", "e", "en", "en"],
+            ["en", "en", "en", "en"],
+        ];
+        const GEMISCHT: [[&str; 4]; 4] = [
+            ["er", "en", "en", "en"],
+            ["e", "e", "en", "en"],
+            ["es", "es", "en", "en"],
+            ["en", "en", "en", "en"],
+        ];
+        const STARK: [[&str; 4]; 4] = [
+            ["er", "en", "em", "en"],
+            ["e", "e", "er", "er"],
+            ["es", "es", "em", "en"],
+            ["e", "e", "en", "er"],
+        ];
+
+        let (g, k) = (genus.index(), kasus.index());
+        match self {
+            Adjektiv::Schwach => SCHWACH[g][k],
+            Adjektiv::Gemischt => GEMISCHT[g][k],
+            Adjektiv::Stark => STARK[g][k],
+        }
+    }
+}
+
+/// Grupo nominal declinado con la explicación de sus terminaciones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deklination {
+    pub kasus: Kasus,
+    pub genus: Genus,
+    pub artikel: Artikel,
+    /// "dem alten Hund"
+    pub form: String,
+    pub rule: String,
+}
+
+/// Declina el sustantivo (con el adjetivo si se indica) en el caso pedido.
+///
+/// `None` si la palabra no tiene género, si se pide el plural y no hay plural guardado, o si
+/// la combinación no existe ("ein" en plural). Los sustantivos que solo tienen plural se
+/// declinan siempre en plural.
+pub fn decline(
+    noun: &WorteSchema,
+    adjective: Option<&str>,
+    artikel: Artikel,
+    plural: bool,
+    kasus: Kasus,
+) -> Option<Deklination> {
+    let genus_wort = Genus::from_wort(noun)?;
+    let (genus, base) = if genus_wort == Genus::Plural {
+        (Genus::Plural, lemma::base(&noun.worte_de))
+    } else if plural {
+        let plural = noun
+            .plural
+            .as_deref()
+            .map(str::trim)
+            .filter(|p| !p.is_empty())?;
+        (Genus::Plural, lemma::base(plural))
+    } else {
+        (genus_wort, lemma::base(&noun.worte_de))
+    };
+
+    let art = artikel.form(genus, kasus)?;
+    let (noun_form, noun_rule) = noun_form(base, genus_wort, genus, kasus);
+
+    let mut rule = vec![match artikel {
+        Artikel::Ohne => format!("{} {} {}.", kasus.label(), genus.label(), artikel.label()),
+        _ => format!(
+            "{} {} con {}: «{}».",
+            kasus.label(),
+            genus.label(),
+            artikel.label(),
+            art
+        ),
+    }];
+
+    let mut words = vec![];
+    if !art.is_empty() {
+        words.push(art.clone());
+    }
+    if let Some(adjective) = adjective.map(str::trim).filter(|a| !a.is_empty()) {
+        let declension = Adjektiv::from_artikel(artikel);
+        let ending = declension.ending(genus, kasus);
+        words.push(format!("{}{}", adjective_stem(adjective), ending));
+        rule.push(adjective_rule(declension, genus, kasus, &art, ending));
+    }
+    words.push(noun_form);
+    rule.extend(noun_rule);
+
+    Some(Deklination {
+        kasus,
+        genus,
+        artikel,
+        form: words.join(" "),
+        rule: rule.join(" "),
+    })
+}
+
+/// Los cuatro casos del grupo nominal.
+pub fn decline_all(
+    noun: &WorteSchema,
+    adjective: Option<&str>,
+    artikel: Artikel,
+    plural: bool,
+) -> Vec<Deklination> {
+    Kasus::ALL
+        .into_iter()
+        .filter_map(|kasus| decline(noun, adjective, artikel, plural, kasus))
+        .collect()
+}
+
+/// Indica si el masculino es de la n-Deklination ("Student" -> "den Studenten").
+pub fn is_weak_noun(noun: &str) -> bool {
+    let noun = lemma::base(noun);
+    WEAK_NOUNS.contains(&noun)
+        || WEAK_NOUNS_NS.contains(&noun)
+        || (noun.chars().count() > 4
+            && WEAK_SUFFIXES.iter().any(|s| noun.ends_with(s))
+            && !NOT_WEAK_NOUNS.iter().any(|n| noun.ends_with(n)))
+}
+
+/// Forma del sustantivo y, si cambia, la regla que lo explica.
+fn noun_form(
+    base: &str,
+    genus_wort: Genus,
+    genus: Genus,
+    kasus: Kasus,
+) -> (String, Option<String>) {
+    if genus == Genus::Plural {
+        if kasus == Kasus::Dativ && !base.ends_with(['n', 's']) {
+            return (
+                format!("{}n", base),
+                Some(
+                    "En dativo plural el sustantivo añade -n (salvo si ya termina en -n o -s)."
+                        .into(),
+                ),
+            );
+        }
+        return (base.to_string(), None);
+    }
+
+    if genus_wort == Genus::Maskulin && is_weak_noun(base) {
+        if kasus == Kasus::Nominativ {
+            return (base.to_string(), None);
+        }
+        let ending = match (base, kasus) {
+            (b, Kasus::Genitiv) if WEAK_NOUNS_NS.contains(&b) => "ns",
+            ("Herr" | "Nachbar", _) => "n",
+            (b, _) if b.ends_with('e') || b.ends_with("er") => "n",
+            _ => "en",
+        };
+        return (
+            format!("{}{}", base, ending),
+            Some(format!(
+                "«{}» es de la n-Deklination: fuera del nominativo singular añade -{}.",
+                base, ending
+            )),
+        );
+    }
+
+    if kasus == Kasus::Genitiv && genus_wort != Genus::Feminin {
+        let form = if base.ends_with("nis") {
+            format!("{}ses", base)
+        } else if base.ends_with(['s', 'ß', 'x', 'z'])
+            || base.ends_with("sch")
+            || syllables(base) == 1
+        {
+            format!("{}es", base)
+        } else {
+            format!("{}s", base)
+        };
+        return (
+            form,
+            Some(
+                "En genitivo el sustantivo masculino o neutro añade -s (-es si tiene una sílaba o \
+                 termina en s, ß, x, z, sch)."
+                    .into(),
+            ),
+        );
+    }
+
+    (base.to_string(), None)
+}
+
+fn adjective_rule(
+    declension: Adjektiv,
+    genus: Genus,
+    kasus: Kasus,
+    art: &str,
+    ending: &str,
+) -> String {
+    match declension {
+        Adjektiv::Schwach => format!(
+            "Tras el artículo definido el adjetivo lleva la terminación débil -{}: -e solo en \
+             nominativo singular y en acusativo femenino y neutro, -en en todo lo demás.",
+            ending
+        ),
+        Adjektiv::Gemischt if ending == "er" || ending == "es" => format!(
+            "«{}» no muestra el género, así que el adjetivo lo marca con -{}.",
+            art, ending
+        ),
+        Adjektiv::Gemischt => format!(
+            "«{}» ya muestra el caso, así que el adjetivo lleva -{} como en la declinación débil.",
+            art, ending
+        ),
+        Adjektiv::Stark
+            if kasus == Kasus::Genitiv && matches!(genus, Genus::Maskulin | Genus::Neutrum) =>
+        {
+            "Sin artículo el adjetivo toma la terminación del artículo definido, excepto en \
+             genitivo masculino y neutro: el sustantivo ya lleva -s y el adjetivo toma -en."
+                .into()
+        }
+        Adjektiv::Stark => format!(
+            "Sin artículo el adjetivo toma la terminación del artículo definido \
+             (der -> -er, dem -> -em): -{}.",
+            ending
+        ),
+    }
+}
+
+/// Sílabas aproximadas: grupos de vocales ("Haus" 1, "Vater" 2).
+fn syllables(word: &str) -> usize {
+    let vowel = |c: char| c.to_lowercase().any(|c| "aeiouäöüy".contains(c));
+    let chars: Vec<char> = word.chars().collect();
+    chars
+        .iter()
+        .enumerate()
+        .filter(|(i, c)| vowel(**c) && (*i == 0 || !vowel(chars[i - 1])))
+        .count()
+}
+
+/// Raíz del adjetivo a la que se le agrega la terminación: "dunkel" -> "dunkl",
+/// "teuer" -> "teur", "hoch" -> "hoh", "leise" -> "leis".
+fn adjective_stem(adjective: &str) -> String {
+    if adjective == "hoch" {
+        return "hoh".into();
+    }
+    // "hell" y "viel" no pierden la e
+    if let Some(root) = adjective.strip_suffix("el")
+        && root.contains(['a', 'e', 'i', 'o', 'u', 'ä', 'ö', 'ü'])
+        && !root.ends_with(['a', 'e', 'i', 'o', 'u'])
+    {
+        return format!("{}l", root);
+    }
+    if let Some(root) = adjective
+        .strip_suffix("euer")
+        .or_else(|| adjective.strip_suffix("auer"))
+    {
+        let diphthong = &adjective[root.len()..adjective.len() - 2];
+        return format!("{}{}r", root, diphthong);
+    }
+    adjective.strip_suffix('e').unwrap_or(adjective).to_string()
+}
+
+/// Indica si el adjetivo se puede declinar (una sola palabra).
+fn is_declinable(adjective: &WorteSchema) -> bool {
+    let a = adjective.worte_de.trim();
+    !a.is_empty() && !a.contains(char::is_whitespace) && !["lila", "rosa", "prima"].contains(&a)
+}
+
+/// Grupo nominal que se pregunta en el drill.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DeklinationKey {
+    pub noun_id: i32,
+    pub adjective_id: Option<i32>,
+    pub artikel: Artikel,
+    pub plural: bool,
+    pub kasus: Kasus,
+}
+
+#[derive(Debug, Clone)]
+pub struct DeklinationCard {
+    pub key: DeklinationKey,
+    pub noun: WorteSchema,
+    pub adjective: Option<WorteSchema>,
+    pub deklination: Deklination,
+}
+
+impl SessionCard for DeklinationCard {
+    type Key = DeklinationKey;
+
+    fn key(&self) -> DeklinationKey {
+        self.key
+    }
+
+    fn expected(&self) -> String {
+        self.deklination.form.clone()
+    }
+
+    /// Las mayúsculas cuentan (el sustantivo va con mayúscula); los espacios no.
+    fn normalize(&self, input: &str) -> String {
+        input.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+
+    fn quelle(&self) -> Quelle {
+        Quelle::wort(Art::Deklination, self.key.noun_id).with_detail(self.key.kasus.label())
+    }
+
+    fn fetch(conn: &Connection, keys: &[DeklinationKey]) -> Result<Vec<Self>> {
+        let mut ids: Vec<i32> = keys
+            .iter()
+            .flat_map(|k| [Some(k.noun_id), k.adjective_id])
+            .flatten()
+            .collect();
+        ids.sort();
+        ids.dedup();
+
+        let worte: HashMap<i32, WorteSchema> = WorteRepo::fetch_by_id(conn, &ids)?
+            .into_iter()
+            .map(|w| (w.id, w))
+            .collect();
+
+        let mut out = vec![];
+        for key in keys {
+            let Some(noun) = worte.get(&key.noun_id) else {
+                continue;
+            };
+            let adjective = key.adjective_id.and_then(|id| worte.get(&id));
+            let Some(deklination) = decline(
+                noun,
+                adjective.map(|a| a.worte_de.as_str()),
+                key.artikel,
+                key.plural,
+                key.kasus,
+            ) else {
+                continue;
+            };
+
+            out.push(DeklinationCard {
+                key: *key,
+                noun: noun.clone(),
+                adjective: adjective.cloned(),
+                deklination,
+            });
+        }
+
+        Ok(out)
+    }
+}
+
+/// Hasta `count` grupos nominales al azar con los sustantivos y adjetivos de `decks`:
+/// caso, determinante y número al azar, con adjetivo en tres de cada cuatro.
+pub fn build_drill(
+    conn: &Connection,
+    decks: Option<&[i32]>,
+    count: usize,
+) -> Result<Vec<DeklinationKey>> {
+    let ids = deck::filter_worte(conn, WorteRepo::fetch_id_nouns(conn)?, decks)?;
+    let nouns: Vec<WorteSchema> = WorteRepo::fetch_by_id(conn, &ids)?
+        .into_iter()
+        .filter(|w| Genus::from_wort(w).is_some())
+        .collect();
+    let ids = deck::filter_worte(
+        conn,
+        WorteRepo::fetch_id_by_gram_code(conn, "adjective")?,
+        decks,
+    )?;
+    let adjectives: Vec<WorteSchema> = WorteRepo::fetch_by_id(conn, &ids)?
+        .into_iter()
+        .filter(is_declinable)
+        .collect();
+
+    let mut rng = rand::rng();
+    let mut out: Vec<DeklinationKey> = vec![];
+    // Algunas combinaciones no existen o se repiten; se limita el número de intentos
+    for _ in 0..count * 10 {
+        if out.len() >= count {
+            break;
+        }
+        let Some(noun) = nouns.choose(&mut rng) else {
+            break;
+        };
+        let adjective = adjectives
+            .choose(&mut rng)
+            .filter(|_| rng.random_ratio(3, 4))
+            .map(|a| a.id);
+
+        let key = DeklinationKey {
+            noun_id: noun.id,
+            adjective_id: adjective,
+            artikel: *Artikel::ALL.choose(&mut rng).unwrap(),
+            plural: rng.random_bool(0.3),
+            kasus: *Kasus::ALL.choose(&mut rng).unwrap(),
+        };
+        if out.contains(&key) {
+            continue;
+        }
+        if decline(noun, None, key.artikel, key.plural, key.kasus).is_some() {
+            out.push(key);
+        }
+    }
+
+    Ok(out)
+}
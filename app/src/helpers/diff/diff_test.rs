@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod test_diff {
+    use crate::helpers::diff::{
+        DiffPart::{Equal, Extra, Missing},
+        diff_words,
+    };
+
+    #[test]
+    fn test_diff_words() {
+        assert_eq!(
+            diff_words("Ich habe einen Hund.", "Ich habe einen Hund"),
+            vec![
+                Equal("Ich".into()),
+                Equal("habe".into()),
+                Equal("einen".into()),
+                Equal("Hund.".into()),
+            ]
+        );
+
+        // Una palabra cambiada se marca como sobrante y faltante
+        assert_eq!(
+            diff_words("Ich habe einen Hund", "Ich hat einen hund"),
+            vec![
+                Equal("Ich".into()),
+                Extra("hat".into()),
+                Missing("habe".into()),
+                Equal("einen".into()),
+                Extra("hund".into()),
+                Missing("Hund".into()),
+            ]
+        );
+
+        assert_eq!(
+            diff_words("der Hund", "Hund ist"),
+            vec![
+                Missing("der".into()),
+                Equal("Hund".into()),
+                Extra("ist".into()),
+            ]
+        );
+
+        assert_eq!(
+            diff_words("der Hund", ""),
+            vec![Missing("der".into()), Missing("Hund".into()),]
+        );
+    }
+}
@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod diff_test;
+
+/// Fragmento de la comparación entre la respuesta esperada y la del usuario.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffPart {
+    /// Palabra que el usuario escribió bien
+    Equal(String),
+    /// Palabra de la respuesta esperada que faltó o se escribió mal
+    Missing(String),
+    /// Palabra que el usuario escribió de más
+    Extra(String),
+}
+
+/// Compara palabra por palabra la respuesta del usuario contra la esperada.
+///
+/// Las mayúsculas cuentan (los sustantivos van con mayúscula), la puntuación al final de
+/// cada palabra no.
+pub fn diff_words(expected: &str, input: &str) -> Vec<DiffPart> {
+    let expected: Vec<&str> = expected.split_whitespace().collect();
+    let input: Vec<&str> = input.split_whitespace().collect();
+    let same = |a: &str, b: &str| trim_punctuation(a) == trim_punctuation(b);
+
+    // lcs[i][j] -> subsecuencia común más larga de expected[i..] e input[j..]
+    let mut lcs = vec![vec![0usize; input.len() + 1]; expected.len() + 1];
+    for i in (0..expected.len()).rev() {
+        for j in (0..input.len()).rev() {
+            lcs[i][j] = if same(expected[i], input[j]) {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut out = Vec::with_capacity(expected.len().max(input.len()));
+    let (mut i, mut j) = (0, 0);
+    while i < expected.len() && j < input.len() {
+        if same(expected[i], input[j]) {
+            out.push(DiffPart::Equal(expected[i].to_string()));
+            i += 1;
+            j += 1;
+        } else if lcs[i][j + 1] >= lcs[i + 1][j] {
+            // Lo que escribió el usuario va antes de lo que le faltó: "Haus Hause"
+            out.push(DiffPart::Extra(input[j].to_string()));
+            j += 1;
+        } else {
+            out.push(DiffPart::Missing(expected[i].to_string()));
+            i += 1;
+        }
+    }
+    out.extend(
+        expected[i..]
+            .iter()
+            .map(|w| DiffPart::Missing(w.to_string())),
+    );
+    out.extend(input[j..].iter().map(|w| DiffPart::Extra(w.to_string())));
+    out
+}
+
+fn trim_punctuation(word: &str) -> &str {
+    word.trim_matches(|c: char| c.is_ascii_punctuation() || c == '¿' || c == '¡')
+}
@@ -0,0 +1,265 @@
+use color_eyre::eyre::Result;
+use rusqlite::Connection;
+
+use crate::{
+    db::{
+        deck::DeckRepo,
+        schemas::{
+            gram_type::GramTypeSchema,
+            niveau_liste::NiveauListeSchema,
+            schwirig_liste::SchwirigListeSchema,
+            setze::{NewSetzeSchema, SetzeSchema},
+            tags::TagsSchema,
+            worte::{NewWorteSchema, WorteSchema},
+            worte_gender::WorteGenderSchema,
+        },
+        setze::SetzeRepo,
+        worte::WorteRepo,
+    },
+    helpers::{tags, ui},
+    traits::string::StringConvertion,
+};
+
+const TEXT_ACTIONS: &str = r##"
+¿Qué quieres hacer?
+  1. Editar.
+  2. Borrar.
+
+Para regresar presiona enter.
+"##;
+
+const TEXT_EDIT: &str = r##"
+Para dejar un campo igual presiona enter.
+Para vaciar un campo opcional escribe "-".
+"##;
+
+/// Pide un campo de texto mostrando el valor actual. Enter lo deja igual.
+fn prompt_field(label: &str, current: &str) -> Result<String> {
+    match ui::prompt(&format!("{} [{}]: ", label, current))? {
+        Some(v) if !v.is_empty() => Ok(v),
+        _ => Ok(current.to_string()),
+    }
+}
+
+/// Igual que `prompt_field` pero "-" vacía el campo.
+fn prompt_optional(label: &str, current: Option<&str>) -> Result<Option<String>> {
+    let value = prompt_field(label, current.unwrap_or(""))?;
+    match value.as_str() {
+        "-" | "" => Ok(None),
+        _ => Ok(Some(value)),
+    }
+}
+
+/// Pide un campo hasta que `parse` lo acepte.
+fn prompt_parsed<T, F>(label: &str, current: &str, parse: F) -> Result<T>
+where
+    F: Fn(&str) -> Result<T>,
+{
+    loop {
+        let value = prompt_field(label, current)?;
+        match parse(&value) {
+            Ok(v) => return Ok(v),
+            Err(err) => println!("Valor no válido: {}", err),
+        }
+    }
+}
+
+/// Ruta del deck ("Goethe B1::Kapitel 4"), se crea si no existe.
+fn prompt_deck_id(conn: &mut Connection, current: i32) -> Result<i32> {
+    let current = DeckRepo::fetch_by_id(conn, &[current])?
+        .pop()
+        .map(|d| d.name)
+        .unwrap_or_default();
+
+    loop {
+        let path = prompt_field("deck", &current)?;
+        match DeckRepo::ensure_path(conn, &path) {
+            Ok(deck) => return Ok(deck.id),
+            Err(err) => println!("Valor no válido: {}", err),
+        }
+    }
+}
+
+/// Etiquetas separadas por coma; "-" las quita todas.
+fn prompt_tags(current: &[TagsSchema]) -> Result<Vec<String>> {
+    let current = current
+        .iter()
+        .map(|t| t.name.clone())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    Ok(prompt_optional("tags", Some(&current))?
+        .map(|v| tags::parse_tags(&v))
+        .unwrap_or_default())
+}
+
+fn prompt_action() -> Result<Option<String>> {
+    println!("{}", TEXT_ACTIONS);
+    let Some(input) = ui::prompt("> ")? else {
+        return Ok(None);
+    };
+
+    match input.trim() {
+        "1" | "2" => Ok(Some(input.trim().to_string())),
+        _ => Ok(None),
+    }
+}
+
+fn confirm(text: &str) -> Result<bool> {
+    println!("{} (si/no)", text);
+    Ok(ui::prompt("> ")?.is_some_and(|a| a.to_bool()))
+}
+
+fn bool_2_string(v: Option<bool>) -> &'static str {
+    match v {
+        Some(true) => "Si",
+        Some(false) => "No",
+        None => "",
+    }
+}
+
+/// Muestra la palabra y permite editarla o borrarla (borrado lógico).
+pub fn edit_wort(conn: &mut Connection, id: i32) -> Result<()> {
+    let Some(wort) = WorteRepo::fetch_by_id(conn, &[id])?.pop() else {
+        println!("No existe la palabra con id: {}", id);
+        return Ok(());
+    };
+
+    println!();
+    println!("Palabra {}: {} - {}", wort.id, wort.worte_de, wort.worte_es);
+
+    match prompt_action()?.as_deref() {
+        Some("1") => {
+            let new = prompt_wort(conn, &wort)?;
+            match WorteRepo::update(conn, id, &new) {
+                Ok(_) => println!("Palabra actualizada."),
+                Err(err) => println!("Ups ha ocurrido un error: {:#?}", err),
+            }
+        }
+        Some("2") if confirm("¿Seguro que quieres borrar la palabra?")? => {
+            WorteRepo::soft_delete(conn, &[id])?;
+            println!("Palabra borrada. Puedes restaurarla desde el menú de edición.");
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+fn prompt_wort(conn: &mut Connection, wort: &WorteSchema) -> Result<NewWorteSchema> {
+    println!("{}", TEXT_EDIT);
+
+    let gram_type_current = wort
+        .gram_type_id
+        .iter()
+        .map(|g| g.code.clone())
+        .collect::<Vec<_>>()
+        .join(",");
+    let gram_type = prompt_parsed("gram_type", &gram_type_current, |v| {
+        v.split(',')
+            .map(|gt| Ok(GramTypeSchema::from_code(gt.trim())?.id))
+            .collect::<Result<Vec<i32>>>()
+    })?;
+
+    let tags = prompt_tags(&wort.tags)?;
+    let deck_id = prompt_deck_id(conn, wort.deck_id)?;
+
+    let gender_current = wort.gender_id.as_ref().map(|g| g.gender.as_str());
+    let gender_id = prompt_parsed("gender", gender_current.unwrap_or(""), |v| match v {
+        "-" | "" => Ok(None),
+        _ => Ok(Some(WorteGenderSchema::from_gender(v)?.id)),
+    })?;
+
+    let worte_de = prompt_field("worte_de", &wort.worte_de)?;
+    let worte_es = prompt_field("worte_es", &wort.worte_es)?;
+    let plural = prompt_optional("plural", wort.plural.as_deref())?;
+    let niveau_id = prompt_parsed("niveau", &wort.niveau_id.niveau, |v| {
+        Ok(NiveauListeSchema::from_niveau(v)?.id)
+    })?;
+    let example_de = prompt_field("example_de", &wort.example_de)?;
+    let example_es = prompt_field("example_es", &wort.example_es)?;
+
+    let verb_aux = prompt_optional("verb_aux", wort.verb_aux.as_deref())?;
+    let trennbar = prompt_optional("trennbar (Si/No)", Some(bool_2_string(wort.trennbar)))?
+        .map(|v| v.to_bool());
+    let reflexiv = prompt_optional("reflexiv (Si/No)", Some(bool_2_string(wort.reflexiv)))?
+        .map(|v| v.to_bool());
+
+    let komparativ = prompt_optional("komparativ", wort.komparativ.as_deref())?;
+    let superlativ = prompt_optional("superlativ", wort.superlativ.as_deref())?;
+
+    Ok(NewWorteSchema {
+        gram_type,
+        tags,
+        gender_id,
+        worte_de,
+        worte_es,
+        plural,
+        niveau_id,
+        example_de,
+        example_es,
+        verb_aux,
+        trennbar,
+        reflexiv,
+        komparativ,
+        superlativ,
+        deck_id,
+    })
+}
+
+/// Muestra la oración y permite editarla o borrarla (borrado lógico).
+pub fn edit_satz(conn: &mut Connection, id: i32) -> Result<()> {
+    let Some(satz) = SetzeRepo::fetch_by_id(conn, &[id])?.pop() else {
+        println!("No existe la oración con id: {}", id);
+        return Ok(());
+    };
+
+    println!();
+    println!(
+        "Oración {}: {} - {}",
+        satz.id, satz.setze_deutsch, satz.setze_spanisch
+    );
+
+    match prompt_action()?.as_deref() {
+        Some("1") => {
+            let new = prompt_satz(conn, &satz)?;
+            match SetzeRepo::update(conn, id, &new) {
+                Ok(_) => println!("Oración actualizada."),
+                Err(err) => println!("Ups ha ocurrido un error: {:#?}", err),
+            }
+        }
+        Some("2") if confirm("¿Seguro que quieres borrar la oración?")? => {
+            SetzeRepo::soft_delete(conn, &[id])?;
+            println!("Oración borrada. Puedes restaurarla desde el menú de edición.");
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+fn prompt_satz(conn: &mut Connection, satz: &SetzeSchema) -> Result<NewSetzeSchema> {
+    println!("{}", TEXT_EDIT);
+
+    let setze_spanisch = prompt_field("setze_spanisch", &satz.setze_spanisch)?;
+    let setze_deutsch = prompt_field("setze_deutsch", &satz.setze_deutsch)?;
+    let thema = prompt_field("thema", &satz.thema)?;
+    let tags = prompt_tags(&satz.tags)?;
+    let deck_id = prompt_deck_id(conn, satz.deck_id)?;
+    let niveau_id = prompt_parsed("niveau", &satz.niveau_id.niveau, |v| {
+        Ok(NiveauListeSchema::from_niveau(v)?.id)
+    })?;
+    let schwirig_id = prompt_parsed("schwirig", &satz.schwirig_id.schwirig, |v| {
+        Ok(SchwirigListeSchema::parse(v)?.id)
+    })?;
+
+    Ok(NewSetzeSchema {
+        setze_spanisch,
+        setze_deutsch,
+        niveau_id,
+        thema,
+        deck_id,
+        schwirig_id,
+        tags,
+    })
+}
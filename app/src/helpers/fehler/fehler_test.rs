@@ -0,0 +1,192 @@
+use crate::db::setup_test_db;
+
+#[cfg(test)]
+mod test_fehler {
+    use rusqlite::Connection;
+
+    use crate::{
+        db::{
+            fehler::FehlerRepo, schemas::setze::NewSetzeSchema, seeders::init_data,
+            setze::SetzeRepo, worte::WorteRepo,
+        },
+        helpers::{
+            fehler::{Art, Kategorie, analyse, classify, save},
+            konjugation::{KonjugationCard, KonjugationKey, Tempus},
+            session::SessionCard,
+            test_worte::wort,
+        },
+    };
+
+    use super::*;
+
+    /// 1 Hund, 2 gehen; oración 1 en el tema "Tiere"
+    fn init_data_local(conn: &mut Connection) {
+        init_data(conn).unwrap();
+        WorteRepo::bulk_insert(conn, &[wort("Hund", 0, Some(0)), wort("gehen", 2, None)]).unwrap();
+        SetzeRepo::bulk_insert(
+            conn,
+            &[NewSetzeSchema {
+                setze_spanisch: "El perro juega en el jardín".into(),
+                setze_deutsch: "Der Hund spielt im Garten".into(),
+                niveau_id: 2,
+                thema: "Tiere".into(),
+                deck_id: 1,
+                schwirig_id: 0,
+                tags: vec![],
+            }],
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_classify() {
+        let cases = [
+            (Art::Wort, "der Hund", "die Hund", Kategorie::Artikel),
+            (Art::Wort, "der Hund", "Hund", Kategorie::Artikel),
+            (Art::Wort, "das Mädchen", "das Madchen", Kategorie::Umlaut),
+            (Art::Wort, "die Straße", "die Strasse", Kategorie::Umlaut),
+            (Art::Wort, "die Tür", "die Tuer", Kategorie::Umlaut),
+            (
+                Art::Satz,
+                "Ja, ich komme.",
+                "Ja ich komme",
+                Kategorie::Zeichensetzung,
+            ),
+            (
+                Art::Wort,
+                "der Hund",
+                "der hund",
+                Kategorie::Grossschreibung,
+            ),
+            (
+                Art::Satz,
+                "Ich gehe nach Hause",
+                "Ich gehen nach Hause",
+                Kategorie::Verbform,
+            ),
+            (
+                Art::Satz,
+                "Ich sehe den alten Mann",
+                "Ich sehe den alte Mann",
+                Kategorie::Artikel,
+            ),
+            (
+                Art::Satz,
+                "Morgen gehe ich",
+                "Morgen ich gehe",
+                Kategorie::Wortstellung,
+            ),
+            (
+                Art::Satz,
+                "Der Hund spielt",
+                "Der Hund schläft",
+                Kategorie::Unbekannt,
+            ),
+            (Art::Wort, "der Hund", "die Katze", Kategorie::Unbekannt),
+            // En los drills cuenta lo que se pregunta
+            (Art::Konjugation, "ging", "gehte", Kategorie::Verbform),
+            (Art::Praeposition, "Dativ", "Akkusativ", Kategorie::Artikel),
+        ];
+        for (art, expected, input, kategorie) in cases {
+            assert_eq!(classify(art, expected, input), kategorie, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_classify_umlaut_only_at_same_position() {
+        // "ue" solo es la ü si en la otra palabra hay una ü en ese lugar
+        assert_ne!(classify(Art::Wort, "neu", "neue"), Kategorie::Umlaut);
+        assert_ne!(classify(Art::Wort, "neue", "neu"), Kategorie::Umlaut);
+        assert_ne!(
+            classify(Art::Satz, "Das Auto ist neu", "Das Auto ist neue"),
+            Kategorie::Umlaut
+        );
+        assert_ne!(
+            classify(Art::Wort, "der Bauer", "der Baur"),
+            Kategorie::Umlaut
+        );
+        assert_ne!(
+            classify(Art::Wort, "schön", "schon"),
+            Kategorie::Zeichensetzung
+        );
+    }
+
+    #[test]
+    fn test_kategorie_code() {
+        for k in Kategorie::ALL {
+            assert_eq!(Kategorie::from_code(k.code()), Some(k));
+        }
+        assert_eq!(Kategorie::from_code("otra"), None);
+    }
+
+    #[test]
+    fn test_save_and_analyse() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+
+        let worte = WorteRepo::fetch_by_id(&conn, &[1]).unwrap();
+        save(
+            &mut conn,
+            vec![
+                (worte[0].clone(), "die Hund".to_string()),
+                (worte[0].clone(), "das Hund".to_string()),
+            ],
+        )
+        .unwrap();
+        let setze = SetzeRepo::fetch_by_id(&conn, &[1]).unwrap();
+        save(
+            &mut conn,
+            vec![(setze[0].clone(), "Der Hund im Garten spielt".to_string())],
+        )
+        .unwrap();
+        let konjugation = KonjugationCard::fetch(
+            &conn,
+            &[KonjugationKey {
+                wort_id: 2,
+                tempus: Tempus::Praeteritum,
+            }],
+        )
+        .unwrap();
+        save(
+            &mut conn,
+            vec![(konjugation[0].clone(), "gehte".to_string())],
+        )
+        .unwrap();
+
+        let fehler = FehlerRepo::fetch_all(&conn).unwrap();
+        assert_eq!(fehler.len(), 4);
+        let satz = fehler.iter().find(|f| f.satz_id == Some(1)).unwrap();
+        assert_eq!(satz.kategorie, "wortstellung");
+        let input: String = conn
+            .query_row("SELECT input FROM fehler WHERE id = ?1", [satz.id], |r| {
+                r.get(0)
+            })
+            .unwrap();
+        assert_eq!(input, "Der Hund im Garten spielt");
+        let verb = fehler.iter().find(|f| f.art == "konjugation").unwrap();
+        assert_eq!(verb.kategorie, "verbform");
+        assert_eq!(verb.detail.as_deref(), Some("Präteritum"));
+
+        let a = analyse(&conn, None).unwrap();
+        assert_eq!(a.total, 4);
+        assert_eq!(a.kategorie[0].label, Kategorie::Artikel.label());
+        assert_eq!(a.kategorie[0].count, 2);
+        assert_eq!(a.kategorie[0].percent, 50.0);
+        assert_eq!(a.art[0].label, Art::Wort.label());
+        assert_eq!(a.art[0].count, 2);
+        assert_eq!(a.art.len(), 3);
+        assert_eq!(a.detail.len(), 1);
+        assert_eq!(a.detail[0].label, "Forma del verbo (Präteritum)");
+        assert_eq!(a.thema.len(), 1);
+        assert_eq!(a.thema[0].label, "Tiere");
+        assert_eq!(a.thema[0].percent, 25.0);
+        assert_eq!(a.gram_type.len(), 2);
+        assert_eq!(a.gram_type[0].count, 2);
+        assert_eq!(a.niveau.iter().map(|z| z.count).sum::<usize>(), 4);
+
+        // Sin errores en el deck no hay nada que agrupar
+        let a = analyse(&conn, Some(&[999])).unwrap();
+        assert_eq!(a.total, 0);
+        assert!(a.kategorie.is_empty());
+    }
+}
@@ -0,0 +1,441 @@
+use std::collections::HashMap;
+
+use color_eyre::eyre::Result;
+use rusqlite::Connection;
+use serde::Serialize;
+
+use crate::{
+    db::{
+        fehler::FehlerRepo,
+        schemas::{fehler::NewFehlerSchema, setze::SetzeSchema, worte::WorteSchema},
+        setze::SetzeRepo,
+        worte::WorteRepo,
+    },
+    helpers::{deck, session::SessionCard, verwechslung},
+};
+
+#[cfg(test)]
+mod fehler_test;
+
+/// Artículos y determinantes que se comparan al buscar errores de artículo o género.
+const ARTIKEL: [&str; 18] = [
+    "der", "die", "das", "den", "dem", "des", "ein", "eine", "einen", "einem", "einer", "eines",
+    "kein", "keine", "keinen", "keinem", "keiner", "keines",
+];
+
+/// Tipo de tarjeta en la que se cometió el error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Art {
+    Wort,
+    Satz,
+    Konjugation,
+    Deklination,
+    Praeposition,
+    Steigerung,
+}
+
+impl Art {
+    pub const ALL: [Art; 6] = [
+        Art::Wort,
+        Art::Satz,
+        Art::Konjugation,
+        Art::Deklination,
+        Art::Praeposition,
+        Art::Steigerung,
+    ];
+
+    /// Código con el que se guarda en la base.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Art::Wort => "wort",
+            Art::Satz => "satz",
+            Art::Konjugation => "konjugation",
+            Art::Deklination => "deklination",
+            Art::Praeposition => "praeposition",
+            Art::Steigerung => "steigerung",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|a| a.code() == code)
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Art::Wort => "Palabras",
+            Art::Satz => "Oraciones",
+            Art::Konjugation => "Conjugación",
+            Art::Deklination => "Declinación",
+            Art::Praeposition => "Preposiciones",
+            Art::Steigerung => "Comparativo y superlativo",
+        }
+    }
+}
+
+/// Clasificación de una respuesta incorrecta.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Kategorie {
+    /// Artículo, género o caso ("die Hund", "mit den Mann")
+    Artikel,
+    /// Falta o sobra una diéresis o la ß ("Madchen", "Strasse")
+    Umlaut,
+    /// Solo cambian mayúsculas y minúsculas ("der hund")
+    Grossschreibung,
+    /// Solo cambian los signos de puntuación ("Ja, ich komme" / "Ja ich komme.")
+    Zeichensetzung,
+    /// Otra persona, tiempo o terminación del verbo ("ich gehen")
+    Verbform,
+    /// Las palabras correctas en otro orden
+    Wortstellung,
+    /// Ninguna de las anteriores: la palabra no se conoce
+    Unbekannt,
+}
+
+impl Kategorie {
+    pub const ALL: [Kategorie; 7] = [
+        Kategorie::Artikel,
+        Kategorie::Umlaut,
+        Kategorie::Grossschreibung,
+        Kategorie::Zeichensetzung,
+        Kategorie::Verbform,
+        Kategorie::Wortstellung,
+        Kategorie::Unbekannt,
+    ];
+
+    /// Código con el que se guarda en la base.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Kategorie::Artikel => "artikel",
+            Kategorie::Umlaut => "umlaut",
+            Kategorie::Grossschreibung => "grossschreibung",
+            Kategorie::Zeichensetzung => "zeichensetzung",
+            Kategorie::Verbform => "verbform",
+            Kategorie::Wortstellung => "wortstellung",
+            Kategorie::Unbekannt => "unbekannt",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|k| k.code() == code)
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Kategorie::Artikel => "Artículo, género o caso",
+            Kategorie::Umlaut => "Umlaut o ß",
+            Kategorie::Grossschreibung => "Mayúsculas",
+            Kategorie::Zeichensetzung => "Puntuación",
+            Kategorie::Verbform => "Forma del verbo",
+            Kategorie::Wortstellung => "Orden de las palabras",
+            Kategorie::Unbekannt => "Palabra desconocida",
+        }
+    }
+}
+
+/// De dónde viene una tarjeta, para guardar y agrupar sus errores.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Quelle {
+    pub art: Art,
+    pub wort_id: Option<i32>,
+    pub satz_id: Option<i32>,
+    /// Caso, tiempo o grado que se preguntó ("Dativ", "Präteritum")
+    pub detail: Option<String>,
+}
+
+impl Quelle {
+    pub fn wort(art: Art, wort_id: i32) -> Self {
+        Self {
+            art,
+            wort_id: Some(wort_id),
+            satz_id: None,
+            detail: None,
+        }
+    }
+
+    pub fn satz(satz_id: i32) -> Self {
+        Self {
+            art: Art::Satz,
+            wort_id: None,
+            satz_id: Some(satz_id),
+            detail: None,
+        }
+    }
+
+    pub fn with_detail(mut self, detail: &str) -> Self {
+        self.detail = Some(detail.to_string());
+        self
+    }
+}
+
+/// Palabras en minúsculas y sin signos de puntuación.
+fn tokens(s: &str) -> Vec<String> {
+    s.split_whitespace()
+        .map(|t| {
+            t.chars()
+                .filter(|c| c.is_alphanumeric())
+                .collect::<String>()
+                .to_lowercase()
+        })
+        .filter(|t| !t.is_empty())
+        .collect()
+}
+
+/// Formas en que se escribe una diéresis o la ß sin el teclado alemán.
+fn umlaut_spellings(c: char) -> &'static [&'static str] {
+    match c {
+        'ä' => &["a", "ae"],
+        'ö' => &["o", "oe"],
+        'ü' => &["u", "ue"],
+        'ß' => &["ss", "s"],
+        _ => &[],
+    }
+}
+
+/// `b` es `a` con alguna de sus diéresis o ß escrita de otra forma en la misma posición.
+fn umlaut_folds_to(a: &[char], b: &[char]) -> bool {
+    let Some((c, rest)) = a.split_first() else {
+        return b.is_empty();
+    };
+
+    if b.first() == Some(c) && umlaut_folds_to(rest, &b[1..]) {
+        return true;
+    }
+
+    umlaut_spellings(*c).iter().any(|spelling| {
+        let spelling: Vec<char> = spelling.chars().collect();
+        b.starts_with(&spelling) && umlaut_folds_to(rest, &b[spelling.len()..])
+    })
+}
+
+/// La misma palabra salvo por una diéresis o ß: "mädchen" / "madchen" / "maedchen".
+fn same_but_umlaut(a: &str, b: &str) -> bool {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    umlaut_folds_to(&a, &b) || umlaut_folds_to(&b, &a)
+}
+
+/// Misma raíz con otra terminación: "gehe" / "gehen", "alte" / "alten".
+fn same_stem(a: &str, b: &str) -> bool {
+    let prefix = a.chars().zip(b.chars()).take_while(|(x, y)| x == y).count();
+    prefix >= 3 || (prefix >= 2 && a.chars().count().min(b.chars().count()) == prefix)
+}
+
+/// Clasifica la respuesta incorrecta `input` comparándola con `expected`.
+///
+/// Las reglas van de la más específica a la más general; lo que no encaja en ninguna se
+/// cuenta como palabra desconocida.
+pub fn classify(art: Art, expected: &str, input: &str) -> Kategorie {
+    let expected_ws = expected.split_whitespace().collect::<Vec<_>>().join(" ");
+    let input_ws = input.split_whitespace().collect::<Vec<_>>().join(" ");
+    if expected_ws.to_lowercase() == input_ws.to_lowercase() {
+        return Kategorie::Grossschreibung;
+    }
+
+    let expected = tokens(expected);
+    let input = tokens(input);
+    if expected == input {
+        return Kategorie::Zeichensetzung;
+    }
+
+    if expected.len() == input.len()
+        && expected
+            .iter()
+            .zip(&input)
+            .all(|(e, i)| same_but_umlaut(e, i))
+    {
+        return Kategorie::Umlaut;
+    }
+
+    let sorted = |t: &[String]| {
+        let mut t = t.to_vec();
+        t.sort();
+        t
+    };
+    if sorted(&expected) == sorted(&input) {
+        return Kategorie::Wortstellung;
+    }
+
+    let without_artikel = |t: &[String]| {
+        t.iter()
+            .filter(|w| !ARTIKEL.contains(&w.as_str()))
+            .cloned()
+            .collect::<Vec<_>>()
+    };
+    if without_artikel(&expected) == without_artikel(&input) {
+        return Kategorie::Artikel;
+    }
+
+    match art {
+        Art::Konjugation => return Kategorie::Verbform,
+        Art::Deklination | Art::Praeposition => return Kategorie::Artikel,
+        _ => {}
+    }
+
+    // Misma raíz con otra terminación: después de un artículo es la declinación, si no el verbo
+    if expected.len() == input.len() {
+        let differing: Vec<usize> = (0..expected.len())
+            .filter(|&i| expected[i] != input[i])
+            .collect();
+        if differing
+            .iter()
+            .all(|&i| same_stem(&expected[i], &input[i]))
+        {
+            let after_artikel = differing
+                .iter()
+                .any(|&i| i > 0 && ARTIKEL.contains(&expected[i - 1].as_str()));
+            return if after_artikel {
+                Kategorie::Artikel
+            } else {
+                Kategorie::Verbform
+            };
+        }
+    }
+
+    Kategorie::Unbekannt
+}
+
+/// Clasifica lo que escribió el usuario para la tarjeta.
+pub fn classify_card<T: SessionCard>(card: &T, input: &str) -> Kategorie {
+    classify(
+        card.quelle().art,
+        &card.expected(),
+        &card.parse_input(input),
+    )
+}
+
+/// Guarda las respuestas incorrectas de una sesión con su clasificación. Las palabras
+/// respondidas con otra palabra guardada se registran además como confusión.
+pub fn save<T: SessionCard>(conn: &mut Connection, mistakes: Vec<(T, String)>) -> Result<()> {
+    let data: Vec<NewFehlerSchema> = mistakes
+        .into_iter()
+        .map(|(card, input)| {
+            let quelle = card.quelle();
+            NewFehlerSchema {
+                art: quelle.art.code().to_string(),
+                kategorie: classify_card(&card, &input).code().to_string(),
+                detail: quelle.detail,
+                wort_id: quelle.wort_id,
+                satz_id: quelle.satz_id,
+                expected: card.expected(),
+                input: card.parse_input(&input),
+            }
+        })
+        .collect();
+
+    FehlerRepo::bulk_insert(conn, &data)?;
+
+    let worte: Vec<(i32, String)> = data
+        .into_iter()
+        .filter(|f| f.art == Art::Wort.code())
+        .filter_map(|f| Some((f.wort_id?, f.input)))
+        .collect();
+    verwechslung::record(conn, &worte)?;
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Zeile {
+    pub label: String,
+    pub count: usize,
+    /// Porcentaje del total de errores
+    pub percent: f64,
+}
+
+/// Errores agrupados por categoría, tipo gramatical, tema y nivel.
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct Analyse {
+    pub total: usize,
+    pub kategorie: Vec<Zeile>,
+    /// Tipo de ejercicio en el que se cometió el error
+    pub art: Vec<Zeile>,
+    /// Categoría con el caso, tiempo o grado del drill: "Artículo, género o caso (Dativ)"
+    pub detail: Vec<Zeile>,
+    pub gram_type: Vec<Zeile>,
+    pub thema: Vec<Zeile>,
+    pub niveau: Vec<Zeile>,
+}
+
+/// Agrupa los errores de las tarjetas de `decks` (todos si no se indica).
+pub fn analyse(conn: &Connection, decks: Option<&[i32]>) -> Result<Analyse> {
+    let fehler = FehlerRepo::fetch_all(conn)?;
+
+    let mut wort_ids: Vec<i32> = fehler.iter().filter_map(|f| f.wort_id).collect();
+    wort_ids.sort();
+    wort_ids.dedup();
+    let mut satz_ids: Vec<i32> = fehler.iter().filter_map(|f| f.satz_id).collect();
+    satz_ids.sort();
+    satz_ids.dedup();
+
+    let worte: HashMap<i32, WorteSchema> =
+        WorteRepo::fetch_by_id(conn, &deck::filter_worte(conn, wort_ids, decks)?)?
+            .into_iter()
+            .map(|w| (w.id, w))
+            .collect();
+    let setze: HashMap<i32, SetzeSchema> =
+        SetzeRepo::fetch_by_id(conn, &deck::filter_setze(conn, satz_ids, decks)?)?
+            .into_iter()
+            .map(|s| (s.id, s))
+            .collect();
+
+    let mut total = 0;
+    let mut kategorie: HashMap<String, usize> = HashMap::new();
+    let mut art: HashMap<String, usize> = HashMap::new();
+    let mut detail: HashMap<String, usize> = HashMap::new();
+    let mut gram_type: HashMap<String, usize> = HashMap::new();
+    let mut thema: HashMap<String, usize> = HashMap::new();
+    let mut niveau: HashMap<String, usize> = HashMap::new();
+
+    for f in &fehler {
+        let wort = f.wort_id.and_then(|id| worte.get(&id));
+        let satz = f.satz_id.and_then(|id| setze.get(&id));
+        if wort.is_none() && satz.is_none() {
+            continue;
+        }
+        total += 1;
+
+        let label = Kategorie::from_code(&f.kategorie)
+            .map_or(f.kategorie.clone(), |k| k.label().to_string());
+        if let Some(d) = &f.detail {
+            *detail.entry(format!("{} ({})", label, d)).or_default() += 1;
+        }
+        *kategorie.entry(label).or_default() += 1;
+
+        let art_label = Art::from_code(&f.art).map_or(f.art.clone(), |a| a.label().to_string());
+        *art.entry(art_label).or_default() += 1;
+
+        if let Some(w) = wort {
+            for g in &w.gram_type_id {
+                *gram_type.entry(g.name.clone()).or_default() += 1;
+            }
+            *niveau.entry(w.niveau_id.niveau.clone()).or_default() += 1;
+        }
+        if let Some(s) = satz {
+            *thema.entry(s.thema.clone()).or_default() += 1;
+            *niveau.entry(s.niveau_id.niveau.clone()).or_default() += 1;
+        }
+    }
+
+    let zeilen = |counts: HashMap<String, usize>| {
+        let mut out: Vec<Zeile> = counts
+            .into_iter()
+            .map(|(label, count)| Zeile {
+                label,
+                count,
+                percent: (count as f64 * 1000.0 / total as f64).round() / 10.0,
+            })
+            .collect();
+        out.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.label.cmp(&b.label)));
+        out
+    };
+
+    Ok(Analyse {
+        total,
+        kategorie: zeilen(kategorie),
+        art: zeilen(art),
+        detail: zeilen(detail),
+        gram_type: zeilen(gram_type),
+        thema: zeilen(thema),
+        niveau: zeilen(niveau),
+    })
+}
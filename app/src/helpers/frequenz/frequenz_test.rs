@@ -0,0 +1,126 @@
+use crate::db::setup_test_db;
+
+#[cfg(test)]
+mod test_frequenz {
+    use std::collections::HashMap;
+
+    use rusqlite::Connection;
+
+    use crate::{
+        db::{
+            schemas::{
+                word_frequency::NewWordFrequencySchema, worte::NewWorteSchema,
+                worte_review::NewWorteReviewSchema,
+            },
+            seeders::init_data,
+            worte::WorteRepo,
+            worte_review::WorteReviewRepo,
+        },
+        helpers::{
+            frequenz::{abdeckung, import, lemma, order_by_rank, order_neue_worte, ranks},
+            test_worte,
+        },
+    };
+
+    use super::*;
+
+    fn wort(worte_de: &str) -> NewWorteSchema {
+        test_worte::wort(worte_de, 2, None)
+    }
+
+    fn row(lemma: &str, rank: u32) -> NewWordFrequencySchema {
+        NewWordFrequencySchema {
+            lemma: lemma.into(),
+            rank,
+        }
+    }
+
+    /// 1 Tisch, 2 gehen (repasada), 3 sich freuen, 4 Mädchen
+    fn init_data_local(conn: &mut Connection) {
+        init_data(conn).unwrap();
+        WorteRepo::bulk_insert(
+            conn,
+            &[
+                wort("Tisch"),
+                wort("gehen"),
+                wort("sich freuen"),
+                wort("Mädchen"),
+            ],
+        )
+        .unwrap();
+        WorteReviewRepo::bulk_insert(
+            conn,
+            &[NewWorteReviewSchema {
+                wort_id: 2,
+                interval: 1,
+                ease_factor: 2.5,
+                repetitions: 1,
+                last_review: "2020-01-01 00:00:00".into(),
+                next_review: "2020-01-02 00:00:00".into(),
+            }],
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_lemma() {
+        assert_eq!(lemma(" Mädchen "), "mädchen");
+        assert_eq!(lemma("sich freuen"), "freuen");
+        assert_eq!(lemma("Sich  Freuen"), "freuen");
+        // El artículo con el que se guarda un sustantivo no cuenta
+        assert_eq!(lemma("der Hund"), "hund");
+        assert_eq!(lemma("Die Leute"), "leute");
+    }
+
+    #[test]
+    fn test_import_and_ranks() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+
+        let rows = [
+            row("gehen", 3),
+            row("freuen", 40),
+            row("sein", 1),
+            row("gehen", 9),
+            row("mädchen", 12),
+        ];
+        let report = import(&mut conn, &rows).unwrap();
+        // "gehen" repetido se guarda una vez con su mejor rango
+        assert_eq!(report.imported, 4);
+        assert_eq!(report.matched, 3);
+
+        let ranks = ranks(&conn, &[1, 2, 3, 4]).unwrap();
+        assert_eq!(ranks, HashMap::from([(2, 3), (3, 40), (4, 12)]));
+        assert_eq!(order_by_rank(vec![1, 3, 4, 2], &ranks), vec![2, 4, 3, 1]);
+
+        // Sin `frequenz.order_neue` el orden no cambia
+        assert_eq!(
+            order_neue_worte(&conn, vec![1, 3, 4]).unwrap(),
+            vec![1, 3, 4]
+        );
+    }
+
+    #[test]
+    fn test_abdeckung() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+        assert_eq!(abdeckung(&conn, None, 3).unwrap(), None);
+
+        let rows = [
+            row("sein", 1),
+            row("gehen", 2),
+            row("mädchen", 3),
+            row("freuen", 4),
+        ];
+        import(&mut conn, &rows).unwrap();
+
+        // Banda: sein, gehen, mädchen
+        let a = abdeckung(&conn, None, 3).unwrap().unwrap();
+        assert_eq!((a.top_n, a.vorhanden, a.gelernt), (3, 2, 1));
+        assert!((a.percent - 100.0 / 3.0).abs() < 1e-9);
+
+        // Una banda más grande que la lista se limita a la lista
+        let a = abdeckung(&conn, None, 100).unwrap().unwrap();
+        assert_eq!((a.top_n, a.vorhanden, a.gelernt), (4, 3, 1));
+    }
+}
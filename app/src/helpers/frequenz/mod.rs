@@ -0,0 +1,134 @@
+use std::collections::{HashMap, HashSet};
+
+use color_eyre::eyre::Result;
+use rusqlite::Connection;
+use serde::Serialize;
+
+use crate::{
+    db::{
+        schemas::word_frequency::NewWordFrequencySchema, word_frequency::WordFrequencyRepo,
+        worte::WorteRepo,
+    },
+    helpers::{deck, lemma, settings},
+};
+
+#[cfg(test)]
+mod frequenz_test;
+
+/// Texto con el que una palabra se busca en la lista: `lemma::base` en minúsculas.
+pub fn lemma(worte_de: &str) -> String {
+    lemma::base(worte_de).to_lowercase()
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ImportReport {
+    /// Lemas guardados
+    pub imported: usize,
+    /// Palabras guardadas que quedaron con rango
+    pub matched: usize,
+}
+
+/// Reemplaza la lista de frecuencia. Un lema repetido se queda con su mejor rango.
+pub fn import(conn: &mut Connection, rows: &[NewWordFrequencySchema]) -> Result<ImportReport> {
+    let mut best: HashMap<&str, u32> = HashMap::new();
+    for row in rows {
+        let rank = best.entry(row.lemma.as_str()).or_insert(row.rank);
+        *rank = (*rank).min(row.rank);
+    }
+    let mut data: Vec<NewWordFrequencySchema> = best
+        .into_iter()
+        .map(|(lemma, rank)| NewWordFrequencySchema {
+            lemma: lemma.to_string(),
+            rank,
+        })
+        .collect();
+    data.sort_by(|a, b| a.rank.cmp(&b.rank).then_with(|| a.lemma.cmp(&b.lemma)));
+
+    let imported = WordFrequencyRepo::replace_all(conn, &data)?.len();
+    let matched = ranks(conn, &WorteRepo::fetch_all_only_ids(conn)?)?.len();
+
+    Ok(ImportReport { imported, matched })
+}
+
+/// Rango de cada palabra de `ids` que aparece en la lista; las demás no vienen.
+pub fn ranks(conn: &Connection, ids: &[i32]) -> Result<HashMap<i32, u32>> {
+    let liste: HashMap<String, u32> = WordFrequencyRepo::fetch_all(conn)?
+        .into_iter()
+        .map(|f| (f.lemma, f.rank))
+        .collect();
+    if liste.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    Ok(WorteRepo::fetch_by_id(conn, ids)?
+        .into_iter()
+        .filter_map(|w| Some((w.id, *liste.get(&lemma(&w.worte_de))?)))
+        .collect())
+}
+
+/// Primero las palabras más frecuentes; las que no están en la lista van al final. Entre
+/// iguales se respeta el orden de `ids`.
+pub fn order_by_rank(mut ids: Vec<i32>, ranks: &HashMap<i32, u32>) -> Vec<i32> {
+    ids.sort_by_key(|id| ranks.get(id).copied().unwrap_or(u32::MAX));
+    ids
+}
+
+/// Con `frequenz.order_neue` activo ordena las palabras nuevas con `order_by_rank`; si no,
+/// las regresa igual.
+pub fn order_neue_worte(conn: &Connection, ids: Vec<i32>) -> Result<Vec<i32>> {
+    if !settings::get().frequenz.order_neue {
+        return Ok(ids);
+    }
+
+    let ranks = ranks(conn, &ids)?;
+    Ok(order_by_rank(ids, &ranks))
+}
+
+/// Cobertura de los `top_n` lemas más frecuentes.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Abdeckung {
+    pub top_n: usize,
+    /// Lemas de la banda que están entre tus palabras
+    pub vorhanden: usize,
+    /// Lemas de la banda que ya repasaste al menos una vez
+    pub gelernt: usize,
+    /// `gelernt` sobre el tamaño de la banda
+    pub percent: f64,
+}
+
+/// Cobertura de la banda con las palabras de `decks` (todas si no se indica). `None` si no
+/// se ha importado una lista de frecuencia.
+pub fn abdeckung(
+    conn: &Connection,
+    decks: Option<&[i32]>,
+    top_n: usize,
+) -> Result<Option<Abdeckung>> {
+    let band: Vec<String> = WordFrequencyRepo::fetch_all(conn)?
+        .into_iter()
+        .take(top_n)
+        .map(|f| f.lemma)
+        .collect();
+    if band.is_empty() {
+        return Ok(None);
+    }
+
+    let ids = deck::filter_worte(conn, WorteRepo::fetch_all_only_ids(conn)?, decks)?;
+    let neue: HashSet<i32> = WorteRepo::fetch_id_neue_worte(conn)?.into_iter().collect();
+    let mut vorhanden: HashSet<String> = HashSet::new();
+    let mut gelernt: HashSet<String> = HashSet::new();
+    for w in WorteRepo::fetch_by_id(conn, &ids)? {
+        let lemma = lemma(&w.worte_de);
+        if !neue.contains(&w.id) {
+            gelernt.insert(lemma.clone());
+        }
+        vorhanden.insert(lemma);
+    }
+
+    let gelernt = band.iter().filter(|l| gelernt.contains(*l)).count();
+    Ok(Some(Abdeckung {
+        top_n: band.len(),
+        vorhanden: band.iter().filter(|l| vorhanden.contains(*l)).count(),
+        gelernt,
+        percent: gelernt as f64 * 100.0 / band.len() as f64,
+    }))
+}
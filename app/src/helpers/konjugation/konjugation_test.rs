@@ -0,0 +1,232 @@
+use crate::db::setup_test_db;
+
+#[cfg(test)]
+mod test_konjugation {
+    use rusqlite::Connection;
+
+    use crate::{
+        db::{
+            konjugation_review::KonjugationReviewRepo,
+            schemas::{
+                konjugation_review::NewKonjugationReviewSchema,
+                verb_konjugation::NewVerbKonjugationSchema,
+                worte::{NewWorteSchema, WorteSchema},
+            },
+            seeders::init_data,
+            verb_konjugation::VerbKonjugationRepo,
+            worte::WorteRepo,
+        },
+        helpers::{
+            konjugation::{
+                KonjugationKey, Person, Tempus, build_drill, conjugate, conjugate_infinitive, table,
+            },
+            test_worte::{self, wort_schema},
+        },
+    };
+
+    use super::*;
+
+    fn wort(code: &str, worte_de: &str, trennbar: Option<bool>) -> WorteSchema {
+        WorteSchema {
+            verb_aux: Some("haben".into()),
+            trennbar,
+            ..wort_schema(code, worte_de)
+        }
+    }
+
+    fn forms(infinitive: &str, tempus: Tempus) -> Vec<String> {
+        conjugate_infinitive(infinitive)
+            .into_iter()
+            .filter(|k| k.tempus == tempus)
+            .map(|k| k.form)
+            .collect()
+    }
+
+    #[test]
+    fn test_regular() {
+        assert_eq!(
+            forms("spielen", Tempus::Praesens),
+            vec![
+                "spiele", "spielst", "spielt", "spielen", "spielt", "spielen"
+            ]
+        );
+        assert_eq!(
+            forms("spielen", Tempus::Praeteritum),
+            vec![
+                "spielte",
+                "spieltest",
+                "spielte",
+                "spielten",
+                "spieltet",
+                "spielten"
+            ]
+        );
+        assert_eq!(forms("spielen", Tempus::Partizip), vec!["gespielt"]);
+        assert_eq!(
+            forms("spielen", Tempus::Imperativ),
+            vec!["spiel", "spielt", "spielen Sie"]
+        );
+
+        // "e" de enlace, -ern/-eln, sibilantes y participios sin "ge"
+        assert_eq!(
+            forms("arbeiten", Tempus::Praesens)[1..3],
+            ["arbeitest", "arbeitet"]
+        );
+        assert_eq!(forms("arbeiten", Tempus::Praeteritum)[0], "arbeitete");
+        assert_eq!(forms("öffnen", Tempus::Partizip), vec!["geöffnet"]);
+        assert_eq!(
+            forms("wandern", Tempus::Praesens),
+            vec![
+                "wandere", "wanderst", "wandert", "wandern", "wandert", "wandern"
+            ]
+        );
+        assert_eq!(forms("sammeln", Tempus::Imperativ)[0], "sammle");
+        assert_eq!(forms("tanzen", Tempus::Praesens)[1], "tanzt");
+        assert_eq!(forms("studieren", Tempus::Partizip), vec!["studiert"]);
+        assert_eq!(forms("besuchen", Tempus::Partizip), vec!["besucht"]);
+        assert_eq!(forms("bellen", Tempus::Partizip), vec!["gebellt"]);
+    }
+
+    #[test]
+    fn test_irregular() {
+        assert_eq!(
+            forms("fahren", Tempus::Praesens),
+            vec!["fahre", "fährst", "fährt", "fahren", "fahrt", "fahren"]
+        );
+        assert_eq!(forms("fahren", Tempus::Praeteritum)[1], "fuhrst");
+        assert_eq!(forms("fahren", Tempus::Imperativ)[0], "fahr");
+        assert_eq!(forms("sehen", Tempus::Imperativ)[0], "sieh");
+        assert_eq!(forms("lesen", Tempus::Praesens)[1..3], ["liest", "liest"]);
+        assert_eq!(forms("halten", Tempus::Praesens)[1..3], ["hältst", "hält"]);
+        assert_eq!(forms("essen", Tempus::Praeteritum)[1], "aßest");
+        assert_eq!(
+            forms("finden", Tempus::Praeteritum)[1..5],
+            ["fandest", "fand", "fanden", "fandet"]
+        );
+
+        // Mixtos y modales
+        assert_eq!(forms("denken", Tempus::Praeteritum)[0], "dachte");
+        assert_eq!(forms("denken", Tempus::Partizip), vec!["gedacht"]);
+        assert_eq!(forms("können", Tempus::Praesens)[0], "kann");
+        assert_eq!(forms("können", Tempus::Praeteritum)[0], "konnte");
+        assert!(forms("können", Tempus::Imperativ).is_empty());
+        assert_eq!(
+            forms("sein", Tempus::Imperativ),
+            vec!["sei", "seid", "seien Sie"]
+        );
+
+        // Con prefijo inseparable toma las formas de la base
+        assert_eq!(forms("verstehen", Tempus::Praeteritum)[0], "verstand");
+        assert_eq!(forms("verstehen", Tempus::Partizip), vec!["verstanden"]);
+        assert_eq!(forms("bekommen", Tempus::Partizip), vec!["bekommen"]);
+    }
+
+    #[test]
+    fn test_conjugate_wort() {
+        let anrufen = conjugate(&wort("verb_separable", "anrufen", Some(true)));
+        let find = |tempus: Tempus, person: Option<Person>| {
+            anrufen
+                .iter()
+                .find(|k| k.tempus == tempus && k.person == person)
+                .map(|k| k.form.as_str())
+        };
+        assert_eq!(find(Tempus::Praesens, Some(Person::Ich)), Some("rufe an"));
+        assert_eq!(find(Tempus::Praeteritum, Some(Person::Er)), Some("rief an"));
+        assert_eq!(find(Tempus::Partizip, None), Some("angerufen"));
+        assert_eq!(
+            find(Tempus::Imperativ, Some(Person::Sie)),
+            Some("rufen Sie an")
+        );
+
+        let freuen = conjugate(&wort("verb_reflexive", "sich freuen", None));
+        assert_eq!(freuen[0].form, "freue");
+        assert_eq!(freuen[0].label(), "Präsens · ich");
+    }
+
+    fn init_data_local(conn: &mut Connection) {
+        init_data(conn).unwrap();
+        let verb = |gram_type: i32, worte_de: &str| NewWorteSchema {
+            verb_aux: Some("haben".into()),
+            ..test_worte::wort(worte_de, gram_type, None)
+        };
+        let hund = NewWorteSchema {
+            verb_aux: Some("".into()),
+            ..verb(1, "Hund")
+        };
+        WorteRepo::bulk_insert(conn, &[verb(2, "spielen"), hund, verb(2, "backen")]).unwrap();
+    }
+
+    #[test]
+    fn test_table_override() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+
+        VerbKonjugationRepo::bulk_insert(
+            &mut conn,
+            &[NewVerbKonjugationSchema {
+                wort_id: 3,
+                tempus: "praeteritum".into(),
+                person: "ich".into(),
+                form: "buk".into(),
+            }],
+        )
+        .unwrap();
+
+        let worte = WorteRepo::fetch_by_id(&conn, &[1, 2, 3]).unwrap();
+        let tables = table(&conn, &worte).unwrap();
+        // El sustantivo no tiene tabla
+        assert_eq!(tables.len(), 2);
+        let backen = &tables[&3];
+        let praeteritum: Vec<&str> = backen
+            .iter()
+            .filter(|k| k.tempus == Tempus::Praeteritum)
+            .map(|k| k.form.as_str())
+            .collect();
+        assert_eq!(praeteritum[..2], ["buk", "backtest"]);
+    }
+
+    #[test]
+    fn test_build_drill() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+
+        // Dos verbos con 4 familias cada uno
+        let keys = build_drill(&conn, None, 100).unwrap();
+        assert_eq!(keys.len(), 8);
+        assert!(keys.iter().all(|k| k.wort_id != 2));
+        assert_eq!(build_drill(&conn, None, 3).unwrap().len(), 3);
+
+        let review = |tempus: &str, next_review: &str| NewKonjugationReviewSchema {
+            wort_id: 1,
+            tempus: tempus.into(),
+            interval: 1,
+            ease_factor: 2.5,
+            repetitions: 1,
+            last_review: "2020-01-01 00:00:00".into(),
+            next_review: next_review.into(),
+            lapses: 0,
+        };
+        KonjugationReviewRepo::bulk_insert(
+            &mut conn,
+            &[
+                review("praesens", "2020-01-02 00:00:00"),
+                review("partizip2", "2999-01-01 00:00:00"),
+            ],
+        )
+        .unwrap();
+
+        // La familia vencida siempre entra; la programada ya no es nueva
+        let keys = build_drill(&conn, None, 0).unwrap();
+        assert_eq!(
+            keys,
+            vec![KonjugationKey {
+                wort_id: 1,
+                tempus: Tempus::Praesens
+            }]
+        );
+        assert_eq!(build_drill(&conn, None, 100).unwrap().len(), 7);
+
+        // Fuera del deck no hay verbos
+        assert!(build_drill(&conn, Some(&[99]), 100).unwrap().is_empty());
+    }
+}
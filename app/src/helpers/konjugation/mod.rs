@@ -0,0 +1,818 @@
+use std::collections::HashMap;
+
+use color_eyre::eyre::{Result, bail};
+use once_cell::sync::Lazy;
+use rand::seq::{IndexedRandom, SliceRandom};
+use rusqlite::Connection;
+
+use crate::{
+    db::{
+        konjugation_review::KonjugationReviewRepo,
+        schemas::{
+            verb_konjugation::{NewVerbKonjugationSchema, VerbKonjugationSchema},
+            worte::WorteSchema,
+        },
+        verb_konjugation::VerbKonjugationRepo,
+        worte::WorteRepo,
+    },
+    helpers::{
+        csv::KonjugationCsv,
+        deck,
+        fehler::{Art, Quelle},
+        lemma,
+        session::SessionCard,
+        time,
+    },
+};
+
+#[cfg(test)]
+mod konjugation_test;
+
+/// Prefijos inseparables: el Partizip II no lleva "ge" ("verstanden", "besucht").
+const INSEPARABLE_PREFIXES: [&str; 12] = [
+    "hinter", "miss", "über", "unter", "wider", "emp", "ent", "ver", "zer", "be", "er", "ge",
+];
+
+/// Tiempo o forma del verbo. Cada uno se repasa por separado en el drill.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tempus {
+    Praesens,
+    Praeteritum,
+    Partizip,
+    Imperativ,
+}
+
+impl Tempus {
+    pub const ALL: [Tempus; 4] = [
+        Tempus::Praesens,
+        Tempus::Praeteritum,
+        Tempus::Partizip,
+        Tempus::Imperativ,
+    ];
+
+    /// Código con el que se guarda en la base y en el CSV.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Tempus::Praesens => "praesens",
+            Tempus::Praeteritum => "praeteritum",
+            Tempus::Partizip => "partizip2",
+            Tempus::Imperativ => "imperativ",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Tempus::Praesens => "Präsens",
+            Tempus::Praeteritum => "Präteritum",
+            Tempus::Partizip => "Partizip II",
+            Tempus::Imperativ => "Imperativ",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|t| t.code() == s.trim().to_lowercase())
+    }
+
+    /// Personas de cada tiempo; el Partizip II es una sola forma.
+    pub fn persons(&self) -> &'static [Person] {
+        match self {
+            Tempus::Praesens | Tempus::Praeteritum => &Person::ALL,
+            Tempus::Partizip => &[],
+            Tempus::Imperativ => &[Person::Du, Person::Ihr, Person::Sie],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Person {
+    Ich,
+    Du,
+    Er,
+    Wir,
+    Ihr,
+    Sie,
+}
+
+impl Person {
+    pub const ALL: [Person; 6] = [
+        Person::Ich,
+        Person::Du,
+        Person::Er,
+        Person::Wir,
+        Person::Ihr,
+        Person::Sie,
+    ];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Person::Ich => "ich",
+            Person::Du => "du",
+            Person::Er => "er",
+            Person::Wir => "wir",
+            Person::Ihr => "ihr",
+            Person::Sie => "sie",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Person::Er => "er/sie/es",
+            Person::Sie => "sie/Sie",
+            p => p.code(),
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|p| p.code() == s.trim().to_lowercase())
+    }
+
+    fn index(&self) -> usize {
+        Self::ALL.iter().position(|p| p == self).unwrap()
+    }
+}
+
+/// Una forma conjugada. `person` es `None` en el Partizip II.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Konjugation {
+    pub tempus: Tempus,
+    pub person: Option<Person>,
+    pub form: String,
+}
+
+impl Konjugation {
+    fn new(tempus: Tempus, person: Option<Person>, form: impl Into<String>) -> Self {
+        Self {
+            tempus,
+            person,
+            form: form.into(),
+        }
+    }
+
+    /// "Präsens · du", "Partizip II"
+    pub fn label(&self) -> String {
+        match (self.tempus, self.person) {
+            (Tempus::Imperativ, Some(Person::Sie)) => format!("{} · Sie", self.tempus.label()),
+            (tempus, Some(person)) => format!("{} · {}", tempus.label(), person.label()),
+            (tempus, None) => tempus.label().to_string(),
+        }
+    }
+}
+
+/// Presente de un verbo irregular.
+enum Praesens {
+    Regular,
+    /// Raíz de du y er/sie/es con cambio de vocal: "fähr", "sieh", "nimm"
+    Stem(&'static str),
+    /// Todas las personas: ich, du, er, wir, ihr, sie
+    Full([&'static str; 6]),
+}
+
+struct Irregular {
+    praesens: Praesens,
+    /// Raíz del pretérito: fuerte ("fuhr") o con terminaciones débiles ("dacht", "konnt")
+    praeteritum: &'static str,
+    weak_praeteritum: bool,
+    partizip: &'static str,
+    /// Solo para los verbos con presente completo; los demás siguen la regla
+    imperativ: Option<[&'static str; 3]>,
+}
+
+const fn strong(
+    stem: Option<&'static str>,
+    praeteritum: &'static str,
+    partizip: &'static str,
+) -> Irregular {
+    Irregular {
+        praesens: match stem {
+            Some(s) => Praesens::Stem(s),
+            None => Praesens::Regular,
+        },
+        praeteritum,
+        weak_praeteritum: false,
+        partizip,
+        imperativ: None,
+    }
+}
+
+const fn mixed(praeteritum: &'static str, partizip: &'static str) -> Irregular {
+    Irregular {
+        praesens: Praesens::Regular,
+        praeteritum,
+        weak_praeteritum: true,
+        partizip,
+        imperativ: None,
+    }
+}
+
+const fn full(
+    praesens: [&'static str; 6],
+    praeteritum: &'static str,
+    weak_praeteritum: bool,
+    partizip: &'static str,
+    imperativ: Option<[&'static str; 3]>,
+) -> Irregular {
+    Irregular {
+        praesens: Praesens::Full(praesens),
+        praeteritum,
+        weak_praeteritum,
+        partizip,
+        imperativ,
+    }
+}
+
+/// Verbos irregulares frecuentes. Los verbos con prefijo ("verstehen", "anrufen") toman las
+/// formas de su base.
+static IRREGULAR: Lazy<HashMap<&'static str, Irregular>> = Lazy::new(|| {
+    HashMap::from([
+        (
+            "sein",
+            full(
+                ["bin", "bist", "ist", "sind", "seid", "sind"],
+                "war",
+                false,
+                "gewesen",
+                Some(["sei", "seid", "seien Sie"]),
+            ),
+        ),
+        (
+            "haben",
+            full(
+                ["habe", "hast", "hat", "haben", "habt", "haben"],
+                "hatt",
+                true,
+                "gehabt",
+                Some(["hab", "habt", "haben Sie"]),
+            ),
+        ),
+        (
+            "werden",
+            full(
+                ["werde", "wirst", "wird", "werden", "werdet", "werden"],
+                "wurd",
+                true,
+                "geworden",
+                Some(["werde", "werdet", "werden Sie"]),
+            ),
+        ),
+        (
+            "wissen",
+            full(
+                ["weiß", "weißt", "weiß", "wissen", "wisst", "wissen"],
+                "wusst",
+                true,
+                "gewusst",
+                Some(["wisse", "wisst", "wissen Sie"]),
+            ),
+        ),
+        (
+            "tun",
+            full(
+                ["tue", "tust", "tut", "tun", "tut", "tun"],
+                "tat",
+                false,
+                "getan",
+                Some(["tu", "tut", "tun Sie"]),
+            ),
+        ),
+        (
+            "können",
+            full(
+                ["kann", "kannst", "kann", "können", "könnt", "können"],
+                "konnt",
+                true,
+                "gekonnt",
+                None,
+            ),
+        ),
+        (
+            "müssen",
+            full(
+                ["muss", "musst", "muss", "müssen", "müsst", "müssen"],
+                "musst",
+                true,
+                "gemusst",
+                None,
+            ),
+        ),
+        (
+            "wollen",
+            full(
+                ["will", "willst", "will", "wollen", "wollt", "wollen"],
+                "wollt",
+                true,
+                "gewollt",
+                None,
+            ),
+        ),
+        (
+            "dürfen",
+            full(
+                ["darf", "darfst", "darf", "dürfen", "dürft", "dürfen"],
+                "durft",
+                true,
+                "gedurft",
+                None,
+            ),
+        ),
+        (
+            "sollen",
+            full(
+                ["soll", "sollst", "soll", "sollen", "sollt", "sollen"],
+                "sollt",
+                true,
+                "gesollt",
+                None,
+            ),
+        ),
+        (
+            "mögen",
+            full(
+                ["mag", "magst", "mag", "mögen", "mögt", "mögen"],
+                "mocht",
+                true,
+                "gemocht",
+                None,
+            ),
+        ),
+        ("denken", mixed("dacht", "gedacht")),
+        ("bringen", mixed("bracht", "gebracht")),
+        ("kennen", mixed("kannt", "gekannt")),
+        ("nennen", mixed("nannt", "genannt")),
+        ("rennen", mixed("rannt", "gerannt")),
+        ("brennen", mixed("brannt", "gebrannt")),
+        ("gehen", strong(None, "ging", "gegangen")),
+        ("kommen", strong(None, "kam", "gekommen")),
+        ("bleiben", strong(None, "blieb", "geblieben")),
+        ("schreiben", strong(None, "schrieb", "geschrieben")),
+        ("steigen", strong(None, "stieg", "gestiegen")),
+        ("finden", strong(None, "fand", "gefunden")),
+        ("trinken", strong(None, "trank", "getrunken")),
+        ("singen", strong(None, "sang", "gesungen")),
+        ("springen", strong(None, "sprang", "gesprungen")),
+        ("schwimmen", strong(None, "schwamm", "geschwommen")),
+        ("beginnen", strong(None, "begann", "begonnen")),
+        ("stehen", strong(None, "stand", "gestanden")),
+        ("sitzen", strong(None, "saß", "gesessen")),
+        ("liegen", strong(None, "lag", "gelegen")),
+        ("ziehen", strong(None, "zog", "gezogen")),
+        ("fliegen", strong(None, "flog", "geflogen")),
+        ("schließen", strong(None, "schloss", "geschlossen")),
+        ("verlieren", strong(None, "verlor", "verloren")),
+        ("rufen", strong(None, "rief", "gerufen")),
+        ("heißen", strong(None, "hieß", "geheißen")),
+        ("fahren", strong(Some("fähr"), "fuhr", "gefahren")),
+        ("tragen", strong(Some("träg"), "trug", "getragen")),
+        ("waschen", strong(Some("wäsch"), "wusch", "gewaschen")),
+        ("schlafen", strong(Some("schläf"), "schlief", "geschlafen")),
+        ("fallen", strong(Some("fäll"), "fiel", "gefallen")),
+        ("halten", strong(Some("hält"), "hielt", "gehalten")),
+        ("lassen", strong(Some("läss"), "ließ", "gelassen")),
+        ("laufen", strong(Some("läuf"), "lief", "gelaufen")),
+        ("sehen", strong(Some("sieh"), "sah", "gesehen")),
+        ("lesen", strong(Some("lies"), "las", "gelesen")),
+        ("essen", strong(Some("iss"), "aß", "gegessen")),
+        ("vergessen", strong(Some("vergiss"), "vergaß", "vergessen")),
+        ("geben", strong(Some("gib"), "gab", "gegeben")),
+        ("nehmen", strong(Some("nimm"), "nahm", "genommen")),
+        ("sprechen", strong(Some("sprich"), "sprach", "gesprochen")),
+        ("helfen", strong(Some("hilf"), "half", "geholfen")),
+        ("treffen", strong(Some("triff"), "traf", "getroffen")),
+        ("werfen", strong(Some("wirf"), "warf", "geworfen")),
+        ("sterben", strong(Some("stirb"), "starb", "gestorben")),
+        (
+            "empfehlen",
+            strong(Some("empfiehl"), "empfahl", "empfohlen"),
+        ),
+    ])
+});
+
+/// Indica si la palabra es un verbo (por su tipo gramatical o porque tiene auxiliar).
+pub fn is_verb(wort: &WorteSchema) -> bool {
+    // El CSV guarda el auxiliar vacío en las palabras que no son verbos
+    wort.gram_type_id.iter().any(|g| g.code.starts_with("verb"))
+        || wort
+            .verb_aux
+            .as_deref()
+            .is_some_and(|a| !a.trim().is_empty())
+}
+
+/// Tabla de conjugación generada para el verbo: Präsens y Präteritum en todas las personas,
+/// Partizip II e Imperativ (du, ihr, Sie).
+///
+/// Los verbos separables se conjugan con el prefijo al final ("rufe an") y los reflexivos
+/// sin el pronombre. Las formas irregulares salen de una lista de verbos frecuentes; las que
+/// falten se pueden importar por CSV (ver `table`).
+pub fn conjugate(wort: &WorteSchema) -> Vec<Konjugation> {
+    let infinitive = wort.worte_de.trim();
+    let infinitive = infinitive
+        .strip_prefix("sich ")
+        .unwrap_or(infinitive)
+        .trim();
+
+    let separable =
+        wort.trennbar == Some(true) || wort.gram_type_id.iter().any(|g| g.code == "verb_separable");
+    match lemma::split_separable(infinitive).filter(|_| separable) {
+        Some((prefix, base)) => conjugate_infinitive(base)
+            .into_iter()
+            .map(|k| {
+                let form = match k.tempus {
+                    Tempus::Partizip => format!("{}{}", prefix, k.form),
+                    _ => format!("{} {}", k.form, prefix),
+                };
+                Konjugation { form, ..k }
+            })
+            .collect(),
+        None => conjugate_infinitive(infinitive),
+    }
+}
+
+/// Conjuga un infinitivo sin prefijo separable.
+pub fn conjugate_infinitive(infinitive: &str) -> Vec<Konjugation> {
+    let (prefix, base, irregular) = find_irregular(infinitive);
+    let stem = stem(base);
+
+    let mut out = vec![];
+
+    // Präsens
+    let praesens: Vec<String> = match irregular.map(|i| &i.praesens) {
+        Some(Praesens::Full(forms)) => forms.iter().map(|f| f.to_string()).collect(),
+        Some(Praesens::Stem(changed)) => {
+            let mut forms = praesens_regular(base, stem);
+            forms[Person::Du.index()] = if ends_with_sibilant(changed) {
+                format!("{}t", changed)
+            } else {
+                format!("{}st", changed)
+            };
+            // "hält", no "hältt"
+            forms[Person::Er.index()] = match changed.strip_suffix('t') {
+                Some(_) => changed.to_string(),
+                None => format!("{}t", changed),
+            };
+            forms
+        }
+        _ => praesens_regular(base, stem),
+    };
+    for (person, form) in Person::ALL.iter().zip(&praesens) {
+        out.push(Konjugation::new(
+            Tempus::Praesens,
+            Some(*person),
+            format!("{}{}", prefix, form),
+        ));
+    }
+
+    // Präteritum
+    let praeteritum: Vec<String> = match irregular {
+        Some(i) if i.weak_praeteritum => weak_endings(i.praeteritum),
+        Some(i) => {
+            let p = i.praeteritum;
+            let e = if needs_e(p) || ends_with_sibilant(p) {
+                "e"
+            } else {
+                ""
+            };
+            let et = if needs_e(p) { "e" } else { "" };
+            vec![
+                p.to_string(),
+                format!("{}{}st", p, e),
+                p.to_string(),
+                format!("{}en", p),
+                format!("{}{}t", p, et),
+                format!("{}en", p),
+            ]
+        }
+        None => weak_endings(&format!(
+            "{}{}t",
+            stem,
+            if needs_e(stem) { "e" } else { "" }
+        )),
+    };
+    for (person, form) in Person::ALL.iter().zip(&praeteritum) {
+        out.push(Konjugation::new(
+            Tempus::Praeteritum,
+            Some(*person),
+            format!("{}{}", prefix, form),
+        ));
+    }
+
+    // Partizip II
+    let partizip = match irregular {
+        Some(i) if !prefix.is_empty() => {
+            format!(
+                "{}{}",
+                prefix,
+                i.partizip.strip_prefix("ge").unwrap_or(i.partizip)
+            )
+        }
+        Some(i) => i.partizip.to_string(),
+        None => {
+            let ending = if needs_e(stem) { "et" } else { "t" };
+            let inseparable = INSEPARABLE_PREFIXES
+                .iter()
+                .any(|p| base.starts_with(p) && base.len() > p.len() + 4);
+            if inseparable || base.ends_with("ieren") {
+                format!("{}{}", stem, ending)
+            } else {
+                format!("ge{}{}", stem, ending)
+            }
+        }
+    };
+    out.push(Konjugation::new(Tempus::Partizip, None, partizip));
+
+    // Imperativ
+    let imperativ: Option<[String; 3]> = match irregular {
+        Some(Irregular {
+            praesens: Praesens::Full(_),
+            imperativ,
+            ..
+        }) => imperativ.map(|forms| forms.map(|f| format!("{}{}", prefix, f))),
+        _ => {
+            let du = match irregular.map(|i| &i.praesens) {
+                // Solo el cambio e -> i/ie pasa al imperativo: "sieh", pero "fahr"
+                Some(Praesens::Stem(changed)) if changed.contains('i') && stem.contains('e') => {
+                    changed.to_string()
+                }
+                _ if needs_e(stem) || base.ends_with("eln") || base.ends_with("ern") => {
+                    praesens[Person::Ich.index()].clone()
+                }
+                _ => stem.to_string(),
+            };
+            Some([
+                format!("{}{}", prefix, du),
+                format!("{}{}", prefix, praesens[Person::Ihr.index()]),
+                format!("{}{} Sie", prefix, base),
+            ])
+        }
+    };
+    if let Some(forms) = imperativ {
+        for (person, form) in Tempus::Imperativ.persons().iter().zip(forms) {
+            out.push(Konjugation::new(Tempus::Imperativ, Some(*person), form));
+        }
+    }
+
+    out
+}
+
+/// Tabla de conjugación de cada verbo: la generada, reemplazando las formas importadas
+/// (table verb_konjugation). wort_id -> formas.
+pub fn table(conn: &Connection, worte: &[WorteSchema]) -> Result<HashMap<i32, Vec<Konjugation>>> {
+    let ids: Vec<i32> = worte.iter().map(|w| w.id).collect();
+    let mut saved: HashMap<i32, Vec<VerbKonjugationSchema>> = HashMap::new();
+    for k in VerbKonjugationRepo::fetch_by_wort_id(conn, &ids)? {
+        saved.entry(k.wort_id).or_default().push(k);
+    }
+
+    let mut out = HashMap::new();
+    for wort in worte.iter().filter(|w| is_verb(w)) {
+        let mut forms = conjugate(wort);
+        for s in saved.remove(&wort.id).unwrap_or_default() {
+            let (Some(tempus), person) = (Tempus::parse(&s.tempus), Person::parse(&s.person))
+            else {
+                continue;
+            };
+            match forms
+                .iter_mut()
+                .find(|k| k.tempus == tempus && k.person == person)
+            {
+                Some(k) => k.form = s.form,
+                None => forms.push(Konjugation::new(tempus, person, s.form)),
+            }
+        }
+        out.insert(wort.id, forms);
+    }
+
+    Ok(out)
+}
+
+/// Guarda las formas del CSV en los verbos con el mismo infinitivo (de `decks` si se indica).
+/// Falla sin guardar nada si algún verbo no existe.
+pub fn import(
+    conn: &mut Connection,
+    rows: &[KonjugationCsv],
+    decks: Option<&[i32]>,
+) -> Result<Vec<VerbKonjugationSchema>> {
+    let ids = deck::filter_worte(conn, WorteRepo::fetch_id_verbs(conn)?, decks)?;
+    let mut verben: HashMap<String, Vec<i32>> = HashMap::new();
+    for w in WorteRepo::fetch_by_id(conn, &ids)? {
+        verben
+            .entry(w.worte_de.trim().to_lowercase())
+            .or_default()
+            .push(w.id);
+    }
+
+    let mut data = vec![];
+    let mut missing = vec![];
+    for row in rows {
+        let Some(ids) = verben.get(&row.worte_de.to_lowercase()) else {
+            missing.push(row.worte_de.as_str());
+            continue;
+        };
+        for wort_id in ids {
+            data.push(NewVerbKonjugationSchema {
+                wort_id: *wort_id,
+                tempus: row.tempus.code().to_string(),
+                person: row.person.map_or("", |p| p.code()).to_string(),
+                form: row.form.clone(),
+            });
+        }
+    }
+
+    if !missing.is_empty() {
+        missing.sort();
+        missing.dedup();
+        bail!("No existen los verbos: {}", missing.join(", "));
+    }
+
+    VerbKonjugationRepo::bulk_insert(conn, &data)
+}
+
+/// Familia de formas que se repasa por separado: un tiempo de un verbo.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KonjugationKey {
+    pub wort_id: i32,
+    pub tempus: Tempus,
+}
+
+/// Tarjeta del drill: una persona al azar dentro de la familia.
+#[derive(Debug, Clone)]
+pub struct KonjugationCard {
+    pub wort: WorteSchema,
+    pub konjugation: Konjugation,
+}
+
+impl SessionCard for KonjugationCard {
+    type Key = KonjugationKey;
+
+    fn key(&self) -> KonjugationKey {
+        KonjugationKey {
+            wort_id: self.wort.id,
+            tempus: self.konjugation.tempus,
+        }
+    }
+
+    fn expected(&self) -> String {
+        self.konjugation.form.clone()
+    }
+
+    fn normalize(&self, input: &str) -> String {
+        input
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase()
+    }
+
+    fn quelle(&self) -> Quelle {
+        Quelle::wort(Art::Konjugation, self.wort.id).with_detail(self.konjugation.tempus.label())
+    }
+
+    fn fetch(conn: &Connection, keys: &[KonjugationKey]) -> Result<Vec<Self>> {
+        let mut ids: Vec<i32> = keys.iter().map(|k| k.wort_id).collect();
+        ids.sort();
+        ids.dedup();
+
+        let worte: HashMap<i32, WorteSchema> = WorteRepo::fetch_by_id(conn, &ids)?
+            .into_iter()
+            .map(|w| (w.id, w))
+            .collect();
+        let worte_vec: Vec<WorteSchema> = worte.values().cloned().collect();
+        let tables = table(conn, &worte_vec)?;
+
+        let mut rng = rand::rng();
+        let mut out = vec![];
+        for key in keys {
+            let (Some(wort), Some(forms)) = (worte.get(&key.wort_id), tables.get(&key.wort_id))
+            else {
+                continue;
+            };
+            let family: Vec<&Konjugation> =
+                forms.iter().filter(|k| k.tempus == key.tempus).collect();
+            if let Some(k) = family.choose(&mut rng) {
+                out.push(KonjugationCard {
+                    wort: wort.clone(),
+                    konjugation: (*k).clone(),
+                });
+            }
+        }
+
+        Ok(out)
+    }
+}
+
+/// Familias por repasar hoy más hasta `max_neue` familias nuevas de los verbos de `decks`,
+/// en orden aleatorio.
+pub fn build_drill(
+    conn: &Connection,
+    decks: Option<&[i32]>,
+    max_neue: usize,
+) -> Result<Vec<KonjugationKey>> {
+    let ids = deck::filter_worte(conn, WorteRepo::fetch_id_verbs(conn)?, decks)?;
+    let verben = WorteRepo::fetch_by_id(conn, &ids)?;
+    let tables = table(conn, &verben)?;
+
+    let date_review = time::today_local_string(1);
+    let mut due: Vec<KonjugationKey> =
+        KonjugationReviewRepo::fetch_review_key_by_day(conn, date_review)?
+            .into_iter()
+            .filter(|k| tables.contains_key(&k.wort_id))
+            .collect();
+
+    let reviewed = KonjugationReviewRepo::fetch_keys_by_wort_id(conn, &ids)?;
+    let mut neue: Vec<KonjugationKey> = verben
+        .iter()
+        .filter_map(|w| tables.get(&w.id).map(|forms| (w.id, forms)))
+        .flat_map(|(wort_id, forms)| {
+            Tempus::ALL
+                .into_iter()
+                .filter(|t| forms.iter().any(|k| k.tempus == *t))
+                .map(|tempus| KonjugationKey { wort_id, tempus })
+                .collect::<Vec<_>>()
+        })
+        .filter(|k| !reviewed.contains(k))
+        .collect();
+
+    let mut rng = rand::rng();
+    neue.shuffle(&mut rng);
+    due.extend(neue.into_iter().take(max_neue));
+    due.shuffle(&mut rng);
+
+    Ok(due)
+}
+
+/// Busca el verbo en la lista de irregulares, también con un prefijo inseparable
+/// ("verstehen" -> "stehen"). Regresa (prefijo, base, irregular).
+fn find_irregular(infinitive: &str) -> (&str, &str, Option<&'static Irregular>) {
+    if let Some(i) = IRREGULAR.get(infinitive) {
+        return ("", infinitive, Some(i));
+    }
+
+    for prefix in INSEPARABLE_PREFIXES {
+        if let Some(base) = infinitive.strip_prefix(prefix)
+            && let Some(i) = IRREGULAR.get(base)
+        {
+            return (prefix, base, Some(i));
+        }
+    }
+
+    ("", infinitive, None)
+}
+
+/// Raíz del verbo: "spielen" -> "spiel", "wandern" -> "wander".
+fn stem(infinitive: &str) -> &str {
+    if infinitive.ends_with("eln") || infinitive.ends_with("ern") {
+        return &infinitive[..infinitive.len() - 1];
+    }
+    infinitive
+        .strip_suffix("en")
+        .or_else(|| infinitive.strip_suffix('n'))
+        .unwrap_or(infinitive)
+}
+
+/// La raíz necesita una "e" antes de -st/-t: "arbeitest", "öffnet".
+fn needs_e(stem: &str) -> bool {
+    let chars: Vec<char> = stem.chars().collect();
+    match chars.as_slice() {
+        [.., 't' | 'd'] => true,
+        [.., before, 'm' | 'n'] => !"aeiouäöülrmnh".contains(*before),
+        _ => false,
+    }
+}
+
+fn ends_with_sibilant(stem: &str) -> bool {
+    stem.ends_with(['s', 'ß', 'z', 'x'])
+}
+
+fn praesens_regular(infinitive: &str, stem: &str) -> Vec<String> {
+    let ich = match infinitive.strip_suffix("eln") {
+        // "sammeln" -> "sammle"
+        Some(root) => format!("{}le", root),
+        None => format!("{}e", stem),
+    };
+    let e = if needs_e(stem) { "e" } else { "" };
+    let du = if ends_with_sibilant(stem) {
+        format!("{}t", stem)
+    } else {
+        format!("{}{}st", stem, e)
+    };
+    let t = format!("{}{}t", stem, e);
+
+    vec![
+        ich,
+        du,
+        t.clone(),
+        infinitive.to_string(),
+        t,
+        infinitive.to_string(),
+    ]
+}
+
+/// Terminaciones débiles sobre una raíz que ya termina en -t: "spielt" -> "spielte".
+fn weak_endings(stem: &str) -> Vec<String> {
+    ["e", "est", "e", "en", "et", "en"]
+        .iter()
+        .map(|end| format!("{}{}", stem, end))
+        .collect()
+}
@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod test_lemma {
+    use crate::{
+        db::schemas::worte::WorteSchema,
+        helpers::{
+            lemma::{Form, SetzeIndex, forms, matches, tokens},
+            test_worte::wort_schema,
+        },
+    };
+
+    fn wort(
+        code: &str,
+        worte_de: &str,
+        plural: Option<&str>,
+        trennbar: Option<bool>,
+    ) -> WorteSchema {
+        WorteSchema {
+            plural: plural.map(|p| p.into()),
+            trennbar,
+            ..wort_schema(code, worte_de)
+        }
+    }
+
+    fn found(wort: &WorteSchema, satz: &str) -> bool {
+        matches(&forms(wort), &tokens(satz))
+    }
+
+    #[test]
+    fn test_nouns() {
+        let hund = wort("noun_common", "der Hund", Some("Hunde"), None);
+        assert!(found(&hund, "Der Hund spielt."));
+        assert!(found(&hund, "Die Hunde spielen."));
+        assert!(found(&hund, "Ich spiele mit den Hunden."));
+        assert!(!found(&hund, "Ich habe hundert Euro."));
+
+        let kind = wort("noun_common", "Kind", Some("Kinder"), None);
+        assert!(found(&kind, "Ich helfe den Kindern."));
+        assert!(found(&kind, "Das Spielzeug des Kindes."));
+    }
+
+    #[test]
+    fn test_verbs() {
+        let spielen = wort("verb_main", "spielen", None, None);
+        assert!(found(&spielen, "Du spielst gut."));
+        assert!(found(&spielen, "Wir haben gestern gespielt."));
+        assert!(found(&spielen, "Er spielte Fußball."));
+        assert!(!found(&spielen, "Das Spiel ist aus."));
+
+        // Formas irregulares
+        let sein = wort("verb_aux", "sein", None, None);
+        assert!(found(&sein, "Ich bin müde."));
+        assert!(found(&sein, "Wir waren zu Hause."));
+
+        let fahren = wort("verb_main", "fahren", None, None);
+        assert!(found(&fahren, "Er fährt nach Berlin."));
+
+        // Las mismas formas que la tabla de conjugación
+        let singen = wort("verb_main", "singen", None, None);
+        assert!(found(&singen, "Sie sang ein Lied."));
+        let werfen = wort("verb_main", "werfen", None, None);
+        assert!(found(&werfen, "Er warf den Ball."));
+        let verstehen = wort("verb_main", "verstehen", None, None);
+        assert!(found(&verstehen, "Ich habe dich verstanden."));
+
+        // Verbos reflexivos y en -ern
+        let freuen = wort("verb_main", "sich freuen", None, None);
+        assert!(found(&freuen, "Ich freue mich."));
+        let wandern = wort("verb_main", "wandern", None, None);
+        assert!(found(&wandern, "Wir wandern gern."));
+        assert!(found(&wandern, "Er wandert viel."));
+    }
+
+    #[test]
+    fn test_separable_verbs() {
+        let anrufen = wort("verb_separable", "anrufen", None, Some(true));
+        assert!(found(&anrufen, "Ich rufe dich morgen an."));
+        assert!(found(&anrufen, "Ich habe dich angerufen."));
+        assert!(found(&anrufen, "Ich will dich anrufen."));
+        assert!(found(&anrufen, "Vergiss nicht, mich anzurufen!"));
+        assert!(!found(&anrufen, "Ich rufe dich."));
+        // El prefijo va después de la base
+        assert!(!found(&anrufen, "An der Tür rufe ich."));
+
+        let aufstehen = wort("verb_separable", "aufstehen", None, Some(true));
+        assert!(found(&aufstehen, "Er stand um sieben Uhr auf."));
+        assert!(forms(&aufstehen).contains(&Form::Split {
+            base: "steht".into(),
+            prefix: "auf".into(),
+        }));
+    }
+
+    #[test]
+    fn test_adjectives_and_phrases() {
+        let gross = wort("adjective", "groß", None, None);
+        assert!(found(&gross, "Das ist ein großes Haus."));
+        assert!(found(&gross, "Das Haus ist groß."));
+
+        let phrase = wort("expression", "zum Beispiel", None, None);
+        assert_eq!(
+            forms(&phrase),
+            vec![Form::Phrase(vec!["zum".into(), "beispiel".into()])]
+        );
+        assert!(found(&phrase, "Zum Beispiel: Äpfel."));
+        assert!(!found(&phrase, "Ein Beispiel."));
+    }
+
+    #[test]
+    fn test_setze_index() {
+        let index = SetzeIndex::new([
+            (10, "Ich rufe dich an."),
+            (11, "Der Hund schläft."),
+            (12, "Die Hunde rufen laut."),
+        ]);
+
+        let hund = wort("noun_common", "Hund", Some("Hunde"), None);
+        assert_eq!(index.find(&forms(&hund)), vec![11, 12]);
+
+        let anrufen = wort("verb_separable", "anrufen", None, Some(true));
+        assert_eq!(index.find(&forms(&anrufen)), vec![10]);
+
+        let katze = wort("noun_common", "Katze", Some("Katzen"), None);
+        assert!(index.find(&forms(&katze)).is_empty());
+    }
+}
@@ -0,0 +1,238 @@
+use std::collections::HashMap;
+
+use crate::{
+    db::schemas::worte::WorteSchema,
+    helpers::{konjugation, scramble},
+};
+
+#[cfg(test)]
+mod lemma_test;
+
+/// Prefijos separables, del más largo al más corto para quedarse con el más específico.
+const SEPARABLE_PREFIXES: [&str; 24] = [
+    "zusammen", "zurück", "vorbei", "heraus", "herein", "hinaus", "weiter", "fern", "fest",
+    "statt", "teil", "nach", "auf", "aus", "ein", "mit", "vor", "weg", "her", "hin", "los", "ab",
+    "an", "zu",
+];
+
+/// Palabra sin el "sich" de los verbos reflexivos ni el artículo con el que a veces se
+/// guardan los sustantivos: "sich freuen" -> "freuen", "der Hund" -> "Hund".
+pub fn base(worte_de: &str) -> &str {
+    let worte_de = worte_de.trim();
+    ["sich ", "der ", "die ", "das "]
+        .iter()
+        .find_map(|p| {
+            worte_de
+                .get(..p.len())
+                .filter(|s| s.eq_ignore_ascii_case(p))
+                .map(|_| &worte_de[p.len()..])
+        })
+        .unwrap_or(worte_de)
+        .trim()
+}
+
+/// Forma en la que una palabra puede aparecer dentro de una oración.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Form {
+    /// Una sola palabra: "Hunde", "gehst"
+    Token(String),
+    /// Verbo separable: la base conjugada y después, en la misma oración, el prefijo
+    /// ("Ich rufe dich an")
+    Split { base: String, prefix: String },
+    /// Expresión de varias palabras que deben aparecer todas ("zum Beispiel")
+    Phrase(Vec<String>),
+}
+
+/// Formas de la palabra que se buscan en las oraciones (en minúsculas).
+///
+/// Es un mapa de lemas sencillo: sustantivos con sus terminaciones de caso y plural,
+/// adjetivos con sus terminaciones, verbos con su tabla de conjugación (`konjugation`) y los
+/// verbos separables también partidos.
+pub fn forms(wort: &WorteSchema) -> Vec<Form> {
+    let codes: Vec<&str> = wort.gram_type_id.iter().map(|g| g.code.as_str()).collect();
+    let is_verb = konjugation::is_verb(wort);
+    let is_noun = codes.iter().any(|c| c.starts_with("noun")) || wort.gender_id.is_some();
+    let is_adjective = codes.contains(&"adjective");
+    let separable = wort.trennbar == Some(true) || codes.contains(&"verb_separable");
+
+    // "sich freuen" se busca como "freuen"; el artículo de un sustantivo tampoco cuenta
+    let worte_de = if is_verb || is_noun {
+        base(&wort.worte_de)
+    } else {
+        wort.worte_de.trim()
+    };
+    let tokens: Vec<String> = scramble::tokenize(worte_de)
+        .iter()
+        .map(|t| t.to_lowercase())
+        .collect();
+
+    let mut out = vec![];
+    match tokens.as_slice() {
+        [] => {}
+        [lemma] if is_verb && separable => out.extend(separable_forms(lemma)),
+        [lemma] if is_verb => out.extend(verb_forms(lemma).into_iter().map(Form::Token)),
+        [lemma] if is_noun => {
+            let plural = wort.plural.as_deref().map(str::to_lowercase);
+            out.extend(
+                noun_forms(lemma, plural.as_deref())
+                    .into_iter()
+                    .map(Form::Token),
+            );
+        }
+        [lemma] if is_adjective => {
+            out.extend(
+                ["", "e", "en", "er", "es", "em"]
+                    .iter()
+                    .map(|end| Form::Token(format!("{}{}", lemma, end))),
+            );
+        }
+        [lemma] => out.push(Form::Token(lemma.clone())),
+        _ => out.push(Form::Phrase(tokens)),
+    }
+
+    dedup(out)
+}
+
+/// Indica si alguna de las formas aparece en la oración ya separada en palabras (minúsculas).
+fn matches(forms: &[Form], tokens: &[String]) -> bool {
+    forms.iter().any(|form| match form {
+        Form::Token(t) => tokens.contains(t),
+        Form::Split { base, prefix } => tokens
+            .iter()
+            .position(|t| t == base)
+            .is_some_and(|i| tokens[i + 1..].contains(prefix)),
+        Form::Phrase(words) => words.iter().all(|w| tokens.contains(w)),
+    })
+}
+
+/// Palabras de la oración en minúsculas y sin puntuación, como las compara `matches`.
+fn tokens(satz_de: &str) -> Vec<String> {
+    scramble::tokenize(satz_de)
+        .iter()
+        .map(|t| t.to_lowercase())
+        .collect()
+}
+
+/// Índice invertido de oraciones para buscar muchas palabras sin recorrer todas las
+/// oraciones con cada una.
+#[derive(Debug, Default)]
+pub struct SetzeIndex {
+    setze: Vec<(i32, Vec<String>)>,
+    by_token: HashMap<String, Vec<usize>>,
+}
+
+impl SetzeIndex {
+    pub fn new<'a>(setze: impl IntoIterator<Item = (i32, &'a str)>) -> Self {
+        let mut index = Self::default();
+        for (id, satz_de) in setze {
+            let tokens = tokens(satz_de);
+            for t in &tokens {
+                let positions = index.by_token.entry(t.clone()).or_default();
+                if positions.last() != Some(&index.setze.len()) {
+                    positions.push(index.setze.len());
+                }
+            }
+            index.setze.push((id, tokens));
+        }
+        index
+    }
+
+    /// Ids de las oraciones donde aparece alguna de las formas, en el orden del índice.
+    pub fn find(&self, forms: &[Form]) -> Vec<i32> {
+        let mut candidates: Vec<usize> = forms
+            .iter()
+            .filter_map(|form| match form {
+                Form::Token(t) => Some(t),
+                Form::Split { base, .. } => Some(base),
+                Form::Phrase(words) => words.first(),
+            })
+            .filter_map(|t| self.by_token.get(t))
+            .flatten()
+            .copied()
+            .collect();
+        candidates.sort();
+        candidates.dedup();
+
+        candidates
+            .into_iter()
+            .map(|i| &self.setze[i])
+            .filter(|(_, tokens)| matches(forms, tokens))
+            .map(|(id, _)| *id)
+            .collect()
+    }
+}
+
+fn noun_forms(lemma: &str, plural: Option<&str>) -> Vec<String> {
+    let mut out: Vec<String> = ["", "e", "n", "en", "s", "es"]
+        .iter()
+        .map(|end| format!("{}{}", lemma, end))
+        .collect();
+    if let Some(plural) = plural.filter(|p| !p.is_empty()) {
+        out.push(plural.to_string());
+        // Dativo plural: "den Kindern"
+        if !plural.ends_with('n') && !plural.ends_with('s') {
+            out.push(format!("{}n", plural));
+        }
+    }
+    out
+}
+
+/// Infinitivo y formas conjugadas del verbo, tomadas de la misma tabla que el ejercicio de
+/// conjugación para que los irregulares ("sang", "warf") coincidan en los dos lados.
+fn verb_forms(infinitive: &str) -> Vec<String> {
+    let mut out = vec![infinitive.to_string()];
+    out.extend(
+        konjugation::conjugate_infinitive(infinitive)
+            .into_iter()
+            // El imperativo sin terminación ("spiel") se confunde con el sustantivo
+            .filter(|k| k.tempus != konjugation::Tempus::Imperativ)
+            .map(|k| k.form.to_lowercase()),
+    );
+    out
+}
+
+/// El token es un prefijo separable suelto ("an" en "ich rufe dich an").
+pub fn is_separable_prefix(token: &str) -> bool {
+    SEPARABLE_PREFIXES.contains(&token)
+}
+
+/// Separa el prefijo de un verbo separable: "anrufen" -> ("an", "rufen").
+pub fn split_separable(infinitive: &str) -> Option<(&'static str, &str)> {
+    SEPARABLE_PREFIXES
+        .iter()
+        .find(|p| infinitive.starts_with(*p) && infinitive.len() > p.len() + 2)
+        .map(|p| (*p, &infinitive[p.len()..]))
+}
+
+fn separable_forms(infinitive: &str) -> Vec<Form> {
+    let Some((prefix, base)) = split_separable(infinitive) else {
+        return verb_forms(infinitive)
+            .into_iter()
+            .map(Form::Token)
+            .collect();
+    };
+
+    let base_forms = verb_forms(base);
+
+    // Junto: "anrufen", "angerufen", "anzurufen"; separado: "rufe ... an"
+    let mut out: Vec<Form> = base_forms
+        .iter()
+        .map(|f| Form::Token(format!("{}{}", prefix, f)))
+        .collect();
+    out.push(Form::Token(format!("{}zu{}", prefix, base)));
+    out.extend(base_forms.into_iter().map(|base| Form::Split {
+        base,
+        prefix: prefix.to_string(),
+    }));
+    out
+}
+
+fn dedup(forms: Vec<Form>) -> Vec<Form> {
+    let mut out: Vec<Form> = Vec::with_capacity(forms.len());
+    for f in forms {
+        if !out.contains(&f) {
+            out.push(f);
+        }
+    }
+    out
+}
@@ -1,6 +1,30 @@
 pub mod audios;
 pub mod console;
 pub mod csv;
+pub mod daily;
+pub mod deck;
+pub mod deklination;
+pub mod diff;
+pub mod editor;
+pub mod fehler;
+pub mod frequenz;
+pub mod konjugation;
+pub mod lemma;
+pub mod niveau;
+pub mod nomen;
+pub mod praeposition;
+pub mod profile;
 pub mod review_state;
+pub mod scramble;
+pub mod search;
+pub mod session;
+pub mod settings;
+pub mod stats;
+pub mod steigerung;
+pub mod tags;
+#[cfg(test)]
+pub mod test_worte;
 pub mod time;
 pub mod ui;
+pub mod vergleich;
+pub mod verwechslung;
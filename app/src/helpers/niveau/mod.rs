@@ -0,0 +1,140 @@
+use std::collections::{HashMap, HashSet};
+
+use color_eyre::eyre::Result;
+use rusqlite::Connection;
+use serde::Serialize;
+
+use crate::{
+    db::{
+        niveau_liste::NiveauListeRepo, setze::SetzeRepo, worte::WorteRepo,
+        worte_setze::MATURE_INTERVAL,
+    },
+    helpers::{deck, settings},
+};
+
+#[cfg(test)]
+mod niveau_test;
+
+/// Tarjetas de un nivel y cuántas ya son maduras (intervalo de al menos `MATURE_INTERVAL`).
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct Beherrschung {
+    pub total: usize,
+    pub mature: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NiveauFortschritt {
+    pub niveau_id: i32,
+    pub niveau: String,
+    pub worte: Beherrschung,
+    pub setze: Beherrschung,
+    /// Porcentaje de palabras y oraciones maduras
+    pub percent: f64,
+    pub mastered: bool,
+}
+
+/// Avance de cada nivel en `decks` (todos si no se indica). Un nivel está dominado cuando al
+/// menos `mastery_percent`% de sus tarjetas son maduras; un nivel sin tarjetas no lo está.
+pub fn fortschritt(
+    conn: &Connection,
+    decks: Option<&[i32]>,
+    mastery_percent: usize,
+) -> Result<Vec<NiveauFortschritt>> {
+    let worte = WorteRepo::fetch_niveau_interval(conn)?;
+    let ids = deck::filter_worte(conn, worte.iter().map(|(id, _, _)| *id).collect(), decks)?;
+    let worte = zaehlen(&worte, &ids.into_iter().collect());
+
+    let setze = SetzeRepo::fetch_niveau_interval(conn)?;
+    let ids = deck::filter_setze(conn, setze.iter().map(|(id, _, _)| *id).collect(), decks)?;
+    let setze = zaehlen(&setze, &ids.into_iter().collect());
+
+    Ok(NiveauListeRepo::fetch_all(conn)?
+        .into_iter()
+        .map(|n| {
+            let worte = worte.get(&n.id).cloned().unwrap_or_default();
+            let setze = setze.get(&n.id).cloned().unwrap_or_default();
+            let total = worte.total + setze.total;
+            let percent = match total {
+                0 => 0.0,
+                t => (worte.mature + setze.mature) as f64 * 100.0 / t as f64,
+            };
+            NiveauFortschritt {
+                niveau_id: n.id,
+                niveau: n.niveau,
+                worte,
+                setze,
+                percent,
+                mastered: total > 0 && percent >= mastery_percent as f64,
+            }
+        })
+        .collect())
+}
+
+fn zaehlen(rows: &[(i32, i32, u32)], ids: &HashSet<i32>) -> HashMap<i32, Beherrschung> {
+    let mut out: HashMap<i32, Beherrschung> = HashMap::new();
+    for (id, niveau_id, interval) in rows {
+        if !ids.contains(id) {
+            continue;
+        }
+        let b = out.entry(*niveau_id).or_default();
+        b.total += 1;
+        if *interval >= MATURE_INTERVAL {
+            b.mature += 1;
+        }
+    }
+    out
+}
+
+/// Nivel más bajo con tarjetas que todavía no está dominado.
+pub fn aktuelles_niveau(fortschritt: &[NiveauFortschritt]) -> Option<i32> {
+    fortschritt
+        .iter()
+        .find(|n| !n.mastered && n.worte.total + n.setze.total > 0)
+        .map(|n| n.niveau_id)
+}
+
+/// Primero las tarjetas del nivel `aktuell`, luego las de niveles más altos y al final lo que
+/// quede de los niveles ya dominados. Dentro de cada nivel se respeta el orden de `ids`.
+pub fn order_by_niveau(
+    mut ids: Vec<i32>,
+    niveau: &HashMap<i32, i32>,
+    aktuell: Option<i32>,
+) -> Vec<i32> {
+    let aktuell = aktuell.unwrap_or(i32::MIN);
+    ids.sort_by_key(|id| {
+        let n = niveau.get(id).copied().unwrap_or(i32::MAX);
+        (n < aktuell, n)
+    });
+    ids
+}
+
+/// Con `niveau.unlock` activo ordena las palabras nuevas con `order_by_niveau`; si no, las
+/// regresa igual.
+pub fn unlock_worte(conn: &Connection, ids: Vec<i32>, decks: Option<&[i32]>) -> Result<Vec<i32>> {
+    let config = settings::get().niveau;
+    if !config.unlock {
+        return Ok(ids);
+    }
+
+    let aktuell = aktuelles_niveau(&fortschritt(conn, decks, config.mastery_percent)?);
+    let niveau = WorteRepo::fetch_niveau_interval(conn)?
+        .into_iter()
+        .map(|(id, n, _)| (id, n))
+        .collect();
+    Ok(order_by_niveau(ids, &niveau, aktuell))
+}
+
+/// Igual que `unlock_worte` para las oraciones nuevas.
+pub fn unlock_setze(conn: &Connection, ids: Vec<i32>, decks: Option<&[i32]>) -> Result<Vec<i32>> {
+    let config = settings::get().niveau;
+    if !config.unlock {
+        return Ok(ids);
+    }
+
+    let aktuell = aktuelles_niveau(&fortschritt(conn, decks, config.mastery_percent)?);
+    let niveau = SetzeRepo::fetch_niveau_interval(conn)?
+        .into_iter()
+        .map(|(id, n, _)| (id, n))
+        .collect();
+    Ok(order_by_niveau(ids, &niveau, aktuell))
+}
@@ -0,0 +1,147 @@
+use crate::db::setup_test_db;
+
+#[cfg(test)]
+mod test_niveau {
+    use std::{collections::HashMap, fs};
+
+    use rusqlite::Connection;
+
+    use crate::{
+        db::{
+            deck::DeckRepo,
+            schemas::{setze_review::NewSetzeReviewSchema, worte_review::NewWorteReviewSchema},
+            seeders::init_data,
+            setze::SetzeRepo,
+            setze_review::SetzeReviewRepo,
+            worte::WorteRepo,
+            worte_review::WorteReviewRepo,
+        },
+        helpers::{
+            csv,
+            niveau::{aktuelles_niveau, fortschritt, order_by_niveau, unlock_worte},
+        },
+    };
+
+    use super::*;
+
+    const WORTE_CSV: &str = "\
+gram_type,gender_id,worte_de,worte_es,plural,niveau_id,example_de,example_es,verb_aux,trennbar,reflexiv
+noun_common,Maskuline,Hund,el perro,Hunde,A1,Der Hund spielt.,El perro juega.,,,
+noun_common,Femenin,Katze,el gato,Katzen,A1,Die Katze schläft.,El gato duerme.,,,
+verb_main,,gehen,ir,,A2,Ich gehe.,Voy.,sein,No,No
+verb_main,,laufen,correr,,B1,Ich laufe.,Corro.,sein,No,No
+";
+
+    const SETZE_CSV: &str = "\
+setze_spanisch,setze_deutsch,thema,schwirig_id,niveau
+Tengo un perro,Ich habe einen Hund,Akkusativ,0,A1
+Voy a casa,Ich gehe nach Hause,Dativ,1,A2
+";
+
+    fn worte_review(wort_id: i32, interval: u32) -> NewWorteReviewSchema {
+        NewWorteReviewSchema {
+            wort_id,
+            interval,
+            ease_factor: 2.5,
+            repetitions: 3,
+            last_review: "2020-01-01 00:00:00".into(),
+            next_review: "2999-01-01 00:00:00".into(),
+        }
+    }
+
+    /// A1: Hund (maduro), Katze (maduro) y una oración que no lo es; A2 y B1 sin repasar.
+    fn init_data_local(conn: &mut Connection, name: &str) {
+        init_data(conn).unwrap();
+
+        let dir = std::env::temp_dir().join(format!("anki_niveau_{}_{}", name, std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let worte = dir.join("worte.csv");
+        let setze = dir.join("setze.csv");
+        fs::write(&worte, WORTE_CSV).unwrap();
+        fs::write(&setze, SETZE_CSV).unwrap();
+
+        let deck = DeckRepo::ensure_path(conn, "Goethe A1").unwrap();
+        let data = csv::extract_worte_csv(worte.to_str().unwrap(), deck.id).unwrap();
+        WorteRepo::bulk_insert(conn, &data).unwrap();
+        let data = csv::extract_sentences_csv(setze.to_str().unwrap(), deck.id).unwrap();
+        SetzeRepo::bulk_insert(conn, &data).unwrap();
+
+        fs::remove_dir_all(&dir).unwrap();
+
+        WorteReviewRepo::bulk_insert(conn, &[worte_review(1, 30), worte_review(2, 21)]).unwrap();
+        SetzeReviewRepo::bulk_insert(
+            conn,
+            &[NewSetzeReviewSchema {
+                satz_id: 1,
+                interval: 5,
+                ease_factor: 2.5,
+                repetitions: 2,
+                last_review: "2020-01-01 00:00:00".into(),
+                next_review: "2999-01-01 00:00:00".into(),
+                lapses: 0,
+                schwirig_id: None,
+            }],
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_fortschritt() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn, "fortschritt");
+
+        let list = fortschritt(&conn, None, 80).unwrap();
+        let niveaus: Vec<&str> = list.iter().map(|n| n.niveau.as_str()).collect();
+        assert_eq!(niveaus, ["A1", "A2", "B1", "B2", "C1", "C2"]);
+
+        let a1 = &list[0];
+        assert_eq!((a1.worte.total, a1.worte.mature), (2, 2));
+        assert_eq!((a1.setze.total, a1.setze.mature), (1, 0));
+        assert!((a1.percent - 200.0 / 3.0).abs() < 1e-9);
+        assert!(!a1.mastered);
+        assert_eq!(list[1].worte.total + list[1].setze.total, 2);
+        assert_eq!(list[1].percent, 0.0);
+        // Sin tarjetas no se da por dominado
+        assert!(!list[5].mastered);
+        assert_eq!(aktuelles_niveau(&list), Some(list[0].niveau_id));
+
+        let list = fortschritt(&conn, None, 60).unwrap();
+        assert!(list[0].mastered);
+        assert_eq!(aktuelles_niveau(&list), Some(list[1].niveau_id));
+
+        // Un deck sin tarjetas
+        let otro = DeckRepo::ensure_path(&mut conn, "Otro").unwrap();
+        let list = fortschritt(&conn, Some(&[otro.id]), 80).unwrap();
+        assert!(list.iter().all(|n| n.worte.total + n.setze.total == 0));
+        assert_eq!(aktuelles_niveau(&list), None);
+    }
+
+    #[test]
+    fn test_order_by_niveau() {
+        // id -> nivel: 1, 2 en A1 (0); 3 en A2 (1); 4 en B1 (2)
+        let niveau = HashMap::from([(1, 0), (2, 0), (3, 1), (4, 2)]);
+
+        assert_eq!(
+            order_by_niveau(vec![4, 2, 3, 1], &niveau, Some(1)),
+            vec![3, 4, 2, 1]
+        );
+        assert_eq!(
+            order_by_niveau(vec![4, 2, 3, 1], &niveau, Some(0)),
+            vec![2, 1, 3, 4]
+        );
+        // Todo dominado: de menor a mayor
+        assert_eq!(
+            order_by_niveau(vec![4, 3, 2, 1], &niveau, None),
+            vec![2, 1, 3, 4]
+        );
+    }
+
+    #[test]
+    fn test_unlock_desactivado() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn, "unlock");
+
+        // Sin `niveau.unlock` el orden no cambia
+        assert_eq!(unlock_worte(&conn, vec![4, 3], None).unwrap(), vec![4, 3]);
+    }
+}
@@ -0,0 +1,135 @@
+use crate::{db::schemas::worte::WorteSchema, helpers::deklination::Genus};
+
+#[cfg(test)]
+mod nomen_test;
+
+/// Error específico al escribir un sustantivo con su artículo.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NomenFehler {
+    /// "Hund" en lugar de "der Hund"
+    FehlenderArtikel,
+    /// "die Hund": el artículo que se escribió
+    FalscherArtikel(String),
+    /// "der hund": el sustantivo va con mayúscula
+    Kleinschreibung,
+    /// "Der Hund": el artículo va en minúscula
+    GrossArtikel,
+    /// "die Hunde": se escribió el plural en lugar del singular
+    Plural,
+}
+
+/// Sustantivos con género: su respuesta es "artículo sustantivo".
+pub fn is_nomen(wort: &WorteSchema) -> bool {
+    wort.gender_id.is_some()
+        && wort
+            .gram_type_id
+            .iter()
+            .any(|g| g.code.starts_with("noun_"))
+}
+
+/// Errores de artículo, mayúsculas o número en la respuesta `input` para el sustantivo `wort`.
+///
+/// Regresa vacío si no es un sustantivo, si la respuesta es correcta o si el sustantivo es
+/// otro (ahí no hay nada específico que explicar).
+pub fn check(wort: &WorteSchema, input: &str) -> Vec<NomenFehler> {
+    let (Some(gender), Some(genus)) = (&wort.gender_id, Genus::from_wort(wort)) else {
+        return vec![];
+    };
+    if !is_nomen(wort) {
+        return vec![];
+    }
+    let artikel = gender.artikel.to_lowercase();
+    let nomen = wort.worte_de.trim();
+
+    let tokens: Vec<&str> = input.split_whitespace().collect();
+    let (artikel_in, nomen_in) = match tokens.as_slice() {
+        [n] => (None, *n),
+        [a, n] => (Some(*a), *n),
+        _ => return vec![],
+    };
+
+    let plural = wort
+        .plural
+        .as_deref()
+        .map(str::trim)
+        .filter(|p| !p.is_empty() && genus != Genus::Plural);
+    if plural.is_some_and(|p| p.to_lowercase() == nomen_in.to_lowercase()) {
+        return vec![NomenFehler::Plural];
+    }
+    if nomen_in.to_lowercase() != nomen.to_lowercase() {
+        return vec![];
+    }
+
+    let mut out = vec![];
+    match artikel_in {
+        None => out.push(NomenFehler::FehlenderArtikel),
+        Some(a) if a.to_lowercase() != artikel => {
+            out.push(NomenFehler::FalscherArtikel(a.to_lowercase()))
+        }
+        Some(a) if a != artikel => out.push(NomenFehler::GrossArtikel),
+        Some(_) => {}
+    }
+    if nomen_in != nomen {
+        out.push(NomenFehler::Kleinschreibung);
+    }
+    out
+}
+
+/// Explicación de cada error para el usuario.
+pub fn feedback(wort: &WorteSchema, fehler: &[NomenFehler]) -> Vec<String> {
+    let (Some(gender), Some(genus)) = (&wort.gender_id, Genus::from_wort(wort)) else {
+        return vec![];
+    };
+    let artikel = gender.artikel.to_lowercase();
+    let nomen = wort.worte_de.trim();
+    let richtig = format!("{} {}", artikel, nomen);
+
+    fehler
+        .iter()
+        .map(|f| match f {
+            NomenFehler::FehlenderArtikel => format!(
+                "Falta el artículo: «{}» es {}, se escribe «{}».",
+                nomen,
+                genus.label(),
+                richtig
+            ),
+            NomenFehler::FalscherArtikel(a) if genus == Genus::Plural => format!(
+                "«{}» solo se usa en plural y el plural siempre lleva «die», no «{}».",
+                nomen, a
+            ),
+            NomenFehler::FalscherArtikel(a) if a == "die" => format!(
+                "«die» es el artículo del femenino y del plural; «{}» es {}: «{}».",
+                nomen,
+                genus.label(),
+                richtig
+            ),
+            NomenFehler::FalscherArtikel(a) => format!(
+                "«{}» es {}: lleva «{}», no «{}».",
+                nomen,
+                genus.label(),
+                artikel,
+                a
+            ),
+            NomenFehler::Kleinschreibung => format!(
+                "Los sustantivos en alemán siempre van con mayúscula: «{}».",
+                nomen
+            ),
+            NomenFehler::GrossArtikel => {
+                format!("El artículo va en minúscula: «{}».", richtig)
+            }
+            NomenFehler::Plural => format!(
+                "Escribiste el plural; se pide el singular: «{}» (plural: «die {}»).",
+                richtig,
+                wort.plural.as_deref().unwrap_or_default().trim()
+            ),
+        })
+        .collect()
+}
+
+/// Solo fallaron las mayúsculas: el artículo y el sustantivo son los correctos.
+pub fn is_partial(fehler: &[NomenFehler]) -> bool {
+    !fehler.is_empty()
+        && fehler
+            .iter()
+            .all(|f| matches!(f, NomenFehler::Kleinschreibung | NomenFehler::GrossArtikel))
+}
@@ -0,0 +1,136 @@
+use crate::db::setup_test_db;
+
+#[cfg(test)]
+mod test_nomen {
+    use rusqlite::Connection;
+
+    use crate::{
+        db::{
+            schemas::worte::{NewWorteSchema, WorteSchema},
+            seeders::init_data,
+            worte::WorteRepo,
+        },
+        helpers::{
+            nomen::{NomenFehler, check, feedback, is_nomen, is_partial},
+            review_state::{ReviewState, SchedulerParams},
+            session::{Outcome, Session, SessionCard},
+            test_worte,
+        },
+    };
+
+    use super::*;
+
+    fn wort(
+        worte_de: &str,
+        gram_type: i32,
+        gender_id: Option<i32>,
+        plural: Option<&str>,
+    ) -> NewWorteSchema {
+        NewWorteSchema {
+            plural: plural.map(str::to_string),
+            ..test_worte::wort(worte_de, gram_type, gender_id)
+        }
+    }
+
+    /// 1 der Hund, 2 die Katze, 3 die Leute (plural), 4 gehen
+    fn init_data_local(conn: &mut Connection) {
+        init_data(conn).unwrap();
+        WorteRepo::bulk_insert(
+            conn,
+            &[
+                wort("Hund", 0, Some(0), Some("Hunde")),
+                wort("Katze", 0, Some(1), Some("Katzen")),
+                wort("Leute", 0, Some(3), None),
+                wort("gehen", 2, None, None),
+            ],
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_check() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+        let worte = WorteRepo::fetch_by_id(&conn, &[1, 2, 3, 4]).unwrap();
+        let (hund, katze, leute, gehen) = (&worte[0], &worte[1], &worte[2], &worte[3]);
+
+        assert!(is_nomen(hund));
+        assert!(!is_nomen(gehen));
+        assert!(check(gehen, "Gehen").is_empty());
+
+        assert!(check(hund, "der Hund").is_empty());
+        assert_eq!(check(hund, "Hund"), vec![NomenFehler::FehlenderArtikel]);
+        assert_eq!(
+            check(hund, "die Hund"),
+            vec![NomenFehler::FalscherArtikel("die".into())]
+        );
+        assert_eq!(check(hund, "der hund"), vec![NomenFehler::Kleinschreibung]);
+        assert_eq!(check(hund, "Der Hund"), vec![NomenFehler::GrossArtikel]);
+        assert_eq!(
+            check(hund, "das hund"),
+            vec![
+                NomenFehler::FalscherArtikel("das".into()),
+                NomenFehler::Kleinschreibung
+            ]
+        );
+        assert_eq!(check(hund, "die Hunde"), vec![NomenFehler::Plural]);
+        assert_eq!(check(katze, "katzen"), vec![NomenFehler::Plural]);
+        // Otro sustantivo: nada específico que explicar
+        assert!(check(hund, "die Katze").is_empty());
+        assert_eq!(
+            check(leute, "der Leute"),
+            vec![NomenFehler::FalscherArtikel("der".into())]
+        );
+    }
+
+    #[test]
+    fn test_feedback_and_partial() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+        let worte = WorteRepo::fetch_by_id(&conn, &[1, 3]).unwrap();
+        let (hund, leute) = (&worte[0], &worte[1]);
+
+        let hint = |w, input| feedback(w, &check(w, input));
+        assert_eq!(
+            hint(hund, "Hund"),
+            vec!["Falta el artículo: «Hund» es masculino, se escribe «der Hund»."]
+        );
+        // "die" del femenino y del plural
+        assert!(
+            hint(hund, "die Hund")[0].starts_with("«die» es el artículo del femenino y del plural")
+        );
+        assert!(hint(leute, "der Leute")[0].contains("siempre lleva «die»"));
+        assert!(hint(hund, "die Hunde")[0].contains("se pide el singular: «der Hund»"));
+
+        // Solo las mayúsculas dan crédito parcial
+        assert!(is_partial(&check(hund, "der hund")));
+        assert!(is_partial(&check(hund, "Der Hund")));
+        assert!(!is_partial(&check(hund, "die hund")));
+        assert!(!is_partial(&check(hund, "der Hund")));
+        assert!(hund.partial_credit("der hund"));
+        assert!(!hund.partial_credit("Hund"));
+        assert_eq!(hund.hints("Hund").len(), 1);
+    }
+
+    #[test]
+    fn test_partial_keeps_interval() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+
+        // Solo el artículo en mayúscula: se guarda como error pero no es un lapso
+        let mut session = Session::<WorteSchema>::new(&conn, vec![1], 1).unwrap();
+        assert_eq!(session.answer(&conn, "Der Hund").unwrap(), Outcome::Partial);
+        assert_eq!(session.take_mistakes().len(), 1);
+        let results = session.into_results();
+        assert_eq!(results, [(1, 2)]);
+
+        let state = ReviewState {
+            interval: 10,
+            ease_factor: 2.5,
+            repetitions: 4,
+        };
+        let next = state.review_with(results[0].1, &SchedulerParams::default());
+        assert_eq!(next.repetitions, 5);
+        assert!(next.interval > 10);
+    }
+}
@@ -0,0 +1,306 @@
+use std::collections::{HashMap, HashSet};
+
+use color_eyre::eyre::Result;
+use rand::seq::{IndexedRandom, SliceRandom};
+use rusqlite::Connection;
+
+use crate::{
+    db::{
+        praeposition_review::PraepositionReviewRepo, schemas::worte::WorteSchema, worte::WorteRepo,
+    },
+    helpers::{
+        deck,
+        deklination::{Artikel, Genus, Kasus, decline},
+        fehler::{Art, Quelle},
+        session::SessionCard,
+        time,
+    },
+};
+
+#[cfg(test)]
+mod praeposition_test;
+
+/// Caso que rige la preposición según su tipo gramatical.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rektion {
+    Kasus(Kasus),
+    /// Wechselpräposition: dativo con Wo?, acusativo con Wohin?
+    Wechsel,
+}
+
+impl Rektion {
+    pub fn from_wort(wort: &WorteSchema) -> Option<Self> {
+        wort.gram_type_id
+            .iter()
+            .find_map(|g| match g.code.as_str() {
+                "preposition_dative" => Some(Rektion::Kasus(Kasus::Dativ)),
+                "preposition_akkusative" => Some(Rektion::Kasus(Kasus::Akkusativ)),
+                "preposition_genitive" => Some(Rektion::Kasus(Kasus::Genitiv)),
+                "preposition_two_way" => Some(Rektion::Wechsel),
+                _ => None,
+            })
+    }
+}
+
+/// Pregunta con la que se elige el caso de una preposición doble.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Frage {
+    /// Lugar: dativo
+    Wo,
+    /// Dirección: acusativo
+    Wohin,
+}
+
+impl Frage {
+    pub fn kasus(&self) -> Kasus {
+        match self {
+            Frage::Wo => Kasus::Dativ,
+            Frage::Wohin => Kasus::Akkusativ,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Frage::Wo => "Wo?",
+            Frage::Wohin => "Wohin?",
+        }
+    }
+}
+
+/// Pregunta del drill: el caso de la preposición, o el artículo en una mini oración con
+/// Wo?/Wohin? para las preposiciones dobles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PraepositionKey {
+    pub wort_id: i32,
+    pub frage: Option<(Frage, i32)>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PraepositionCard {
+    pub key: PraepositionKey,
+    pub praeposition: WorteSchema,
+    pub rektion: Rektion,
+    /// Sustantivo ya declinado de la mini oración ("Tisch", "Studenten")
+    pub noun: Option<String>,
+    answer: String,
+}
+
+impl PraepositionCard {
+    /// Lo que se le pregunta al usuario.
+    pub fn question(&self) -> String {
+        let praeposition = self.praeposition.worte_de.trim();
+        match (self.key.frage, &self.noun) {
+            (Some((frage, _)), Some(noun)) => {
+                let verb = match frage {
+                    Frage::Wo => "ist",
+                    Frage::Wohin => "geht",
+                };
+                format!(
+                    "{} Die Katze {} {} ___ {}.",
+                    frage.label(),
+                    verb,
+                    praeposition,
+                    noun
+                )
+            }
+            _ => format!("¿Qué caso rige «{}»?", praeposition),
+        }
+    }
+
+    /// Explicación que se muestra al fallar.
+    pub fn rule(&self) -> String {
+        let praeposition = self.praeposition.worte_de.trim();
+        match (self.rektion, self.key.frage) {
+            (Rektion::Wechsel, Some((frage, _))) => format!(
+                "«{}» es una preposición doble: con {} (lugar) va en dativo, con {} (dirección) en \
+                 acusativo. Aquí la pregunta es {}, así que va en {}.",
+                praeposition,
+                Frage::Wo.label(),
+                Frage::Wohin.label(),
+                frage.label(),
+                frage.kasus().label()
+            ),
+            (Rektion::Wechsel, None) => format!(
+                "«{}» es una preposición doble: dativo con Wo?, acusativo con Wohin?.",
+                praeposition
+            ),
+            (Rektion::Kasus(kasus), _) => {
+                format!("«{}» siempre rige {}.", praeposition, kasus.label())
+            }
+        }
+    }
+}
+
+impl SessionCard for PraepositionCard {
+    type Key = PraepositionKey;
+
+    fn key(&self) -> PraepositionKey {
+        self.key
+    }
+
+    fn expected(&self) -> String {
+        self.answer.clone()
+    }
+
+    fn normalize(&self, input: &str) -> String {
+        input.trim().to_lowercase()
+    }
+
+    /// Acepta abreviaturas del caso: "d", "dat", "a", "akk", "g", "gen".
+    fn parse_input(&self, input: &str) -> String {
+        if self.key.frage.is_some() {
+            return input.to_string();
+        }
+
+        let input = input.trim().to_lowercase();
+        let kasus = [Kasus::Dativ, Kasus::Akkusativ, Kasus::Genitiv]
+            .into_iter()
+            .find(|k| {
+                let label = k.label().to_lowercase();
+                !input.is_empty()
+                    && (label.starts_with(&input)
+                        || (input.starts_with("ac") && label.starts_with("ak")))
+            });
+        match kasus {
+            Some(k) => k.label().to_string(),
+            None => input,
+        }
+    }
+
+    fn quelle(&self) -> Quelle {
+        let kasus = match (self.rektion, self.key.frage) {
+            (_, Some((frage, _))) => frage.kasus(),
+            (Rektion::Kasus(kasus), None) => kasus,
+            // Sin pregunta una preposición doble no tiene un caso concreto
+            (Rektion::Wechsel, None) => {
+                return Quelle::wort(Art::Praeposition, self.key.wort_id);
+            }
+        };
+        Quelle::wort(Art::Praeposition, self.key.wort_id).with_detail(kasus.label())
+    }
+
+    fn fetch(conn: &Connection, keys: &[PraepositionKey]) -> Result<Vec<Self>> {
+        let mut ids: Vec<i32> = keys
+            .iter()
+            .flat_map(|k| [Some(k.wort_id), k.frage.map(|(_, noun_id)| noun_id)])
+            .flatten()
+            .collect();
+        ids.sort();
+        ids.dedup();
+
+        let worte: HashMap<i32, WorteSchema> = WorteRepo::fetch_by_id(conn, &ids)?
+            .into_iter()
+            .map(|w| (w.id, w))
+            .collect();
+
+        let mut out = vec![];
+        for key in keys {
+            let Some(praeposition) = worte.get(&key.wort_id) else {
+                continue;
+            };
+            let Some(rektion) = Rektion::from_wort(praeposition) else {
+                continue;
+            };
+
+            let (noun, answer) = match key.frage {
+                Some((frage, noun_id)) => {
+                    let Some(noun) = worte.get(&noun_id) else {
+                        continue;
+                    };
+                    // "dem Studenten" -> ("dem", "Studenten")
+                    let Some(deklination) =
+                        decline(noun, None, Artikel::Bestimmt, false, frage.kasus())
+                    else {
+                        continue;
+                    };
+                    let Some((answer, noun)) = deklination.form.split_once(' ') else {
+                        continue;
+                    };
+                    (Some(noun.to_string()), answer.to_string())
+                }
+                None => match rektion {
+                    Rektion::Kasus(kasus) => (None, kasus.label().to_string()),
+                    // Sin sustantivos se pregunta por el caso con Wo?
+                    Rektion::Wechsel => (None, Kasus::Dativ.label().to_string()),
+                },
+            };
+
+            out.push(PraepositionCard {
+                key: *key,
+                praeposition: praeposition.clone(),
+                rektion,
+                noun,
+                answer,
+            });
+        }
+
+        Ok(out)
+    }
+}
+
+/// Preposiciones por repasar hoy más hasta `max_neue` nuevas de `decks`, en orden aleatorio.
+///
+/// A las dobles se les pregunta con una mini oración Wo?/Wohin? con un sustantivo al azar
+/// (sin sustantivos con género no entran); a las demás, el caso que rigen.
+pub fn build_drill(
+    conn: &Connection,
+    decks: Option<&[i32]>,
+    max_neue: usize,
+) -> Result<Vec<PraepositionKey>> {
+    let ids = deck::filter_worte(
+        conn,
+        WorteRepo::fetch_id_by_gram_code(conn, "preposition%")?,
+        decks,
+    )?;
+    let praepositionen: HashMap<i32, Rektion> = WorteRepo::fetch_by_id(conn, &ids)?
+        .iter()
+        .filter_map(|w| Rektion::from_wort(w).map(|r| (w.id, r)))
+        .collect();
+
+    let ids = deck::filter_worte(conn, WorteRepo::fetch_id_nouns(conn)?, decks)?;
+    let nouns: Vec<i32> = WorteRepo::fetch_by_id(conn, &ids)?
+        .iter()
+        .filter(|w| Genus::from_wort(w).is_some())
+        .map(|w| w.id)
+        .collect();
+
+    let date_review = time::today_local_string(1);
+    let mut wort_ids: Vec<i32> =
+        PraepositionReviewRepo::fetch_review_wort_id_by_day(conn, date_review)?
+            .into_iter()
+            .filter(|id| praepositionen.contains_key(id))
+            .collect();
+
+    let mut all: Vec<i32> = praepositionen.keys().copied().collect();
+    all.sort();
+    let reviewed: HashSet<i32> = PraepositionReviewRepo::fetch_by_wort_id(conn, &all)?
+        .into_iter()
+        .map(|r| r.wort_id)
+        .collect();
+    let mut neue: Vec<i32> = all
+        .into_iter()
+        .filter(|id| !reviewed.contains(id))
+        .collect();
+
+    let mut rng = rand::rng();
+    neue.shuffle(&mut rng);
+    wort_ids.extend(neue.into_iter().take(max_neue));
+    wort_ids.shuffle(&mut rng);
+
+    let mut out = vec![];
+    for wort_id in wort_ids {
+        let frage = match praepositionen[&wort_id] {
+            Rektion::Wechsel => {
+                let Some(noun_id) = nouns.choose(&mut rng) else {
+                    continue;
+                };
+                let frage = *[Frage::Wo, Frage::Wohin].choose(&mut rng).unwrap();
+                Some((frage, *noun_id))
+            }
+            Rektion::Kasus(_) => None,
+        };
+        out.push(PraepositionKey { wort_id, frage });
+    }
+
+    Ok(out)
+}
@@ -0,0 +1,140 @@
+use crate::db::setup_test_db;
+
+#[cfg(test)]
+mod test_praeposition {
+    use rusqlite::Connection;
+
+    use crate::{
+        db::{
+            praeposition_review::PraepositionReviewRepo,
+            schemas::praeposition_review::NewPraepositionReviewSchema, seeders::init_data,
+            worte::WorteRepo,
+        },
+        helpers::{
+            deklination::Kasus,
+            praeposition::{Frage, PraepositionCard, PraepositionKey, Rektion, build_drill},
+            session::SessionCard,
+            test_worte::wort,
+        },
+    };
+
+    use super::*;
+
+    /// 1 mit, 2 für, 3 wegen, 4 in, 5 Tisch, 6 Student
+    fn init_data_local(conn: &mut Connection) {
+        init_data(conn).unwrap();
+        WorteRepo::bulk_insert(
+            conn,
+            &[
+                wort("mit", 23, None),
+                wort("für", 24, None),
+                wort("wegen", 25, None),
+                wort("in", 26, None),
+                wort("Tisch", 0, Some(0)),
+                wort("Student", 0, Some(0)),
+            ],
+        )
+        .unwrap();
+    }
+
+    fn card(conn: &Connection, wort_id: i32, frage: Option<(Frage, i32)>) -> PraepositionCard {
+        PraepositionCard::fetch(conn, &[PraepositionKey { wort_id, frage }])
+            .unwrap()
+            .remove(0)
+    }
+
+    #[test]
+    fn test_rektion() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+
+        let worte = WorteRepo::fetch_by_id(&conn, &[1, 2, 3, 4, 5]).unwrap();
+        let rektion: Vec<Option<Rektion>> = worte.iter().map(Rektion::from_wort).collect();
+        assert_eq!(
+            rektion,
+            vec![
+                Some(Rektion::Kasus(Kasus::Dativ)),
+                Some(Rektion::Kasus(Kasus::Akkusativ)),
+                Some(Rektion::Kasus(Kasus::Genitiv)),
+                Some(Rektion::Wechsel),
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_card() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+
+        // Caso que rige, con abreviaturas
+        let mit = card(&conn, 1, None);
+        assert_eq!(mit.expected(), "Dativ");
+        assert_eq!(mit.question(), "¿Qué caso rige «mit»?");
+        let answer = |c: &PraepositionCard, input: &str| c.normalize(&c.parse_input(input));
+        assert_eq!(answer(&mit, "dat"), mit.normalize(&mit.expected()));
+        assert_eq!(answer(&mit, "D"), mit.normalize(&mit.expected()));
+        let fuer = card(&conn, 2, None);
+        assert_eq!(answer(&fuer, "akk"), fuer.normalize(&fuer.expected()));
+        assert_eq!(answer(&fuer, "acusativo"), fuer.normalize(&fuer.expected()));
+        assert_ne!(answer(&fuer, "gen"), fuer.normalize(&fuer.expected()));
+        assert!(fuer.rule().contains("siempre rige Akkusativ"));
+
+        // Wo? -> dativo, Wohin? -> acusativo
+        let wo = card(&conn, 4, Some((Frage::Wo, 5)));
+        assert_eq!(wo.question(), "Wo? Die Katze ist in ___ Tisch.");
+        assert_eq!(wo.expected(), "dem");
+        assert!(wo.rule().contains("Wo?, así que va en Dativ"));
+        let wohin = card(&conn, 4, Some((Frage::Wohin, 5)));
+        assert_eq!(wohin.question(), "Wohin? Die Katze geht in ___ Tisch.");
+        assert_eq!(wohin.expected(), "den");
+
+        // El sustantivo se declina: n-Deklination
+        let student = card(&conn, 4, Some((Frage::Wo, 6)));
+        assert_eq!(student.question(), "Wo? Die Katze ist in ___ Studenten.");
+    }
+
+    #[test]
+    fn test_build_drill() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+
+        let keys = build_drill(&conn, None, 10).unwrap();
+        let mut ids: Vec<i32> = keys.iter().map(|k| k.wort_id).collect();
+        ids.sort();
+        assert_eq!(ids, vec![1, 2, 3, 4]);
+        for key in &keys {
+            match key.wort_id {
+                4 => assert!(matches!(key.frage, Some((_, 5 | 6)))),
+                _ => assert!(key.frage.is_none()),
+            }
+        }
+        assert_eq!(build_drill(&conn, None, 2).unwrap().len(), 2);
+
+        // Las ya repasadas solo entran cuando les toca
+        PraepositionReviewRepo::bulk_insert(
+            &mut conn,
+            &[1, 2, 3].map(|wort_id| NewPraepositionReviewSchema {
+                wort_id,
+                interval: 1,
+                ease_factor: 2.5,
+                repetitions: 1,
+                last_review: "2020-01-01 00:00:00".into(),
+                next_review: if wort_id == 1 {
+                    "2020-01-02 00:00:00".into()
+                } else {
+                    "2999-01-01 00:00:00".into()
+                },
+                lapses: 0,
+            }),
+        )
+        .unwrap();
+        let mut ids: Vec<i32> = build_drill(&conn, None, 10)
+            .unwrap()
+            .iter()
+            .map(|k| k.wort_id)
+            .collect();
+        ids.sort();
+        assert_eq!(ids, vec![1, 4]);
+    }
+}
@@ -0,0 +1,273 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use color_eyre::eyre::{Context, Result, bail};
+use once_cell::sync::OnceCell;
+
+#[cfg(test)]
+mod profile_test;
+
+pub const DEFAULT_PROFILE: &str = "default";
+pub const APP_DIR_NAME: &str = "anki-sentences";
+pub const DB_FILE_NAME: &str = "anki_satze.sql";
+pub const CONFIG_FILE_NAME: &str = "config.toml";
+
+pub const ENV_PROFILE: &str = "ANKI_SENTENCES_PROFILE";
+pub const ENV_DATA_DIR: &str = "ANKI_SENTENCES_DATA_DIR";
+pub const ENV_DB: &str = "ANKI_SENTENCES_DB";
+
+/// Ubicación anterior de los datos, relativa a la carpeta desde donde se ejecutaba.
+const LEGACY_DB: &str = "anki_satze.sql";
+const LEGACY_AUDIOS: &str = "assets/audios";
+
+pub const TEXT_HELP: &str = r#"
+Uso: anki_sentences [opciones] [comando]
+
+Opciones:
+    --profile <nombre>    Perfil a usar, cada perfil tiene su base de datos y sus audios.
+    --data-dir <ruta>     Carpeta donde se guardan los perfiles.
+    --db <archivo>        Base de datos a usar, ignora la del perfil.
+    --list-profiles       Muestra los perfiles existentes.
+    -h, --help            Muestra esta ayuda.
+
+Variables de entorno:
+    ANKI_SENTENCES_PROFILE, ANKI_SENTENCES_DATA_DIR, ANKI_SENTENCES_DB
+
+Por defecto los datos se guardan en $XDG_DATA_HOME/anki-sentences
+(~/.local/share/anki-sentences). Cada perfil tiene su configuración en config.toml.
+"#;
+
+static APP_PATHS: OnceCell<AppPaths> = OnceCell::new();
+
+/// Opciones de la línea de comandos que definen dónde están los datos.
+#[derive(Debug, Default, PartialEq)]
+pub struct ProfileArgs {
+    pub profile: Option<String>,
+    pub data_dir: Option<PathBuf>,
+    pub db: Option<PathBuf>,
+    pub list_profiles: bool,
+    pub help: bool,
+    /// Argumentos que no son de perfil, en el orden en que llegaron.
+    pub rest: Vec<String>,
+}
+
+impl ProfileArgs {
+    pub fn parse<I>(args: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut out = Self::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            // Acepta "--profile ana" y "--profile=ana"
+            let (flag, inline) = match arg.split_once('=') {
+                Some((f, v)) if f.starts_with("--") => (f.to_string(), Some(v.to_string())),
+                _ => (arg.clone(), None),
+            };
+
+            let mut value = |name: &str| match inline.clone().or_else(|| args.next()) {
+                Some(v) if !v.is_empty() => Ok(v),
+                _ => bail!("Falta el valor de {}", name),
+            };
+
+            match flag.as_str() {
+                "--profile" => out.profile = Some(value("--profile")?),
+                "--data-dir" => out.data_dir = Some(value("--data-dir")?.into()),
+                "--db" => out.db = Some(value("--db")?.into()),
+                "--list-profiles" => out.list_profiles = true,
+                "-h" | "--help" => out.help = true,
+                _ => out.rest.push(arg),
+            }
+        }
+
+        Ok(out)
+    }
+}
+
+/// Rutas de los datos del perfil activo.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AppPaths {
+    pub profile: String,
+    /// Carpeta de la aplicación, contiene `profiles/<perfil>`.
+    pub data_dir: PathBuf,
+    pub db: PathBuf,
+    pub audios: PathBuf,
+    /// Configuración del perfil (`config.toml`).
+    pub config: PathBuf,
+}
+
+impl AppPaths {
+    /// Resuelve las rutas con la prioridad: opción de la línea de comandos, variable de
+    /// entorno y por último las rutas XDG.
+    pub fn resolve<F>(args: &ProfileArgs, env: F) -> Result<Self>
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let env = |key: &str| env(key).filter(|v| !v.trim().is_empty());
+
+        let profile = args
+            .profile
+            .clone()
+            .or_else(|| env(ENV_PROFILE))
+            .unwrap_or_else(|| DEFAULT_PROFILE.into());
+        validate_profile(&profile)?;
+
+        let data_dir = match args
+            .data_dir
+            .clone()
+            .or_else(|| env(ENV_DATA_DIR).map(Into::into))
+        {
+            Some(dir) => dir,
+            None => xdg_data_home(&env)?.join(APP_DIR_NAME),
+        };
+
+        let profile_dir = data_dir.join("profiles").join(&profile);
+        let db = args
+            .db
+            .clone()
+            .or_else(|| env(ENV_DB).map(Into::into))
+            .unwrap_or_else(|| profile_dir.join(DB_FILE_NAME));
+
+        Ok(Self {
+            profile,
+            data_dir,
+            db,
+            audios: profile_dir.join("audios"),
+            config: profile_dir.join(CONFIG_FILE_NAME),
+        })
+    }
+
+    pub fn audios_worte(&self) -> PathBuf {
+        self.audios.join("worte")
+    }
+
+    pub fn audios_setze(&self) -> PathBuf {
+        self.audios.join("setze")
+    }
+
+    /// Perfiles con carpeta en `data_dir`, ordenados por nombre.
+    pub fn list_profiles(&self) -> Result<Vec<String>> {
+        let dir = self.data_dir.join("profiles");
+        if !dir.exists() {
+            return Ok(vec![]);
+        }
+
+        let mut out = fs::read_dir(&dir)
+            .with_context(|| format!("No se pudo leer la carpeta: {}", dir.display()))?
+            .filter_map(|e| e.ok())
+            .filter(|e| e.path().is_dir())
+            .filter_map(|e| e.file_name().into_string().ok())
+            .collect::<Vec<_>>();
+        out.sort();
+        Ok(out)
+    }
+
+    /// Crea las carpetas del perfil. Si es el perfil por defecto, usa su propia base (sin
+    /// `--db` ni `ANKI_SENTENCES_DB`) y aún no la tiene, copia la base y los audios de la
+    /// ubicación anterior (`./anki_satze.sql`, `./assets/audios`).
+    ///
+    /// Regresa `true` si se copiaron los datos anteriores.
+    pub fn prepare(&self, legacy_dir: &Path) -> Result<bool> {
+        if let Some(parent) = self.db.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("No se pudo crear la carpeta: {}", parent.display()))?;
+        }
+
+        let legacy_db = legacy_dir.join(LEGACY_DB);
+        let profile_db = self
+            .data_dir
+            .join("profiles")
+            .join(&self.profile)
+            .join(DB_FILE_NAME);
+        let import = self.profile == DEFAULT_PROFILE
+            && self.db == profile_db
+            && !self.db.exists()
+            && legacy_db.is_file()
+            && legacy_db != self.db;
+
+        if import {
+            fs::copy(&legacy_db, &self.db)
+                .with_context(|| format!("No se pudo copiar la base: {}", legacy_db.display()))?;
+            let legacy_audios = legacy_dir.join(LEGACY_AUDIOS);
+            if legacy_audios.is_dir() && !self.audios.exists() {
+                copy_dir(&legacy_audios, &self.audios)?;
+            }
+        }
+
+        for dir in [self.audios_worte(), self.audios_setze()] {
+            fs::create_dir_all(&dir)
+                .with_context(|| format!("No se pudo crear la carpeta: {}", dir.display()))?;
+        }
+
+        Ok(import)
+    }
+}
+
+/// Guarda las rutas del perfil activo. Solo se puede llamar una vez.
+pub fn init(paths: AppPaths) -> Result<()> {
+    if APP_PATHS.set(paths).is_err() {
+        bail!("Las rutas del perfil ya se habían iniciado");
+    }
+    Ok(())
+}
+
+/// Rutas del perfil activo; si no se iniciaron se resuelven con el entorno actual.
+pub fn paths() -> &'static AppPaths {
+    APP_PATHS.get_or_init(|| {
+        AppPaths::resolve(&ProfileArgs::default(), |k| std::env::var(k).ok())
+            .expect("No se pudieron resolver las rutas del perfil")
+    })
+}
+
+/// Los perfiles se usan como nombre de carpeta.
+fn validate_profile(profile: &str) -> Result<()> {
+    let valid = !profile.is_empty()
+        && profile
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+
+    if !valid {
+        bail!(
+            "Nombre de perfil no válido: \"{}\". Usa letras, números, \"-\" o \"_\"",
+            profile
+        );
+    }
+    Ok(())
+}
+
+fn xdg_data_home<F>(env: &F) -> Result<PathBuf>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    // La especificación XDG ignora las rutas relativas
+    if let Some(dir) = env("XDG_DATA_HOME").map(PathBuf::from)
+        && dir.is_absolute()
+    {
+        return Ok(dir);
+    }
+
+    match env("HOME") {
+        Some(home) => Ok(PathBuf::from(home).join(".local").join("share")),
+        None => bail!(
+            "No se encontró $HOME, indica la carpeta de datos con --data-dir o {}",
+            ENV_DATA_DIR
+        ),
+    }
+}
+
+fn copy_dir(from: &Path, to: &Path) -> Result<()> {
+    fs::create_dir_all(to)?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        if entry.path().is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else {
+            fs::copy(entry.path(), &target)?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,157 @@
+#[cfg(test)]
+mod test_profile {
+    use std::{collections::HashMap, fs, path::PathBuf};
+
+    use crate::helpers::profile::{AppPaths, ProfileArgs};
+
+    fn args(list: &[&str]) -> ProfileArgs {
+        ProfileArgs::parse(list.iter().map(|s| s.to_string())).expect("Argumentos válidos")
+    }
+
+    fn env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> + use<> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |k| vars.get(k).cloned()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let a = args(&[
+            "--profile",
+            "ana",
+            "--data-dir=/tmp/datos",
+            "daily",
+            "--json",
+        ]);
+        assert_eq!(a.profile.as_deref(), Some("ana"));
+        assert_eq!(a.data_dir, Some(PathBuf::from("/tmp/datos")));
+        assert_eq!(a.db, None);
+        assert_eq!(a.rest, vec!["daily".to_string(), "--json".to_string()]);
+
+        let a = args(&["--list-profiles", "-h"]);
+        assert!(a.list_profiles);
+        assert!(a.help);
+
+        assert!(ProfileArgs::parse(["--profile".to_string()]).is_err());
+        assert!(ProfileArgs::parse(["--db=".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_resolve_xdg_defaults() {
+        let paths = AppPaths::resolve(&args(&[]), env(&[("HOME", "/home/ana")])).unwrap();
+
+        assert_eq!(paths.profile, "default");
+        assert_eq!(
+            paths.db,
+            PathBuf::from("/home/ana/.local/share/anki-sentences/profiles/default/anki_satze.sql")
+        );
+        assert_eq!(
+            paths.audios_setze(),
+            PathBuf::from("/home/ana/.local/share/anki-sentences/profiles/default/audios/setze")
+        );
+        assert_eq!(
+            paths.config,
+            PathBuf::from("/home/ana/.local/share/anki-sentences/profiles/default/config.toml")
+        );
+
+        // XDG_DATA_HOME tiene prioridad sobre $HOME, solo si es absoluta
+        let paths = AppPaths::resolve(
+            &args(&[]),
+            env(&[("HOME", "/home/ana"), ("XDG_DATA_HOME", "/datos")]),
+        )
+        .unwrap();
+        assert_eq!(paths.data_dir, PathBuf::from("/datos/anki-sentences"));
+
+        let paths = AppPaths::resolve(
+            &args(&[]),
+            env(&[("HOME", "/home/ana"), ("XDG_DATA_HOME", "relativa")]),
+        )
+        .unwrap();
+        assert_eq!(
+            paths.data_dir,
+            PathBuf::from("/home/ana/.local/share/anki-sentences")
+        );
+
+        assert!(AppPaths::resolve(&args(&[]), env(&[])).is_err());
+    }
+
+    #[test]
+    fn test_resolve_overrides() {
+        let vars = env(&[
+            ("HOME", "/home/ana"),
+            ("ANKI_SENTENCES_PROFILE", "luis"),
+            ("ANKI_SENTENCES_DATA_DIR", "/srv/anki"),
+        ]);
+
+        // Variables de entorno
+        let paths = AppPaths::resolve(&args(&[]), &vars).unwrap();
+        assert_eq!(paths.profile, "luis");
+        assert_eq!(
+            paths.db,
+            PathBuf::from("/srv/anki/profiles/luis/anki_satze.sql")
+        );
+
+        // La línea de comandos gana sobre el entorno
+        let paths = AppPaths::resolve(&args(&["--profile", "ana", "--db", "/tmp/otra.sql"]), &vars)
+            .unwrap();
+        assert_eq!(paths.profile, "ana");
+        assert_eq!(paths.db, PathBuf::from("/tmp/otra.sql"));
+        assert_eq!(paths.audios, PathBuf::from("/srv/anki/profiles/ana/audios"));
+
+        // El perfil se usa como carpeta
+        assert!(AppPaths::resolve(&args(&["--profile", "../ana"]), &vars).is_err());
+        assert!(AppPaths::resolve(&args(&["--profile", "ana luis"]), &vars).is_err());
+    }
+
+    #[test]
+    fn test_prepare_imports_legacy_data() {
+        let dir = std::env::temp_dir().join(format!("anki_profile_{}", std::process::id()));
+        let legacy = dir.join("legacy");
+        fs::create_dir_all(legacy.join("assets/audios/setze")).unwrap();
+        fs::write(legacy.join("anki_satze.sql"), b"db").unwrap();
+        fs::write(legacy.join("assets/audios/setze/satz_000001.mp3"), b"mp3").unwrap();
+
+        let data_dir = dir.join("data");
+        let data = data_dir.to_string_lossy().into_owned();
+        let vars = env(&[("ANKI_SENTENCES_DATA_DIR", &data)]);
+
+        // Otro perfil empieza vacío
+        let luis = AppPaths::resolve(&args(&["--profile", "luis"]), &vars).unwrap();
+        assert!(!luis.prepare(&legacy).unwrap());
+        assert!(!luis.db.exists());
+        assert!(luis.audios_worte().is_dir());
+
+        // El perfil por defecto copia los datos anteriores una sola vez
+        let paths = AppPaths::resolve(&args(&[]), &vars).unwrap();
+        assert!(paths.prepare(&legacy).unwrap());
+        assert_eq!(fs::read(&paths.db).unwrap(), b"db");
+        assert!(paths.audios_setze().join("satz_000001.mp3").is_file());
+        assert!(legacy.join("anki_satze.sql").is_file());
+
+        fs::write(&paths.db, b"nueva").unwrap();
+        assert!(!paths.prepare(&legacy).unwrap());
+        assert_eq!(fs::read(&paths.db).unwrap(), b"nueva");
+
+        // Con otra base indicada no se copian los datos anteriores
+        let otra = dir.join("otra.sql").to_string_lossy().into_owned();
+        let paths = AppPaths::resolve(&args(&["--db", &otra]), &vars).unwrap();
+        assert!(!paths.prepare(&legacy).unwrap());
+        assert!(!paths.db.exists());
+        let vars_db = env(&[
+            ("ANKI_SENTENCES_DATA_DIR", &data),
+            ("ANKI_SENTENCES_DB", &otra),
+        ]);
+        let paths = AppPaths::resolve(&args(&[]), &vars_db).unwrap();
+        assert!(!paths.prepare(&legacy).unwrap());
+        assert!(!paths.db.exists());
+
+        assert_eq!(
+            paths.list_profiles().unwrap(),
+            vec!["default".to_string(), "luis".to_string()]
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,8 +1,55 @@
 use chrono::{DateTime, Duration, Utc};
 
+use crate::db::schemas::schwirig_liste::{SCHWIRIG_ID_EINFACH, SCHWIRIG_ID_SCHWIRIG};
+
 #[cfg(test)]
 mod review_state_test;
 
+/// Parámetros del scheduler que se pueden ajustar por deck.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchedulerParams {
+    /// Ease factor con el que empieza una tarjeta nueva.
+    pub initial_ease: f32,
+    /// Multiplicador de los intervalos a partir de la cuarta repetición.
+    pub interval_modifier: f32,
+    /// Intervalo máximo en días.
+    pub max_interval: u32,
+}
+
+impl Default for SchedulerParams {
+    fn default() -> Self {
+        Self {
+            initial_ease: 2.5,
+            interval_modifier: 1.0,
+            max_interval: 36500,
+        }
+    }
+}
+
+/// A partir de cuántos fallos una tarjeta se considera difícil.
+const SCHWIRIG_MIN_LAPSES: u32 = 3;
+/// Ease factor por debajo del cual una tarjeta se considera difícil.
+const SCHWIRIG_MAX_EASE: f32 = 1.8;
+/// Repeticiones seguidas sin fallos para considerar una tarjeta fácil.
+const EINFACH_MIN_REPETITIONS: u32 = 3;
+const EINFACH_MIN_EASE: f32 = 2.5;
+
+/// Dificultad según el desempeño en las revisiones (table schwirig_liste).
+///
+/// Solo da un veredicto cuando los datos son claros; en otro caso regresa `None` y se
+/// usa la dificultad con la que se registró la tarjeta.
+pub fn schwirig_from_performance(ease_factor: f32, lapses: u32, repetitions: u32) -> Option<i32> {
+    if lapses >= SCHWIRIG_MIN_LAPSES || ease_factor < SCHWIRIG_MAX_EASE {
+        return Some(SCHWIRIG_ID_SCHWIRIG);
+    }
+
+    if lapses == 0 && repetitions >= EINFACH_MIN_REPETITIONS && ease_factor >= EINFACH_MIN_EASE {
+        return Some(SCHWIRIG_ID_EINFACH);
+    }
+
+    None
+}
+
 #[derive(Debug, Clone)]
 pub struct ReviewState {
     pub interval: u32,
@@ -11,10 +58,10 @@ pub struct ReviewState {
 }
 
 impl ReviewState {
-    pub fn new() -> Self {
+    pub fn new_with(params: &SchedulerParams) -> Self {
         Self {
             interval: 1,
-            ease_factor: 2.5,
+            ease_factor: params.initial_ease.max(1.3),
             repetitions: 0,
         }
     }
@@ -27,8 +74,13 @@ impl ReviewState {
         }
     }
 
-    pub fn review(mut self, quality: u8) -> Self {
-        if quality < 2 {
+    /// Una respuesta con calidad menor a 2 reinicia la tarjeta y cuenta como fallo.
+    pub fn is_lapse(quality: u8) -> bool {
+        quality < 2
+    }
+
+    pub fn review_with(mut self, quality: u8, params: &SchedulerParams) -> Self {
+        if Self::is_lapse(quality) {
             self.repetitions = 0;
             self.interval = 1;
             self.ease_factor = (self.ease_factor - 0.2).max(1.3);
@@ -44,8 +96,10 @@ impl ReviewState {
         } else if self.repetitions == 3 {
             self.interval = 4;
         } else {
-            self.interval = (self.interval as f32 * self.ease_factor).round() as u32;
+            self.interval =
+                (self.interval as f32 * self.ease_factor * params.interval_modifier).round() as u32;
         }
+        self.interval = self.interval.clamp(1, params.max_interval.max(1));
 
         let ef = self.ease_factor
             + (0.1 - (3.0 - quality as f32) * (0.08 + (3.0 - quality as f32) * 0.02));
@@ -55,6 +109,16 @@ impl ReviewState {
         self
     }
 
+    /// Repaso que no hace avanzar la tarjeta: un acierto conserva intervalo, repeticiones y
+    /// ease factor; un fallo se califica igual que con `review_with`.
+    pub fn review_sin_avance(self, quality: u8, params: &SchedulerParams) -> Self {
+        if Self::is_lapse(quality) {
+            return self.review_with(quality, params);
+        }
+
+        self
+    }
+
     pub fn rounded(&self) -> Self {
         Self {
             interval: self.interval,
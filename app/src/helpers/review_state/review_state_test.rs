@@ -2,7 +2,7 @@
 mod test_review_state {
     use insta::assert_debug_snapshot;
 
-    use crate::helpers::review_state::ReviewState;
+    use crate::helpers::review_state::{ReviewState, SchedulerParams, schwirig_from_performance};
 
     fn approx(a: f32, b: f32) -> bool {
         (a - b).abs() < 1e-6
@@ -10,7 +10,7 @@ mod test_review_state {
 
     #[test]
     fn review_state_new_defaults() {
-        let s = ReviewState::new();
+        let s = ReviewState::new_with(&SchedulerParams::default());
 
         // Asserts "clásicos"
         assert_eq!(s.interval, 1);
@@ -36,7 +36,7 @@ mod test_review_state {
     #[test]
     fn review_fail_resets_interval_and_reps() {
         let s = ReviewState::from(10, 2.0, 3);
-        let out = s.review(0); // quality < 2 → fallo
+        let out = s.review_with(0, &SchedulerParams::default()); // quality < 2 → fallo
 
         assert_eq!(out.repetitions, 0);
         assert_eq!(out.interval, 1);
@@ -48,7 +48,7 @@ mod test_review_state {
     #[test]
     fn review_fail_never_drops_below_min_ease_factor() {
         let s = ReviewState::from(5, 1.3, 10);
-        let out = s.review(0);
+        let out = s.review_with(0, &SchedulerParams::default());
 
         assert_eq!(out.repetitions, 0);
         assert_eq!(out.interval, 1);
@@ -59,23 +59,23 @@ mod test_review_state {
 
     #[test]
     fn first_three_successes_interval_sequence() {
-        let mut s = ReviewState::new();
+        let mut s = ReviewState::new_with(&SchedulerParams::default());
         let mut log = Vec::new();
 
         // 1er éxito
-        s = s.review(3);
+        s = s.review_with(3, &SchedulerParams::default());
         log.push(s.rounded());
         assert_eq!(s.repetitions, 1);
         assert_eq!(s.interval, 1);
 
         // 2º éxito
-        s = s.review(3);
+        s = s.review_with(3, &SchedulerParams::default());
         log.push(s.rounded());
         assert_eq!(s.repetitions, 2);
         assert_eq!(s.interval, 2);
 
         // 3er éxito
-        s = s.review(3);
+        s = s.review_with(3, &SchedulerParams::default());
         log.push(s.rounded());
         assert_eq!(s.repetitions, 3);
         assert_eq!(s.interval, 4);
@@ -87,7 +87,7 @@ mod test_review_state {
     fn interval_growth_after_third_success() {
         // simulamos que ya va en interval=4, reps=3, ef=2.5
         let s = ReviewState::from(4, 2.5, 3);
-        let out = s.review(3);
+        let out = s.review_with(3, &SchedulerParams::default());
 
         // interval = round(4 * 2.5) = 10
         assert_eq!(out.repetitions, 4);
@@ -98,27 +98,65 @@ mod test_review_state {
 
     #[test]
     fn full_review_story_log() {
-        let mut s = ReviewState::new();
+        let mut s = ReviewState::new_with(&SchedulerParams::default());
         let mut log = Vec::new();
 
         // historia realista: fallo, bien, bien, difícil, bien
-        s = s.review(0);
+        s = s.review_with(0, &SchedulerParams::default());
         log.push(s.rounded());
 
-        s = s.review(3);
+        s = s.review_with(3, &SchedulerParams::default());
         log.push(s.rounded());
 
-        s = s.review(3);
+        s = s.review_with(3, &SchedulerParams::default());
         log.push(s.rounded());
 
-        s = s.review(2);
+        s = s.review_with(2, &SchedulerParams::default());
         log.push(s.rounded());
 
-        s = s.review(3);
+        s = s.review_with(3, &SchedulerParams::default());
         log.push(s.rounded());
 
         assert_eq!(log.len(), 5);
 
         assert_debug_snapshot!("full_review_story", log);
     }
+
+    #[test]
+    fn review_with_deck_params() {
+        let params = SchedulerParams {
+            initial_ease: 2.0,
+            interval_modifier: 0.5,
+            max_interval: 5,
+        };
+
+        let s = ReviewState::new_with(&params);
+        assert!(approx(s.ease_factor, 2.0));
+
+        // A partir de la cuarta repetición se aplica el modificador
+        let out = ReviewState::from(8, 2.0, 3).review_with(3, &params);
+        assert_eq!(out.interval, 5); // 8 * 2.0 * 0.5 = 8, limitado a 5
+
+        let out = ReviewState::from(4, 2.0, 3).review_with(3, &params);
+        assert_eq!(out.interval, 4);
+    }
+
+    #[test]
+    fn schwirig_from_review_performance() {
+        // Sin datos suficientes se usa la dificultad registrada
+        assert_eq!(schwirig_from_performance(2.5, 0, 1), None);
+        assert_eq!(schwirig_from_performance(2.3, 1, 2), None);
+
+        // Muchos fallos o ease bajo: difícil
+        assert_eq!(schwirig_from_performance(2.5, 3, 4), Some(2));
+        assert_eq!(schwirig_from_performance(1.7, 1, 0), Some(2));
+
+        // Varias repeticiones sin fallos: fácil
+        assert_eq!(schwirig_from_performance(2.6, 0, 3), Some(0));
+        assert_eq!(schwirig_from_performance(2.6, 1, 3), None);
+
+        // Cada fallo reinicia la tarjeta
+        assert!(ReviewState::is_lapse(1));
+        assert!(!ReviewState::is_lapse(2));
+    }
 }
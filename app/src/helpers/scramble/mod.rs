@@ -0,0 +1,247 @@
+use rand::seq::SliceRandom;
+
+use crate::db::schemas::setze::SetzeSchema;
+
+#[cfg(test)]
+mod scramble_test;
+
+const DETERMINERS: [&str; 28] = [
+    "der", "die", "das", "den", "dem", "des", "ein", "eine", "einen", "einem", "einer", "eines",
+    "kein", "keine", "keinen", "keinem", "mein", "meine", "meinen", "meinem", "dein", "deine",
+    "sein", "seine", "ihr", "ihre", "unser", "unsere",
+];
+
+const PREPOSITIONS: [&str; 33] = [
+    "in", "im", "ins", "an", "am", "ans", "auf", "mit", "nach", "zu", "zum", "zur", "von", "vom",
+    "bei", "beim", "aus", "für", "ohne", "um", "durch", "gegen", "seit", "über", "unter", "vor",
+    "hinter", "neben", "zwischen", "während", "wegen", "trotz", "bis",
+];
+
+const SUBORDINATORS: [&str; 18] = [
+    "dass", "weil", "wenn", "ob", "obwohl", "als", "damit", "bevor", "nachdem", "während", "da",
+    "falls", "sobald", "bis", "seitdem", "indem", "sodass", "solange",
+];
+
+const W_WORDS: [&str; 12] = [
+    "was", "wer", "wen", "wem", "wessen", "wo", "wohin", "woher", "wann", "warum", "wie", "welche",
+];
+
+/// Oración con sus palabras desordenadas para el ejercicio.
+#[derive(Debug, Clone)]
+pub struct ScrambleCard {
+    pub satz: SetzeSchema,
+    pub shuffled: Vec<String>,
+}
+
+impl ScrambleCard {
+    pub fn new(satz: SetzeSchema) -> Self {
+        let shuffled = shuffle_tokens(&tokenize(&satz.setze_deutsch));
+        Self { satz, shuffled }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VerbSlotKind {
+    /// Verbo conjugado en segunda posición (oración principal)
+    Second,
+    /// Verbo al final de la oración subordinada
+    Final,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerbSlot {
+    pub index: usize,
+    pub kind: VerbSlotKind,
+    pub word: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScrambleFeedback {
+    /// Respuesta del usuario con las posiciones incorrectas marcadas entre corchetes
+    pub marked: String,
+    pub hints: Vec<String>,
+}
+
+#[inline]
+fn strip_token(t: &str) -> String {
+    t.trim_matches(|c: char| !c.is_alphanumeric()).to_string()
+}
+
+/// Separa una oración en palabras, sin signos de puntuación.
+pub fn tokenize(sentence: &str) -> Vec<String> {
+    sentence
+        .split_whitespace()
+        .map(strip_token)
+        .filter(|t| !t.is_empty())
+        .collect()
+}
+
+/// Regresa las palabras de la oración en un orden distinto al original (si es posible).
+pub fn shuffle_tokens(tokens: &[String]) -> Vec<String> {
+    let mut shuffled = tokens.to_vec();
+    if tokens.len() < 2 {
+        return shuffled;
+    }
+
+    let mut seed_rand = rand::rng();
+    for _ in 0..10 {
+        shuffled.shuffle(&mut seed_rand);
+        if shuffled != tokens {
+            break;
+        }
+    }
+
+    shuffled
+}
+
+/// Convierte la respuesta del usuario en palabras.
+///
+/// Acepta los índices (base 1) de `shuffled` separados por espacios, ejem: "3 1 2".
+/// En cualquier otro caso se regresa la respuesta tal cual.
+pub fn parse_answer(input: &str, shuffled: &[String]) -> String {
+    let indices: Option<Vec<usize>> = input
+        .split_whitespace()
+        .map(|p| {
+            p.parse::<usize>()
+                .ok()
+                .filter(|i| (1..=shuffled.len()).contains(i))
+        })
+        .collect();
+
+    match indices {
+        Some(idx) if !idx.is_empty() => idx
+            .iter()
+            .map(|i| shuffled[i - 1].clone())
+            .collect::<Vec<_>>()
+            .join(" "),
+        _ => input.trim().to_string(),
+    }
+}
+
+/// Longitud del primer elemento de la oración (ejem: "Der kleine Hund" = 3).
+fn first_constituent_len(clause: &[String]) -> usize {
+    let Some(first) = clause.first() else {
+        return 0;
+    };
+
+    let first = first.to_lowercase();
+    if !DETERMINERS.contains(&first.as_str()) && !PREPOSITIONS.contains(&first.as_str()) {
+        return 1;
+    }
+
+    // Se extiende hasta el primer sustantivo (palabra con mayúscula)
+    clause
+        .iter()
+        .skip(1)
+        .position(|t| t.chars().next().is_some_and(|c| c.is_uppercase()))
+        .map(|p| p + 2)
+        .unwrap_or(1)
+}
+
+/// Detecta, de forma heurística, dónde debe ir el verbo conjugado en la oración correcta.
+///
+/// - Oración principal: el verbo va después del primer elemento (Verbzweitstellung).
+/// - Oración subordinada (weil, dass, wenn...): el verbo va al final (Verbletztstellung).
+pub fn verb_slots(sentence: &str) -> Vec<VerbSlot> {
+    // Separamos por cláusulas usando las comas
+    let mut clauses: Vec<Vec<String>> = vec![vec![]];
+    for raw in sentence.split_whitespace() {
+        let t = strip_token(raw);
+        if !t.is_empty() {
+            clauses.last_mut().unwrap().push(t);
+        }
+        if raw.ends_with(',') {
+            clauses.push(vec![]);
+        }
+    }
+    clauses.retain(|c| !c.is_empty());
+
+    let is_question = sentence.trim_end().ends_with('?');
+    let mut slots = vec![];
+    let mut offset = 0;
+    let mut main_found = false;
+    let mut prev_subordinate = false;
+
+    for clause in &clauses {
+        let first = clause[0].to_lowercase();
+        // Una sola cláusula nunca es subordinada ("Während des Urlaubs lese ich.")
+        let subordinate = clauses.len() > 1 && SUBORDINATORS.contains(&first.as_str());
+
+        if subordinate && clause.len() > 2 {
+            slots.push(VerbSlot {
+                index: offset + clause.len() - 1,
+                kind: VerbSlotKind::Final,
+                word: clause[clause.len() - 1].clone(),
+            });
+        } else if !subordinate && !main_found {
+            main_found = true;
+
+            // Si la oración empieza con una subordinada, esta ocupa la primera posición y el
+            // verbo es la primera palabra de la principal
+            let verb_pos = if prev_subordinate {
+                Some(0)
+            } else if is_question && !W_WORDS.contains(&first.as_str()) {
+                // Pregunta cerrada: el verbo va al inicio, no se revisa
+                None
+            } else {
+                Some(first_constituent_len(clause))
+            };
+
+            if let Some(pos) = verb_pos.filter(|p| *p < clause.len()) {
+                slots.push(VerbSlot {
+                    index: offset + pos,
+                    kind: VerbSlotKind::Second,
+                    word: clause[pos].clone(),
+                });
+            }
+        }
+
+        prev_subordinate = subordinate;
+        offset += clause.len();
+    }
+
+    slots
+}
+
+/// Compara la respuesta con la oración correcta, marca las posiciones incorrectas y da
+/// pistas cuando el verbo no quedó en su lugar.
+pub fn feedback(sentence: &str, answer: &str) -> ScrambleFeedback {
+    let correct = tokenize(sentence);
+    let answer = tokenize(answer);
+
+    let same = |a: Option<&String>, b: Option<&String>| match (a, b) {
+        (Some(a), Some(b)) => a.to_lowercase() == b.to_lowercase(),
+        _ => false,
+    };
+
+    let marked = answer
+        .iter()
+        .enumerate()
+        .map(|(i, t)| {
+            if same(Some(t), correct.get(i)) {
+                t.clone()
+            } else {
+                format!("[{}]", t)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let hints = verb_slots(sentence)
+        .into_iter()
+        .filter(|slot| !same(answer.get(slot.index), Some(&slot.word)))
+        .map(|slot| match slot.kind {
+            VerbSlotKind::Second => format!(
+                "El verbo conjugado \"{}\" debe ir en la posición {} (Verbzweitstellung).",
+                slot.word,
+                slot.index + 1
+            ),
+            VerbSlotKind::Final => format!(
+                "En la oración subordinada el verbo \"{}\" va al final (Verbletztstellung).",
+                slot.word
+            ),
+        })
+        .collect();
+
+    ScrambleFeedback { marked, hints }
+}
@@ -0,0 +1,227 @@
+use crate::db::setup_test_db;
+
+#[cfg(test)]
+mod test_scramble {
+    use rusqlite::Connection;
+
+    use crate::{
+        db::{
+            schemas::{setze::NewSetzeSchema, setze_review::NewSetzeReviewSchema},
+            seeders::init_data,
+            setze::SetzeRepo,
+            setze_review::SetzeReviewRepo,
+        },
+        helpers::{
+            console,
+            scramble::{
+                ScrambleCard, VerbSlot, VerbSlotKind, feedback, parse_answer, shuffle_tokens,
+                tokenize, verb_slots,
+            },
+            session::Session,
+        },
+    };
+
+    use super::*;
+
+    /// Oración 1 ya repasada tres veces sin fallos
+    fn init_data_local(conn: &mut Connection) {
+        init_data(conn).unwrap();
+        SetzeRepo::bulk_insert(
+            conn,
+            &[NewSetzeSchema {
+                setze_spanisch: "El perro juega en el jardín".into(),
+                setze_deutsch: "Der Hund spielt im Garten".into(),
+                niveau_id: 1,
+                thema: "Tiere".into(),
+                deck_id: 1,
+                schwirig_id: 0,
+                tags: vec![],
+            }],
+        )
+        .unwrap();
+        SetzeReviewRepo::bulk_insert(
+            conn,
+            &[NewSetzeReviewSchema {
+                satz_id: 1,
+                interval: 4,
+                ease_factor: 2.5,
+                repetitions: 3,
+                last_review: "2020-01-01 00:00:00".into(),
+                next_review: "2020-01-05 00:00:00".into(),
+                lapses: 0,
+                schwirig_id: None,
+            }],
+        )
+        .unwrap();
+    }
+
+    fn tokens(s: &str) -> Vec<String> {
+        s.split_whitespace().map(|t| t.to_string()).collect()
+    }
+
+    #[test]
+    fn tokenize_strips_punctuation() {
+        assert_eq!(
+            tokenize("Ich habe einen Hund, weil ich Tiere mag."),
+            tokens("Ich habe einen Hund weil ich Tiere mag")
+        );
+    }
+
+    #[test]
+    fn shuffle_changes_order_but_keeps_tokens() {
+        let t = tokens("Ich habe einen Hund");
+        let s = shuffle_tokens(&t);
+
+        assert_ne!(s, t);
+
+        let mut a = s.clone();
+        let mut b = t.clone();
+        a.sort();
+        b.sort();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn parse_answer_accepts_indices_or_words() {
+        let shuffled = tokens("Hund Ich einen habe");
+
+        assert_eq!(parse_answer("2 4 3 1", &shuffled), "Ich habe einen Hund");
+        assert_eq!(
+            parse_answer("Ich habe einen Hund", &shuffled),
+            "Ich habe einen Hund"
+        );
+
+        // Índices fuera de rango se toman como texto
+        assert_eq!(parse_answer("2 9", &shuffled), "2 9");
+    }
+
+    #[test]
+    fn verb_second_after_noun_phrase() {
+        assert_eq!(
+            verb_slots("Der kleine Hund spielt im Garten."),
+            vec![VerbSlot {
+                index: 3,
+                kind: VerbSlotKind::Second,
+                word: "spielt".into()
+            }]
+        );
+
+        assert_eq!(
+            verb_slots("Am Montag gehe ich ins Kino."),
+            vec![VerbSlot {
+                index: 2,
+                kind: VerbSlotKind::Second,
+                word: "gehe".into()
+            }]
+        );
+    }
+
+    #[test]
+    fn verb_final_in_subordinate_clause() {
+        assert_eq!(
+            verb_slots("Ich bleibe zu Hause, weil ich krank bin."),
+            vec![
+                VerbSlot {
+                    index: 1,
+                    kind: VerbSlotKind::Second,
+                    word: "bleibe".into()
+                },
+                VerbSlot {
+                    index: 7,
+                    kind: VerbSlotKind::Final,
+                    word: "bin".into()
+                },
+            ]
+        );
+
+        // Subordinada al inicio: el verbo de la principal va justo después
+        assert_eq!(
+            verb_slots("Weil ich krank bin, bleibe ich zu Hause."),
+            vec![
+                VerbSlot {
+                    index: 3,
+                    kind: VerbSlotKind::Final,
+                    word: "bin".into()
+                },
+                VerbSlot {
+                    index: 4,
+                    kind: VerbSlotKind::Second,
+                    word: "bleibe".into()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn single_clause_is_never_subordinate() {
+        assert_eq!(
+            verb_slots("Während des Urlaubs lese ich viel."),
+            vec![VerbSlot {
+                index: 3,
+                kind: VerbSlotKind::Second,
+                word: "lese".into()
+            }]
+        );
+    }
+
+    #[test]
+    fn yes_no_question_is_not_checked() {
+        assert!(verb_slots("Hast du einen Hund?").is_empty());
+        assert_eq!(verb_slots("Wo wohnst du?")[0].word, "wohnst");
+    }
+
+    #[test]
+    fn feedback_marks_positions_and_hints_verb() {
+        let f = feedback(
+            "Ich bleibe zu Hause, weil ich krank bin.",
+            "Ich bleibe zu Hause weil ich bin krank",
+        );
+
+        assert_eq!(f.marked, "Ich bleibe zu Hause weil ich [bin] [krank]");
+        assert_eq!(f.hints.len(), 1);
+        assert!(f.hints[0].contains("\"bin\""));
+
+        let f = feedback("Heute gehe ich ins Kino.", "Heute ich gehe ins Kino");
+        assert_eq!(f.marked, "Heute [ich] [gehe] ins Kino");
+        assert_eq!(f.hints.len(), 1);
+        assert!(f.hints[0].contains("posición 2"));
+
+        let f = feedback("Heute gehe ich ins Kino.", "heute gehe ich ins kino");
+        assert!(f.hints.is_empty());
+        assert!(!f.marked.contains('['));
+    }
+
+    #[test]
+    fn correct_scramble_keeps_review_progress() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+
+        console::save_scramble_review(&mut conn, vec![(1, 2)]).unwrap();
+        let r = &SetzeReviewRepo::fetch_by_satz_id(&conn, &[1]).unwrap()[0];
+        assert_eq!((r.interval, r.repetitions, r.lapses), (4, 3, 0));
+        assert_eq!(r.ease_factor, 2.5);
+
+        // Un error dentro de la sesión sí cuenta como fallo
+        console::save_scramble_review(&mut conn, vec![(1, 1)]).unwrap();
+        let r = &SetzeReviewRepo::fetch_by_satz_id(&conn, &[1]).unwrap()[0];
+        assert_eq!((r.interval, r.repetitions, r.lapses), (1, 0, 1));
+    }
+
+    #[test]
+    fn override_returns_words_instead_of_indices() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+
+        let mut s: Session<ScrambleCard> = Session::new(&conn, vec![1], 1).unwrap();
+        let shuffled = s.current().unwrap().shuffled.clone();
+        let position = |w: &str| shuffled.iter().position(|t| t == w).unwrap() + 1;
+        // "Der Hund spielt Garten im"
+        let input = ["Der", "Hund", "spielt", "Garten", "im"]
+            .map(|w| position(w).to_string())
+            .join(" ");
+
+        s.answer(&conn, &input).unwrap();
+        let r = s.override_last(&conn).unwrap();
+        assert_eq!(r, Some((1, "Der Hund spielt Garten im".to_string())));
+    }
+}
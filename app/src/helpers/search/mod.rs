@@ -0,0 +1,530 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Utc};
+use color_eyre::eyre::{Result, bail};
+use rusqlite::{Connection, types::Value};
+
+use crate::{
+    db::{
+        schemas::{
+            search::{MARK_START, SearchHit},
+            setze::SetzeSchema,
+            worte::WorteSchema,
+        },
+        search::SearchRepo,
+        setze::SetzeRepo,
+        setze_audio::SetzeAudioRepo,
+        setze_review::SetzeReviewRepo,
+        worte::WorteRepo,
+        worte_audio::WorteAudioRepo,
+        worte_review::WorteReviewRepo,
+        worte_setze::WorteSetzeRepo,
+    },
+    helpers::{session::SessionCard, time},
+};
+
+#[cfg(test)]
+mod search_test;
+
+pub const PER_PAGE: usize = 20;
+/// Oraciones que se muestran por palabra.
+const USAGES: usize = 3;
+
+/// Claves que acepta `SearchFilter::set`, también como `clave:valor` en una búsqueda.
+pub const FILTER_KEYS: [&str; 8] = [
+    "text", "gram", "gender", "niveau", "thema", "due", "ease", "lapses",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DueState {
+    /// Sin ninguna revisión
+    Neue,
+    /// Toca repasarla hoy
+    Due,
+    /// Ya revisada y programada para otro día
+    Scheduled,
+}
+
+impl DueState {
+    pub fn parse(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "new" | "neue" => Ok(Self::Neue),
+            "due" => Ok(Self::Due),
+            "scheduled" => Ok(Self::Scheduled),
+            _ => bail!("Estado no válido: {}. Usa new, due o scheduled", s),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Neue => "new",
+            Self::Due => "due",
+            Self::Scheduled => "scheduled",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Neue => "Nueva",
+            Self::Due => "Por repasar",
+            Self::Scheduled => "Programada",
+        }
+    }
+}
+
+/// Filtros para buscar palabras u oraciones. Los que son `None` no filtran.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SearchFilter {
+    /// Texto en alemán o español
+    pub text: Option<String>,
+    /// Código o nombre del tipo gramatical (ejem: "verb_main"), solo palabras
+    pub gram_type: Option<String>,
+    /// Género o artículo (ejem: "Femenin", "die"), solo palabras
+    pub gender: Option<String>,
+    pub niveau: Option<String>,
+    /// Solo oraciones
+    pub thema: Option<String>,
+    pub due: Option<DueState>,
+    pub ease_min: Option<f32>,
+    pub ease_max: Option<f32>,
+    /// Mínimo de veces que se falló, solo oraciones
+    pub lapses_min: Option<u32>,
+    /// Decks en los que se busca, `None` para todos
+    pub decks: Option<Vec<i32>>,
+}
+
+impl SearchFilter {
+    /// Asigna un filtro por su clave (ver `FILTER_KEYS`).
+    pub fn set(&mut self, key: &str, value: &str) -> Result<()> {
+        let value = value.trim();
+        if value.is_empty() {
+            bail!("Falta el valor de {}", key);
+        }
+
+        match key {
+            "text" => {
+                // Varias palabras sueltas forman un solo texto
+                self.text = Some(match self.text.take() {
+                    Some(t) => format!("{} {}", t, value),
+                    None => value.to_string(),
+                })
+            }
+            "gram" => self.gram_type = Some(value.into()),
+            "gender" => self.gender = Some(value.into()),
+            "niveau" => self.niveau = Some(value.into()),
+            "thema" => self.thema = Some(value.into()),
+            "due" => self.due = Some(DueState::parse(value)?),
+            "ease" => {
+                let Some((min, max)) = value.split_once("..") else {
+                    bail!("Rango no válido: {}. Usa min..max, min.. o ..max", value);
+                };
+                let parse = |s: &str| -> Result<Option<f32>> {
+                    match s.trim() {
+                        "" => Ok(None),
+                        s => match s.parse::<f32>() {
+                            Ok(v) => Ok(Some(v)),
+                            Err(_) => bail!("Número no válido en ease: {}", s),
+                        },
+                    }
+                };
+                self.ease_min = parse(min)?;
+                self.ease_max = parse(max)?;
+            }
+            "lapses" => match value.parse::<u32>() {
+                Ok(v) => self.lapses_min = Some(v),
+                Err(_) => bail!("Número no válido en lapses: {}", value),
+            },
+            _ => bail!(
+                "Filtro no reconocido: {}. Usa {}",
+                key,
+                FILTER_KEYS.join(", ")
+            ),
+        }
+        Ok(())
+    }
+
+    /// Lee una búsqueda como `Hund niveau:A1 due:new ease:..2.0`. Las palabras sin clave son
+    /// el texto; los valores con espacios van entre comillas: `thema:"Mi tema"`.
+    pub fn parse(input: &str) -> Result<Self> {
+        let mut filter = Self::default();
+        for token in split_quoted(input) {
+            match token.split_once(':') {
+                Some((key, value)) if FILTER_KEYS.contains(&key) => filter.set(key, value)?,
+                _ => filter.set("text", &token)?,
+            }
+        }
+        Ok(filter)
+    }
+
+    /// Condiciones comunes a palabras y oraciones: nivel, decks, estado de repaso, facilidad
+    /// y fallos.
+    ///
+    /// `card` y `review` son los alias de la tabla de la tarjeta y de su revisión (unida con
+    /// LEFT JOIN). Regresa las condiciones y sus parámetros en el orden de los `?`.
+    pub fn to_sql(&self, card: &str, review: &str, date_review: &str) -> (Vec<String>, Vec<Value>) {
+        let mut conditions = vec![];
+        let mut params: Vec<Value> = vec![];
+
+        if let Some(niveau) = &self.niveau {
+            conditions.push(format!(
+                "{card}.niveau_id IN (SELECT id FROM niveau_liste WHERE niveau LIKE ?)"
+            ));
+            params.push(niveau.clone().into());
+        }
+        if let Some(decks) = &self.decks {
+            let placeholders = vec!["?"; decks.len()].join(",");
+            conditions.push(format!("{card}.deck_id IN ({placeholders})"));
+            params.extend(decks.iter().map(|d| Value::from(*d)));
+        }
+        match self.due {
+            Some(DueState::Neue) => conditions.push(format!("{review}.id IS NULL")),
+            Some(DueState::Due) => {
+                conditions.push(format!("{review}.next_review < ?"));
+                params.push(date_review.to_string().into());
+            }
+            Some(DueState::Scheduled) => {
+                conditions.push(format!("{review}.next_review >= ?"));
+                params.push(date_review.to_string().into());
+            }
+            None => {}
+        }
+        if let Some(min) = self.ease_min {
+            conditions.push(format!("{review}.ease_factor >= ?"));
+            params.push(Value::Real(min as f64));
+        }
+        if let Some(max) = self.ease_max {
+            conditions.push(format!("{review}.ease_factor <= ?"));
+            params.push(Value::Real(max as f64));
+        }
+        if let Some(lapses) = self.lapses_min {
+            conditions.push(format!("{review}.lapses >= ?"));
+            params.push(Value::Integer(lapses as i64));
+        }
+
+        (conditions, params)
+    }
+}
+
+/// Separa por espacios respetando lo que va entre comillas.
+fn split_quoted(input: &str) -> Vec<String> {
+    let mut out = vec![];
+    let mut current = String::new();
+    let mut quoted = false;
+
+    for c in input.chars() {
+        match c {
+            '"' => quoted = !quoted,
+            c if c.is_whitespace() && !quoted => {
+                if !current.is_empty() {
+                    out.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        out.push(current);
+    }
+    out
+}
+
+/// Estado de repaso de una tarjeta encontrada.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReviewInfo {
+    pub state: DueState,
+    pub next_review: Option<DateTime<Utc>>,
+    pub interval: u32,
+    pub ease_factor: f32,
+    pub repetitions: u32,
+    /// Solo las oraciones guardan sus fallos
+    pub lapses: Option<u32>,
+}
+
+impl ReviewInfo {
+    fn neue() -> Self {
+        Self {
+            state: DueState::Neue,
+            next_review: None,
+            interval: 0,
+            ease_factor: 0.0,
+            repetitions: 0,
+            lapses: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Found<T> {
+    pub card: T,
+    pub review: ReviewInfo,
+    pub audio: bool,
+    /// Texto en alemán con lo que coincidió resaltado, si se buscó por texto
+    pub highlight: Option<String>,
+    /// Oraciones guardadas que usan la palabra (solo palabras)
+    pub usages: Vec<String>,
+    /// En cuántas oraciones aparece la palabra, en cualquiera de sus formas (solo palabras)
+    pub sentences: Option<usize>,
+}
+
+impl<T> Found<T> {
+    /// Estado de repaso y audio, en el orden en que se muestran.
+    fn review_details(&self) -> Vec<(&'static str, String)> {
+        let r = &self.review;
+        let mut out = vec![("Estado", r.state.label().to_string())];
+        if let Some(next) = r.next_review {
+            out.push(("Próximo repaso", next.format("%Y-%m-%d").to_string()));
+            out.push(("Intervalo", format!("{} días", r.interval)));
+            out.push(("Facilidad", format!("{:.2}", r.ease_factor)));
+            out.push(("Repeticiones", r.repetitions.to_string()));
+        }
+        if let Some(lapses) = r.lapses {
+            out.push(("Fallos", lapses.to_string()));
+        }
+        out.push(("Audio", if self.audio { "Sí" } else { "No" }.to_string()));
+        out
+    }
+
+    /// Coincidencia resaltada y oraciones que usan la palabra.
+    fn search_details(&self) -> Vec<(&'static str, String)> {
+        let mut out = vec![];
+        if let Some(highlight) = &self.highlight {
+            out.push(("Coincidencia", highlight.clone()));
+        }
+        if let Some(n) = self.sentences {
+            out.push(("Aparece en", format!("{} de tus oraciones", n)));
+        }
+        out.extend(self.usages.iter().map(|u| ("Oración", u.clone())));
+        out
+    }
+}
+
+impl Found<WorteSchema> {
+    /// Campos de la palabra con su estado de repaso: (etiqueta, valor).
+    pub fn details(&self) -> Vec<(&'static str, String)> {
+        let w = &self.card;
+        let join = |v: Vec<String>| v.join(", ");
+
+        let mut out = vec![
+            ("Id", w.id.to_string()),
+            ("Alemán", w.expected()),
+            ("Español", w.worte_es.clone()),
+            (
+                "Tipo",
+                join(w.gram_type_id.iter().map(|g| g.name.clone()).collect()),
+            ),
+            ("Nivel", w.niveau_id.niveau.clone()),
+        ];
+        let optional = [
+            ("Plural", &w.plural),
+            ("Auxiliar", &w.verb_aux),
+            ("Komparativ", &w.komparativ),
+            ("Superlativ", &w.superlativ),
+        ];
+        for (label, value) in optional {
+            if let Some(v) = value.as_ref().filter(|v| !v.is_empty()) {
+                out.push((label, v.clone()));
+            }
+        }
+        out.push(("Ejemplo", w.example_de.clone()));
+        out.push(("Traducción", w.example_es.clone()));
+        out.push((
+            "Etiquetas",
+            join(w.tags.iter().map(|t| t.name.clone()).collect()),
+        ));
+        out.extend(self.search_details());
+        out.extend(self.review_details());
+        out
+    }
+}
+
+impl Found<SetzeSchema> {
+    /// Campos de la oración con su estado de repaso: (etiqueta, valor).
+    pub fn details(&self) -> Vec<(&'static str, String)> {
+        let s = &self.card;
+        let mut out = vec![
+            ("Id", s.id.to_string()),
+            ("Alemán", s.setze_deutsch.clone()),
+            ("Español", s.setze_spanisch.clone()),
+            ("Tema", s.thema.clone()),
+            ("Nivel", s.niveau_id.niveau.clone()),
+            ("Dificultad", s.schwirig_id.schwirig.clone()),
+            (
+                "Etiquetas",
+                s.tags
+                    .iter()
+                    .map(|t| t.name.clone())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            ),
+        ];
+        out.extend(self.search_details());
+        out.extend(self.review_details());
+        out
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Page<T> {
+    /// Empieza en 1
+    pub page: usize,
+    pub pages: usize,
+    pub total: usize,
+    pub items: Vec<T>,
+}
+
+/// Página `page` (desde 1) de los ids; una página fuera de rango regresa la última.
+fn paginate(ids: &[i32], page: usize, per_page: usize) -> (usize, usize, &[i32]) {
+    let per_page = per_page.max(1);
+    let pages = ids.len().div_ceil(per_page).max(1);
+    let page = page.clamp(1, pages);
+    let start = (page - 1) * per_page;
+    let end = (start + per_page).min(ids.len());
+    (page, pages, &ids[start.min(end)..end])
+}
+
+/// Ordena los ids por relevancia del texto completo; los que solo coinciden de otra forma
+/// (ejem: en español) quedan al final en su orden. Regresa el texto resaltado de cada id.
+fn rank(ids: &mut [i32], hits: Vec<SearchHit>) -> HashMap<i32, String> {
+    let position: HashMap<i32, usize> = hits.iter().enumerate().map(|(i, h)| (h.id, i)).collect();
+    ids.sort_by_key(|id| position.get(id).copied().unwrap_or(usize::MAX));
+
+    hits.into_iter()
+        .map(|h| {
+            // Si la palabra no coincidió, lo resaltado está en el ejemplo
+            let text = match h.example {
+                Some(example) if !h.text.contains(MARK_START) => example,
+                _ => h.text,
+            };
+            (h.id, text)
+        })
+        .collect()
+}
+
+fn due_state(next_review: DateTime<Utc>, date_review: &str) -> DueState {
+    if time::datetime_2_string(next_review).as_str() < date_review {
+        DueState::Due
+    } else {
+        DueState::Scheduled
+    }
+}
+
+pub fn search_worte(
+    conn: &Connection,
+    filter: &SearchFilter,
+    page: usize,
+    per_page: usize,
+) -> Result<Page<Found<WorteSchema>>> {
+    if filter.thema.is_some() {
+        bail!("El filtro thema solo aplica a oraciones");
+    }
+    if filter.lapses_min.is_some() {
+        bail!("El filtro lapses solo aplica a oraciones");
+    }
+
+    let date_review = time::today_local_string(1);
+    let mut ids = WorteRepo::fetch_id_search(conn, filter, &date_review)?;
+    let highlights = match &filter.text {
+        Some(text) => rank(&mut ids, SearchRepo::fetch_worte(conn, text, usize::MAX)?),
+        None => HashMap::new(),
+    };
+    let (page, pages, ids_page) = paginate(&ids, page, per_page);
+
+    let mut reviews: HashMap<i32, ReviewInfo> = HashMap::new();
+    for r in WorteReviewRepo::fetch_by_wort_id(conn, ids_page)? {
+        let info = ReviewInfo {
+            state: due_state(r.next_review, &date_review),
+            next_review: Some(r.next_review),
+            interval: r.interval,
+            ease_factor: r.ease_factor,
+            repetitions: r.repetitions,
+            lapses: None,
+        };
+        reviews.insert(r.wort_id, info);
+    }
+    let audios: HashSet<i32> = WorteAudioRepo::fetch_by_id(conn, ids_page)?
+        .into_iter()
+        .map(|a| a.wort_id)
+        .collect();
+
+    let sentences = WorteSetzeRepo::count_by_wort_id(conn, ids_page)?;
+    let mut usages = WorteSetzeRepo::fetch_setze_by_wort_id(conn, ids_page, USAGES)?;
+
+    let mut items = vec![];
+    for w in WorteRepo::fetch_by_id(conn, ids_page)? {
+        items.push(Found {
+            review: reviews.remove(&w.id).unwrap_or_else(ReviewInfo::neue),
+            audio: audios.contains(&w.id),
+            highlight: highlights.get(&w.id).cloned(),
+            usages: usages.remove(&w.id).unwrap_or_default(),
+            sentences: Some(sentences.get(&w.id).copied().unwrap_or(0)),
+            card: w,
+        });
+    }
+    items.sort_by_key(|f| ids_page.iter().position(|id| *id == f.card.id));
+
+    Ok(Page {
+        page,
+        pages,
+        total: ids.len(),
+        items,
+    })
+}
+
+pub fn search_setze(
+    conn: &Connection,
+    filter: &SearchFilter,
+    page: usize,
+    per_page: usize,
+) -> Result<Page<Found<SetzeSchema>>> {
+    if filter.gram_type.is_some() {
+        bail!("El filtro gram solo aplica a palabras");
+    }
+    if filter.gender.is_some() {
+        bail!("El filtro gender solo aplica a palabras");
+    }
+
+    let date_review = time::today_local_string(1);
+    let mut ids = SetzeRepo::fetch_id_search(conn, filter, &date_review)?;
+    let highlights = match &filter.text {
+        Some(text) => rank(&mut ids, SearchRepo::fetch_setze(conn, text, usize::MAX)?),
+        None => HashMap::new(),
+    };
+    let (page, pages, ids_page) = paginate(&ids, page, per_page);
+
+    let mut reviews: HashMap<i32, ReviewInfo> = HashMap::new();
+    for r in SetzeReviewRepo::fetch_by_satz_id(conn, ids_page)? {
+        let info = ReviewInfo {
+            state: due_state(r.next_review, &date_review),
+            next_review: Some(r.next_review),
+            interval: r.interval,
+            ease_factor: r.ease_factor,
+            repetitions: r.repetitions,
+            lapses: Some(r.lapses),
+        };
+        reviews.insert(r.satz_id, info);
+    }
+    let audios: HashSet<i32> = SetzeAudioRepo::fetch_by_id(conn, ids_page)?
+        .into_iter()
+        .map(|a| a.satz_id)
+        .collect();
+
+    let mut items: Vec<_> = SetzeRepo::fetch_by_id(conn, ids_page)?
+        .into_iter()
+        .map(|s| Found {
+            review: reviews.remove(&s.id).unwrap_or_else(ReviewInfo::neue),
+            audio: audios.contains(&s.id),
+            highlight: highlights.get(&s.id).cloned(),
+            usages: vec![],
+            sentences: None,
+            card: s,
+        })
+        .collect();
+    items.sort_by_key(|f| ids_page.iter().position(|id| *id == f.card.id));
+
+    Ok(Page {
+        page,
+        pages,
+        total: ids.len(),
+        items,
+    })
+}
@@ -0,0 +1,234 @@
+use crate::db::setup_test_db;
+
+#[cfg(test)]
+mod test_search {
+    use std::fs;
+
+    use chrono::Utc;
+    use rusqlite::Connection;
+
+    use crate::{
+        db::{
+            deck::DeckRepo,
+            schemas::{
+                setze_review::NewSetzeReviewSchema, worte_audio::NewWorteAudioSchema,
+                worte_review::NewWorteReviewSchema,
+            },
+            seeders::init_data,
+            setze::SetzeRepo,
+            setze_review::SetzeReviewRepo,
+            worte::WorteRepo,
+            worte_audio::WorteAudioRepo,
+            worte_review::WorteReviewRepo,
+        },
+        helpers::{
+            csv,
+            search::{DueState, SearchFilter, search_setze, search_worte},
+            time,
+        },
+    };
+
+    use super::*;
+
+    const WORTE_CSV: &str = "\
+gram_type,gender_id,worte_de,worte_es,plural,niveau_id,example_de,example_es,verb_aux,trennbar,reflexiv
+noun_common,Maskuline,Hund,el perro,Hunde,A1,Der Hund spielt.,El perro juega.,,,
+verb_main,,gehen,ir,,A1,Ich gehe.,Voy.,sein,No,No
+noun_common,Femenin,Katze,el gato,Katzen,A2,Die Katze schläft.,El gato duerme.,,,
+";
+
+    const SETZE_CSV: &str = "\
+setze_spanisch,setze_deutsch,thema,schwirig_id
+Tengo un perro,Ich habe einen Hund,Akkusativ,0
+Voy a casa,Ich gehe nach Hause,Dativ,1
+El perro está en casa,Der Hund ist zu Hause,Dativ,0
+";
+
+    fn init_data_local(conn: &mut Connection) {
+        init_data(conn).unwrap();
+
+        let dir = std::env::temp_dir().join(format!("anki_search_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let worte = dir.join("worte.csv");
+        let setze = dir.join("setze.csv");
+        fs::write(&worte, WORTE_CSV).unwrap();
+        fs::write(&setze, SETZE_CSV).unwrap();
+
+        let deck = DeckRepo::ensure_path(conn, "Goethe A1").unwrap();
+        let data = csv::extract_worte_csv(worte.to_str().unwrap(), deck.id).unwrap();
+        WorteRepo::bulk_insert(conn, &data).unwrap();
+        let data = csv::extract_sentences_csv(setze.to_str().unwrap(), deck.id).unwrap();
+        SetzeRepo::bulk_insert(conn, &data).unwrap();
+
+        fs::remove_dir_all(&dir).unwrap();
+
+        let now = time::datetime_2_string(Utc::now());
+        WorteReviewRepo::bulk_insert(
+            conn,
+            &[
+                NewWorteReviewSchema {
+                    wort_id: 1,
+                    interval: 1,
+                    ease_factor: 1.8,
+                    repetitions: 1,
+                    last_review: now.clone(),
+                    next_review: "2020-01-01 00:00:00".into(),
+                },
+                NewWorteReviewSchema {
+                    wort_id: 3,
+                    interval: 10,
+                    ease_factor: 2.6,
+                    repetitions: 4,
+                    last_review: now,
+                    next_review: "2999-01-01 00:00:00".into(),
+                },
+            ],
+        )
+        .unwrap();
+        SetzeReviewRepo::bulk_insert(
+            conn,
+            &[NewSetzeReviewSchema {
+                satz_id: 2,
+                interval: 1,
+                ease_factor: 1.3,
+                repetitions: 0,
+                last_review: "2020-01-01 00:00:00".into(),
+                next_review: "2020-01-02 00:00:00".into(),
+                lapses: 3,
+                schwirig_id: None,
+            }],
+        )
+        .unwrap();
+        WorteAudioRepo::bulk_insert(
+            conn,
+            &[NewWorteAudioSchema {
+                wort_id: 1,
+                file_path: "hund.mp3".into(),
+                voice_id: "voz".into(),
+            }],
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_parse() {
+        let filter =
+            SearchFilter::parse(r#"der Hund niveau:A1 due:new ease:1.5..2.5 thema:"Mi tema""#)
+                .unwrap();
+        assert_eq!(
+            filter,
+            SearchFilter {
+                text: Some("der Hund".into()),
+                niveau: Some("A1".into()),
+                due: Some(DueState::Neue),
+                ease_min: Some(1.5),
+                ease_max: Some(2.5),
+                thema: Some("Mi tema".into()),
+                ..Default::default()
+            }
+        );
+
+        // Una clave desconocida se toma como texto
+        let filter = SearchFilter::parse("uhr:zeit ease:..2").unwrap();
+        assert_eq!(filter.text.as_deref(), Some("uhr:zeit"));
+        assert_eq!((filter.ease_min, filter.ease_max), (None, Some(2.0)));
+
+        assert!(SearchFilter::parse("due:mañana").is_err());
+        assert!(SearchFilter::parse("ease:2").is_err());
+        assert!(SearchFilter::parse("lapses:x").is_err());
+        assert!(SearchFilter::parse("niveau:").is_err());
+    }
+
+    #[test]
+    fn test_search_worte() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+
+        let ids = |filter: &str| -> Vec<i32> {
+            let filter = SearchFilter::parse(filter).unwrap();
+            search_worte(&conn, &filter, 1, 20)
+                .unwrap()
+                .items
+                .iter()
+                .map(|f| f.card.id)
+                .collect()
+        };
+
+        assert_eq!(ids("gato"), vec![3]);
+        // El alemán se busca sin umlauts y también en los ejemplos
+        assert_eq!(ids("schlaft"), vec![3]);
+        assert_eq!(ids("gram:noun_common"), vec![1, 3]);
+        assert_eq!(ids("gram:verb_main"), vec![2]);
+        assert_eq!(ids("gender:die"), vec![3]);
+        assert_eq!(ids("niveau:A1"), vec![1, 2]);
+        assert_eq!(ids("due:new"), vec![2]);
+        assert_eq!(ids("due:due"), vec![1]);
+        assert_eq!(ids("due:scheduled"), vec![3]);
+        assert_eq!(ids("ease:2.0.."), vec![3]);
+        assert_eq!(ids("niveau:A1 ease:..2"), vec![1]);
+
+        let filter = SearchFilter::parse("Hund").unwrap();
+        let page = search_worte(&conn, &filter, 1, 20).unwrap();
+        let found = &page.items[0];
+        assert_eq!(found.review.state, DueState::Due);
+        assert_eq!(found.review.repetitions, 1);
+        assert!(found.audio);
+        assert_eq!(found.highlight.as_deref(), Some("[Hund]"));
+        assert_eq!(
+            found.usages,
+            vec!["Ich habe einen Hund", "Der Hund ist zu Hause"]
+        );
+        assert_eq!(found.sentences, Some(2));
+        assert!(
+            found
+                .details()
+                .contains(&("Aparece en", "2 de tus oraciones".to_string()))
+        );
+
+        // Las oraciones tienen sus propios filtros
+        let filter = SearchFilter::parse("thema:Dativ").unwrap();
+        assert!(search_worte(&conn, &filter, 1, 20).is_err());
+    }
+
+    #[test]
+    fn test_search_setze() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+
+        let filter = SearchFilter::parse("thema:Dativ").unwrap();
+        let page = search_setze(&conn, &filter, 1, 20).unwrap();
+        assert_eq!(page.total, 2);
+
+        let filter = SearchFilter::parse("lapses:2").unwrap();
+        let page = search_setze(&conn, &filter, 1, 20).unwrap();
+        assert_eq!(page.items.len(), 1);
+        let found = &page.items[0];
+        assert_eq!(found.card.id, 2);
+        assert_eq!(found.review.lapses, Some(3));
+        assert!(!found.audio);
+
+        let filter = SearchFilter::parse("gender:der").unwrap();
+        assert!(search_setze(&conn, &filter, 1, 20).is_err());
+    }
+
+    #[test]
+    fn test_pagination() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+
+        let filter = SearchFilter::default();
+        let page = search_setze(&conn, &filter, 2, 2).unwrap();
+        assert_eq!((page.page, page.pages, page.total), (2, 2, 3));
+        assert_eq!(page.items.len(), 1);
+        assert_eq!(page.items[0].card.id, 3);
+
+        // Una página fuera de rango regresa la última
+        let page = search_setze(&conn, &filter, 9, 2).unwrap();
+        assert_eq!(page.page, 2);
+
+        let filter = SearchFilter::parse("nichts").unwrap();
+        let page = search_worte(&conn, &filter, 1, 20).unwrap();
+        assert_eq!((page.page, page.pages, page.total), (1, 1, 0));
+        assert!(page.items.is_empty());
+    }
+}
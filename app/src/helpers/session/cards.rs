@@ -0,0 +1,191 @@
+use std::collections::HashMap;
+
+use color_eyre::eyre::Result;
+use rusqlite::Connection;
+
+use crate::{
+    db::{
+        schemas::{setze::SetzeSchema, worte::WorteSchema},
+        setze::SetzeRepo,
+        worte::WorteRepo,
+    },
+    helpers::{
+        daily::{StudyCard, StudyKey},
+        fehler::{Art, Quelle},
+        nomen,
+        scramble::{self, ScrambleCard},
+        session::SessionCard,
+        settings, vergleich,
+    },
+};
+
+impl SessionCard for WorteSchema {
+    type Key = i32;
+
+    fn key(&self) -> i32 {
+        self.id
+    }
+
+    fn expected(&self) -> String {
+        match &self.gender_id {
+            Some(v) => format!("{} {}", v.artikel.to_lowercase(), self.worte_de),
+            None => self.worte_de.clone(),
+        }
+    }
+
+    fn normalize(&self, input: &str) -> String {
+        input.trim().to_string()
+    }
+
+    fn hints(&self, input: &str) -> Vec<String> {
+        nomen::feedback(self, &nomen::check(self, input))
+    }
+
+    fn partial_credit(&self, input: &str) -> bool {
+        nomen::is_partial(&nomen::check(self, input))
+    }
+
+    fn quelle(&self) -> Quelle {
+        Quelle::wort(Art::Wort, self.id)
+    }
+
+    fn fetch(conn: &Connection, keys: &[i32]) -> Result<Vec<Self>> {
+        WorteRepo::fetch_by_id(conn, keys)
+    }
+}
+
+impl SessionCard for SetzeSchema {
+    type Key = i32;
+
+    fn key(&self) -> i32 {
+        self.id
+    }
+
+    fn expected(&self) -> String {
+        self.setze_deutsch.clone()
+    }
+
+    fn normalize(&self, input: &str) -> String {
+        vergleich::normalize(input, &settings::get().vergleich)
+    }
+
+    fn matches(&self, expected: &str, input: &str) -> bool {
+        vergleich::equivalent(expected, input, &settings::get().vergleich)
+    }
+
+    fn quelle(&self) -> Quelle {
+        Quelle::satz(self.id)
+    }
+
+    fn fetch(conn: &Connection, keys: &[i32]) -> Result<Vec<Self>> {
+        SetzeRepo::fetch_by_id(conn, keys)
+    }
+}
+
+impl SessionCard for ScrambleCard {
+    type Key = i32;
+
+    fn key(&self) -> i32 {
+        self.satz.id
+    }
+
+    fn expected(&self) -> String {
+        self.satz.setze_deutsch.clone()
+    }
+
+    fn normalize(&self, input: &str) -> String {
+        vergleich::normalize(input, &settings::get().vergleich)
+    }
+
+    fn matches(&self, expected: &str, input: &str) -> bool {
+        vergleich::equivalent(expected, input, &settings::get().vergleich)
+    }
+
+    fn parse_input(&self, input: &str) -> String {
+        scramble::parse_answer(input, &self.shuffled)
+    }
+
+    fn quelle(&self) -> Quelle {
+        Quelle::satz(self.satz.id)
+    }
+
+    fn fetch(conn: &Connection, keys: &[i32]) -> Result<Vec<Self>> {
+        let setze = SetzeRepo::fetch_by_id(conn, keys)?;
+        Ok(setze.into_iter().map(ScrambleCard::new).collect())
+    }
+}
+
+impl SessionCard for StudyCard {
+    type Key = StudyKey;
+
+    fn key(&self) -> StudyKey {
+        match self {
+            StudyCard::Wort(w) => StudyKey::Wort(w.key()),
+            StudyCard::Satz(s) => StudyKey::Satz(s.key()),
+        }
+    }
+
+    fn expected(&self) -> String {
+        match self {
+            StudyCard::Wort(w) => w.expected(),
+            StudyCard::Satz(s) => s.expected(),
+        }
+    }
+
+    fn normalize(&self, input: &str) -> String {
+        match self {
+            StudyCard::Wort(w) => w.normalize(input),
+            StudyCard::Satz(s) => s.normalize(input),
+        }
+    }
+
+    fn matches(&self, expected: &str, input: &str) -> bool {
+        match self {
+            StudyCard::Wort(w) => w.matches(expected, input),
+            StudyCard::Satz(s) => s.matches(expected, input),
+        }
+    }
+
+    fn hints(&self, input: &str) -> Vec<String> {
+        match self {
+            StudyCard::Wort(w) => w.hints(input),
+            StudyCard::Satz(s) => s.hints(input),
+        }
+    }
+
+    fn partial_credit(&self, input: &str) -> bool {
+        match self {
+            StudyCard::Wort(w) => w.partial_credit(input),
+            StudyCard::Satz(s) => s.partial_credit(input),
+        }
+    }
+
+    fn quelle(&self) -> Quelle {
+        match self {
+            StudyCard::Wort(w) => w.quelle(),
+            StudyCard::Satz(s) => s.quelle(),
+        }
+    }
+
+    fn fetch(conn: &Connection, keys: &[StudyKey]) -> Result<Vec<Self>> {
+        let mut ids_worte = vec![];
+        let mut ids_setze = vec![];
+        for k in keys {
+            match k {
+                StudyKey::Wort(id) => ids_worte.push(*id),
+                StudyKey::Satz(id) => ids_setze.push(*id),
+            }
+        }
+
+        let mut cards: HashMap<StudyKey, StudyCard> = HashMap::new();
+        for w in WorteRepo::fetch_by_id(conn, &ids_worte)? {
+            cards.insert(StudyKey::Wort(w.id), StudyCard::Wort(w));
+        }
+        for s in SetzeRepo::fetch_by_id(conn, &ids_setze)? {
+            cards.insert(StudyKey::Satz(s.id), StudyCard::Satz(s));
+        }
+
+        // Respetamos el orden intercalado de las llaves
+        Ok(keys.iter().filter_map(|k| cards.remove(k)).collect())
+    }
+}
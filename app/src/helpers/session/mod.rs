@@ -0,0 +1,317 @@
+use std::{collections::HashMap, fmt::Debug, hash::Hash};
+
+use color_eyre::eyre::Result;
+use rusqlite::Connection;
+
+use crate::helpers::fehler::Quelle;
+
+mod cards;
+
+#[cfg(test)]
+mod session_test;
+
+/// Tarjeta que puede estudiarse dentro de una `Session`.
+pub trait SessionCard: Clone {
+    type Key: Copy + Eq + Hash + Debug;
+
+    fn key(&self) -> Self::Key;
+
+    /// Respuesta correcta tal como se le muestra al usuario.
+    fn expected(&self) -> String;
+
+    /// Normalización que se aplica a la respuesta esperada y a lo que escribe el usuario
+    /// antes de compararlas.
+    fn normalize(&self, input: &str) -> String;
+
+    /// `input` (ya interpretado) es una respuesta válida para `expected`.
+    fn matches(&self, expected: &str, input: &str) -> bool {
+        self.normalize(expected) == self.normalize(input)
+    }
+
+    /// Interpreta lo que escribió el usuario antes de normalizarlo (ejem: índices de palabras).
+    fn parse_input(&self, input: &str) -> String {
+        input.to_string()
+    }
+
+    /// Explicaciones específicas de una respuesta incorrecta (ejem: falta el artículo).
+    fn hints(&self, _input: &str) -> Vec<String> {
+        vec![]
+    }
+
+    /// Respuesta casi correcta que cuenta como acierto aunque se guarda como error (ejem: el
+    /// sustantivo en minúscula con el artículo correcto).
+    fn partial_credit(&self, _input: &str) -> bool {
+        false
+    }
+
+    /// Origen de la tarjeta con el que se guardan sus respuestas incorrectas.
+    fn quelle(&self) -> Quelle;
+
+    fn fetch(conn: &Connection, keys: &[Self::Key]) -> Result<Vec<Self>>;
+}
+
+#[derive(Debug, Clone)]
+struct ManageRepetitions {
+    once_mistake: bool,
+    repetition: u8,
+}
+
+impl ManageRepetitions {
+    fn new_error() -> Self {
+        Self {
+            once_mistake: true,
+            repetition: 0,
+        }
+    }
+
+    fn add_repetition(&mut self) {
+        self.repetition += 1;
+    }
+}
+
+/// Foto del estado de la sesión antes de calificar una respuesta, para poder deshacerla.
+#[derive(Clone)]
+struct SessionSnapshot<T: SessionCard> {
+    queue: Vec<T>,
+    pending: Vec<T::Key>,
+    already_studied: HashMap<T::Key, ManageRepetitions>,
+    results_len: usize,
+    mistakes_len: usize,
+    /// Some(input) si la respuesta calificada fue incorrecta
+    wrong_input: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    /// Casi correcta: la tarjeta avanza como un acierto sin reiniciar su intervalo, pero la
+    /// respuesta se guarda como error
+    Partial,
+    Wrong,
+}
+
+/// Motor de una sesión de estudio, independiente de la consola.
+///
+/// Mantiene el bloque de tarjetas en estudio (`queue`), las que faltan por cargar (`pending`)
+/// y la calificación de cada tarjeta terminada:
+/// - 0 -> No se la sabe
+/// - 1 -> Se la sabe pero dificil
+/// - 2 -> Se la sabe facil
+pub struct Session<T: SessionCard> {
+    queue: Vec<T>,
+    pending: Vec<T::Key>,
+    already_studied: HashMap<T::Key, ManageRepetitions>,
+    results: Vec<(T::Key, u8)>,
+    alternatives: HashMap<T::Key, Vec<String>>,
+    history: Vec<SessionSnapshot<T>>,
+    /// Respuestas incorrectas con lo que escribió el usuario, pendientes de guardar
+    mistakes: Vec<(T, String)>,
+}
+
+impl<T: SessionCard> Session<T> {
+    pub fn new(conn: &Connection, keys: Vec<T::Key>, offset: usize) -> Result<Self> {
+        let mut pending = keys;
+
+        let take = pending.len().min(offset);
+        let first: Vec<T::Key> = pending.drain(..take).collect();
+
+        // Obtenemos toda la info del bloque de tarjetas que vamos a usar
+        let queue = T::fetch(conn, &first)?;
+
+        Ok(Self {
+            queue,
+            pending,
+            already_studied: HashMap::new(),
+            results: vec![],
+            alternatives: HashMap::new(),
+            history: vec![],
+            mistakes: vec![],
+        })
+    }
+
+    /// Respuestas alternativas aceptadas, por tarjeta.
+    pub fn with_alternatives(mut self, alternatives: HashMap<T::Key, Vec<String>>) -> Self {
+        self.alternatives = alternatives;
+        self
+    }
+
+    pub fn current(&self) -> Option<&T> {
+        self.queue.first()
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.queue.len() + self.pending.len()
+    }
+
+    pub fn into_results(self) -> Vec<(T::Key, u8)> {
+        self.results
+    }
+
+    /// Saca las respuestas incorrectas acumuladas para guardarlas.
+    pub fn take_mistakes(&mut self) -> Vec<(T, String)> {
+        std::mem::take(&mut self.mistakes)
+    }
+
+    /// Valida si `input` es una respuesta correcta para `card`.
+    pub fn is_correct(&self, card: &T, input: &str) -> bool {
+        let input = card.parse_input(input);
+        if card.matches(&card.expected(), &input) {
+            return true;
+        }
+
+        self.alternatives
+            .get(&card.key())
+            .is_some_and(|alts| alts.iter().any(|a| card.matches(a, &input)))
+    }
+
+    /// Califica la respuesta del usuario para la tarjeta actual.
+    pub fn answer(&mut self, conn: &Connection, input: &str) -> Result<Outcome> {
+        let Some(card) = self.current().cloned() else {
+            return Ok(Outcome::Correct);
+        };
+
+        if self.is_correct(&card, input) {
+            self.push_history(None);
+            self.apply_correct(conn, &card)?;
+            return Ok(Outcome::Correct);
+        }
+
+        self.push_history(Some(input.trim().to_string()));
+        self.mistakes.push((card.clone(), input.trim().to_string()));
+
+        if card.partial_credit(input) {
+            // Cuenta como acierto: solo un error real en la sesión reinicia el intervalo
+            self.apply_correct(conn, &card)?;
+            return Ok(Outcome::Partial);
+        }
+
+        self.already_studied
+            .insert(card.key(), ManageRepetitions::new_error());
+        self.queue.rotate_left(1); // mueve el primer elemento al final del vector
+
+        Ok(Outcome::Wrong)
+    }
+
+    /// Revierte la última calificación, incluida la posición en la cola y el resultado
+    /// pendiente de guardar.
+    pub fn undo(&mut self) -> bool {
+        let Some(snapshot) = self.history.pop() else {
+            return false;
+        };
+
+        self.restore(snapshot);
+        true
+    }
+
+    /// Reclasifica como correcta la última respuesta si fue incorrecta.
+    ///
+    /// return:
+    /// - None si no hay una respuesta incorrecta que corregir
+    /// - Some((key, input)) con la tarjeta corregida y el texto que escribió el usuario, ya
+    ///   interpretado con `parse_input` (ejem: las palabras en lugar de sus índices)
+    pub fn override_last(&mut self, conn: &Connection) -> Result<Option<(T::Key, String)>> {
+        let Some(wrong_input) = self.history.last().and_then(|s| s.wrong_input.clone()) else {
+            return Ok(None);
+        };
+
+        let snapshot = self.history.pop().unwrap();
+        self.restore(snapshot);
+
+        let Some(card) = self.current().cloned() else {
+            return Ok(None);
+        };
+
+        self.push_history(None);
+        self.apply_correct(conn, &card)?;
+
+        Ok(Some((card.key(), card.parse_input(&wrong_input))))
+    }
+
+    /// Agrega una respuesta alternativa aceptada para el resto de la sesión.
+    pub fn add_alternative(&mut self, key: T::Key, antwort: &str) {
+        self.alternatives
+            .entry(key)
+            .or_default()
+            .push(antwort.to_string());
+    }
+
+    /// Vuelve a consultar una tarjeta de la sesión después de editarla. Si ya no existe
+    /// (se borró) sale de la sesión sin calificarse.
+    ///
+    /// El historial se limpia para que "undo" no regrese la versión anterior de la tarjeta.
+    pub fn reload_card(&mut self, conn: &Connection, key: T::Key) -> Result<()> {
+        let Some(pos) = self.queue.iter().position(|c| c.key() == key) else {
+            return Ok(());
+        };
+
+        self.history.clear();
+
+        match T::fetch(conn, &[key])?.pop() {
+            Some(card) => self.queue[pos] = card,
+            None => {
+                self.queue.remove(pos);
+                self.already_studied.remove(&key);
+
+                if !self.pending.is_empty() {
+                    let key_new = self.pending.remove(0);
+                    self.queue.extend(T::fetch(conn, &[key_new])?);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn apply_correct(&mut self, conn: &Connection, card: &T) -> Result<()> {
+        let key = card.key();
+
+        if let Some(rep) = self.already_studied.get_mut(&key) {
+            if rep.repetition < 1 {
+                // Primera vez que la acierta: subimos contador pero aún no la graduamos
+                rep.add_repetition();
+                self.queue.rotate_left(1);
+                return Ok(());
+            }
+
+            // Si la bandera de once_mistake esta en true, quiere decir que se equivoco con la
+            // tarjeta por lo menos una vez
+            let easy = if rep.once_mistake { 1 } else { 2 };
+            self.results.push((key, easy));
+
+            // limpiamos el hashmap de la tarjeta que ya no se va a repetir
+            self.already_studied.remove(&key);
+        } else {
+            // La tuvo correcta a la primera
+            self.results.push((key, 2));
+        }
+
+        self.queue.remove(0);
+
+        if !self.pending.is_empty() {
+            // Consultamos una nueva tarjeta y la añadimos al arreglo para su estudio
+            let key_new = self.pending.remove(0);
+            self.queue.extend(T::fetch(conn, &[key_new])?);
+        }
+
+        Ok(())
+    }
+
+    fn push_history(&mut self, wrong_input: Option<String>) {
+        self.history.push(SessionSnapshot {
+            queue: self.queue.clone(),
+            pending: self.pending.clone(),
+            already_studied: self.already_studied.clone(),
+            results_len: self.results.len(),
+            mistakes_len: self.mistakes.len(),
+            wrong_input,
+        });
+    }
+
+    fn restore(&mut self, snapshot: SessionSnapshot<T>) {
+        self.queue = snapshot.queue;
+        self.pending = snapshot.pending;
+        self.already_studied = snapshot.already_studied;
+        self.results.truncate(snapshot.results_len);
+        self.mistakes.truncate(snapshot.mistakes_len);
+    }
+}
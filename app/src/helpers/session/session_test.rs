@@ -0,0 +1,237 @@
+#[cfg(test)]
+mod test_session {
+    use std::cell::RefCell;
+
+    use color_eyre::eyre::Result;
+    use rusqlite::Connection;
+
+    use crate::helpers::{
+        fehler::{Art, Quelle},
+        session::{Outcome, Session, SessionCard},
+    };
+
+    thread_local! {
+        /// Simula las ediciones hechas en la base de datos: id -> respuesta (None = borrada)
+        static EDITS: RefCell<Vec<(i32, Option<String>)>> = const { RefCell::new(vec![]) };
+    }
+
+    #[derive(Debug, Clone)]
+    struct DummyCard {
+        id: i32,
+        antwort: String,
+    }
+
+    impl SessionCard for DummyCard {
+        type Key = i32;
+
+        fn key(&self) -> i32 {
+            self.id
+        }
+
+        fn expected(&self) -> String {
+            self.antwort.clone()
+        }
+
+        fn normalize(&self, input: &str) -> String {
+            input.trim().to_string()
+        }
+
+        /// "casi wort 1" es casi correcta
+        fn partial_credit(&self, input: &str) -> bool {
+            input.trim() == format!("casi {}", self.antwort)
+        }
+
+        fn quelle(&self) -> Quelle {
+            Quelle::wort(Art::Wort, self.id)
+        }
+
+        fn fetch(_conn: &Connection, keys: &[i32]) -> Result<Vec<Self>> {
+            let edits = EDITS.with(|e| e.borrow().clone());
+            Ok(keys
+                .iter()
+                .filter_map(|k| {
+                    match edits.iter().rev().find(|(id, _)| id == k) {
+                        Some((_, antwort)) => antwort.clone(),
+                        None => Some(format!("wort {k}")),
+                    }
+                    .map(|antwort| DummyCard { id: *k, antwort })
+                })
+                .collect())
+        }
+    }
+
+    fn new_session(conn: &Connection, ids: Vec<i32>, offset: usize) -> Session<DummyCard> {
+        Session::new(conn, ids, offset).expect("Error al crear la sesión")
+    }
+
+    #[test]
+    fn correct_first_try_graduates_easy() {
+        let conn = Connection::open_in_memory().unwrap();
+        let mut s = new_session(&conn, vec![1, 2, 3], 2);
+
+        assert_eq!(s.remaining(), 3);
+        assert_eq!(s.current().unwrap().id, 1);
+
+        assert_eq!(s.answer(&conn, "wort 1").unwrap(), Outcome::Correct);
+        assert_eq!(s.results, [(1, 2)]);
+
+        // Se carga la siguiente tarjeta pendiente al final del bloque
+        assert_eq!(s.current().unwrap().id, 2);
+        assert_eq!(s.remaining(), 2);
+    }
+
+    #[test]
+    fn wrong_answer_needs_two_hits_and_grades_hard() {
+        let conn = Connection::open_in_memory().unwrap();
+        let mut s = new_session(&conn, vec![1], 10);
+
+        assert_eq!(s.answer(&conn, "falsch").unwrap(), Outcome::Wrong);
+        assert!(s.results.is_empty());
+
+        assert_eq!(s.answer(&conn, "wort 1").unwrap(), Outcome::Correct);
+        assert!(s.results.is_empty());
+
+        assert_eq!(s.answer(&conn, "wort 1").unwrap(), Outcome::Correct);
+        assert_eq!(s.results, [(1, 1)]);
+        assert!(s.current().is_none());
+    }
+
+    #[test]
+    fn undo_reverts_queue_and_pending_result() {
+        let conn = Connection::open_in_memory().unwrap();
+        let mut s = new_session(&conn, vec![1, 2, 3], 2);
+
+        s.answer(&conn, "wort 1").unwrap();
+        assert_eq!(s.results.len(), 1);
+        assert_eq!(s.remaining(), 2);
+
+        assert!(s.undo());
+        assert!(s.results.is_empty());
+        assert_eq!(s.current().unwrap().id, 1);
+        assert_eq!(s.remaining(), 3);
+
+        // Sin historial no hay nada que deshacer
+        assert!(!s.undo());
+    }
+
+    #[test]
+    fn undo_wrong_answer_clears_mistake() {
+        let conn = Connection::open_in_memory().unwrap();
+        let mut s = new_session(&conn, vec![1, 2], 2);
+
+        s.answer(&conn, "falsch").unwrap();
+        assert_eq!(s.current().unwrap().id, 2);
+
+        assert!(s.undo());
+        assert_eq!(s.current().unwrap().id, 1);
+
+        s.answer(&conn, "wort 1").unwrap();
+        assert_eq!(s.results, [(1, 2)]);
+    }
+
+    #[test]
+    fn override_reclassifies_last_wrong_answer() {
+        let conn = Connection::open_in_memory().unwrap();
+        let mut s = new_session(&conn, vec![1, 2], 2);
+
+        // Sin respuesta incorrecta no hay nada que corregir
+        assert!(s.override_last(&conn).unwrap().is_none());
+
+        s.answer(&conn, "Wort 1").unwrap();
+        let r = s.override_last(&conn).unwrap();
+        assert_eq!(r, Some((1, "Wort 1".to_string())));
+        assert_eq!(s.results, [(1, 2)]);
+
+        // El override también se puede deshacer
+        assert!(s.undo());
+        assert!(s.results.is_empty());
+        assert_eq!(s.current().unwrap().id, 1);
+    }
+
+    #[test]
+    fn wrong_answers_are_kept_until_undone() {
+        let conn = Connection::open_in_memory().unwrap();
+        let mut s = new_session(&conn, vec![1, 2], 2);
+
+        s.answer(&conn, " falsch ").unwrap();
+        s.answer(&conn, "auch falsch").unwrap();
+        assert_eq!(s.mistakes.len(), 2);
+        assert_eq!(s.mistakes[0].1, "falsch");
+
+        // Deshacer o corregir una respuesta la quita de los errores
+        assert!(s.undo());
+        assert_eq!(s.mistakes.len(), 1);
+        s.answer(&conn, "Wort 2").unwrap();
+        s.override_last(&conn).unwrap();
+        assert_eq!(s.mistakes.len(), 1);
+
+        let mistakes = s.take_mistakes();
+        assert_eq!(mistakes[0].0.id, 1);
+        assert!(s.mistakes.is_empty());
+    }
+
+    #[test]
+    fn partial_answer_graduates_without_lapse_and_counts_as_mistake() {
+        let conn = Connection::open_in_memory().unwrap();
+        let mut s = new_session(&conn, vec![1, 2], 2);
+
+        assert_eq!(s.answer(&conn, "casi wort 1").unwrap(), Outcome::Partial);
+        assert_eq!(s.results, [(1, 2)]);
+        assert_eq!(s.mistakes.len(), 1);
+        assert_eq!(s.current().unwrap().id, 2);
+
+        // Tras un error, la respuesta casi correcta cuenta como un acierto más
+        s.answer(&conn, "falsch").unwrap();
+        assert_eq!(s.answer(&conn, "casi wort 2").unwrap(), Outcome::Partial);
+        assert_eq!(s.current().unwrap().id, 2);
+        assert_eq!(s.answer(&conn, "wort 2").unwrap(), Outcome::Correct);
+        assert_eq!(s.results, [(1, 2), (2, 1)]);
+
+        // Se puede marcar como correcta
+        let mut s = new_session(&conn, vec![1], 1);
+        s.answer(&conn, "casi wort 1").unwrap();
+        assert!(s.override_last(&conn).unwrap().is_some());
+        assert_eq!(s.results, [(1, 2)]);
+        assert!(s.mistakes.is_empty());
+    }
+
+    #[test]
+    fn alternatives_are_accepted() {
+        let conn = Connection::open_in_memory().unwrap();
+        let mut s = new_session(&conn, vec![1, 2], 2)
+            .with_alternatives([(1, vec!["anders 1".to_string()])].into());
+
+        assert_eq!(s.answer(&conn, " anders 1 ").unwrap(), Outcome::Correct);
+
+        s.add_alternative(2, "anders 2");
+        assert_eq!(s.answer(&conn, "anders 2").unwrap(), Outcome::Correct);
+        assert_eq!(s.results, [(1, 2), (2, 2)]);
+    }
+
+    #[test]
+    fn reload_card_applies_edits_and_drops_deleted() {
+        let conn = Connection::open_in_memory().unwrap();
+        let mut s = new_session(&conn, vec![1, 2, 3], 2);
+
+        s.answer(&conn, "falsch").unwrap();
+        s.answer(&conn, "falsch").unwrap();
+        assert_eq!(s.current().unwrap().id, 1);
+
+        EDITS.with(|e| e.borrow_mut().push((1, Some("neu 1".into()))));
+        s.reload_card(&conn, 1).unwrap();
+        assert_eq!(s.current().unwrap().expected(), "neu 1");
+
+        // Después de editar no se puede deshacer
+        assert!(!s.undo());
+
+        // La tarjeta borrada sale de la sesión y entra la siguiente pendiente
+        EDITS.with(|e| e.borrow_mut().push((1, None)));
+        s.reload_card(&conn, 1).unwrap();
+        assert_eq!(s.remaining(), 2);
+        assert_eq!(s.current().unwrap().id, 2);
+
+        s.answer(&conn, "wort 2").unwrap();
+        s.answer(&conn, "wort 3").unwrap();
+        assert_eq!(s.results, [(3, 2)]);
+    }
+}
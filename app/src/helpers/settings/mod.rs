@@ -0,0 +1,290 @@
+use std::{fs, path::Path, sync::Mutex};
+
+use color_eyre::eyre::{Context, Result, bail};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod settings_test;
+
+/// Configuración cargada al iniciar; los menús la leen con `settings::get()`.
+static SETTINGS: Lazy<Mutex<Settings>> = Lazy::new(|| Mutex::new(Settings::default()));
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Settings {
+    pub session: SessionSettings,
+    pub daily: DailySettings,
+    pub tts: TtsSettings,
+    pub vergleich: VergleichSettings,
+    pub niveau: NiveauSettings,
+    pub frequenz: FrequenzSettings,
+}
+
+/// Tarjetas por bloque en cada menú de práctica.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SessionSettings {
+    pub setze_random: usize,
+    pub setze_thema: usize,
+    pub setze_schwirig: usize,
+    pub setze_neue: usize,
+    pub setze_scramble: usize,
+    pub setze_tags: usize,
+    pub worte_neue: usize,
+    pub worte_review: usize,
+    pub worte_tags: usize,
+    pub konjugation: usize,
+    pub deklination: usize,
+    pub praeposition: usize,
+    pub steigerung: usize,
+    /// Pares de palabras confundidas; cada par son dos tarjetas
+    pub verwechslung: usize,
+    pub daily: usize,
+}
+
+impl Default for SessionSettings {
+    fn default() -> Self {
+        Self {
+            setze_random: 10,
+            setze_thema: 15,
+            setze_schwirig: 10,
+            setze_neue: 10,
+            setze_scramble: 10,
+            setze_tags: 15,
+            worte_neue: 10,
+            worte_review: 20,
+            worte_tags: 20,
+            konjugation: 20,
+            deklination: 15,
+            praeposition: 15,
+            steigerung: 15,
+            verwechslung: 10,
+            daily: 20,
+        }
+    }
+}
+
+/// Proporción palabras:oraciones que propone el estudio diario.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DailySettings {
+    pub worte_ratio: usize,
+    pub setze_ratio: usize,
+}
+
+impl Default for DailySettings {
+    fn default() -> Self {
+        Self {
+            worte_ratio: 2,
+            setze_ratio: 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TtsSettings {
+    pub model_id: String,
+    pub output_format: String,
+    pub stability: f32,
+    pub similarity_boost: f32,
+    pub style: f32,
+    pub use_speaker_boost: bool,
+    pub voice_id_de: String,
+    pub voice_id_es: String,
+    /// Etiquetas con las que se guardan los audios en `worte_audio.voice_id`.
+    pub voice_label_de: String,
+    pub voice_label_es: String,
+}
+
+impl Default for TtsSettings {
+    fn default() -> Self {
+        Self {
+            model_id: "eleven_flash_v2_5".into(),
+            output_format: "mp3_22050_32".into(),
+            stability: 0.6,
+            similarity_boost: 0.8,
+            style: 0.4,
+            use_speaker_boost: true,
+            voice_id_de: "g1jpii0iyvtRs8fqXsd1".into(),
+            voice_id_es: "zl1Ut8dvwcVSuQSB9XkG".into(),
+            voice_label_de: "masc_eleven_labs".into(),
+            // Etiqueta con la que ya se guardaron los audios existentes
+            voice_label_es: "masc_eleven_labs".into(),
+        }
+    }
+}
+
+/// Equivalencias que se aceptan al calificar oraciones.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct VergleichSettings {
+    /// "zum" = "zu dem", "ins" = "in das"...
+    pub kontraktionen: bool,
+    /// "3" = "drei"
+    pub zahlen: bool,
+    /// "ich rufe dich an morgen" = "ich rufe dich morgen an"
+    pub trennbare_verben: bool,
+}
+
+impl Default for VergleichSettings {
+    fn default() -> Self {
+        Self {
+            kontraktionen: true,
+            zahlen: true,
+            trennbare_verben: true,
+        }
+    }
+}
+
+/// Progreso por nivel (A1–C2).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct NiveauSettings {
+    /// Las tarjetas nuevas salen primero del nivel más bajo sin dominar
+    pub unlock: bool,
+    /// Porcentaje de tarjetas maduras con el que un nivel se da por dominado
+    pub mastery_percent: usize,
+}
+
+impl Default for NiveauSettings {
+    fn default() -> Self {
+        Self {
+            unlock: false,
+            mastery_percent: 80,
+        }
+    }
+}
+
+/// Lista de frecuencia importada con `import frequency`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FrequenzSettings {
+    /// Las palabras nuevas salen de la más frecuente a la menos frecuente
+    pub order_neue: bool,
+    /// Tamaño de la banda de palabras más frecuentes que se mide en las estadísticas
+    pub top_n: usize,
+}
+
+impl Default for FrequenzSettings {
+    fn default() -> Self {
+        Self {
+            order_neue: false,
+            top_n: 1000,
+        }
+    }
+}
+
+impl Settings {
+    /// Lee la configuración de un archivo TOML; los valores que falten toman el valor por
+    /// defecto.
+    pub fn parse(text: &str) -> Result<Self> {
+        let settings: Settings =
+            toml::from_str(text).context("Archivo de configuración no válido")?;
+        settings.validate()?;
+        Ok(settings)
+    }
+
+    pub fn to_toml(&self) -> Result<String> {
+        Ok(toml::to_string_pretty(self)?)
+    }
+
+    /// Carga el archivo; si no existe lo crea con los valores por defecto.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            let settings = Self::default();
+            settings.save(path)?;
+            return Ok(settings);
+        }
+
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("No se pudo leer: {}", path.display()))?;
+        Self::parse(&text).with_context(|| format!("Revisa el archivo: {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        self.validate()?;
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, self.to_toml()?)
+            .with_context(|| format!("No se pudo guardar: {}", path.display()))
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        let s = &self.session;
+        let sizes = [
+            ("session.setze_random", s.setze_random),
+            ("session.setze_thema", s.setze_thema),
+            ("session.setze_schwirig", s.setze_schwirig),
+            ("session.setze_neue", s.setze_neue),
+            ("session.setze_scramble", s.setze_scramble),
+            ("session.setze_tags", s.setze_tags),
+            ("session.worte_neue", s.worte_neue),
+            ("session.worte_review", s.worte_review),
+            ("session.worte_tags", s.worte_tags),
+            ("session.konjugation", s.konjugation),
+            ("session.deklination", s.deklination),
+            ("session.praeposition", s.praeposition),
+            ("session.steigerung", s.steigerung),
+            ("session.verwechslung", s.verwechslung),
+            ("session.daily", s.daily),
+            ("frequenz.top_n", self.frequenz.top_n),
+        ];
+        for (name, value) in sizes {
+            if value == 0 {
+                bail!("{} debe ser mayor a 0", name);
+            }
+        }
+
+        if self.daily.worte_ratio == 0 && self.daily.setze_ratio == 0 {
+            bail!("daily.worte_ratio y daily.setze_ratio no pueden ser ambos 0");
+        }
+
+        if !(1..=100).contains(&self.niveau.mastery_percent) {
+            bail!(
+                "niveau.mastery_percent debe estar entre 1 y 100, se encontró {}",
+                self.niveau.mastery_percent
+            );
+        }
+
+        let t = &self.tts;
+        for (name, value) in [
+            ("tts.stability", t.stability),
+            ("tts.similarity_boost", t.similarity_boost),
+            ("tts.style", t.style),
+        ] {
+            if !(0.0..=1.0).contains(&value) {
+                bail!("{} debe estar entre 0 y 1, se encontró {}", name, value);
+            }
+        }
+
+        let texts = [
+            ("tts.model_id", &t.model_id),
+            ("tts.output_format", &t.output_format),
+            ("tts.voice_id_de", &t.voice_id_de),
+            ("tts.voice_id_es", &t.voice_id_es),
+            ("tts.voice_label_de", &t.voice_label_de),
+            ("tts.voice_label_es", &t.voice_label_es),
+        ];
+        for (name, value) in texts {
+            if value.trim().is_empty() {
+                bail!("{} no puede estar vacío", name);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub fn get() -> Settings {
+    SETTINGS
+        .lock()
+        .expect("Mutex envenenado en SETTINGS")
+        .clone()
+}
+
+pub fn set(settings: Settings) {
+    *SETTINGS.lock().expect("Mutex envenenado en SETTINGS") = settings;
+}
@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod test_settings {
+    use std::fs;
+
+    use crate::helpers::settings::Settings;
+
+    #[test]
+    fn test_parse_partial_file() {
+        let text = r#"
+            [session]
+            daily = 30
+
+            [tts]
+            voice_id_es = "otra_voz"
+
+            [vergleich]
+            zahlen = false
+        "#;
+
+        let settings = Settings::parse(text).unwrap();
+        let default = Settings::default();
+
+        assert_eq!(settings.session.daily, 30);
+        assert_eq!(settings.session.worte_review, default.session.worte_review);
+        assert_eq!(settings.tts.voice_id_es, "otra_voz");
+        assert_eq!(settings.tts.voice_label_es, "masc_eleven_labs");
+        assert_eq!(settings.tts.voice_id_de, default.tts.voice_id_de);
+        assert_eq!(settings.daily, default.daily);
+        assert!(!settings.vergleich.zahlen);
+        assert!(settings.vergleich.kontraktionen);
+
+        assert_eq!(Settings::parse("").unwrap(), default);
+    }
+
+    #[test]
+    fn test_validate() {
+        let invalid = [
+            "[session]\nsetze_random = 0",
+            "[session]\nworte_tags = -1",
+            "[daily]\nworte_ratio = 0\nsetze_ratio = 0",
+            "[tts]\nstability = 1.5",
+            "[tts]\nmodel_id = \"  \"",
+            "[tts]\nvoice_id_de = \"\"",
+            "[niveau]\nmastery_percent = 0",
+            "[niveau]\nmastery_percent = 120",
+            "[frequenz]\ntop_n = 0",
+            // Un error de dedo no se ignora
+            "[session]\ndayly = 10",
+        ];
+        for text in invalid {
+            assert!(Settings::parse(text).is_err(), "{}", text);
+        }
+
+        assert!(Settings::parse("[daily]\nworte_ratio = 0").is_ok());
+    }
+
+    #[test]
+    fn test_load_and_save() {
+        let dir = std::env::temp_dir().join(format!("anki_settings_{}", std::process::id()));
+        let path = dir.join("perfil").join("config.toml");
+
+        // Si no existe se crea con los valores por defecto
+        let settings = Settings::load(&path).unwrap();
+        assert_eq!(settings, Settings::default());
+        assert!(path.is_file());
+
+        let mut settings = settings;
+        settings.session.setze_thema = 25;
+        settings.tts.use_speaker_boost = false;
+        settings.save(&path).unwrap();
+        assert_eq!(Settings::load(&path).unwrap(), settings);
+
+        // Una configuración no válida no se guarda
+        let mut invalid = settings.clone();
+        invalid.tts.style = -0.1;
+        assert!(invalid.save(&path).is_err());
+        assert_eq!(Settings::load(&path).unwrap(), settings);
+
+        fs::write(&path, "[session\n").unwrap();
+        assert!(Settings::load(&path).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,95 @@
+use color_eyre::eyre::Result;
+use rusqlite::Connection;
+use serde::Serialize;
+
+use crate::{
+    db::{
+        setze::SetzeRepo, setze_review::SetzeReviewRepo, worte::WorteRepo,
+        worte_review::WorteReviewRepo,
+    },
+    helpers::{
+        deck,
+        frequenz::{self, Abdeckung},
+        settings, time,
+    },
+};
+
+#[cfg(test)]
+mod stats_test;
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct CardStats {
+    pub total: usize,
+    /// Tarjetas sin ninguna revisión.
+    pub neue: usize,
+    /// Tarjetas que toca repasar hoy.
+    pub due: usize,
+    /// Tarjetas repasadas hoy.
+    pub reviewed_today: usize,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct Stats {
+    pub worte: CardStats,
+    pub setze: CardStats,
+    /// Cobertura de las palabras más frecuentes, si se importó una lista de frecuencia.
+    pub frequenz: Option<Abdeckung>,
+}
+
+/// Ids de las palabras y oraciones que toca repasar hoy, en el orden del repaso.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct DueIds {
+    pub worte: Vec<i32>,
+    pub setze: Vec<i32>,
+}
+
+/// Ids por repasar hoy, limitados a `decks` si se indica.
+pub fn due_ids(conn: &Connection, decks: Option<&[i32]>) -> Result<DueIds> {
+    let date_review = time::today_local_string(1);
+
+    let worte = WorteReviewRepo::fetch_review_wort_id_by_day(conn, date_review.clone())?;
+    let setze = SetzeReviewRepo::fetch_review_satz_id_by_day(conn, date_review)?;
+
+    Ok(DueIds {
+        worte: deck::filter_worte(conn, worte, decks)?,
+        setze: deck::filter_setze(conn, setze, decks)?,
+    })
+}
+
+/// Conteo de tarjetas, limitado a `decks` si se indica.
+pub fn collect(conn: &Connection, decks: Option<&[i32]>) -> Result<Stats> {
+    let today = time::today_local_string(0);
+    let due = due_ids(conn, decks)?;
+
+    let worte = CardStats {
+        total: deck::filter_worte(conn, WorteRepo::fetch_all_only_ids(conn)?, decks)?.len(),
+        neue: deck::filter_worte(conn, WorteRepo::fetch_id_neue_worte(conn)?, decks)?.len(),
+        due: due.worte.len(),
+        reviewed_today: deck::filter_worte(
+            conn,
+            WorteReviewRepo::fetch_wort_id_reviewed_since(conn, today.clone())?,
+            decks,
+        )?
+        .len(),
+    };
+
+    let setze = CardStats {
+        total: deck::filter_setze(conn, SetzeRepo::fetch_all_only_ids(conn)?, decks)?.len(),
+        neue: deck::filter_setze(conn, SetzeRepo::fetch_id_neue_sentences(conn)?, decks)?.len(),
+        due: due.setze.len(),
+        reviewed_today: deck::filter_setze(
+            conn,
+            SetzeReviewRepo::fetch_satz_id_reviewed_since(conn, today)?,
+            decks,
+        )?
+        .len(),
+    };
+
+    let frequenz = frequenz::abdeckung(conn, decks, settings::get().frequenz.top_n)?;
+
+    Ok(Stats {
+        worte,
+        setze,
+        frequenz,
+    })
+}
@@ -0,0 +1,135 @@
+use crate::db::setup_test_db;
+
+#[cfg(test)]
+mod test_stats {
+    use std::fs;
+
+    use chrono::Utc;
+    use rusqlite::Connection;
+
+    use crate::{
+        db::{
+            deck::DeckRepo,
+            schemas::{setze_review::NewSetzeReviewSchema, worte_review::NewWorteReviewSchema},
+            seeders::init_data,
+            setze::SetzeRepo,
+            setze_review::SetzeReviewRepo,
+            worte::WorteRepo,
+            worte_review::WorteReviewRepo,
+        },
+        helpers::{
+            csv,
+            stats::{CardStats, collect, due_ids},
+            time,
+        },
+    };
+
+    use super::*;
+
+    const WORTE_CSV: &str = "\
+gram_type,gender_id,worte_de,worte_es,plural,niveau_id,example_de,example_es,verb_aux,trennbar,reflexiv
+noun_common,Maskuline,Hund,el perro,Hunde,A1,Der Hund spielt.,El perro juega.,,,
+verb_main,,gehen,ir,,A1,Ich gehe.,Voy.,sein,No,No
+";
+
+    const SETZE_CSV: &str = "\
+setze_spanisch,setze_deutsch,thema,schwirig_id
+Tengo un perro,Ich habe einen Hund,Akkusativ,0
+Voy a casa,Ich gehe nach Hause,Dativ,1
+";
+
+    fn init_data_local(conn: &mut Connection) {
+        init_data(conn).unwrap();
+
+        let dir = std::env::temp_dir().join(format!("anki_stats_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let worte = dir.join("worte.csv");
+        let setze = dir.join("setze.csv");
+        fs::write(&worte, WORTE_CSV).unwrap();
+        fs::write(&setze, SETZE_CSV).unwrap();
+
+        let deck = DeckRepo::ensure_path(conn, "Goethe A1").unwrap();
+        let data = csv::extract_worte_csv(worte.to_str().unwrap(), deck.id).unwrap();
+        WorteRepo::bulk_insert(conn, &data).unwrap();
+        let data = csv::extract_sentences_csv(setze.to_str().unwrap(), deck.id).unwrap();
+        SetzeRepo::bulk_insert(conn, &data).unwrap();
+
+        // Una palabra más en otro deck
+        let otro = DeckRepo::ensure_path(conn, "Otro").unwrap();
+        let data = csv::extract_worte_csv(worte.to_str().unwrap(), otro.id).unwrap();
+        WorteRepo::bulk_insert(conn, &data[..1]).unwrap();
+
+        fs::remove_dir_all(&dir).unwrap();
+
+        let now = time::datetime_2_string(Utc::now());
+        WorteReviewRepo::bulk_insert(
+            conn,
+            &[NewWorteReviewSchema {
+                wort_id: 1,
+                interval: 1,
+                ease_factor: 2.5,
+                repetitions: 1,
+                last_review: now,
+                next_review: "2020-01-01 00:00:00".into(),
+            }],
+        )
+        .unwrap();
+        SetzeReviewRepo::bulk_insert(
+            conn,
+            &[NewSetzeReviewSchema {
+                satz_id: 2,
+                interval: 10,
+                ease_factor: 2.5,
+                repetitions: 3,
+                last_review: "2020-01-01 00:00:00".into(),
+                next_review: "2999-01-01 00:00:00".into(),
+                lapses: 0,
+                schwirig_id: None,
+            }],
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_collect() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+
+        let stats = collect(&conn, None).unwrap();
+        assert_eq!(
+            stats.worte,
+            CardStats {
+                total: 3,
+                neue: 2,
+                due: 1,
+                reviewed_today: 1,
+            }
+        );
+        assert_eq!(
+            stats.setze,
+            CardStats {
+                total: 2,
+                neue: 1,
+                due: 0,
+                reviewed_today: 0,
+            }
+        );
+
+        // Solo el deck "Otro"
+        let otro = DeckRepo::ensure_path(&mut conn, "Otro").unwrap();
+        let stats = collect(&conn, Some(&[otro.id])).unwrap();
+        assert_eq!(stats.worte.total, 1);
+        assert_eq!(stats.worte.due, 0);
+        assert_eq!(stats.setze.total, 0);
+    }
+
+    #[test]
+    fn test_due_ids() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+
+        let due = due_ids(&conn, None).unwrap();
+        assert_eq!(due.worte, vec![1]);
+        assert!(due.setze.is_empty());
+    }
+}
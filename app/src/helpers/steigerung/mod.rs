@@ -0,0 +1,263 @@
+use std::collections::HashMap;
+
+use color_eyre::eyre::Result;
+use rand::seq::IndexedRandom;
+use rusqlite::Connection;
+
+use crate::{
+    db::{schemas::worte::WorteSchema, worte::WorteRepo},
+    helpers::{
+        deck,
+        fehler::{Art, Quelle},
+        session::SessionCard,
+    },
+};
+
+#[cfg(test)]
+mod steigerung_test;
+
+/// Adjetivos irregulares: (positivo, komparativ, superlativ).
+const IRREGULAR: [(&str, &str, &str); 7] = [
+    ("gut", "besser", "am besten"),
+    ("viel", "mehr", "am meisten"),
+    ("gern", "lieber", "am liebsten"),
+    ("bald", "eher", "am ehesten"),
+    ("hoch", "höher", "am höchsten"),
+    ("nah", "näher", "am nächsten"),
+    ("groß", "größer", "am größten"),
+];
+
+/// Adjetivos de una sílaba que llevan umlaut: (positivo, raíz con umlaut).
+const UMLAUT: [(&str, &str); 20] = [
+    ("alt", "ält"),
+    ("arm", "ärm"),
+    ("dumm", "dümm"),
+    ("gesund", "gesünd"),
+    ("grob", "gröb"),
+    ("hart", "härt"),
+    ("jung", "jüng"),
+    ("kalt", "kält"),
+    ("klug", "klüg"),
+    ("krank", "kränk"),
+    ("kurz", "kürz"),
+    ("lang", "läng"),
+    ("nass", "näss"),
+    ("oft", "öft"),
+    ("scharf", "schärf"),
+    ("schwach", "schwäch"),
+    ("schwarz", "schwärz"),
+    ("stark", "stärk"),
+    ("warm", "wärm"),
+    ("rot", "röt"),
+];
+
+/// Terminaciones con las que el superlativo lleva "e": "am ältesten", "am kürzesten".
+const SUPERLATIV_E: [&str; 9] = ["d", "t", "s", "ß", "x", "z", "sch", "au", "eu"];
+
+/// Grado de comparación que se pregunta en el drill.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Grad {
+    Komparativ,
+    Superlativ,
+}
+
+impl Grad {
+    pub const ALL: [Grad; 2] = [Grad::Komparativ, Grad::Superlativ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Grad::Komparativ => "Komparativ",
+            Grad::Superlativ => "Superlativ",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Steigerung {
+    /// "älter"
+    pub komparativ: String,
+    /// "am ältesten"
+    pub superlativ: String,
+}
+
+impl Steigerung {
+    pub fn form(&self, grad: Grad) -> &str {
+        match grad {
+            Grad::Komparativ => &self.komparativ,
+            Grad::Superlativ => &self.superlativ,
+        }
+    }
+}
+
+/// Komparativ y Superlativ generados con las reglas regulares y la lista de irregulares.
+///
+/// - Irregulares: "gut" -> "besser", "am besten".
+/// - Umlaut en algunos de una sílaba: "alt" -> "älter", "am ältesten".
+/// - "-el" y "-er" tras diptongo pierden la "e" en el komparativ: "dunkler", "teurer".
+/// - El superlativo lleva "e" tras d, t, s, ß, x, z, sch o diptongo: "am kürzesten".
+pub fn steigern(adjektiv: &str) -> Steigerung {
+    let adjektiv = adjektiv.trim();
+    if let Some((_, komparativ, superlativ)) = IRREGULAR.iter().find(|(a, _, _)| *a == adjektiv) {
+        return Steigerung {
+            komparativ: komparativ.to_string(),
+            superlativ: superlativ.to_string(),
+        };
+    }
+
+    let stamm = UMLAUT
+        .iter()
+        .find(|(a, _)| *a == adjektiv)
+        .map_or(adjektiv, |(_, u)| u);
+
+    let komparativ = if let Some(base) = stamm.strip_suffix("el") {
+        format!("{}ler", base)
+    } else if let Some(base) = stamm
+        .strip_suffix("er")
+        .filter(|b| b.ends_with("au") || b.ends_with("eu"))
+    {
+        format!("{}rer", base)
+    } else if stamm.ends_with('e') {
+        format!("{}r", stamm)
+    } else {
+        format!("{}er", stamm)
+    };
+
+    // Los participios ("spannend") y los de varias sílabas en "-isch" ("typisch") no llevan
+    // "e"; los de una sílaba sí ("frisch" -> "am frischesten")
+    let lleva_e = SUPERLATIV_E.iter().any(|e| stamm.ends_with(e))
+        && !stamm.ends_with("end")
+        && !(stamm.ends_with("isch") && stamm.chars().count() > 6);
+    let superlativ = if stamm.ends_with('e') {
+        format!("am {}sten", stamm)
+    } else if lleva_e {
+        format!("am {}esten", stamm)
+    } else {
+        format!("am {}sten", stamm)
+    };
+
+    Steigerung {
+        komparativ,
+        superlativ,
+    }
+}
+
+/// Formas del adjetivo: las guardadas en la palabra y, si no hay, las generadas.
+pub fn steigerung(wort: &WorteSchema) -> Steigerung {
+    let generated = steigern(&wort.worte_de);
+    let stored = |v: &Option<String>| {
+        v.as_deref()
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .map(str::to_string)
+    };
+
+    Steigerung {
+        komparativ: stored(&wort.komparativ).unwrap_or(generated.komparativ),
+        superlativ: stored(&wort.superlativ).unwrap_or(generated.superlativ),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SteigerungKey {
+    pub wort_id: i32,
+    pub grad: Grad,
+}
+
+#[derive(Debug, Clone)]
+pub struct SteigerungCard {
+    pub key: SteigerungKey,
+    pub wort: WorteSchema,
+    pub steigerung: Steigerung,
+}
+
+impl SessionCard for SteigerungCard {
+    type Key = SteigerungKey;
+
+    fn key(&self) -> SteigerungKey {
+        self.key
+    }
+
+    fn expected(&self) -> String {
+        self.steigerung.form(self.key.grad).to_string()
+    }
+
+    fn normalize(&self, input: &str) -> String {
+        input
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase()
+    }
+
+    /// En el superlativo el "am" es opcional: "besten" vale como "am besten".
+    fn parse_input(&self, input: &str) -> String {
+        let input = input.trim();
+        match self.key.grad {
+            Grad::Superlativ if !input.to_lowercase().starts_with("am ") => {
+                format!("am {}", input)
+            }
+            _ => input.to_string(),
+        }
+    }
+
+    fn quelle(&self) -> Quelle {
+        Quelle::wort(Art::Steigerung, self.key.wort_id).with_detail(self.key.grad.label())
+    }
+
+    fn fetch(conn: &Connection, keys: &[SteigerungKey]) -> Result<Vec<Self>> {
+        let mut ids: Vec<i32> = keys.iter().map(|k| k.wort_id).collect();
+        ids.sort();
+        ids.dedup();
+
+        let worte: HashMap<i32, WorteSchema> = WorteRepo::fetch_by_id(conn, &ids)?
+            .into_iter()
+            .map(|w| (w.id, w))
+            .collect();
+
+        Ok(keys
+            .iter()
+            .filter_map(|key| {
+                let wort = worte.get(&key.wort_id)?;
+                Some(SteigerungCard {
+                    key: *key,
+                    wort: wort.clone(),
+                    steigerung: steigerung(wort),
+                })
+            })
+            .collect())
+    }
+}
+
+/// Hasta `count` preguntas al azar con los adjetivos de `decks`, cada una con un grado.
+pub fn build_drill(
+    conn: &Connection,
+    decks: Option<&[i32]>,
+    count: usize,
+) -> Result<Vec<SteigerungKey>> {
+    let ids = deck::filter_worte(
+        conn,
+        WorteRepo::fetch_id_by_gram_code(conn, "adjective")?,
+        decks,
+    )?;
+    if ids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let mut rng = rand::rng();
+    let mut out: Vec<SteigerungKey> = vec![];
+    // Con pocos adjetivos las combinaciones se acaban; se limita el número de intentos
+    for _ in 0..count * 10 {
+        if out.len() >= count {
+            break;
+        }
+        let key = SteigerungKey {
+            wort_id: *ids.choose(&mut rng).unwrap(),
+            grad: *Grad::ALL.choose(&mut rng).unwrap(),
+        };
+        if !out.contains(&key) {
+            out.push(key);
+        }
+    }
+
+    Ok(out)
+}
@@ -0,0 +1,127 @@
+use crate::db::setup_test_db;
+
+#[cfg(test)]
+mod test_steigerung {
+    use std::fs;
+
+    use rusqlite::Connection;
+
+    use crate::{
+        db::{seeders::init_data, worte::WorteRepo},
+        helpers::{
+            csv,
+            session::SessionCard,
+            steigerung::{Grad, SteigerungCard, SteigerungKey, build_drill, steigern, steigerung},
+        },
+    };
+
+    use super::*;
+
+    /// "rosa" guarda sus formas en el CSV; "schnell" las genera.
+    const WORTE_CSV: &str = "\
+gram_type,gender_id,worte_de,worte_es,plural,niveau_id,example_de,example_es,verb_aux,trennbar,reflexiv,komparativ,superlativ
+adjective,,schnell,rápido,,A1,Das Auto ist schnell.,El coche es rápido.,,,,,
+adjective,,rosa,rosa,,A2,Das Kleid ist rosa.,El vestido es rosa.,,,,rosaner,am rosansten
+noun_common,Maskuline,Hund,el perro,Hunde,A1,Der Hund spielt.,El perro juega.,,,,,
+";
+
+    fn init_data_local(conn: &mut Connection) {
+        init_data(conn).unwrap();
+
+        let dir = std::env::temp_dir().join(format!("anki_steigerung_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let worte = dir.join("worte.csv");
+        fs::write(&worte, WORTE_CSV).unwrap();
+        csv::is_csv_valid(worte.to_str().unwrap(), csv::CsvType::Worte).unwrap();
+        let data = csv::extract_worte_csv(worte.to_str().unwrap(), 1).unwrap();
+        WorteRepo::bulk_insert(conn, &data).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    fn forms(adjektiv: &str) -> (String, String) {
+        let s = steigern(adjektiv);
+        (s.komparativ, s.superlativ)
+    }
+
+    #[test]
+    fn test_steigern() {
+        let cases = [
+            ("schnell", "schneller", "am schnellsten"),
+            ("klein", "kleiner", "am kleinsten"),
+            ("leise", "leiser", "am leisesten"),
+            ("müde", "müder", "am müdesten"),
+            ("interessant", "interessanter", "am interessantesten"),
+            ("heiß", "heißer", "am heißesten"),
+            ("neu", "neuer", "am neuesten"),
+            ("frisch", "frischer", "am frischesten"),
+            ("typisch", "typischer", "am typischsten"),
+            ("spannend", "spannender", "am spannendsten"),
+            // "-el" y "-er" tras diptongo
+            ("dunkel", "dunkler", "am dunkelsten"),
+            ("teuer", "teurer", "am teuersten"),
+            ("sicher", "sicherer", "am sichersten"),
+            // Umlaut
+            ("alt", "älter", "am ältesten"),
+            ("jung", "jünger", "am jüngsten"),
+            ("kurz", "kürzer", "am kürzesten"),
+            ("gesund", "gesünder", "am gesündesten"),
+            // Irregulares
+            ("gut", "besser", "am besten"),
+            ("viel", "mehr", "am meisten"),
+            ("hoch", "höher", "am höchsten"),
+            ("groß", "größer", "am größten"),
+        ];
+        for (adjektiv, komparativ, superlativ) in cases {
+            assert_eq!(
+                forms(adjektiv),
+                (komparativ.to_string(), superlativ.to_string()),
+                "{}",
+                adjektiv
+            );
+        }
+    }
+
+    #[test]
+    fn test_stored_forms() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+
+        let worte = WorteRepo::fetch_by_id(&conn, &[1, 2]).unwrap();
+        assert_eq!(worte[0].komparativ, None);
+        assert_eq!(worte[1].komparativ.as_deref(), Some("rosaner"));
+
+        // Las formas guardadas reemplazan a las generadas
+        assert_eq!(steigerung(&worte[0]).komparativ, "schneller");
+        assert_eq!(steigerung(&worte[1]).komparativ, "rosaner");
+        assert_eq!(steigerung(&worte[1]).superlativ, "am rosansten");
+    }
+
+    #[test]
+    fn test_card_and_drill() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+
+        let card = SteigerungCard::fetch(
+            &conn,
+            &[SteigerungKey {
+                wort_id: 1,
+                grad: Grad::Superlativ,
+            }],
+        )
+        .unwrap()
+        .remove(0);
+        assert_eq!(card.expected(), "am schnellsten");
+        // El "am" es opcional
+        let answer = |input: &str| card.normalize(&card.parse_input(input));
+        assert_eq!(answer("schnellsten"), card.normalize(&card.expected()));
+        assert_eq!(answer("Am  schnellsten"), card.normalize(&card.expected()));
+
+        // Solo entran los adjetivos y cada combinación una vez
+        let keys = build_drill(&conn, None, 10).unwrap();
+        assert!(!keys.is_empty() && keys.len() <= 4);
+        assert!(keys.iter().all(|k| [1, 2].contains(&k.wort_id)));
+        let mut unique = keys.clone();
+        unique.dedup();
+        assert_eq!(unique.len(), keys.len());
+    }
+}
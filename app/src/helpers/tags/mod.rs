@@ -0,0 +1,196 @@
+use color_eyre::eyre::{Result, bail};
+
+#[cfg(test)]
+mod tags_test;
+
+/// Expresión para filtrar tarjetas por etiquetas.
+///
+/// Ejem: `Genitiv AND (Arbeit OR "Buch Kapitel 3") AND NOT Reisen`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TagExpr {
+    Tag(String),
+    And(Box<TagExpr>, Box<TagExpr>),
+    Or(Box<TagExpr>, Box<TagExpr>),
+    Not(Box<TagExpr>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Tag(String),
+    And,
+    Or,
+    Not,
+    Open,
+    Close,
+}
+
+/// Separa las etiquetas de un texto como "Genitiv, Arbeit, Buch Kapitel 3".
+pub fn parse_tags(input: &str) -> Vec<String> {
+    input
+        .split(',')
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty())
+        .collect()
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>> {
+    let mut tokens = vec![];
+    let mut chars = input.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '(' | ')' | '&' | '|' | '!' => {
+                chars.next();
+                tokens.push(match c {
+                    '(' => Token::Open,
+                    ')' => Token::Close,
+                    '&' => Token::And,
+                    '|' => Token::Or,
+                    _ => Token::Not,
+                });
+            }
+            '"' => {
+                chars.next();
+                let mut tag = String::new();
+                let mut closed = false;
+                for c in chars.by_ref() {
+                    if c == '"' {
+                        closed = true;
+                        break;
+                    }
+                    tag.push(c);
+                }
+                if !closed {
+                    bail!("Falta cerrar las comillas de la etiqueta: \"{}", tag);
+                }
+                if tag.trim().is_empty() {
+                    bail!("Etiqueta vacía entre comillas");
+                }
+                tokens.push(Token::Tag(tag.trim().to_string()));
+            }
+            _ => {
+                let mut word = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c.is_whitespace() || "()&|!\"".contains(c) {
+                        break;
+                    }
+                    word.push(c);
+                    chars.next();
+                }
+
+                tokens.push(match word.to_uppercase().as_str() {
+                    "AND" => Token::And,
+                    "OR" => Token::Or,
+                    "NOT" => Token::Not,
+                    _ => Token::Tag(word),
+                });
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let t = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        t
+    }
+
+    // or := and (OR and)*
+    fn parse_or(&mut self) -> Result<TagExpr> {
+        let mut left = self.parse_and()?;
+        while self.peek() == Some(&Token::Or) {
+            self.next();
+            let right = self.parse_and()?;
+            left = TagExpr::Or(Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    // and := not (AND not)*
+    fn parse_and(&mut self) -> Result<TagExpr> {
+        let mut left = self.parse_not()?;
+        while self.peek() == Some(&Token::And) {
+            self.next();
+            let right = self.parse_not()?;
+            left = TagExpr::And(Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    // not := NOT not | ( or ) | tag
+    fn parse_not(&mut self) -> Result<TagExpr> {
+        match self.next() {
+            Some(Token::Not) => Ok(TagExpr::Not(Box::new(self.parse_not()?))),
+            Some(Token::Open) => {
+                let expr = self.parse_or()?;
+                if self.next() != Some(Token::Close) {
+                    bail!("Falta cerrar un paréntesis");
+                }
+                Ok(expr)
+            }
+            Some(Token::Tag(tag)) => Ok(TagExpr::Tag(tag)),
+            Some(t) => bail!("Se esperaba una etiqueta y se encontró {:?}", t),
+            None => bail!("La expresión está incompleta"),
+        }
+    }
+}
+
+impl TagExpr {
+    /// Interpreta una expresión con AND / OR / NOT (o `&`, `|`, `!`) y paréntesis.
+    /// Las etiquetas con espacios van entre comillas dobles.
+    pub fn parse(input: &str) -> Result<Self> {
+        let tokens = tokenize(input)?;
+        if tokens.is_empty() {
+            bail!("La expresión está vacía");
+        }
+
+        let mut parser = Parser { tokens, pos: 0 };
+        let expr = parser.parse_or()?;
+
+        if let Some(t) = parser.peek() {
+            bail!("Se esperaba AND / OR y se encontró {:?}", t);
+        }
+
+        Ok(expr)
+    }
+
+    /// Convierte la expresión en una condición SQL.
+    ///
+    /// `exists` es la subconsulta que valida una sola etiqueta, con un `?` para su nombre.
+    /// Regresa la condición y los nombres de las etiquetas en el orden de los `?`.
+    pub fn to_sql(&self, exists: &str) -> (String, Vec<String>) {
+        match self {
+            TagExpr::Tag(tag) => (exists.to_string(), vec![tag.clone()]),
+            TagExpr::Not(e) => {
+                let (sql, params) = e.to_sql(exists);
+                (format!("NOT ({})", sql), params)
+            }
+            TagExpr::And(a, b) | TagExpr::Or(a, b) => {
+                let op = if matches!(self, TagExpr::And(..)) {
+                    "AND"
+                } else {
+                    "OR"
+                };
+                let (sql_a, mut params) = a.to_sql(exists);
+                let (sql_b, params_b) = b.to_sql(exists);
+                params.extend(params_b);
+                (format!("({} {} {})", sql_a, op, sql_b), params)
+            }
+        }
+    }
+}
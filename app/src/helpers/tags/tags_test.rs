@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod test_tags {
+    use crate::helpers::tags::{TagExpr, parse_tags};
+
+    fn tag(name: &str) -> Box<TagExpr> {
+        Box::new(TagExpr::Tag(name.into()))
+    }
+
+    #[test]
+    fn parse_tags_splits_and_trims() {
+        assert_eq!(
+            parse_tags(" Genitiv, Arbeit ,, Buch Kapitel 3 "),
+            vec!["Genitiv", "Arbeit", "Buch Kapitel 3"]
+        );
+        assert!(parse_tags("  ").is_empty());
+    }
+
+    #[test]
+    fn parse_respects_precedence() {
+        // NOT > AND > OR
+        let expr = TagExpr::parse("a OR b AND NOT c").unwrap();
+        assert_eq!(
+            expr,
+            TagExpr::Or(
+                tag("a"),
+                Box::new(TagExpr::And(tag("b"), Box::new(TagExpr::Not(tag("c")))))
+            )
+        );
+
+        let expr = TagExpr::parse("(a | b) & !c").unwrap();
+        assert_eq!(
+            expr,
+            TagExpr::And(
+                Box::new(TagExpr::Or(tag("a"), tag("b"))),
+                Box::new(TagExpr::Not(tag("c")))
+            )
+        );
+    }
+
+    #[test]
+    fn parse_quoted_tags_and_keywords() {
+        let expr = TagExpr::parse(r#"Genitiv and not "Buch Kapitel 3""#).unwrap();
+        assert_eq!(
+            expr,
+            TagExpr::And(
+                tag("Genitiv"),
+                Box::new(TagExpr::Not(tag("Buch Kapitel 3")))
+            )
+        );
+    }
+
+    #[test]
+    fn parse_rejects_invalid_expressions() {
+        for input in [
+            "",
+            "a AND",
+            "(a OR b",
+            "a b",
+            "AND a",
+            r#""""#,
+            "a )",
+            r#""foo"#,
+            r#"a AND "Buch Kapitel"#,
+        ] {
+            assert!(TagExpr::parse(input).is_err(), "debe fallar: {:?}", input);
+        }
+    }
+
+    #[test]
+    fn to_sql_keeps_params_in_order() {
+        let expr = TagExpr::parse("a AND (b OR NOT c)").unwrap();
+        let (sql, params) = expr.to_sql("T(?)");
+
+        assert_eq!(sql, "(T(?) AND (T(?) OR NOT (T(?))))");
+        assert_eq!(params, vec!["a", "b", "c"]);
+    }
+}
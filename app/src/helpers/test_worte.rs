@@ -0,0 +1,67 @@
+//! Palabras de prueba compartidas por los tests de los helpers.
+
+use chrono::Utc;
+
+use crate::db::schemas::{
+    gram_type::GramTypeSchema,
+    niveau_liste::NiveauListeSchema,
+    worte::{NewWorteSchema, WorteSchema},
+};
+
+/// Palabra nueva en el mazo 1, nivel A2, sin plural ni ejemplos.
+/// El resto de campos se ajusta con `..wort(...)`.
+pub fn wort(worte_de: &str, gram_type: i32, gender_id: Option<i32>) -> NewWorteSchema {
+    NewWorteSchema {
+        gram_type: vec![gram_type],
+        tags: vec![],
+        gender_id,
+        worte_de: worte_de.into(),
+        worte_es: "".into(),
+        plural: None,
+        niveau_id: 1,
+        example_de: "".into(),
+        example_es: "".into(),
+        verb_aux: None,
+        trennbar: None,
+        reflexiv: None,
+        komparativ: None,
+        superlativ: None,
+        deck_id: 1,
+    }
+}
+
+/// Palabra ya guardada (id 1, A1) con el tipo gramatical `code`, para los
+/// helpers que trabajan sin base de datos.
+pub fn wort_schema(code: &str, worte_de: &str) -> WorteSchema {
+    WorteSchema {
+        id: 1,
+        gram_type_id: vec![GramTypeSchema {
+            id: 0,
+            code: code.into(),
+            name: code.into(),
+            created_at: Utc::now(),
+            deleted_at: None,
+        }],
+        tags: vec![],
+        gender_id: None,
+        worte_de: worte_de.into(),
+        worte_es: "".into(),
+        plural: None,
+        niveau_id: NiveauListeSchema {
+            id: 0,
+            niveau: "A1".into(),
+            created_at: Utc::now(),
+            deleted_at: None,
+        },
+        deck_id: 1,
+        example_de: "".into(),
+        example_es: "".into(),
+        verb_aux: None,
+        trennbar: None,
+        reflexiv: None,
+        komparativ: None,
+        superlativ: None,
+        created_at: Utc::now(),
+        deleted_at: None,
+    }
+}
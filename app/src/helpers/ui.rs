@@ -41,3 +41,23 @@ pub fn prompt_nonempty(text: &str) -> Result<Option<String>> {
         }
     }
 }
+
+/// Pide un valor mostrando el actual. Enter lo deja igual.
+pub fn prompt_value<T>(label: &str, current: T) -> Result<T>
+where
+    T: std::str::FromStr + std::fmt::Display,
+{
+    loop {
+        let Some(input) = prompt(&format!("{} [{}]: ", label, current))? else {
+            return Ok(current);
+        };
+        if input.trim().is_empty() {
+            return Ok(current);
+        }
+
+        match input.trim().parse::<T>() {
+            Ok(v) => return Ok(v),
+            Err(_) => println!("Valor no válido: {}", input),
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use crate::{
+    helpers::{lemma, settings::VergleichSettings},
+    utils,
+};
+
+#[cfg(test)]
+mod vergleich_test;
+
+/// Preposición + artículo contraídos y su forma completa.
+const KONTRAKTIONEN: [(&str, &str); 16] = [
+    ("zum", "zu dem"),
+    ("zur", "zu der"),
+    ("ins", "in das"),
+    ("im", "in dem"),
+    ("am", "an dem"),
+    ("ans", "an das"),
+    ("beim", "bei dem"),
+    ("vom", "von dem"),
+    ("aufs", "auf das"),
+    ("durchs", "durch das"),
+    ("fürs", "für das"),
+    ("ums", "um das"),
+    ("übers", "über das"),
+    ("unters", "unter das"),
+    ("vors", "vor das"),
+    ("hinters", "hinter das"),
+];
+
+const EINER: [&str; 20] = [
+    "null",
+    "eins",
+    "zwei",
+    "drei",
+    "vier",
+    "fünf",
+    "sechs",
+    "sieben",
+    "acht",
+    "neun",
+    "zehn",
+    "elf",
+    "zwölf",
+    "dreizehn",
+    "vierzehn",
+    "fünfzehn",
+    "sechzehn",
+    "siebzehn",
+    "achtzehn",
+    "neunzehn",
+];
+
+const ZEHNER: [&str; 10] = [
+    "", "", "zwanzig", "dreißig", "vierzig", "fünfzig", "sechzig", "siebzig", "achtzig", "neunzig",
+];
+
+/// Signos que separan las cláusulas; el prefijo separable solo se mueve dentro de la suya.
+const KLAUSEL_ENDE: [char; 6] = [',', ';', ':', '.', '!', '?'];
+
+/// Forma con la que se comparan dos oraciones: sin signos, en minúsculas y con las
+/// equivalencias de `opts` aplicadas ("Ich habe 2 Katzen" -> "ich habe zwei katzen").
+/// Los ordinales se quedan en cifra ("zum 2. Mal" -> "zu dem 2 mal").
+pub fn normalize(satz: &str, opts: &VergleichSettings) -> String {
+    tokens(satz, opts).join(" ")
+}
+
+/// `input` es una respuesta válida para la oración `expected`.
+///
+/// Además de `normalize`, acepta el prefijo separable que cierra una cláusula en otra
+/// posición después del verbo: "ich rufe dich an morgen" para "ich rufe dich morgen an".
+pub fn equivalent(expected: &str, input: &str, opts: &VergleichSettings) -> bool {
+    if normalize(expected, opts) == normalize(input, opts) {
+        return true;
+    }
+    if !opts.trennbare_verben {
+        return false;
+    }
+
+    let (expected, input) = (klauseln(expected, opts), klauseln(input, opts));
+    expected.len() == input.len()
+        && expected
+            .iter()
+            .zip(&input)
+            .all(|(e, i)| e == i || prefix_verschoben(e, i) || prefix_verschoben(i, e))
+}
+
+fn tokens(satz: &str, opts: &VergleichSettings) -> Vec<String> {
+    let words: Vec<&str> = satz.split_whitespace().collect();
+    let mut out = vec![];
+    for (i, word) in words.iter().enumerate() {
+        if ordinalzahl(word, i + 1 < words.len()) {
+            out.push(word.trim_end_matches('.').to_string());
+            continue;
+        }
+        for token in utils::string::clean_sentences(word).split_whitespace() {
+            let kontraktion = KONTRAKTIONEN.iter().find(|(kurz, _)| *kurz == token);
+            match kontraktion {
+                Some((_, lang)) if opts.kontraktionen => {
+                    out.extend(lang.split(' ').map(String::from))
+                }
+                _ if opts.zahlen => out.push(zahl_token(token)),
+                _ => out.push(token.to_string()),
+            }
+        }
+    }
+    out
+}
+
+/// Cláusulas de la oración; el punto de un ordinal ("zum 2. Mal") no cierra la cláusula.
+fn klauseln(satz: &str, opts: &VergleichSettings) -> Vec<Vec<String>> {
+    let words: Vec<&str> = satz.split_whitespace().collect();
+    let mut out = vec![];
+    let mut klausel = vec![];
+    for (i, word) in words.iter().enumerate() {
+        klausel.push(*word);
+        if word.ends_with(KLAUSEL_ENDE) && !ordinalzahl(word, i + 1 < words.len()) {
+            out.push(tokens(&klausel.join(" "), opts));
+            klausel.clear();
+        }
+    }
+    out.push(tokens(&klausel.join(" "), opts));
+    out.retain(|k| !k.is_empty());
+    out
+}
+
+/// Cifra con punto seguida de otra palabra: un ordinal ("2. Mal"), que se deja en cifra
+/// porque no se lee como el cardinal ("zweiten", no "zwei"). Al final de la oración el
+/// punto es el de la oración.
+fn ordinalzahl(word: &str, followed: bool) -> bool {
+    followed
+        && word
+            .strip_suffix('.')
+            .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// `a` termina en un prefijo separable y `b` es la misma cláusula con el prefijo después
+/// del verbo pero no al final.
+fn prefix_verschoben(a: &[String], b: &[String]) -> bool {
+    let Some((prefix, rest)) = a.split_last() else {
+        return false;
+    };
+    if a.len() != b.len() || !lemma::is_separable_prefix(prefix) {
+        return false;
+    }
+
+    // Las dos primeras posiciones son del sujeto y el verbo
+    (2..b.len() - 1).any(|i| b[i] == *prefix && b[..i].iter().chain(&b[i + 1..]).eq(rest))
+}
+
+/// Cifras a palabra ("21" -> "einundzwanzig") y "einhundert"/"eintausend" a su forma corta.
+fn zahl_token(token: &str) -> String {
+    if token.chars().all(|c| c.is_ascii_digit())
+        && let Some(wort) = token.parse().ok().and_then(zahlwort)
+    {
+        return wort;
+    }
+    if token.contains("hundert") || token.contains("tausend") {
+        return token
+            .replace("einhundert", "hundert")
+            .replace("eintausend", "tausend");
+    }
+    token.to_string()
+}
+
+/// El número escrito en alemán, hasta 999 999.
+fn zahlwort(n: u32) -> Option<String> {
+    match n {
+        0 => Some(EINER[0].to_string()),
+        1..=999 => Some(unter_tausend(n, true)),
+        1_000..=999_999 => {
+            let (tausender, rest) = (n / 1_000, n % 1_000);
+            let tausender = match tausender {
+                1 => String::new(),
+                t => unter_tausend(t, false),
+            };
+            Some(format!("{}tausend{}", tausender, unter_tausend(rest, true)))
+        }
+        _ => None,
+    }
+}
+
+/// `allein`: el número termina ahí, 1 es "eins" (101 -> "hunderteins") y no "ein"
+/// (101 000 -> "hunderteintausend").
+fn unter_tausend(n: u32, allein: bool) -> String {
+    let (hunderter, rest) = ((n / 100) as usize, (n % 100) as usize);
+    let hundert = match hunderter {
+        0 => String::new(),
+        1 => "hundert".to_string(),
+        h => format!("{}hundert", EINER[h]),
+    };
+    let rest = match rest {
+        0 => String::new(),
+        1 if !allein => "ein".to_string(),
+        r @ 1..=19 => EINER[r].to_string(),
+        r => match (r / 10, r % 10) {
+            (z, 0) => ZEHNER[z].to_string(),
+            (z, 1) => format!("einund{}", ZEHNER[z]),
+            (z, e) => format!("{}und{}", EINER[e], ZEHNER[z]),
+        },
+    };
+    hundert + &rest
+}
@@ -0,0 +1,167 @@
+#[cfg(test)]
+mod test_vergleich {
+    use crate::helpers::{
+        settings::VergleichSettings,
+        vergleich::{equivalent, normalize, zahlwort},
+    };
+
+    fn alle() -> VergleichSettings {
+        VergleichSettings::default()
+    }
+
+    fn keine() -> VergleichSettings {
+        VergleichSettings {
+            kontraktionen: false,
+            zahlen: false,
+            trennbare_verben: false,
+        }
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(
+            normalize("  Ich gehe  zum Arzt, ÜBER die Straße! ", &alle()),
+            "ich gehe zu dem arzt über die straße"
+        );
+        assert_eq!(
+            normalize("Ich habe 3 Katzen.", &alle()),
+            "ich habe drei katzen"
+        );
+        assert_eq!(
+            normalize("Ich habe 3 Katzen.", &keine()),
+            "ich habe 3 katzen"
+        );
+        assert_eq!(normalize("zum", &keine()), "zum");
+    }
+
+    #[test]
+    fn test_zahlwort() {
+        let cases = [
+            (0, "null"),
+            (1, "eins"),
+            (16, "sechzehn"),
+            (21, "einundzwanzig"),
+            (30, "dreißig"),
+            (99, "neunundneunzig"),
+            (100, "hundert"),
+            (101, "hunderteins"),
+            (245, "zweihundertfünfundvierzig"),
+            (1000, "tausend"),
+            (2024, "zweitausendvierundzwanzig"),
+            (101_001, "hunderteintausendeins"),
+        ];
+        for (n, wort) in cases {
+            assert_eq!(zahlwort(n).as_deref(), Some(wort), "{}", n);
+        }
+        assert_eq!(zahlwort(1_000_000), None);
+    }
+
+    #[test]
+    fn test_equivalent_kontraktionen_und_zahlen() {
+        let opts = alle();
+        assert!(equivalent(
+            "Wir gehen ins Kino.",
+            "wir gehen in das Kino",
+            &opts
+        ));
+        assert!(equivalent(
+            "Er wohnt zu dem Glück hier",
+            "Er wohnt zum Glück hier",
+            &opts
+        ));
+        assert!(equivalent(
+            "Ich bin 21 Jahre alt.",
+            "ich bin einundzwanzig jahre alt",
+            &opts
+        ));
+        assert!(equivalent(
+            "Das kostet einhundert Euro",
+            "Das kostet 100 Euro",
+            &opts
+        ));
+        assert!(!equivalent(
+            "Wir gehen ins Kino.",
+            "wir gehen in dem Kino",
+            &opts
+        ));
+
+        let opts = VergleichSettings {
+            kontraktionen: false,
+            ..alle()
+        };
+        assert!(!equivalent(
+            "Wir gehen ins Kino.",
+            "wir gehen in das Kino",
+            &opts
+        ));
+        assert!(equivalent(
+            "Ich bin 21 Jahre alt.",
+            "ich bin einundzwanzig jahre alt",
+            &opts
+        ));
+    }
+
+    #[test]
+    fn test_ordinalzahlen() {
+        let opts = alle();
+        assert_eq!(
+            normalize("Ich gehe zum 2. Mal.", &opts),
+            "ich gehe zu dem 2 mal"
+        );
+        // El punto final es el de la oración, no el de un ordinal
+        assert_eq!(normalize("Ich bin 2.", &opts), "ich bin zwei");
+        assert!(!equivalent(
+            "Ich gehe zum 2. Mal",
+            "Ich gehe zum zwei Mal",
+            &opts
+        ));
+        assert!(equivalent(
+            "Am 3. Mai rufe ich dich an.",
+            "am 3. Mai rufe ich an dich",
+            &opts
+        ));
+    }
+
+    #[test]
+    fn test_equivalent_trennbare_verben() {
+        let opts = alle();
+        let expected = "Ich rufe dich morgen an, wenn ich Zeit habe.";
+        assert!(equivalent(
+            expected,
+            "ich rufe dich an morgen, wenn ich zeit habe",
+            &opts
+        ));
+        assert!(equivalent(
+            "Ich rufe dich an morgen.",
+            "Ich rufe dich morgen an.",
+            &opts
+        ));
+        // Antes del verbo, en otra cláusula o con otra palabra de más no cuenta
+        assert!(!equivalent("Er kommt heute an", "Er an kommt heute", &opts));
+        assert!(!equivalent(
+            expected,
+            "ich rufe dich morgen, wenn ich an zeit habe",
+            &opts
+        ));
+        assert!(!equivalent(
+            expected,
+            "ich rufe dich an morgen wenn ich zeit habe",
+            &opts
+        ));
+        assert!(!equivalent(
+            "Er kommt heute mit",
+            "Er kommt mit heute mit",
+            &opts
+        ));
+
+        let opts = VergleichSettings {
+            trennbare_verben: false,
+            ..alle()
+        };
+        assert!(!equivalent(
+            expected,
+            "ich rufe dich an morgen, wenn ich zeit habe",
+            &opts
+        ));
+    }
+}
@@ -0,0 +1,220 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::Utc;
+use color_eyre::eyre::Result;
+use rusqlite::Connection;
+use serde::Serialize;
+
+use crate::{
+    db::{
+        schemas::{verwechslung::NewVerwechslungSchema, worte::WorteSchema},
+        verwechslung::VerwechslungRepo,
+        worte::WorteRepo,
+    },
+    helpers::{
+        deck,
+        fehler::{Art, Quelle},
+        session::SessionCard,
+        time,
+    },
+};
+
+#[cfg(test)]
+mod verwechslung_test;
+
+/// Artículos que se quitan de la respuesta antes de buscarla entre las palabras.
+const ARTIKEL: [&str; 3] = ["der", "die", "das"];
+
+/// Otra palabra guardada que coincide exactamente con `input` ("die Katze" -> "Katze").
+///
+/// No cuentan las palabras con el mismo texto en alemán que `wort` (duplicados en otros decks).
+pub fn find_verwechselt(conn: &Connection, wort: &WorteSchema, input: &str) -> Result<Option<i32>> {
+    let input = input.split_whitespace().collect::<Vec<_>>().join(" ");
+    let ohne_artikel = match input.split_once(' ') {
+        Some((artikel, rest)) if ARTIKEL.contains(&artikel.to_lowercase().as_str()) => rest,
+        _ => &input,
+    };
+    if ohne_artikel.is_empty() || ohne_artikel == wort.worte_de.trim() {
+        return Ok(None);
+    }
+
+    let mut ids = WorteRepo::fetch_id_by_wort_de(conn, ohne_artikel)?;
+    if ohne_artikel != input {
+        ids.extend(WorteRepo::fetch_id_by_wort_de(conn, &input)?);
+    }
+
+    Ok(ids.into_iter().find(|id| *id != wort.id))
+}
+
+/// Guarda como confusión cada respuesta incorrecta (wort_id, input) que es otra palabra.
+///
+/// return: número de confusiones registradas
+pub fn record(conn: &mut Connection, mistakes: &[(i32, String)]) -> Result<usize> {
+    let ids: Vec<i32> = mistakes.iter().map(|(id, _)| *id).collect();
+    let worte: HashMap<i32, WorteSchema> = WorteRepo::fetch_by_id(conn, &ids)?
+        .into_iter()
+        .map(|w| (w.id, w))
+        .collect();
+
+    let last_seen = time::datetime_2_string(Utc::now());
+    let mut data = vec![];
+    for (wort_id, input) in mistakes {
+        let Some(wort) = worte.get(wort_id) else {
+            continue;
+        };
+        if let Some(verwechselt_id) = find_verwechselt(conn, wort, input)? {
+            data.push(NewVerwechslungSchema {
+                wort_id: *wort_id,
+                verwechselt_id,
+                last_seen: last_seen.clone(),
+            });
+        }
+    }
+
+    Ok(VerwechslungRepo::bulk_insert(conn, &data)?.len())
+}
+
+/// Par de palabras confundidas: se preguntó `wort` y se respondió `verwechselt`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Paar {
+    pub wort_id: i32,
+    pub wort_de: String,
+    pub wort_es: String,
+    pub verwechselt_id: i32,
+    pub verwechselt_de: String,
+    pub verwechselt_es: String,
+    pub count: u32,
+}
+
+/// Pares de palabras de `decks` (todas si no se indica), los más frecuentes primero.
+pub fn paare(conn: &Connection, decks: Option<&[i32]>) -> Result<Vec<Paar>> {
+    let rows = VerwechslungRepo::fetch_all(conn)?;
+
+    let mut ids: Vec<i32> = rows
+        .iter()
+        .flat_map(|v| [v.wort_id, v.verwechselt_id])
+        .collect();
+    ids.sort();
+    ids.dedup();
+    let worte: HashMap<i32, WorteSchema> =
+        WorteRepo::fetch_by_id(conn, &deck::filter_worte(conn, ids, decks)?)?
+            .into_iter()
+            .map(|w| (w.id, w))
+            .collect();
+
+    Ok(rows
+        .into_iter()
+        .filter_map(|v| {
+            let wort = worte.get(&v.wort_id)?;
+            let verwechselt = worte.get(&v.verwechselt_id)?;
+            Some(Paar {
+                wort_id: wort.id,
+                wort_de: wort.expected(),
+                wort_es: wort.worte_es.clone(),
+                verwechselt_id: verwechselt.id,
+                verwechselt_de: verwechselt.expected(),
+                verwechselt_es: verwechselt.worte_es.clone(),
+                count: v.count,
+            })
+        })
+        .collect())
+}
+
+/// Tarjeta del drill: `wort_id` se pregunta justo antes o después de `gegen_id`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VerwechslungKey {
+    pub wort_id: i32,
+    pub gegen_id: i32,
+}
+
+#[derive(Debug, Clone)]
+pub struct VerwechslungCard {
+    pub key: VerwechslungKey,
+    pub wort: WorteSchema,
+    /// La palabra con la que se confunde
+    pub gegen: WorteSchema,
+}
+
+impl SessionCard for VerwechslungCard {
+    type Key = VerwechslungKey;
+
+    fn key(&self) -> VerwechslungKey {
+        self.key
+    }
+
+    fn expected(&self) -> String {
+        self.wort.expected()
+    }
+
+    fn normalize(&self, input: &str) -> String {
+        self.wort.normalize(input)
+    }
+
+    fn hints(&self, input: &str) -> Vec<String> {
+        self.wort.hints(input)
+    }
+
+    fn partial_credit(&self, input: &str) -> bool {
+        self.wort.partial_credit(input)
+    }
+
+    fn quelle(&self) -> Quelle {
+        Quelle::wort(Art::Wort, self.wort.id)
+    }
+
+    fn fetch(conn: &Connection, keys: &[VerwechslungKey]) -> Result<Vec<Self>> {
+        let mut ids: Vec<i32> = keys.iter().flat_map(|k| [k.wort_id, k.gegen_id]).collect();
+        ids.sort();
+        ids.dedup();
+
+        let worte: HashMap<i32, WorteSchema> = WorteRepo::fetch_by_id(conn, &ids)?
+            .into_iter()
+            .map(|w| (w.id, w))
+            .collect();
+
+        Ok(keys
+            .iter()
+            .filter_map(|key| {
+                Some(VerwechslungCard {
+                    key: *key,
+                    wort: worte.get(&key.wort_id)?.clone(),
+                    gegen: worte.get(&key.gegen_id)?.clone(),
+                })
+            })
+            .collect())
+    }
+}
+
+/// Los `max_paare` pares más frecuentes de `decks`, cada uno como dos tarjetas seguidas.
+///
+/// "gehen" -> "fahren" y "fahren" -> "gehen" son el mismo par.
+pub fn build_drill(
+    conn: &Connection,
+    decks: Option<&[i32]>,
+    max_paare: usize,
+) -> Result<Vec<VerwechslungKey>> {
+    let mut seen: HashSet<(i32, i32)> = HashSet::new();
+    let mut keys = vec![];
+    for p in paare(conn, decks)? {
+        if seen.len() >= max_paare {
+            break;
+        }
+        let pair = (
+            p.wort_id.min(p.verwechselt_id),
+            p.wort_id.max(p.verwechselt_id),
+        );
+        if !seen.insert(pair) {
+            continue;
+        }
+        keys.push(VerwechslungKey {
+            wort_id: p.wort_id,
+            gegen_id: p.verwechselt_id,
+        });
+        keys.push(VerwechslungKey {
+            wort_id: p.verwechselt_id,
+            gegen_id: p.wort_id,
+        });
+    }
+
+    Ok(keys)
+}
@@ -0,0 +1,123 @@
+use crate::db::setup_test_db;
+
+#[cfg(test)]
+mod test_verwechslung {
+    use rusqlite::Connection;
+
+    use crate::{
+        db::{schemas::worte::NewWorteSchema, seeders::init_data, worte::WorteRepo},
+        helpers::{
+            fehler,
+            session::SessionCard,
+            test_worte,
+            verwechslung::{VerwechslungCard, build_drill, find_verwechselt, paare, record},
+        },
+    };
+
+    use super::*;
+
+    fn wort(worte_de: &str, worte_es: &str, gender_id: Option<i32>) -> NewWorteSchema {
+        let gram_type = if gender_id.is_some() { 0 } else { 2 };
+        NewWorteSchema {
+            worte_es: worte_es.into(),
+            ..test_worte::wort(worte_de, gram_type, gender_id)
+        }
+    }
+
+    /// 1 gehen, 2 fahren, 3 legen, 4 liegen, 5 Hund, 6 Katze
+    fn init_data_local(conn: &mut Connection) {
+        init_data(conn).unwrap();
+        WorteRepo::bulk_insert(
+            conn,
+            &[
+                wort("gehen", "ir", None),
+                wort("fahren", "ir en vehículo", None),
+                wort("legen", "poner (acostado)", None),
+                wort("liegen", "estar acostado", None),
+                wort("Hund", "el perro", Some(0)),
+                wort("Katze", "el gato", Some(1)),
+            ],
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_find_verwechselt() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+        let worte = WorteRepo::fetch_by_id(&conn, &[1, 5]).unwrap();
+
+        let find = |wort, input| find_verwechselt(&conn, wort, input).unwrap();
+        assert_eq!(find(&worte[0], "fahren"), Some(2));
+        assert_eq!(find(&worte[0], " fahren "), Some(2));
+        // Con o sin artículo
+        assert_eq!(find(&worte[1], "die Katze"), Some(6));
+        assert_eq!(find(&worte[1], "Katze"), Some(6));
+        // La misma palabra con otro artículo no es una confusión
+        assert_eq!(find(&worte[1], "die Hund"), None);
+        assert_eq!(find(&worte[0], "gehten"), None);
+        // Tiene que coincidir exactamente
+        assert_eq!(find(&worte[0], "fahre"), None);
+    }
+
+    #[test]
+    fn test_record_from_session_mistakes() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+
+        let worte = WorteRepo::fetch_by_id(&conn, &[1, 4]).unwrap();
+        fehler::save(
+            &mut conn,
+            vec![
+                (worte[0].clone(), "fahren".to_string()),
+                (worte[0].clone(), "fahren".to_string()),
+                (worte[1].clone(), "legen".to_string()),
+                (worte[1].clone(), "liegten".to_string()),
+            ],
+        )
+        .unwrap();
+
+        let list = paare(&conn, None).unwrap();
+        let got: Vec<(i32, i32, u32)> = list
+            .iter()
+            .map(|p| (p.wort_id, p.verwechselt_id, p.count))
+            .collect();
+        assert_eq!(got, vec![(1, 2, 2), (4, 3, 1)]);
+        assert_eq!(list[0].wort_es, "ir");
+        assert_eq!(list[0].verwechselt_de, "fahren");
+
+        // Sin palabras en el deck no hay pares
+        assert!(paare(&conn, Some(&[999])).unwrap().is_empty());
+        assert_eq!(record(&mut conn, &[]).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_build_drill() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+
+        record(
+            &mut conn,
+            &[
+                (1, "fahren".to_string()),
+                (1, "fahren".to_string()),
+                (2, "gehen".to_string()),
+                (4, "legen".to_string()),
+            ],
+        )
+        .unwrap();
+
+        // Cada par una vez, sus dos palabras seguidas
+        let keys = build_drill(&conn, None, 10).unwrap();
+        let got: Vec<(i32, i32)> = keys.iter().map(|k| (k.wort_id, k.gegen_id)).collect();
+        assert_eq!(got, vec![(1, 2), (2, 1), (4, 3), (3, 4)]);
+        assert_eq!(build_drill(&conn, None, 1).unwrap().len(), 2);
+
+        // Las tarjetas conservan el orden de las llaves
+        let cards = VerwechslungCard::fetch(&conn, &keys).unwrap();
+        let ids: Vec<i32> = cards.iter().map(|c| c.wort.id).collect();
+        assert_eq!(ids, vec![1, 2, 4, 3]);
+        assert_eq!(cards[0].expected(), "gehen");
+        assert_eq!(cards[0].gegen.worte_es, "ir en vehículo");
+    }
+}
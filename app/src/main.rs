@@ -1,22 +1,96 @@
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{Report, Result};
 
-use crate::helpers::audios::ManageAudios;
+use crate::{
+    cli::Cli,
+    helpers::{
+        profile::{self, AppPaths, ProfileArgs},
+        settings::{self, Settings},
+    },
+};
 
+mod cli;
 mod console;
 mod db;
 mod helpers;
 mod services;
 mod traits;
+mod tui;
 mod utils;
 
 fn main() -> Result<()> {
     color_eyre::install().unwrap();
-    run()
+    let code = run()?;
+    if code != cli::EXIT_OK {
+        std::process::exit(code);
+    }
+    Ok(())
 }
 
-fn run() -> Result<()> {
-    db::init_db()?;
-    ManageAudios::init_dir()?;
-    console::menu_main()?;
-    Ok(())
+/// Regresa el código de salida del comando; el menú interactivo siempre regresa 0.
+fn run() -> Result<i32> {
+    let args = ProfileArgs::parse(std::env::args().skip(1))?;
+    if args.help {
+        println!("{}{}", profile::TEXT_HELP, cli::TEXT_HELP_COMMANDS);
+        return Ok(cli::EXIT_OK);
+    }
+    let command = match Cli::parse(&args.rest) {
+        Ok(c) => c,
+        Err(err) => {
+            eprintln!("{}. Usa --help para ver los comandos", err);
+            return Ok(cli::EXIT_USAGE);
+        }
+    };
+
+    // Con --json los errores al abrir el perfil también salen como JSON
+    let json = command.as_ref().is_some_and(|c| c.json);
+    let paths = match AppPaths::resolve(&args, |k| std::env::var(k).ok()) {
+        Ok(paths) => paths,
+        Err(err) => return fail(json, err),
+    };
+    if args.list_profiles {
+        for p in paths.list_profiles()? {
+            let active = if p == paths.profile { " (activo)" } else { "" };
+            println!("{}{}", p, active);
+        }
+        return Ok(cli::EXIT_OK);
+    }
+
+    if let Err(err) = open(paths, command.is_none()) {
+        return fail(json, err);
+    }
+
+    match command {
+        Some(command) => Ok(cli::run(&mut db::get_conn(), &command)),
+        None => {
+            console::menu_main()?;
+            Ok(cli::EXIT_OK)
+        }
+    }
+}
+
+/// Prepara la carpeta del perfil, carga la configuración y abre la base de datos.
+fn open(paths: AppPaths, interactive: bool) -> Result<()> {
+    // Los avisos van a stderr para no mezclarse con la salida de los comandos
+    if paths.prepare(&std::env::current_dir()?)? {
+        eprintln!(
+            "Se copiaron los datos de la carpeta actual a: {}",
+            paths.db.display()
+        );
+    }
+    if interactive {
+        println!("Perfil: {} ({})", paths.profile, paths.db.display());
+    }
+
+    settings::set(Settings::load(&paths.config)?);
+    profile::init(paths)?;
+    db::init_db()
+}
+
+/// Error antes de ejecutar el comando: con --json se imprime como el de cualquier comando.
+fn fail(json: bool, err: Report) -> Result<i32> {
+    if !json {
+        return Err(err);
+    }
+    cli::print_error(true, &err);
+    Ok(cli::EXIT_ERROR)
 }
@@ -2,8 +2,7 @@ use color_eyre::eyre::Result;
 use reqwest::blocking::Client;
 use serde::Serialize;
 
-static VOICE_ID_DE_MASC: &str = "g1jpii0iyvtRs8fqXsd1";
-static VOICE_ID_ES_FEME: &str = "zl1Ut8dvwcVSuQSB9XkG";
+use crate::helpers::settings;
 
 #[derive(Serialize)]
 struct ElevenRequest<'a> {
@@ -26,27 +25,39 @@ pub enum LanguageVoice {
     Spanisch,
 }
 
+impl LanguageVoice {
+    /// Etiqueta configurada para guardar los audios generados con esta voz.
+    pub fn label(&self) -> String {
+        let tts = settings::get().tts;
+        match self {
+            LanguageVoice::Deutsch => tts.voice_label_de,
+            LanguageVoice::Spanisch => tts.voice_label_es,
+        }
+    }
+}
+
 pub fn generate_tts(text: &str, voice_choice: LanguageVoice) -> Result<Vec<u8>> {
+    let tts = settings::get().tts;
     let voice = match voice_choice {
-        LanguageVoice::Deutsch => VOICE_ID_DE_MASC,
-        LanguageVoice::Spanisch => VOICE_ID_ES_FEME,
+        LanguageVoice::Deutsch => &tts.voice_id_de,
+        LanguageVoice::Spanisch => &tts.voice_id_es,
     };
 
     let url = format!(
-        "https://api.elevenlabs.io/v1/text-to-speech/{}?output_format=mp3_22050_32",
-        voice
+        "https://api.elevenlabs.io/v1/text-to-speech/{}?output_format={}",
+        voice, tts.output_format
     );
 
     let client = Client::new();
 
     let body = ElevenRequest {
         text,
-        model_id: "eleven_flash_v2_5",
+        model_id: &tts.model_id,
         voice_settings: VoiceSettings {
-            stability: 0.6,
-            similarity_boost: 0.8,
-            style: 0.4,
-            use_speaker_boost: true,
+            stability: tts.stability,
+            similarity_boost: tts.similarity_boost,
+            style: tts.style,
+            use_speaker_boost: tts.use_speaker_boost,
         },
     };
 
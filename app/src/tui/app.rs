@@ -0,0 +1,779 @@
+use std::{collections::HashMap, fs::File};
+
+use color_eyre::eyre::Result;
+use rand::seq::SliceRandom;
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use rusqlite::Connection;
+
+use crate::{
+    db::{
+        deck::DeckRepo,
+        schemas::{
+            setze::SetzeSchema, setze_alternativ::NewSetzeAlternativSchema, worte::WorteSchema,
+            worte_alternativ::NewWorteAlternativSchema,
+        },
+        setze::SetzeRepo,
+        setze_alternativ::SetzeAlternativRepo,
+        worte::WorteRepo,
+        worte_alternativ::WorteAlternativRepo,
+    },
+    helpers::{
+        audios::ManageAudios,
+        console,
+        daily::{self, DailyPlan, StudyCard, StudyKey},
+        deck,
+        diff::{self, DiffPart},
+        fehler, frequenz, niveau,
+        search::{self, Found, SearchFilter},
+        session::{Outcome, Session, SessionCard},
+        settings,
+        stats::{self, Stats},
+    },
+};
+
+/// Sesiones que se abren desde el tablero.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Mode {
+    Daily,
+    WorteDue,
+    WorteNeue,
+    SetzeRandom,
+}
+
+impl Mode {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Daily => "Estudio diario",
+            Self::WorteDue => "Repasar palabras",
+            Self::WorteNeue => "Palabras nuevas",
+            Self::SetzeRandom => "Oraciones aleatorias",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Action {
+    Study(Mode),
+    PickThema,
+    PickDeck,
+    Browse,
+    Quit,
+}
+
+/// Opciones del tablero en el orden en que se muestran; también se eligen con su número.
+pub const DASHBOARD_ITEMS: [(&str, Action); 8] = [
+    ("Estudio diario", Action::Study(Mode::Daily)),
+    ("Repasar palabras", Action::Study(Mode::WorteDue)),
+    ("Palabras nuevas", Action::Study(Mode::WorteNeue)),
+    ("Oraciones aleatorias", Action::Study(Mode::SetzeRandom)),
+    ("Oraciones por tema", Action::PickThema),
+    ("Cambiar de deck", Action::PickDeck),
+    ("Explorar tarjetas", Action::Browse),
+    ("Salir", Action::Quit),
+];
+
+pub enum Screen {
+    Dashboard,
+    Picker(Picker),
+    Session(Box<SessionPane>),
+    Browser(Browser),
+}
+
+/// Estado de la interfaz de pantalla completa. No depende de la terminal: recibe teclas y
+/// la vista (`tui::view`) solo lo dibuja.
+pub struct App {
+    pub screen: Screen,
+    /// Deck con el que se practica, `None` para todos
+    pub deck: Option<i32>,
+    pub deck_name: String,
+    pub stats: Stats,
+    /// Opción seleccionada del tablero
+    pub cursor: usize,
+    /// Aviso que se muestra en el tablero (ejem: resultado de la última sesión)
+    pub status: Option<String>,
+    pub quit: bool,
+}
+
+impl App {
+    pub fn new(conn: &Connection, deck: Option<i32>) -> Result<Self> {
+        let mut app = Self {
+            screen: Screen::Dashboard,
+            deck,
+            deck_name: String::new(),
+            stats: Stats::default(),
+            cursor: 0,
+            status: None,
+            quit: false,
+        };
+        app.refresh(conn)?;
+        Ok(app)
+    }
+
+    /// Vuelve a calcular el nombre del deck y los conteos del tablero.
+    pub fn refresh(&mut self, conn: &Connection) -> Result<()> {
+        let deck = match self.deck {
+            Some(id) => DeckRepo::fetch_by_id(conn, &[id])?.pop(),
+            None => None,
+        };
+        self.deck_name = deck.map(|d| d.name).unwrap_or_else(|| "Todos".into());
+        self.stats = stats::collect(conn, self.scope(conn)?.as_deref())?;
+        Ok(())
+    }
+
+    /// Ids del deck seleccionado y sus subdecks.
+    fn scope(&self, conn: &Connection) -> Result<Option<Vec<i32>>> {
+        match self.deck {
+            Some(id) => Ok(Some(DeckRepo::fetch_subtree_ids(conn, id)?)),
+            None => Ok(None),
+        }
+    }
+
+    pub fn handle_key(&mut self, conn: &mut Connection, key: KeyEvent) -> Result<()> {
+        let screen = std::mem::replace(&mut self.screen, Screen::Dashboard);
+        self.screen = match screen {
+            Screen::Dashboard => self.on_dashboard(conn, key)?,
+            Screen::Picker(picker) => self.on_picker(conn, picker, key)?,
+            Screen::Session(pane) => self.on_session(conn, pane, key)?,
+            Screen::Browser(browser) => on_browser(conn, browser, key)?,
+        };
+        Ok(())
+    }
+
+    /// Audio de la tarjeta actual si aún no se ha reproducido.
+    pub fn take_audio(&mut self) -> Option<File> {
+        let Screen::Session(pane) = &mut self.screen else {
+            return None;
+        };
+        let card = pane.session.current()?;
+        if pane.feedback.is_some() || pane.played == Some(card.key()) {
+            return None;
+        }
+
+        pane.played = Some(card.key());
+        match card {
+            StudyCard::Wort(w) => ManageAudios::get_audio_worte(w.id).ok().flatten(),
+            StudyCard::Satz(s) => ManageAudios::get_audio_setze(s.id).ok().flatten(),
+        }
+    }
+
+    fn on_dashboard(&mut self, conn: &mut Connection, key: KeyEvent) -> Result<Screen> {
+        let action = match key.code {
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.cursor = self.cursor.saturating_sub(1);
+                None
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.cursor = (self.cursor + 1).min(DASHBOARD_ITEMS.len() - 1);
+                None
+            }
+            KeyCode::Enter => Some(DASHBOARD_ITEMS[self.cursor].1),
+            KeyCode::Char(c @ '1'..='9') => {
+                let index = c as usize - '1' as usize;
+                DASHBOARD_ITEMS.get(index).map(|(_, a)| *a)
+            }
+            KeyCode::Char('q') | KeyCode::Esc => Some(Action::Quit),
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                Some(Action::Quit)
+            }
+            _ => None,
+        };
+
+        match action {
+            Some(action) => {
+                self.status = None;
+                self.run_action(conn, action)
+            }
+            None => Ok(Screen::Dashboard),
+        }
+    }
+
+    fn run_action(&mut self, conn: &mut Connection, action: Action) -> Result<Screen> {
+        let scope = self.scope(conn)?;
+        match action {
+            Action::Study(mode) => {
+                let (keys, offset) = self.session_keys(conn, mode, scope.as_deref())?;
+                self.start_session(conn, mode.label(), keys, offset)
+            }
+            Action::PickThema => {
+                let themas = SetzeRepo::fetch_all_themas(conn, scope.as_deref())?;
+                if themas.is_empty() {
+                    self.status = Some("No hay oraciones en este deck.".into());
+                    return Ok(Screen::Dashboard);
+                }
+                Ok(Screen::Picker(Picker::new(
+                    "Selecciona los temas a repasar",
+                    themas,
+                    PickerTarget::Thema,
+                )))
+            }
+            Action::PickDeck => {
+                let decks = DeckRepo::fetch_all(conn)?;
+                let mut names = vec!["Todos".to_string()];
+                let mut ids = vec![None];
+                for d in decks {
+                    names.push(d.name);
+                    ids.push(Some(d.id));
+                }
+
+                let mut picker = Picker::new("Selecciona el deck", names, PickerTarget::Deck(ids));
+                if let PickerTarget::Deck(ids) = &picker.target {
+                    picker.cursor = ids.iter().position(|id| *id == self.deck).unwrap_or(0);
+                }
+                Ok(Screen::Picker(picker))
+            }
+            Action::Browse => Ok(Screen::Browser(Browser::load(conn, scope.as_deref())?)),
+            Action::Quit => {
+                self.quit = true;
+                Ok(Screen::Dashboard)
+            }
+        }
+    }
+
+    /// Llaves de la sesión y el número de tarjetas por bloque.
+    fn session_keys(
+        &self,
+        conn: &Connection,
+        mode: Mode,
+        scope: Option<&[i32]>,
+    ) -> Result<(Vec<StudyKey>, usize)> {
+        let session = settings::get().session;
+        let mut rng = rand::rng();
+
+        let (keys, offset) = match mode {
+            Mode::Daily => {
+                let settings = match self.deck {
+                    Some(id) => DeckRepo::fetch_by_id(conn, &[id])?.pop(),
+                    None => None,
+                }
+                .map(|d| d.settings())
+                .unwrap_or_default();
+
+                let daily = settings::get().daily;
+                let plan = DailyPlan {
+                    worte_ratio: daily.worte_ratio,
+                    setze_ratio: daily.setze_ratio,
+                    ..DailyPlan::default()
+                }
+                .with_deck(&settings, scope.map(|s| s.to_vec()));
+                (daily::build_queue(conn, &plan)?, session.daily)
+            }
+            Mode::WorteDue => {
+                let mut ids = stats::due_ids(conn, scope)?.worte;
+                ids.shuffle(&mut rng);
+                (
+                    ids.into_iter().map(StudyKey::Wort).collect(),
+                    session.worte_review,
+                )
+            }
+            Mode::WorteNeue => {
+                let mut ids =
+                    deck::filter_worte(conn, WorteRepo::fetch_id_neue_worte(conn)?, scope)?;
+                ids.shuffle(&mut rng);
+                let ids = frequenz::order_neue_worte(conn, ids)?;
+                let ids = niveau::unlock_worte(conn, ids, scope)?;
+                (
+                    ids.into_iter().map(StudyKey::Wort).collect(),
+                    session.worte_neue,
+                )
+            }
+            Mode::SetzeRandom => {
+                let mut ids =
+                    deck::filter_setze(conn, SetzeRepo::fetch_all_only_ids(conn)?, scope)?;
+                ids.shuffle(&mut rng);
+                (
+                    ids.into_iter().map(StudyKey::Satz).collect(),
+                    session.setze_random,
+                )
+            }
+        };
+        Ok((keys, offset))
+    }
+
+    fn start_session(
+        &mut self,
+        conn: &Connection,
+        label: &str,
+        keys: Vec<StudyKey>,
+        offset: usize,
+    ) -> Result<Screen> {
+        if keys.is_empty() {
+            self.status = Some("No hay tarjetas por estudiar. :)".into());
+            return Ok(Screen::Dashboard);
+        }
+
+        let total = keys.len();
+        let alternatives = fetch_alternatives(conn, &keys)?;
+        let session = Session::new(conn, keys, offset)?.with_alternatives(alternatives);
+
+        Ok(Screen::Session(Box::new(SessionPane {
+            label: label.to_string(),
+            session,
+            total,
+            input: String::new(),
+            feedback: None,
+            message: None,
+            pending_alternative: None,
+            played: None,
+        })))
+    }
+
+    fn on_session(
+        &mut self,
+        conn: &mut Connection,
+        mut pane: Box<SessionPane>,
+        key: KeyEvent,
+    ) -> Result<Screen> {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+
+        // Respuesta a "¿Guardar como alternativa? (s/n)"
+        if let Some((card_key, antwort)) = pane.pending_alternative.take() {
+            pane.message = None;
+            if key.code == KeyCode::Char('s') {
+                save_alternative(conn, card_key, antwort.clone())?;
+                pane.session.add_alternative(card_key, &antwort);
+                pane.message = Some("Respuesta alternativa guardada.".into());
+            }
+        } else {
+            match key.code {
+                KeyCode::Esc => return self.finish_session(conn, *pane),
+                KeyCode::Char('c') if ctrl => {
+                    self.quit = true;
+                    return self.finish_session(conn, *pane);
+                }
+                KeyCode::Char('z') if ctrl => {
+                    pane.feedback = None;
+                    pane.input.clear();
+                    pane.message = Some(if pane.session.undo() {
+                        "Se deshizo la última respuesta.".into()
+                    } else {
+                        "No hay respuestas por deshacer.".into()
+                    });
+                }
+                KeyCode::Char('o') if ctrl => match pane.session.override_last(conn)? {
+                    Some((card_key, wrong_input)) => {
+                        pane.feedback = None;
+                        pane.input.clear();
+                        pane.message = Some(format!(
+                            "Respuesta marcada como correcta. ¿Guardar \"{}\" como respuesta alternativa? (s/n)",
+                            wrong_input
+                        ));
+                        pane.pending_alternative = Some((card_key, wrong_input));
+                    }
+                    None => {
+                        pane.message = Some("No hay una respuesta incorrecta que corregir.".into())
+                    }
+                },
+                KeyCode::Char(c) if !ctrl => pane.input.push(c),
+                KeyCode::Backspace => {
+                    pane.input.pop();
+                }
+                KeyCode::Enter => pane.submit(conn)?,
+                _ => {}
+            }
+        }
+
+        // Con la pregunta pendiente la sesión espera aunque ya no queden tarjetas
+        let finished = pane.session.current().is_none()
+            && pane.feedback.is_none()
+            && pane.pending_alternative.is_none();
+        if finished {
+            return self.finish_session(conn, *pane);
+        }
+        Ok(Screen::Session(pane))
+    }
+
+    /// Guarda las revisiones y los errores de la sesión y regresa al tablero.
+    fn finish_session(&mut self, conn: &mut Connection, mut pane: SessionPane) -> Result<Screen> {
+        let finished = pane.session.current().is_none();
+        fehler::save(conn, pane.session.take_mistakes())?;
+
+        let mut results_worte: Vec<(i32, u8)> = vec![];
+        let mut results_setze: Vec<(i32, u8)> = vec![];
+        for (key, quality) in pane.session.into_results() {
+            match key {
+                StudyKey::Wort(id) => results_worte.push((id, quality)),
+                StudyKey::Satz(id) => results_setze.push((id, quality)),
+            }
+        }
+        let reviewed = results_worte.len() + results_setze.len();
+
+        console::save_worte_review(conn, results_worte)?;
+        console::save_setze_review(conn, results_setze)?;
+
+        self.status = Some(if finished {
+            format!(
+                "Sesión terminada ({}). Tarjetas calificadas: {}",
+                pane.label, reviewed
+            )
+        } else {
+            format!(
+                "Sesión guardada ({}). Tarjetas calificadas: {}",
+                pane.label, reviewed
+            )
+        });
+        self.refresh(conn)?;
+        Ok(Screen::Dashboard)
+    }
+
+    fn on_picker(
+        &mut self,
+        conn: &mut Connection,
+        mut picker: Picker,
+        key: KeyEvent,
+    ) -> Result<Screen> {
+        match key.code {
+            KeyCode::Esc => return Ok(Screen::Dashboard),
+            KeyCode::Up | KeyCode::Char('k') => picker.cursor = picker.cursor.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => {
+                picker.cursor = (picker.cursor + 1).min(picker.items.len().saturating_sub(1))
+            }
+            KeyCode::PageUp => picker.cursor = picker.cursor.saturating_sub(PAGE),
+            KeyCode::PageDown => {
+                picker.cursor = (picker.cursor + PAGE).min(picker.items.len().saturating_sub(1))
+            }
+            KeyCode::Home => picker.cursor = 0,
+            KeyCode::End => picker.cursor = picker.items.len().saturating_sub(1),
+            KeyCode::Char(' ') if picker.is_multi() => {
+                picker.checked[picker.cursor] = !picker.checked[picker.cursor]
+            }
+            KeyCode::Char('a') if picker.is_multi() => {
+                let all = picker.checked.iter().all(|c| *c);
+                picker.checked.iter_mut().for_each(|c| *c = !all);
+            }
+            KeyCode::Enter => return self.confirm_picker(conn, picker),
+            _ => {}
+        }
+        Ok(Screen::Picker(picker))
+    }
+
+    fn confirm_picker(&mut self, conn: &mut Connection, picker: Picker) -> Result<Screen> {
+        match &picker.target {
+            PickerTarget::Deck(ids) => {
+                self.deck = ids[picker.cursor];
+                deck::set_active_deck(self.deck);
+                self.refresh(conn)?;
+                self.status = Some(format!("Deck activo: {}", self.deck_name));
+                Ok(Screen::Dashboard)
+            }
+            PickerTarget::Thema => {
+                let titles = picker.selected();
+                let ids = SetzeRepo::fetch_id_where_thema(conn, &titles)?;
+                let mut ids = deck::filter_setze(conn, ids, self.scope(conn)?.as_deref())?;
+                ids.shuffle(&mut rand::rng());
+
+                let keys = ids.into_iter().map(StudyKey::Satz).collect();
+                let offset = settings::get().session.setze_thema;
+                self.start_session(conn, "Oraciones por tema", keys, offset)
+            }
+        }
+    }
+}
+
+/// Pantalla de una sesión de estudio sobre el mismo motor (`Session`) que la consola.
+pub struct SessionPane {
+    pub label: String,
+    pub session: Session<StudyCard>,
+    /// Tarjetas con las que empezó la sesión, para la barra de progreso
+    pub total: usize,
+    pub input: String,
+    /// Respuesta incorrecta que se está corrigiendo
+    pub feedback: Option<Feedback>,
+    pub message: Option<String>,
+    pending_alternative: Option<(StudyKey, String)>,
+    played: Option<StudyKey>,
+}
+
+impl SessionPane {
+    pub fn done(&self) -> usize {
+        self.total.saturating_sub(self.session.remaining())
+    }
+
+    /// Califica lo que escribió el usuario. Después de un error hay que escribir la respuesta
+    /// correcta para continuar, como en la consola.
+    fn submit(&mut self, conn: &Connection) -> Result<()> {
+        let input = std::mem::take(&mut self.input);
+        if input.trim().is_empty() {
+            return Ok(());
+        }
+        self.message = None;
+
+        if let Some(feedback) = &mut self.feedback {
+            if self.session.is_correct(&feedback.card, &input) {
+                self.feedback = None;
+            } else {
+                *feedback = Feedback::new(feedback.card.clone(), input);
+            }
+            return Ok(());
+        }
+
+        let Some(card) = self.session.current().cloned() else {
+            return Ok(());
+        };
+        match self.session.answer(conn, &input)? {
+            Outcome::Correct => {}
+            Outcome::Partial => {
+                let mut message = format!("Casi correcta, se acepta: {}", card.expected());
+                for hint in card.hints(&input) {
+                    message.push_str(&format!("\n{}", hint));
+                }
+                self.message = Some(message);
+            }
+            Outcome::Wrong => self.feedback = Some(Feedback::new(card, input)),
+        }
+        Ok(())
+    }
+}
+
+pub struct Feedback {
+    pub card: StudyCard,
+    pub input: String,
+    pub diff: Vec<DiffPart>,
+}
+
+impl Feedback {
+    fn new(card: StudyCard, input: String) -> Self {
+        let diff = diff::diff_words(&card.expected(), input.trim());
+        Self { card, input, diff }
+    }
+}
+
+/// Cuántas filas se mueve el cursor con PageUp/PageDown.
+const PAGE: usize = 10;
+
+pub enum PickerTarget {
+    /// Ids de cada opción, `None` es "Todos"
+    Deck(Vec<Option<i32>>),
+    Thema,
+}
+
+/// Lista que se maneja con el teclado. Los decks se eligen de uno en uno, los temas pueden
+/// marcarse varios con espacio.
+pub struct Picker {
+    pub title: String,
+    pub items: Vec<String>,
+    pub checked: Vec<bool>,
+    pub cursor: usize,
+    pub target: PickerTarget,
+}
+
+impl Picker {
+    pub fn new(title: &str, items: Vec<String>, target: PickerTarget) -> Self {
+        Self {
+            title: title.to_string(),
+            checked: vec![false; items.len()],
+            items,
+            cursor: 0,
+            target,
+        }
+    }
+
+    pub fn is_multi(&self) -> bool {
+        matches!(self.target, PickerTarget::Thema)
+    }
+
+    /// Opciones marcadas; si no se marcó ninguna, la que está bajo el cursor.
+    pub fn selected(&self) -> Vec<String> {
+        let checked: Vec<String> = self
+            .items
+            .iter()
+            .zip(&self.checked)
+            .filter(|(_, c)| **c)
+            .map(|(i, _)| i.clone())
+            .collect();
+
+        if checked.is_empty() {
+            self.items.get(self.cursor).cloned().into_iter().collect()
+        } else {
+            checked
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BrowseKind {
+    Worte,
+    Setze,
+}
+
+/// Tarjetas del deck que cumplen con la búsqueda (texto y filtros `clave:valor`), por páginas.
+pub struct Browser {
+    pub kind: BrowseKind,
+    pub query: String,
+    pub cursor: usize,
+    /// Página actual, empieza en 1
+    pub page: usize,
+    pub pages: usize,
+    pub total: usize,
+    /// Error al leer la búsqueda, se muestra en lugar de los resultados
+    pub error: Option<String>,
+    scope: Option<Vec<i32>>,
+    worte: Vec<Found<WorteSchema>>,
+    setze: Vec<Found<SetzeSchema>>,
+}
+
+impl Browser {
+    pub fn load(conn: &Connection, scope: Option<&[i32]>) -> Result<Self> {
+        let mut browser = Self {
+            kind: BrowseKind::Worte,
+            query: String::new(),
+            cursor: 0,
+            page: 1,
+            pages: 1,
+            total: 0,
+            error: None,
+            scope: scope.map(|s| s.to_vec()),
+            worte: vec![],
+            setze: vec![],
+        };
+        browser.search(conn)?;
+        Ok(browser)
+    }
+
+    /// Vuelve a buscar con la consulta, el tipo y la página actuales.
+    fn search(&mut self, conn: &Connection) -> Result<()> {
+        self.cursor = 0;
+        self.worte.clear();
+        self.setze.clear();
+
+        let filter = SearchFilter::parse(&self.query).map(|mut f| {
+            f.decks = self.scope.clone();
+            f
+        });
+        let result = filter.and_then(|filter| match self.kind {
+            BrowseKind::Worte => {
+                let page = search::search_worte(conn, &filter, self.page, search::PER_PAGE)?;
+                self.worte = page.items;
+                Ok((page.page, page.pages, page.total))
+            }
+            BrowseKind::Setze => {
+                let page = search::search_setze(conn, &filter, self.page, search::PER_PAGE)?;
+                self.setze = page.items;
+                Ok((page.page, page.pages, page.total))
+            }
+        });
+
+        match result {
+            Ok((page, pages, total)) => {
+                (self.page, self.pages, self.total) = (page, pages, total);
+                self.error = None;
+            }
+            Err(err) => {
+                (self.page, self.pages, self.total) = (1, 1, 0);
+                self.error = Some(format!("{:#}", err));
+            }
+        }
+        Ok(())
+    }
+
+    /// Tarjetas de la página actual: (id, alemán, español).
+    pub fn rows(&self) -> Vec<(i32, String, String)> {
+        match self.kind {
+            BrowseKind::Worte => self
+                .worte
+                .iter()
+                .map(|f| (f.card.id, f.card.expected(), f.card.worte_es.clone()))
+                .collect(),
+            BrowseKind::Setze => self
+                .setze
+                .iter()
+                .map(|f| {
+                    let s = &f.card;
+                    (s.id, s.setze_deutsch.clone(), s.setze_spanisch.clone())
+                })
+                .collect(),
+        }
+    }
+
+    /// Campos de la tarjeta bajo el cursor.
+    pub fn details(&self) -> Vec<(&'static str, String)> {
+        match self.kind {
+            BrowseKind::Worte => self.worte.get(self.cursor).map(|f| f.details()),
+            BrowseKind::Setze => self.setze.get(self.cursor).map(|f| f.details()),
+        }
+        .unwrap_or_default()
+    }
+}
+
+fn on_browser(conn: &Connection, mut browser: Browser, key: KeyEvent) -> Result<Screen> {
+    let last = browser.rows().len().saturating_sub(1);
+    match key.code {
+        KeyCode::Esc => return Ok(Screen::Dashboard),
+        KeyCode::Tab => {
+            browser.kind = match browser.kind {
+                BrowseKind::Worte => BrowseKind::Setze,
+                BrowseKind::Setze => BrowseKind::Worte,
+            };
+            browser.page = 1;
+            browser.search(conn)?;
+        }
+        KeyCode::Up => browser.cursor = browser.cursor.saturating_sub(1),
+        KeyCode::Down => browser.cursor = (browser.cursor + 1).min(last),
+        KeyCode::PageUp if browser.page > 1 => {
+            browser.page -= 1;
+            browser.search(conn)?;
+        }
+        KeyCode::PageDown if browser.page < browser.pages => {
+            browser.page += 1;
+            browser.search(conn)?;
+        }
+        KeyCode::Backspace => {
+            browser.query.pop();
+            browser.page = 1;
+            browser.search(conn)?;
+        }
+        KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+            browser.query.push(c);
+            browser.page = 1;
+            browser.search(conn)?;
+        }
+        _ => {}
+    }
+    Ok(Screen::Browser(browser))
+}
+
+fn fetch_alternatives(
+    conn: &Connection,
+    keys: &[StudyKey],
+) -> Result<HashMap<StudyKey, Vec<String>>> {
+    let mut ids_worte = vec![];
+    let mut ids_setze = vec![];
+    for k in keys {
+        match k {
+            StudyKey::Wort(id) => ids_worte.push(*id),
+            StudyKey::Satz(id) => ids_setze.push(*id),
+        }
+    }
+
+    let mut alternatives: HashMap<StudyKey, Vec<String>> = HashMap::new();
+    for alt in WorteAlternativRepo::fetch_by_wort_id(conn, &ids_worte)? {
+        alternatives
+            .entry(StudyKey::Wort(alt.wort_id))
+            .or_default()
+            .push(alt.antwort);
+    }
+    for alt in SetzeAlternativRepo::fetch_by_satz_id(conn, &ids_setze)? {
+        alternatives
+            .entry(StudyKey::Satz(alt.satz_id))
+            .or_default()
+            .push(alt.antwort);
+    }
+    Ok(alternatives)
+}
+
+fn save_alternative(conn: &mut Connection, key: StudyKey, antwort: String) -> Result<()> {
+    match key {
+        StudyKey::Wort(wort_id) => {
+            WorteAlternativRepo::bulk_insert(
+                conn,
+                &[NewWorteAlternativSchema { wort_id, antwort }],
+            )?;
+        }
+        StudyKey::Satz(satz_id) => {
+            SetzeAlternativRepo::bulk_insert(
+                conn,
+                &[NewSetzeAlternativSchema { satz_id, antwort }],
+            )?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use color_eyre::eyre::Result;
+use ratatui::crossterm::event::{self, Event, KeyEventKind};
+use rusqlite::Connection;
+
+use crate::helpers::{audios::audio_player::AudioPlayer, deck};
+
+mod app;
+mod view;
+
+#[cfg(test)]
+mod tui_test;
+
+pub use app::App;
+
+/// Interfaz de pantalla completa (`anki_sentences tui`). Usa el deck activo al abrirse.
+pub fn run(conn: &mut Connection) -> Result<()> {
+    let mut app = App::new(conn, deck::active_deck())?;
+
+    // Restaura la terminal también si algo truena a medio camino
+    let mut terminal = ratatui::init();
+    let result = event_loop(conn, &mut app, &mut terminal);
+    ratatui::restore();
+    result
+}
+
+fn event_loop(
+    conn: &mut Connection,
+    app: &mut App,
+    terminal: &mut ratatui::DefaultTerminal,
+) -> Result<()> {
+    // Las bocinas se abren hasta que hay un audio que reproducir
+    let mut player: Option<AudioPlayer> = None;
+
+    while !app.quit {
+        terminal.draw(|frame| view::draw(frame, app))?;
+
+        if let Some(file) = app.take_audio() {
+            player.get_or_insert_with(AudioPlayer::new).play(file)?;
+        }
+
+        if let Event::Key(key) = event::read()?
+            && key.kind == KeyEventKind::Press
+        {
+            app.handle_key(conn, key)?;
+        }
+    }
+
+    Ok(())
+}
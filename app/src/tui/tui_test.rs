@@ -0,0 +1,316 @@
+use crate::db::setup_test_db;
+
+#[cfg(test)]
+mod test_tui {
+    use std::fs;
+
+    use ratatui::{
+        Terminal,
+        backend::TestBackend,
+        crossterm::event::{KeyCode, KeyEvent, KeyModifiers},
+    };
+    use rusqlite::Connection;
+
+    use crate::{
+        db::{
+            deck::DeckRepo, seeders::init_data, setze::SetzeRepo,
+            setze_alternativ::SetzeAlternativRepo, setze_review::SetzeReviewRepo, worte::WorteRepo,
+            worte_review::WorteReviewRepo,
+        },
+        helpers::{csv, diff::DiffPart, session::SessionCard},
+        tui::{
+            App,
+            app::{BrowseKind, Screen},
+            view,
+        },
+    };
+
+    use super::*;
+
+    const WORTE_CSV: &str = "\
+gram_type,gender_id,worte_de,worte_es,plural,niveau_id,example_de,example_es,verb_aux,trennbar,reflexiv
+noun_common,Maskuline,Hund,el perro,Hunde,A1,Der Hund spielt.,El perro juega.,,,
+verb_main,,gehen,ir,,A1,Ich gehe.,Voy.,sein,No,No
+";
+
+    const SETZE_CSV: &str = "\
+setze_spanisch,setze_deutsch,thema,schwirig_id
+Tengo un perro,Ich habe einen Hund,Akkusativ,0
+Voy a casa,Ich gehe nach Hause,Dativ,1
+";
+
+    fn init_data_local(conn: &mut Connection) {
+        init_data(conn).unwrap();
+
+        let dir = std::env::temp_dir().join(format!("anki_tui_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let worte = dir.join("worte.csv");
+        let setze = dir.join("setze.csv");
+        fs::write(&worte, WORTE_CSV).unwrap();
+        fs::write(&setze, SETZE_CSV).unwrap();
+
+        let deck = DeckRepo::ensure_path(conn, "Goethe A1").unwrap();
+        let data = csv::extract_worte_csv(worte.to_str().unwrap(), deck.id).unwrap();
+        WorteRepo::bulk_insert(conn, &data).unwrap();
+        let data = csv::extract_sentences_csv(setze.to_str().unwrap(), deck.id).unwrap();
+        SetzeRepo::bulk_insert(conn, &data).unwrap();
+
+        // Una palabra más en otro deck
+        let otro = DeckRepo::ensure_path(conn, "Otro").unwrap();
+        let data = csv::extract_worte_csv(worte.to_str().unwrap(), otro.id).unwrap();
+        WorteRepo::bulk_insert(conn, &data[..1]).unwrap();
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    fn press(app: &mut App, conn: &mut Connection, code: KeyCode) {
+        app.handle_key(conn, KeyEvent::from(code)).unwrap();
+    }
+
+    fn ctrl(app: &mut App, conn: &mut Connection, c: char) {
+        let key = KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL);
+        app.handle_key(conn, key).unwrap();
+    }
+
+    fn answer(app: &mut App, conn: &mut Connection, text: &str) {
+        for c in text.chars() {
+            press(app, conn, KeyCode::Char(c));
+        }
+        press(app, conn, KeyCode::Enter);
+    }
+
+    fn render(app: &App) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(70, 22)).unwrap();
+        terminal.draw(|frame| view::draw(frame, app)).unwrap();
+        terminal.backend().to_string()
+    }
+
+    #[test]
+    fn test_dashboard_and_deck_picker() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+
+        let mut app = App::new(&conn, None).unwrap();
+        assert_eq!(app.stats.worte.neue, 3);
+        insta::assert_snapshot!("dashboard", render(&app));
+
+        // Sin palabras por repasar la sesión no se abre
+        press(&mut app, &mut conn, KeyCode::Char('2'));
+        assert!(matches!(app.screen, Screen::Dashboard));
+        assert_eq!(
+            app.status.as_deref(),
+            Some("No hay tarjetas por estudiar. :)")
+        );
+
+        press(&mut app, &mut conn, KeyCode::Char('6'));
+        let Screen::Picker(picker) = &app.screen else {
+            panic!("Debe abrir el selector de decks");
+        };
+        let otro = picker.items.iter().position(|i| i == "Otro").unwrap();
+        for _ in 0..otro {
+            press(&mut app, &mut conn, KeyCode::Down);
+        }
+        press(&mut app, &mut conn, KeyCode::Enter);
+
+        assert_eq!(app.deck_name, "Otro");
+        assert_eq!(app.stats.worte.total, 1);
+        assert_eq!(app.stats.setze.total, 0);
+
+        // Esc regresa sin cambiar el deck
+        press(&mut app, &mut conn, KeyCode::Char('6'));
+        press(&mut app, &mut conn, KeyCode::Up);
+        press(&mut app, &mut conn, KeyCode::Esc);
+        assert_eq!(app.deck_name, "Otro");
+
+        press(&mut app, &mut conn, KeyCode::Char('q'));
+        assert!(app.quit);
+        crate::helpers::deck::set_active_deck(None);
+    }
+
+    #[test]
+    fn test_thema_session() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+        let mut app = App::new(&conn, None).unwrap();
+
+        press(&mut app, &mut conn, KeyCode::Char('5'));
+        insta::assert_snapshot!("thema_picker", render(&app));
+
+        // Marcamos solo "Dativ"
+        press(&mut app, &mut conn, KeyCode::Down);
+        press(&mut app, &mut conn, KeyCode::Char(' '));
+        press(&mut app, &mut conn, KeyCode::Enter);
+        let Screen::Session(pane) = &app.screen else {
+            panic!("Debe abrir la sesión");
+        };
+        assert_eq!(pane.total, 1);
+
+        answer(&mut app, &mut conn, "Ich gehe nach Haus");
+        let Screen::Session(pane) = &app.screen else {
+            panic!("La sesión sigue después de un error");
+        };
+        let feedback = pane.feedback.as_ref().unwrap();
+        assert!(feedback.diff.contains(&DiffPart::Missing("Hause".into())));
+        assert!(feedback.diff.contains(&DiffPart::Extra("Haus".into())));
+        insta::assert_snapshot!("session_wrong", render(&app));
+
+        // La marcamos como correcta y guardamos la alternativa
+        ctrl(&mut app, &mut conn, 'o');
+        assert!(matches!(app.screen, Screen::Session(_)));
+        press(&mut app, &mut conn, KeyCode::Char('s'));
+
+        assert!(matches!(app.screen, Screen::Dashboard));
+        assert_eq!(
+            app.status.as_deref(),
+            Some("Sesión terminada (Oraciones por tema). Tarjetas calificadas: 1")
+        );
+        let alternativ = SetzeAlternativRepo::fetch_by_satz_id(&conn, &[2]).unwrap();
+        assert_eq!(alternativ.len(), 1);
+        assert_eq!(alternativ[0].antwort, "Ich gehe nach Haus");
+        assert_eq!(
+            SetzeReviewRepo::fetch_by_satz_id(&conn, &[2])
+                .unwrap()
+                .len(),
+            1
+        );
+        assert_eq!(app.stats.setze.neue, 1);
+    }
+
+    #[test]
+    fn test_worte_session() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+        let mut app = App::new(&conn, None).unwrap();
+
+        press(&mut app, &mut conn, KeyCode::Char('3'));
+        let Screen::Session(pane) = &app.screen else {
+            panic!("Debe abrir la sesión");
+        };
+        assert_eq!((pane.done(), pane.total), (0, 3));
+
+        answer(&mut app, &mut conn, "falsch");
+        let Screen::Session(pane) = &app.screen else {
+            panic!("La sesión sigue después de un error");
+        };
+        let expected = pane.feedback.as_ref().unwrap().card.expected();
+
+        // Hasta escribir la correcta no se quita el error
+        answer(&mut app, &mut conn, "otra vez");
+        let Screen::Session(pane) = &app.screen else {
+            panic!("La sesión sigue después de un error");
+        };
+        assert_eq!(pane.feedback.as_ref().unwrap().input, "otra vez");
+        answer(&mut app, &mut conn, &expected);
+
+        // Ctrl+Z deshace la última calificación
+        let Screen::Session(pane) = &app.screen else {
+            panic!("La sesión sigue");
+        };
+        assert!(pane.feedback.is_none());
+        let current = pane.session.current().unwrap().expected();
+        answer(&mut app, &mut conn, &current);
+        ctrl(&mut app, &mut conn, 'z');
+        let Screen::Session(pane) = &app.screen else {
+            panic!("La sesión sigue");
+        };
+        assert_eq!(pane.session.current().unwrap().expected(), current);
+
+        while let Screen::Session(pane) = &app.screen {
+            let expected = pane.session.current().unwrap().expected();
+            answer(&mut app, &mut conn, &expected);
+        }
+
+        assert_eq!(app.stats.worte.neue, 0);
+        assert_eq!(app.stats.worte.reviewed_today, 3);
+        assert_eq!(
+            WorteReviewRepo::fetch_by_wort_id(&conn, &[1, 2, 3])
+                .unwrap()
+                .len(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_session_exit_saves_results() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+        let mut app = App::new(&conn, None).unwrap();
+
+        press(&mut app, &mut conn, KeyCode::Char('4'));
+        let Screen::Session(pane) = &app.screen else {
+            panic!("Debe abrir la sesión");
+        };
+        let expected = pane.session.current().unwrap().expected();
+        answer(&mut app, &mut conn, &expected);
+        press(&mut app, &mut conn, KeyCode::Esc);
+
+        assert!(matches!(app.screen, Screen::Dashboard));
+        assert_eq!(
+            app.status.as_deref(),
+            Some("Sesión guardada (Oraciones aleatorias). Tarjetas calificadas: 1")
+        );
+        assert_eq!(app.stats.setze.reviewed_today, 1);
+    }
+
+    #[test]
+    fn test_browser() {
+        let mut conn = setup_test_db().unwrap();
+        init_data_local(&mut conn);
+        let mut app = App::new(&conn, None).unwrap();
+
+        press(&mut app, &mut conn, KeyCode::Char('7'));
+        for c in "perro".chars() {
+            press(&mut app, &mut conn, KeyCode::Char(c));
+        }
+        let Screen::Browser(browser) = &app.screen else {
+            panic!("Debe abrir el explorador");
+        };
+        assert_eq!(browser.rows().len(), 2);
+        assert!(
+            browser
+                .details()
+                .contains(&("Alemán", "der Hund".to_string()))
+        );
+        insta::assert_snapshot!("browser", render(&app));
+
+        press(&mut app, &mut conn, KeyCode::Tab);
+        let Screen::Browser(browser) = &app.screen else {
+            panic!("Debe seguir en el explorador");
+        };
+        assert_eq!(browser.kind, BrowseKind::Setze);
+        assert_eq!(browser.rows().len(), 1);
+        assert!(
+            browser
+                .details()
+                .contains(&("Tema", "Akkusativ".to_string()))
+        );
+        assert!(browser.details().contains(&("Estado", "Nueva".to_string())));
+
+        // Filtros con clave:valor
+        for _ in 0.."perro".len() {
+            press(&mut app, &mut conn, KeyCode::Backspace);
+        }
+        for c in "thema:Dativ".chars() {
+            press(&mut app, &mut conn, KeyCode::Char(c));
+        }
+        let Screen::Browser(browser) = &app.screen else {
+            panic!("Debe seguir en el explorador");
+        };
+        assert_eq!(browser.total, 1);
+        assert_eq!(browser.rows()[0].1, "Ich gehe nach Hause");
+
+        // En palabras el filtro de tema no aplica
+        press(&mut app, &mut conn, KeyCode::Tab);
+        let Screen::Browser(browser) = &app.screen else {
+            panic!("Debe seguir en el explorador");
+        };
+        assert!(browser.rows().is_empty());
+        assert_eq!(
+            browser.error.as_deref(),
+            Some("El filtro thema solo aplica a oraciones")
+        );
+
+        press(&mut app, &mut conn, KeyCode::Esc);
+        assert!(matches!(app.screen, Screen::Dashboard));
+    }
+}
@@ -0,0 +1,355 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style, Stylize},
+    text::{Line, Span},
+    widgets::{
+        Block, Borders, Cell, Gauge, List, ListItem, ListState, Paragraph, Row, Table, Tabs, Wrap,
+    },
+};
+
+use crate::helpers::{daily::StudyCard, diff::DiffPart, session::SessionCard};
+
+use super::app::{
+    App, BrowseKind, Browser, DASHBOARD_ITEMS, Feedback, Picker, Screen, SessionPane,
+};
+
+const HELP_DASHBOARD: &str = "↑/↓ mover · Enter o número elegir · q salir";
+const HELP_SESSION: &str = "Enter calificar · Ctrl+Z deshacer · Ctrl+O era correcta · Esc salir";
+const HELP_PICKER_MULTI: &str =
+    "↑/↓ mover · Espacio marcar · a todos · Enter empezar · Esc regresar";
+const HELP_PICKER: &str = "↑/↓ mover · Enter elegir · Esc regresar";
+const HELP_BROWSER: &str = "Buscar (niveau:A1 due:new...) · Tab tipo · RePág/AvPág · Esc regresar";
+
+pub fn draw(frame: &mut Frame, app: &App) {
+    let [header, body, footer] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(0),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+
+    frame.render_widget(
+        Line::from(vec![
+            Span::from(" anki-sentences ").bold().reversed(),
+            Span::from(format!("  Deck: {}", app.deck_name)),
+        ]),
+        header,
+    );
+
+    let help = match &app.screen {
+        Screen::Dashboard => {
+            draw_dashboard(frame, body, app);
+            HELP_DASHBOARD
+        }
+        Screen::Picker(picker) => {
+            draw_picker(frame, body, picker);
+            if picker.is_multi() {
+                HELP_PICKER_MULTI
+            } else {
+                HELP_PICKER
+            }
+        }
+        Screen::Session(pane) => {
+            draw_session(frame, body, pane);
+            HELP_SESSION
+        }
+        Screen::Browser(browser) => {
+            draw_browser(frame, body, browser);
+            HELP_BROWSER
+        }
+    };
+    frame.render_widget(Line::from(help).dark_gray(), footer);
+}
+
+fn draw_dashboard(frame: &mut Frame, area: Rect, app: &App) {
+    let [stats, menu, status] = Layout::vertical([
+        Constraint::Length(5),
+        Constraint::Min(0),
+        Constraint::Length(1),
+    ])
+    .areas(area);
+
+    let rows = [
+        ("Palabras", &app.stats.worte),
+        ("Oraciones", &app.stats.setze),
+    ]
+    .map(|(name, s)| {
+        Row::new(vec![
+            Cell::from(name),
+            Cell::from(s.total.to_string()),
+            Cell::from(s.neue.to_string()),
+            Cell::from(Span::from(s.due.to_string()).yellow().bold()),
+            Cell::from(s.reviewed_today.to_string()),
+        ])
+    });
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(10),
+            Constraint::Length(7),
+            Constraint::Length(7),
+            Constraint::Length(12),
+            Constraint::Length(7),
+        ],
+    )
+    .header(Row::new(["", "total", "neue", "por repasar", "hoy"]).bold())
+    .block(Block::bordered().title(" Hoy "));
+    frame.render_widget(table, stats);
+
+    let items: Vec<ListItem> = DASHBOARD_ITEMS
+        .iter()
+        .enumerate()
+        .map(|(i, (label, _))| ListItem::new(format!("{}. {}", i + 1, label)))
+        .collect();
+    let mut state = ListState::default().with_selected(Some(app.cursor));
+    frame.render_stateful_widget(
+        List::new(items)
+            .block(Block::bordered().title(" Menú "))
+            .highlight_style(Style::new().reversed())
+            .highlight_symbol("> "),
+        menu,
+        &mut state,
+    );
+
+    if let Some(text) = &app.status {
+        frame.render_widget(Line::from(text.as_str()).green(), status);
+    }
+}
+
+fn draw_picker(frame: &mut Frame, area: Rect, picker: &Picker) {
+    let items: Vec<ListItem> = picker
+        .items
+        .iter()
+        .zip(&picker.checked)
+        .map(|(item, checked)| {
+            if picker.is_multi() {
+                let mark = if *checked { "[x]" } else { "[ ]" };
+                ListItem::new(format!("{} {}", mark, item))
+            } else {
+                ListItem::new(item.as_str())
+            }
+        })
+        .collect();
+
+    let mut state = ListState::default().with_selected(Some(picker.cursor));
+    frame.render_stateful_widget(
+        List::new(items)
+            .block(Block::bordered().title(format!(" {} ", picker.title)))
+            .highlight_style(Style::new().reversed())
+            .highlight_symbol("> "),
+        area,
+        &mut state,
+    );
+}
+
+fn draw_session(frame: &mut Frame, area: Rect, pane: &SessionPane) {
+    let [progress, card, input, feedback] = Layout::vertical([
+        Constraint::Length(3),
+        Constraint::Length(6),
+        Constraint::Length(3),
+        Constraint::Min(0),
+    ])
+    .areas(area);
+
+    let ratio = if pane.total == 0 {
+        0.0
+    } else {
+        pane.done() as f64 / pane.total as f64
+    };
+    frame.render_widget(
+        Gauge::default()
+            .block(Block::bordered().title(format!(" {} ", pane.label)))
+            .gauge_style(Style::new().green())
+            .ratio(ratio.clamp(0.0, 1.0))
+            .label(format!("{}/{}", pane.done(), pane.total)),
+        progress,
+    );
+
+    // Después de un error se sigue mostrando la tarjeta que se está corrigiendo
+    let current = pane
+        .feedback
+        .as_ref()
+        .map(|f| &f.card)
+        .or(pane.session.current());
+    if let Some(current) = current {
+        frame.render_widget(
+            Paragraph::new(card_lines(current))
+                .wrap(Wrap { trim: false })
+                .block(Block::bordered().title(" Traduce ")),
+            card,
+        );
+    }
+
+    frame.render_widget(
+        Paragraph::new(format!("> {}▏", pane.input)).block(
+            Block::bordered()
+                .title(" Respuesta ")
+                .title_bottom(" ß ẞ ä ö ü Ä Ö Ü "),
+        ),
+        input,
+    );
+
+    let mut lines = match &pane.feedback {
+        Some(f) => feedback_lines(f),
+        None => vec![],
+    };
+    if let Some(message) = &pane.message {
+        lines.extend(message.lines().map(|l| Line::from(l).cyan()));
+    }
+    frame.render_widget(
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .block(Block::new().borders(Borders::TOP)),
+        feedback,
+    );
+}
+
+fn card_lines(card: &StudyCard) -> Vec<Line<'_>> {
+    let (label, info, kind, question) = match card {
+        StudyCard::Wort(w) => (
+            "Tipo",
+            w.gram_type_id
+                .iter()
+                .map(|g| g.name.clone())
+                .collect::<Vec<_>>()
+                .join(", "),
+            "Palabra",
+            w.worte_es.as_str(),
+        ),
+        StudyCard::Satz(s) => (
+            "Tema",
+            s.thema.clone(),
+            "Oración",
+            s.setze_spanisch.as_str(),
+        ),
+    };
+
+    vec![
+        Line::from(vec![
+            Span::from(format!("{}: ", label)).dark_gray(),
+            Span::from(info),
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::from(format!("{}: ", kind)).dark_gray(),
+            Span::from(question).bold(),
+        ]),
+    ]
+}
+
+/// Respuesta incorrecta: la correcta y la comparación palabra por palabra. En verde lo que
+/// coincide, en rojo tachado lo que sobra y en amarillo lo que faltó.
+fn feedback_lines(feedback: &Feedback) -> Vec<Line<'_>> {
+    let mut diff: Vec<Span> = vec![Span::from("Tu respuesta: ").dark_gray()];
+    for (i, part) in feedback.diff.iter().enumerate() {
+        if i > 0 {
+            diff.push(Span::from(" "));
+        }
+        diff.push(match part {
+            DiffPart::Equal(w) => Span::from(w.as_str()).green(),
+            DiffPart::Missing(w) => Span::from(w.as_str()).yellow().underlined(),
+            DiffPart::Extra(w) => Span::styled(
+                w.as_str(),
+                Style::new()
+                    .fg(Color::Red)
+                    .add_modifier(Modifier::CROSSED_OUT),
+            ),
+        });
+    }
+
+    let mut lines = vec![
+        Line::from("Respuesta incorrecta").red().bold(),
+        Line::from(vec![
+            Span::from("Correcta: ").dark_gray(),
+            Span::from(feedback.card.expected()).bold(),
+        ]),
+        Line::from(diff),
+    ];
+    for hint in feedback.card.hints(&feedback.input) {
+        lines.push(Line::from(hint).yellow());
+    }
+    if let StudyCard::Wort(w) = &feedback.card {
+        lines.push(Line::from(""));
+        lines.push(Line::from(format!("Ejemplo: {}", w.example_de)));
+        lines.push(Line::from(format!("Traducción: {}", w.example_es)));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from("Escribe la respuesta correcta para continuar.").dark_gray());
+    lines
+}
+
+fn draw_browser(frame: &mut Frame, area: Rect, browser: &Browser) {
+    let [tabs, search, body] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Length(3),
+        Constraint::Min(0),
+    ])
+    .areas(area);
+
+    let selected = match browser.kind {
+        BrowseKind::Worte => 0,
+        BrowseKind::Setze => 1,
+    };
+    frame.render_widget(
+        Tabs::new(["Palabras", "Oraciones"])
+            .select(selected)
+            .highlight_style(Style::new().reversed()),
+        tabs,
+    );
+
+    let rows = browser.rows();
+    let title = format!(
+        " Buscar ({} tarjetas, página {} de {}) ",
+        browser.total, browser.page, browser.pages
+    );
+    frame.render_widget(
+        Paragraph::new(format!("{}▏", browser.query)).block(Block::bordered().title(title)),
+        search,
+    );
+
+    if let Some(error) = &browser.error {
+        frame.render_widget(
+            Paragraph::new(error.as_str())
+                .red()
+                .wrap(Wrap { trim: false })
+                .block(Block::bordered().title(" Búsqueda no válida ")),
+            body,
+        );
+        return;
+    }
+
+    let [list, details] =
+        Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)]).areas(body);
+
+    let items: Vec<ListItem> = rows
+        .iter()
+        .map(|(id, de, es)| ListItem::new(format!("{:>5}  {} - {}", id, de, es)))
+        .collect();
+    let mut state =
+        ListState::default().with_selected((!rows.is_empty()).then_some(browser.cursor));
+    frame.render_stateful_widget(
+        List::new(items)
+            .block(Block::bordered())
+            .highlight_style(Style::new().reversed()),
+        list,
+        &mut state,
+    );
+
+    let lines: Vec<Line> = browser
+        .details()
+        .into_iter()
+        .map(|(label, value)| {
+            Line::from(vec![
+                Span::from(format!("{}: ", label)).dark_gray(),
+                Span::from(value),
+            ])
+        })
+        .collect();
+    frame.render_widget(
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .block(Block::bordered().title(" Detalle ")),
+        details,
+    );
+}
@@ -1,9 +1,18 @@
-use std::process::Command;
+use std::io::stdout;
 
+use ratatui::crossterm::{
+    cursor::MoveTo,
+    execute,
+    terminal::{Clear, ClearType},
+};
+
+/// Limpia la terminal sin abrir un proceso de `clear`/`cls`.
 pub fn clean_screen() {
-    if cfg!(target_os = "windows") {
-        Command::new("cmd").arg("/c").arg("cls").status().unwrap();
-    } else {
-        Command::new("clear").status().unwrap();
-    }
+    execute!(
+        stdout(),
+        Clear(ClearType::All),
+        Clear(ClearType::Purge),
+        MoveTo(0, 0)
+    )
+    .ok();
 }
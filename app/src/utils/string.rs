@@ -1,8 +1,11 @@
+/// Deja solo letras, números y un espacio entre palabras, todo en minúsculas.
 #[inline]
 pub fn clean_sentences(s: &str) -> String {
-    s.trim()
-        .chars()
-        .filter(|c| c.is_alphabetic() || c.is_whitespace())
-        .map(|c| c.to_ascii_lowercase())
-        .collect()
+    s.chars()
+        .filter(|c| c.is_alphanumeric() || c.is_whitespace())
+        .collect::<String>()
+        .to_lowercase()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
 }
@@ -1,6 +1,6 @@
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{parse_macro_input, DeriveInput, Ident, Type};
+use syn::{DeriveInput, Ident, Type, parse_macro_input};
 
 #[proc_macro_derive(SqlModel, attributes(sql))]
 pub fn derive_sql_model(input: TokenStream) -> TokenStream {
@@ -83,13 +83,14 @@ pub fn derive_sql_model(input: TokenStream) -> TokenStream {
             quote! { &self.#f }
         });
 
+        // La coma final hace que un solo campo siga siendo tupla: (&self.name,)
         quote! {
             impl sql_model::SqlNew for #struct_name {
-                type Params<'a> = ( #( #param_types ),* )
+                type Params<'a> = ( #( #param_types, )* )
                 where Self: 'a;
 
                 fn to_params<'a>(&'a self) -> Self::Params<'a> {
-                    ( #( #param_values ),* )
+                    ( #( #param_values, )* )
                 }
             }
         }